@@ -932,6 +932,7 @@ impl UpdateCache for Event {
             ChannelDelete(v) => c.update(v),
             ChannelPinsUpdate(v) => c.update(v),
             ChannelUpdate(v) => c.update(v),
+            CommandPermissionsUpdate(_) => {}
             GatewayHeartbeat(_) => {}
             GatewayHeartbeatAck => {}
             GatewayHello(_) => {}
@@ -968,6 +969,7 @@ impl UpdateCache for Event {
             RoleCreate(v) => c.update(v),
             RoleDelete(v) => c.update(v),
             RoleUpdate(v) => c.update(v),
+            ShardCommandRatelimited(_) => {}
             ShardConnected(_) => {}
             ShardConnecting(_) => {}
             ShardDisconnected(_) => {}
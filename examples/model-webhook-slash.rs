@@ -1,135 +1,22 @@
-use ed25519_dalek::{PublicKey, Verifier, PUBLIC_KEY_LENGTH};
-use hex::FromHex;
-use hyper::{
-    header::CONTENT_TYPE,
-    http::StatusCode,
-    service::{make_service_fn, service_fn},
-    Body, Method, Request, Response, Server,
-};
-use once_cell::sync::Lazy;
-use std::future::Future;
+use hyper::{service::make_service_fn, Server};
+use std::{convert::Infallible, env, sync::Arc};
+use twilight_http::Client;
+use twilight_interaction_server::{HandlerError, InteractionService, Reply};
 use twilight_model::application::{
     callback::{CallbackData, InteractionResponse},
     interaction::Interaction,
 };
 
-type GenericError = Box<dyn std::error::Error + Send + Sync>;
-
-/// Public key given from Discord.
-static PUB_KEY: Lazy<PublicKey> = Lazy::new(|| {
-    PublicKey::from_bytes(&<[u8; PUBLIC_KEY_LENGTH] as FromHex>::from_hex("PUBLIC_KEY").unwrap())
-        .unwrap()
-});
-
-/// Main request handler which will handle checking the signature.
-///
-/// Responses are made by giving a function that takes a Interaction and returns
-/// a InteractionResponse or a error.
-async fn interaction_handler<F>(
-    req: Request<Body>,
-    f: impl Fn(Interaction) -> F,
-) -> Result<Response<Body>, GenericError>
-where
-    F: Future<Output = Result<InteractionResponse, GenericError>>,
-{
-    // Check that the method used is a POST, all other methods are not allowed.
-    if req.method() != Method::POST {
-        return Ok(Response::builder()
-            .status(StatusCode::METHOD_NOT_ALLOWED)
-            .body(Body::empty())?);
-    }
-
-    // Check if the path the request is sent to is the root of the domain.
-    //
-    // This filter is for the purposes of this example. The user may filter by
-    // any path they choose.
-    if req.uri().path() != "/" {
-        return Ok(Response::builder()
-            .status(StatusCode::NOT_FOUND)
-            .body(Body::empty())?);
-    }
-
-    // Extract the timestamp header for use later to check the signature.
-    let timestamp = if let Some(ts) = req.headers().get("x-signature-timestamp") {
-        ts.to_owned()
-    } else {
-        return Ok(Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::empty())?);
-    };
-
-    // Extract the signature to check against.
-    let signature = if let Some(hex_sig) = req
-        .headers()
-        .get("x-signature-ed25519")
-        .and_then(|v| v.to_str().ok())
-    {
-        hex_sig.parse().unwrap()
-    } else {
-        return Ok(Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::empty())?);
-    };
-
-    // Fetch the whole body of the request as that is needed to check the
-    // signature against.
-    let whole_body = hyper::body::to_bytes(req).await?;
-
-    // Check if the signature matches and else return a error response.
-    if PUB_KEY
-        .verify(
-            vec![timestamp.as_bytes(), &whole_body].concat().as_ref(),
-            &signature,
-        )
-        .is_err()
-    {
-        return Ok(Response::builder()
-            .status(StatusCode::UNAUTHORIZED)
-            .body(Body::empty())?);
-    }
-
-    // Deserialize the body into a interaction.
-    let interaction = serde_json::from_slice::<Interaction>(&whole_body)?;
-
-    match interaction {
-        // Return a Pong if a Ping is received.
-        Interaction::Ping(_) => {
-            let response = InteractionResponse::Pong;
-
-            let json = serde_json::to_vec(&response)?;
-
-            Ok(Response::builder()
-                .status(StatusCode::OK)
-                .header(CONTENT_TYPE, "application/json")
-                .body(json.into())?)
-        }
-        // Respond to a slash command.
-        Interaction::ApplicationCommand(_) => {
-            // Run the handler to gain a response.
-            let response = f(interaction).await?;
-
-            // Serialize the response and return it back to Discord.
-            let json = serde_json::to_vec(&response)?;
-
-            Ok(Response::builder()
-                .header(CONTENT_TYPE, "application/json")
-                .status(StatusCode::OK)
-                .body(json.into())?)
-        }
-        // Unhandled interaction types.
-        _ => Ok(Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::empty())?),
-    }
-}
-
 /// Interaction handler that matches on the name of the interaction that
 /// have been dispatched from Discord.
-async fn handler(i: Interaction) -> Result<InteractionResponse, GenericError> {
+///
+/// The interaction service verifies the signatures of requests and answers
+/// pings before interactions are passed to the handler.
+async fn handler(i: Interaction) -> Result<Reply, HandlerError> {
     match &i {
         Interaction::ApplicationCommand(cmd) => match cmd.data.name.as_ref() {
             "vroom" => vroom(i).await,
-            "debug" => debug(i).await,
+            "slow" => Ok(slow()),
             _ => debug(i).await,
         },
         _ => Err("invalid interaction data".into()),
@@ -137,44 +24,57 @@ async fn handler(i: Interaction) -> Result<InteractionResponse, GenericError> {
 }
 
 /// Example of a handler that returns the formatted version of the interaction.
-async fn debug(i: Interaction) -> Result<InteractionResponse, GenericError> {
-    Ok(InteractionResponse::ChannelMessageWithSource(
-        CallbackData {
-            allowed_mentions: None,
-            components: None,
-            flags: None,
-            tts: None,
-            content: Some(format!("```rust\n{:?}\n```", i)),
-            embeds: None,
-        },
-    ))
+async fn debug(i: Interaction) -> Result<Reply, HandlerError> {
+    Ok(
+        InteractionResponse::ChannelMessageWithSource(message(format!("```rust\n{:?}\n```", i)))
+            .into(),
+    )
 }
 
 /// Example of interaction that responds with a message saying "Vroom vroom".
-async fn vroom(_: Interaction) -> Result<InteractionResponse, GenericError> {
-    Ok(InteractionResponse::ChannelMessageWithSource(
-        CallbackData {
-            allowed_mentions: None,
-            components: None,
-            flags: None,
-            tts: None,
-            content: Some("Vroom vroom".to_owned()),
-            embeds: None,
-        },
-    ))
+async fn vroom(_: Interaction) -> Result<Reply, HandlerError> {
+    Ok(InteractionResponse::ChannelMessageWithSource(message("Vroom vroom".to_owned())).into())
+}
+
+/// Example of interaction that defers its response, updating it once done.
+fn slow() -> Reply {
+    Reply::defer(async {
+        tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+
+        Ok(message("That took a while".to_owned()))
+    })
+}
+
+fn message(content: String) -> CallbackData {
+    CallbackData {
+        allowed_mentions: None,
+        components: None,
+        flags: None,
+        tts: None,
+        content: Some(content),
+        embeds: None,
+    }
 }
 
 #[tokio::main]
-async fn main() -> Result<(), GenericError> {
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     // Initialize the tracing subscriber.
     tracing_subscriber::fmt::init();
 
+    // The HTTP client is used to update deferred responses.
+    let http = Arc::new(Client::new(env::var("DISCORD_TOKEN")?));
+
+    // Public key given from Discord.
+    let service = InteractionService::new(http, &env::var("PUBLIC_KEY")?, handler)?;
+
     // Local address to bind the service to.
     let addr = "127.0.0.1:3030".parse().unwrap();
 
-    // Make the interaction handler into a service function.
-    let interaction_service = make_service_fn(|_| async {
-        Ok::<_, GenericError>(service_fn(|req| interaction_handler(req, handler)))
+    // Make the interaction service into a service for each connection.
+    let interaction_service = make_service_fn(move |_| {
+        let service = service.clone();
+
+        async move { Ok::<_, Infallible>(service) }
     });
 
     // Construct the server and serve the interaction service.
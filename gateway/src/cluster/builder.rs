@@ -85,6 +85,20 @@ impl ClusterBuilder {
         Cluster::new_with_config(self.0, self.1 .0).await
     }
 
+    /// Set whether to emit an [`Event::ShardCommandRatelimited`] event when a
+    /// command is delayed by a shard's command ratelimiter.
+    ///
+    /// Refer to the shard's [`ShardBuilder::command_ratelimit_events`] for more
+    /// information.
+    ///
+    /// [`Event::ShardCommandRatelimited`]: crate::Event::ShardCommandRatelimited
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn command_ratelimit_events(mut self, command_ratelimit_events: bool) -> Self {
+        self.1 = self.1.command_ratelimit_events(command_ratelimit_events);
+
+        self
+    }
+
     /// Set the event types to process.
     ///
     /// This is an optimization technique; all events not included in the
@@ -41,7 +41,7 @@ pub struct Events {
 
 impl Events {
     /// Create a new stream of shards' events.
-    pub(super) const fn new(stream: SelectAll<ShardEventsWithId>) -> Self {
+    pub(crate) const fn new(stream: SelectAll<ShardEventsWithId>) -> Self {
         Self { stream }
     }
 }
@@ -65,7 +65,7 @@ pub struct ShardEventsWithId {
 
 impl ShardEventsWithId {
     /// Create a new stream with shard's ID and event stream.
-    pub(super) const fn new(id: u64, stream: ShardEvents) -> Self {
+    pub(crate) const fn new(id: u64, stream: ShardEvents) -> Self {
         Self { id, stream }
     }
 }
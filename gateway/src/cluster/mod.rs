@@ -87,7 +87,7 @@ pub mod scheme;
 
 mod builder;
 mod config;
-mod event;
+pub(crate) mod event;
 mod r#impl;
 
 pub use self::{
@@ -142,6 +142,8 @@ bitflags! {
         const VOICE_STATE_UPDATE = 1 << 43;
         /// Webhook in a guild has been updated.
         const WEBHOOKS_UPDATE = 1 << 44;
+        /// Permissions of an application command have been updated.
+        const COMMAND_PERMISSIONS_UPDATE = 1 << 63;
     }
 }
 
@@ -277,6 +279,7 @@ impl From<EventType> for EventTypeFlags {
             EventType::ChannelDelete => EventTypeFlags::CHANNEL_DELETE,
             EventType::ChannelPinsUpdate => EventTypeFlags::CHANNEL_PINS_UPDATE,
             EventType::ChannelUpdate => EventTypeFlags::CHANNEL_UPDATE,
+            EventType::CommandPermissionsUpdate => EventTypeFlags::COMMAND_PERMISSIONS_UPDATE,
             EventType::GatewayHeartbeat => EventTypeFlags::GATEWAY_HEARTBEAT,
             EventType::GatewayHeartbeatAck => EventTypeFlags::GATEWAY_HEARTBEAT_ACK,
             EventType::GatewayHello => EventTypeFlags::GATEWAY_HELLO,
@@ -313,6 +316,9 @@ impl From<EventType> for EventTypeFlags {
             EventType::RoleCreate => EventTypeFlags::ROLE_CREATE,
             EventType::RoleDelete => EventTypeFlags::ROLE_DELETE,
             EventType::RoleUpdate => EventTypeFlags::ROLE_UPDATE,
+            // Opted in to via `ShardBuilder::command_ratelimit_events` instead
+            // of an event type flag.
+            EventType::ShardCommandRatelimited => EventTypeFlags::empty(),
             EventType::ShardConnected => EventTypeFlags::SHARD_CONNECTED,
             EventType::ShardConnecting => EventTypeFlags::SHARD_CONNECTING,
             EventType::ShardDisconnected => EventTypeFlags::SHARD_DISCONNECTED,
@@ -111,6 +111,13 @@
 //!
 //! This is disabled by default.
 //!
+//! ### Test utilities
+//!
+//! The `test-util` feature provides the `mock` module, a mock gateway server
+//! with scripted behavior for testing shards without a connection to Discord.
+//!
+//! This is disabled by default.
+//!
 //! [`native-tls`]: https://crates.io/crates/native-tls
 //! [`rustls`]: https://crates.io/crates/rustls
 //! [`rustls-native-certs`]: https://crates.io/crates/rustls-native-certs
@@ -155,9 +162,15 @@
 )]
 
 pub mod cluster;
+pub mod member_chunk;
+#[cfg(feature = "test-util")]
+pub mod mock;
+pub mod relay;
+pub mod replay;
 pub mod shard;
 
 mod event;
+mod server;
 
 pub use self::event::EventTypeFlags;
 pub use twilight_model::gateway::Intents;
@@ -0,0 +1,610 @@
+//! Collect the members of a guild requested over a shard.
+//!
+//! Requesting guild members over the gateway via [`RequestGuildMembers`]
+//! results in one or more [`MemberChunk`] events being received, identified by
+//! the nonce of the request. The [`MemberChunkCollector`] sends requests on
+//! your behalf, splitting them to respect Discord's limits, and resolves once
+//! every chunk for the requests has been received or a timeout has elapsed.
+//!
+//! Like [`Standby`], the collector must be provided with the shard's events via
+//! [`MemberChunkCollector::process`].
+//!
+//! # Examples
+//!
+//! Request 250 members of a guild, printing how many were found:
+//!
+//! ```no_run
+//! use futures::StreamExt;
+//! use std::{env, sync::Arc};
+//! use twilight_gateway::{member_chunk::MemberChunkCollector, Intents, Shard};
+//! use twilight_model::id::Id;
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let token = env::var("DISCORD_TOKEN")?;
+//! let (shard, mut events) = Shard::new(token, Intents::GUILD_MEMBERS);
+//! let shard = Arc::new(shard);
+//! shard.start().await?;
+//!
+//! let collector = Arc::new(MemberChunkCollector::new());
+//!
+//! tokio::spawn({
+//!     let collector = Arc::clone(&collector);
+//!
+//!     async move {
+//!         while let Some(event) = events.next().await {
+//!             collector.process(&event);
+//!         }
+//!     }
+//! });
+//!
+//! let user_ids = (1..=250).map(Id::new).collect::<Vec<_>>();
+//! let chunks = collector
+//!     .user_ids(&shard, Id::new(1), user_ids, false)
+//!     .await?;
+//!
+//! println!(
+//!     "found {} members; {} were not found",
+//!     chunks.members().len(),
+//!     chunks.not_found().len(),
+//! );
+//! # Ok(()) }
+//! ```
+//!
+//! [`MemberChunk`]: twilight_model::gateway::payload::incoming::MemberChunk
+//! [`Standby`]: https://docs.rs/twilight-standby
+
+use crate::{
+    shard::{CommandError, Shard},
+    Event,
+};
+use futures_util::future;
+use std::{
+    collections::{BTreeSet, HashMap},
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    mem,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+use tokio::{
+    sync::oneshot::{self, Receiver, Sender},
+    time::{self, Instant},
+};
+use twilight_model::{
+    gateway::{
+        payload::{
+            incoming::MemberChunk,
+            outgoing::request_guild_members::{RequestGuildMembers, RequestGuildMembersBuilder},
+        },
+        presence::Presence,
+    },
+    guild::member::Member,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Default amount of time to wait for all chunks of a request.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Maximum number of user IDs that may be requested in a single request.
+const USER_IDS_PER_REQUEST: usize = 100;
+
+/// Requesting members failed.
+#[derive(Debug)]
+pub struct MemberChunkError {
+    kind: MemberChunkErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl MemberChunkError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &MemberChunkErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (MemberChunkErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for MemberChunkError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            MemberChunkErrorType::Sending => f.write_str("sending a member request failed"),
+        }
+    }
+}
+
+impl Error for MemberChunkError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`MemberChunkError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MemberChunkErrorType {
+    /// Sending a request over the shard failed.
+    Sending,
+}
+
+/// Members, presences, and IDs of users not found, collected from all of the
+/// chunks received for a request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberChunks {
+    complete: bool,
+    guild_id: Id<GuildMarker>,
+    members: Vec<Member>,
+    not_found: Vec<Id<UserMarker>>,
+    presences: Vec<Presence>,
+}
+
+impl MemberChunks {
+    const fn new(guild_id: Id<GuildMarker>) -> Self {
+        Self {
+            complete: true,
+            guild_id,
+            members: Vec::new(),
+            not_found: Vec::new(),
+            presences: Vec::new(),
+        }
+    }
+
+    /// Whether all chunks were received before the timeout elapsed.
+    ///
+    /// If not, then the collected members are only those of the chunks that
+    /// were received.
+    pub const fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// ID of the guild the members are in.
+    pub const fn guild_id(&self) -> Id<GuildMarker> {
+        self.guild_id
+    }
+
+    /// Members that were found.
+    pub fn members(&self) -> &[Member] {
+        &self.members
+    }
+
+    /// IDs of requested users that were not found in the guild.
+    pub fn not_found(&self) -> &[Id<UserMarker>] {
+        &self.not_found
+    }
+
+    /// Presences of the members, if presences were requested.
+    pub fn presences(&self) -> &[Presence] {
+        &self.presences
+    }
+
+    /// Consume the collection, returning the members, presences, and IDs of
+    /// users that were not found.
+    pub fn into_parts(self) -> (Vec<Member>, Vec<Presence>, Vec<Id<UserMarker>>) {
+        (self.members, self.presences, self.not_found)
+    }
+
+    fn extend(&mut self, other: Self) {
+        self.complete &= other.complete;
+        self.members.extend(other.members);
+        self.not_found.extend(other.not_found);
+        self.presences.extend(other.presences);
+    }
+}
+
+/// State of a request with chunks that haven't all been received yet.
+#[derive(Debug)]
+struct Pending {
+    /// Total number of chunks, known once the first chunk is received.
+    chunk_count: Option<u32>,
+    /// Indices of chunks received so far.
+    received: BTreeSet<u32>,
+    chunks: MemberChunks,
+    /// Notifier for when all chunks have been received.
+    tx: Option<Sender<()>>,
+}
+
+impl Pending {
+    fn is_complete(&self) -> bool {
+        self.chunk_count
+            .map_or(false, |count| self.received.len() >= count as usize)
+    }
+}
+
+/// Collector of the member chunks received for guild member requests.
+///
+/// Refer to the [module-level] documentation for more information.
+///
+/// [module-level]: self
+#[derive(Debug)]
+pub struct MemberChunkCollector {
+    /// Counter used to generate unique nonces for requests.
+    nonce: AtomicU64,
+    pending: Mutex<HashMap<String, Pending>>,
+    timeout: Duration,
+}
+
+impl MemberChunkCollector {
+    /// Create a new collector, waiting up to 30 seconds for all chunks of a
+    /// request.
+    pub fn new() -> Self {
+        Self::with_timeout(DEFAULT_TIMEOUT)
+    }
+
+    /// Create a new collector, waiting up to the given amount of time for all
+    /// chunks of a request.
+    ///
+    /// The timeout starts when members are requested, so it includes the time
+    /// spent waiting to send the requests over the shard.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            nonce: AtomicU64::new(0),
+            pending: Mutex::new(HashMap::new()),
+            timeout,
+        }
+    }
+
+    /// Process an event, collecting it if it's a chunk of a pending request.
+    ///
+    /// Returns whether the event was a chunk of a pending request.
+    pub fn process(&self, event: &Event) -> bool {
+        if let Event::MemberChunk(chunk) = event {
+            self.process_chunk(chunk)
+        } else {
+            false
+        }
+    }
+
+    /// Number of requests whose chunks haven't all been received yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock over the pending requests is poisoned.
+    pub fn pending(&self) -> usize {
+        self.pending.lock().expect("pending poisoned").len()
+    }
+
+    /// Request members by their user IDs, waiting for all of their chunks.
+    ///
+    /// Any number of IDs may be provided; they are split into multiple
+    /// requests of up to 100 IDs each.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MemberChunkErrorType::Sending`] error type if sending a
+    /// request over the shard failed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock over the pending requests is poisoned.
+    pub async fn user_ids(
+        &self,
+        shard: &Shard,
+        guild_id: Id<GuildMarker>,
+        user_ids: impl Into<Vec<Id<UserMarker>>>,
+        presences: bool,
+    ) -> Result<MemberChunks, MemberChunkError> {
+        let user_ids = user_ids.into();
+
+        let deadline = Instant::now() + self.timeout;
+
+        let requests = user_ids
+            .chunks(USER_IDS_PER_REQUEST)
+            .map(|ids| {
+                self.request(guild_id, |builder| {
+                    builder
+                        .presences(presences)
+                        .user_ids(ids)
+                        .expect("requests have at most 100 user IDs")
+                })
+            })
+            .collect();
+
+        self.collect(shard, guild_id, requests, deadline).await
+    }
+
+    /// Request members whose usernames start with a query, waiting for all of
+    /// their chunks.
+    ///
+    /// No limit is sent as a limit of 0, which Discord treats as no limit. An
+    /// empty query with no limit requests all members of the guild, which
+    /// requires the [`GUILD_MEMBERS`] intent.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MemberChunkErrorType::Sending`] error type if sending the
+    /// request over the shard failed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock over the pending requests is poisoned.
+    ///
+    /// [`GUILD_MEMBERS`]: crate::Intents::GUILD_MEMBERS
+    pub async fn query(
+        &self,
+        shard: &Shard,
+        guild_id: Id<GuildMarker>,
+        query: impl Into<String>,
+        limit: Option<u64>,
+        presences: bool,
+    ) -> Result<MemberChunks, MemberChunkError> {
+        let deadline = Instant::now() + self.timeout;
+
+        let request = self.request(guild_id, |builder| {
+            builder.presences(presences).query(query, limit)
+        });
+
+        self.collect(shard, guild_id, Vec::from([request]), deadline)
+            .await
+    }
+
+    /// Register a new pending request, returning the request to send and a
+    /// receiver notified once all of its chunks have been received.
+    ///
+    /// The request is built from a builder with the request's nonce.
+    fn request(
+        &self,
+        guild_id: Id<GuildMarker>,
+        build: impl FnOnce(RequestGuildMembersBuilder) -> RequestGuildMembers,
+    ) -> (String, RequestGuildMembers, Receiver<()>) {
+        let id = self.nonce.fetch_add(1, Ordering::Relaxed);
+        let nonce = format!("twilight-{:x}", id);
+        let (tx, rx) = oneshot::channel();
+
+        self.pending.lock().expect("pending poisoned").insert(
+            nonce.clone(),
+            Pending {
+                chunk_count: None,
+                received: BTreeSet::new(),
+                chunks: MemberChunks::new(guild_id),
+                tx: Some(tx),
+            },
+        );
+
+        let request = build(RequestGuildMembers::builder(guild_id).nonce(nonce.clone()));
+
+        (nonce, request, rx)
+    }
+
+    /// Send registered requests and wait for all of their chunks until the
+    /// deadline, merging the results.
+    ///
+    /// Requests that haven't been sent by the deadline aren't sent.
+    async fn collect(
+        &self,
+        shard: &Shard,
+        guild_id: Id<GuildMarker>,
+        requests: Vec<(String, RequestGuildMembers, Receiver<()>)>,
+        deadline: Instant,
+    ) -> Result<MemberChunks, MemberChunkError> {
+        // Remove the pending requests even if this future is dropped before
+        // completing.
+        let mut guard = PendingGuard {
+            collector: self,
+            nonces: Vec::with_capacity(requests.len()),
+        };
+        let mut commands = Vec::with_capacity(requests.len());
+        let mut receivers = Vec::with_capacity(requests.len());
+
+        for (nonce, request, rx) in requests {
+            guard.nonces.push(nonce);
+            commands.push(request);
+            receivers.push(rx);
+        }
+
+        let send = async {
+            for request in &commands {
+                shard.command(request).await?;
+            }
+
+            Ok(())
+        };
+
+        let completed = match time::timeout_at(deadline, send).await {
+            Ok(Ok(())) => time::timeout_at(deadline, future::join_all(receivers))
+                .await
+                .is_ok(),
+            Ok(Err(source)) => {
+                guard.take();
+
+                return Err(Self::sending(source));
+            }
+            Err(_) => false,
+        };
+
+        let mut chunks = MemberChunks::new(guild_id);
+        chunks.complete = completed;
+
+        for pending in guard.take() {
+            chunks.extend(pending.chunks);
+        }
+
+        Ok(chunks)
+    }
+
+    /// Process a member chunk, returning whether it was for a pending request.
+    fn process_chunk(&self, chunk: &MemberChunk) -> bool {
+        let nonce = match chunk.nonce.as_deref() {
+            Some(nonce) => nonce,
+            None => return false,
+        };
+
+        let mut pending_requests = self.pending.lock().expect("pending poisoned");
+
+        let pending = match pending_requests.get_mut(nonce) {
+            Some(pending) => pending,
+            None => return false,
+        };
+
+        if !pending.received.insert(chunk.chunk_index) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(%nonce, index = chunk.chunk_index, "received duplicate member chunk");
+
+            return true;
+        }
+
+        pending.chunk_count = Some(chunk.chunk_count);
+        pending.chunks.members.extend(chunk.members.iter().cloned());
+        pending
+            .chunks
+            .not_found
+            .extend(chunk.not_found.iter().copied());
+        pending
+            .chunks
+            .presences
+            .extend(chunk.presences.iter().cloned());
+
+        if pending.is_complete() {
+            if let Some(tx) = pending.tx.take() {
+                let _res = tx.send(());
+            }
+        }
+
+        true
+    }
+
+    /// Remove the pending requests of the given nonces.
+    fn take(&self, nonces: &[String]) -> Vec<Pending> {
+        let mut pending = self.pending.lock().expect("pending poisoned");
+
+        nonces
+            .iter()
+            .filter_map(|nonce| pending.remove(nonce))
+            .collect()
+    }
+
+    fn sending(source: CommandError) -> MemberChunkError {
+        MemberChunkError {
+            kind: MemberChunkErrorType::Sending,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+/// Guard over the nonces of pending requests, removing them when dropped.
+struct PendingGuard<'a> {
+    collector: &'a MemberChunkCollector,
+    nonces: Vec<String>,
+}
+
+impl PendingGuard<'_> {
+    /// Remove the pending requests, returning them.
+    fn take(&mut self) -> Vec<Pending> {
+        let nonces = mem::take(&mut self.nonces);
+
+        self.collector.take(&nonces)
+    }
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        if self.nonces.is_empty() {
+            return;
+        }
+
+        // Avoid panicking while dropping if the lock is poisoned.
+        if let Ok(mut pending) = self.collector.pending.lock() {
+            for nonce in &self.nonces {
+                pending.remove(nonce);
+            }
+        }
+    }
+}
+
+impl Default for MemberChunkCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        MemberChunkCollector, MemberChunkError, MemberChunkErrorType, MemberChunks, PendingGuard,
+    };
+    use crate::Event;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{gateway::payload::incoming::MemberChunk, id::Id};
+
+    assert_impl_all!(MemberChunkCollector: Debug, Default, Send, Sync);
+    assert_impl_all!(MemberChunkErrorType: Debug, Send, Sync);
+    assert_impl_all!(MemberChunkError: Error, Send, Sync);
+    assert_impl_all!(MemberChunks: Clone, Debug, Send, Sync);
+
+    fn chunk(nonce: &str, chunk_index: u32, chunk_count: u32, not_found: u64) -> Event {
+        Event::MemberChunk(MemberChunk {
+            chunk_count,
+            chunk_index,
+            guild_id: Id::new(1),
+            members: Vec::new(),
+            nonce: Some(nonce.to_owned()),
+            not_found: Vec::from([Id::new(not_found)]),
+            presences: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn test_process_unknown_nonce() {
+        let collector = MemberChunkCollector::new();
+
+        assert!(!collector.process(&chunk("unknown", 0, 1, 2)));
+        assert!(!collector.process(&Event::GatewayReconnect));
+    }
+
+    #[tokio::test]
+    async fn test_process_all_chunks() {
+        let collector = MemberChunkCollector::new();
+        let (nonce, request, mut rx) =
+            collector.request(Id::new(1), |builder| builder.user_id(Id::new(2)));
+        assert_eq!(Some(nonce.as_str()), request.d.nonce.as_deref());
+        assert_eq!(Id::new(1), request.d.guild_id);
+        assert_eq!(1, collector.pending());
+
+        assert!(collector.process(&chunk(&nonce, 1, 2, 3)));
+        assert!(rx.try_recv().is_err());
+
+        // Duplicate chunks are ignored.
+        assert!(collector.process(&chunk(&nonce, 1, 2, 3)));
+        assert!(rx.try_recv().is_err());
+
+        assert!(collector.process(&chunk(&nonce, 0, 2, 4)));
+        assert!(rx.try_recv().is_ok());
+
+        let pending = collector.take(&[nonce]);
+        assert_eq!(0, collector.pending());
+        assert_eq!(1, pending.len());
+        assert_eq!(&[Id::new(3), Id::new(4)], pending[0].chunks.not_found());
+    }
+
+    #[test]
+    fn test_dropped_guard_removes_pending() {
+        let collector = MemberChunkCollector::new();
+        let (nonce, _, _rx) = collector.request(Id::new(1), |builder| builder.query("", None));
+        assert_eq!(1, collector.pending());
+
+        drop(PendingGuard {
+            collector: &collector,
+            nonces: Vec::from([nonce.clone()]),
+        });
+        assert_eq!(0, collector.pending());
+        assert!(!collector.process(&chunk(&nonce, 0, 1, 2)));
+    }
+}
@@ -0,0 +1,643 @@
+//! Mock gateway server with scripted behavior for testing shards.
+//!
+//! A [`MockGateway`] is a local websocket server speaking the gateway
+//! protocol that shards connect to via [`ShardBuilder::gateway_url`]. Each
+//! connection it accepts follows a [`Script`] of actions, such as waiting for
+//! the shard to identify, sending dispatch events, requesting a reconnect, or
+//! closing the connection with a [`CloseCode`]. This allows the lifecycle of
+//! shards, such as resuming sessions after disconnects, and event handlers to
+//! be tested deterministically and without a connection to Discord.
+//!
+//! Connections are sent a `Hello` payload once accepted, after which the
+//! actions of their script are run in order. Heartbeats sent by shards are
+//! always acknowledged. Once a script has finished, the connection remains
+//! open until the shard closes it.
+//!
+//! Shards connecting to the mock gateway should use a [`NoOpQueue`], since
+//! identifying with it isn't ratelimited.
+//!
+//! This module requires the `test-util` feature.
+//!
+//! # Examples
+//!
+//! Test that a shard resumes its session after its connection is closed:
+//!
+//! ```no_run
+//! use futures::StreamExt;
+//! use serde_json::json;
+//! use std::sync::Arc;
+//! use tokio::net::TcpListener;
+//! use twilight_gateway::{
+//!     mock::{MockGateway, Script},
+//!     queue::NoOpQueue,
+//!     Event, Intents, Shard,
+//! };
+//! use twilight_model::gateway::CloseCode;
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let gateway = MockGateway::new([
+//!     Script::new().identify().close(CloseCode::UnknownError),
+//!     Script::new().resume().dispatch(
+//!         "GUILD_ROLE_DELETE",
+//!         json!({ "guild_id": "1", "role_id": "2" }),
+//!     ),
+//! ]);
+//!
+//! let listener = TcpListener::bind("127.0.0.1:0").await?;
+//! let url = format!("ws://{}", listener.local_addr()?);
+//! tokio::spawn({
+//!     let gateway = gateway.clone();
+//!
+//!     async move { gateway.serve(listener).await }
+//! });
+//!
+//! let (shard, mut events) = Shard::builder("token".to_owned(), Intents::GUILDS)
+//!     .gateway_url(Some(url))
+//!     .queue(Arc::new(NoOpQueue))
+//!     .build();
+//! shard.start().await?;
+//!
+//! while let Some(event) = events.next().await {
+//!     if matches!(event, Event::RoleDelete(_)) {
+//!         break;
+//!     }
+//! }
+//!
+//! assert_eq!(2, gateway.connections());
+//! # Ok(()) }
+//! ```
+//!
+//! [`CloseCode`]: twilight_model::gateway::CloseCode
+//! [`NoOpQueue`]: crate::queue::NoOpQueue
+//! [`ShardBuilder::gateway_url`]: crate::shard::ShardBuilder::gateway_url
+
+use crate::server::{Connection, Received};
+use futures_util::future::{self, Either};
+use serde_json::{json, Value};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    time,
+};
+use tokio_tungstenite::tungstenite::Error as WebSocketError;
+use twilight_model::gateway::{CloseCode, OpCode};
+
+/// Heartbeat interval sent to connecting shards, in milliseconds.
+const HEARTBEAT_INTERVAL: u64 = 41_250;
+
+/// Serving the mock gateway failed.
+#[derive(Debug)]
+pub struct MockGatewayError {
+    kind: MockGatewayErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl MockGatewayError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &MockGatewayErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (MockGatewayErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for MockGatewayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            MockGatewayErrorType::Accepting => f.write_str("accepting a connection failed"),
+        }
+    }
+}
+
+impl Error for MockGatewayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`MockGatewayError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MockGatewayErrorType {
+    /// Accepting a TCP connection from the listener failed.
+    Accepting,
+}
+
+/// Action of a [`Script`].
+#[derive(Clone, Debug)]
+enum Action {
+    Close(CloseCode),
+    Dispatch { data: Value, event_type: String },
+    Heartbeat,
+    InvalidSession(bool),
+    Ready,
+    Receive(OpCode),
+    Reconnect,
+    Resumed,
+    Sleep(Duration),
+}
+
+/// Script of actions performed on a connection to the [`MockGateway`].
+///
+/// Actions are performed in the order they're added.
+#[derive(Clone, Debug, Default)]
+#[must_use = "scripts have no effect if not used by a mock gateway"]
+pub struct Script {
+    actions: Vec<Action>,
+}
+
+impl Script {
+    /// Create a new script without any actions.
+    pub const fn new() -> Self {
+        Self {
+            actions: Vec::new(),
+        }
+    }
+
+    /// Close the connection with a close code, ending the script.
+    pub fn close(self, code: CloseCode) -> Self {
+        self.action(Action::Close(code))
+    }
+
+    /// Send a dispatch event with the next sequence of the session.
+    pub fn dispatch(self, event_type: impl Into<String>, data: Value) -> Self {
+        self.action(Action::Dispatch {
+            data,
+            event_type: event_type.into(),
+        })
+    }
+
+    /// Request a heartbeat from the shard.
+    pub fn heartbeat(self) -> Self {
+        self.action(Action::Heartbeat)
+    }
+
+    /// Wait for the shard to identify, then send a `Ready` dispatch event.
+    ///
+    /// This is equivalent to receiving [`OpCode::Identify`] followed by
+    /// [`ready`].
+    ///
+    /// [`ready`]: Self::ready
+    pub fn identify(self) -> Self {
+        self.receive(OpCode::Identify).ready()
+    }
+
+    /// Send an invalid session payload, stating whether the session may be
+    /// resumed.
+    pub fn invalid_session(self, resumable: bool) -> Self {
+        self.action(Action::InvalidSession(resumable))
+    }
+
+    /// Send a `Ready` dispatch event.
+    ///
+    /// The session ID of the `Ready` payload is `mock-session-N`, where `N` is
+    /// the index of the connection, starting from 0. The shard ID is the one
+    /// that the shard identified with, if any.
+    pub fn ready(self) -> Self {
+        self.action(Action::Ready)
+    }
+
+    /// Wait for the shard to send a payload with an opcode.
+    ///
+    /// Payloads with other opcodes received in the meantime are ignored,
+    /// other than heartbeats being acknowledged.
+    pub fn receive(self, op: OpCode) -> Self {
+        self.action(Action::Receive(op))
+    }
+
+    /// Request that the shard reconnect.
+    pub fn reconnect(self) -> Self {
+        self.action(Action::Reconnect)
+    }
+
+    /// Wait for the shard to resume, then send a `Resumed` dispatch event.
+    ///
+    /// This is equivalent to receiving [`OpCode::Resume`] followed by
+    /// [`resumed`]. The session continues from the sequence the shard resumed
+    /// with.
+    ///
+    /// [`resumed`]: Self::resumed
+    pub fn resume(self) -> Self {
+        self.receive(OpCode::Resume).resumed()
+    }
+
+    /// Send a `Resumed` dispatch event.
+    pub fn resumed(self) -> Self {
+        self.action(Action::Resumed)
+    }
+
+    /// Wait for a duration before performing the next action.
+    pub fn sleep(self, duration: Duration) -> Self {
+        self.action(Action::Sleep(duration))
+    }
+
+    fn action(mut self, action: Action) -> Self {
+        self.actions.push(action);
+
+        self
+    }
+}
+
+/// Payload received by the [`MockGateway`] from a shard.
+#[derive(Clone, Debug)]
+pub struct ReceivedPayload {
+    connection: usize,
+    data: Value,
+    op: u8,
+}
+
+impl ReceivedPayload {
+    /// Index of the connection the payload was received over, starting from
+    /// 0.
+    pub const fn connection(&self) -> usize {
+        self.connection
+    }
+
+    /// Data of the payload.
+    pub const fn data(&self) -> &Value {
+        &self.data
+    }
+
+    /// Opcode of the payload.
+    pub const fn op(&self) -> u8 {
+        self.op
+    }
+}
+
+#[derive(Debug)]
+struct MockGatewayRef {
+    connections: AtomicUsize,
+    received: Mutex<Vec<ReceivedPayload>>,
+    scripts: Vec<Script>,
+}
+
+/// Mock gateway server following scripts for each connection.
+///
+/// Refer to the [module-level] documentation for more information.
+///
+/// [module-level]: self
+#[derive(Clone, Debug)]
+pub struct MockGateway(Arc<MockGatewayRef>);
+
+impl MockGateway {
+    /// Create a new mock gateway from the scripts of connections, in the
+    /// order the connections are accepted.
+    ///
+    /// Connections accepted after the scripts have run out use a script that
+    /// only [identifies].
+    ///
+    /// [identifies]: Script::identify
+    pub fn new(scripts: impl IntoIterator<Item = Script>) -> Self {
+        Self(Arc::new(MockGatewayRef {
+            connections: AtomicUsize::new(0),
+            received: Mutex::new(Vec::new()),
+            scripts: scripts.into_iter().collect(),
+        }))
+    }
+
+    /// Number of connections accepted by the mock gateway.
+    pub fn connections(&self) -> usize {
+        self.0.connections.load(Ordering::Relaxed)
+    }
+
+    /// Payloads received from shards, in the order they were received.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock over the received payloads is poisoned.
+    pub fn received(&self) -> Vec<ReceivedPayload> {
+        self.0.received.lock().expect("received poisoned").clone()
+    }
+
+    /// Serve the mock gateway, accepting connections from a listener until
+    /// accepting fails.
+    ///
+    /// Each connection is handled in its own task.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MockGatewayErrorType::Accepting`] error type if accepting a
+    /// connection from the listener failed.
+    pub async fn serve(&self, listener: TcpListener) -> Result<(), MockGatewayError> {
+        loop {
+            let (stream, _) = listener.accept().await.map_err(|source| MockGatewayError {
+                kind: MockGatewayErrorType::Accepting,
+                source: Some(Box::new(source)),
+            })?;
+
+            let index = self.0.connections.fetch_add(1, Ordering::Relaxed);
+            let script = self
+                .0
+                .scripts
+                .get(index)
+                .cloned()
+                .unwrap_or_else(|| Script::new().identify());
+            let gateway = self.clone();
+
+            tokio::spawn(async move {
+                if let Err(_source) = gateway.connection(stream, index, script).await {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(index, "mock gateway connection failed: {}", _source);
+                }
+            });
+        }
+    }
+
+    /// Run a script over a connection, then handle the connection until it's
+    /// closed.
+    async fn connection(
+        &self,
+        stream: TcpStream,
+        index: usize,
+        script: Script,
+    ) -> Result<(), WebSocketError> {
+        let mut connection = Connection::accept(stream).await?;
+        let mut session = Session {
+            index,
+            seq: 0,
+            shard: None,
+        };
+
+        connection
+            .send(
+                OpCode::Hello as u8,
+                None,
+                None,
+                &json!({ "heartbeat_interval": HEARTBEAT_INTERVAL }),
+            )
+            .await?;
+
+        for action in script.actions {
+            if !self.perform(&mut connection, &mut session, action).await? {
+                return Ok(());
+            }
+        }
+
+        while self.recv(&mut connection, &mut session).await?.is_some() {}
+
+        Ok(())
+    }
+
+    /// Perform an action of a script, returning whether the connection
+    /// remains open.
+    async fn perform(
+        &self,
+        connection: &mut Connection,
+        session: &mut Session,
+        action: Action,
+    ) -> Result<bool, WebSocketError> {
+        match action {
+            Action::Close(code) => {
+                connection.close(code as u16, close_reason(code)).await?;
+
+                return Ok(false);
+            }
+            Action::Dispatch { data, event_type } => {
+                let seq = session.next_seq();
+
+                connection
+                    .send(OpCode::Event as u8, Some(seq), Some(&event_type), &data)
+                    .await?;
+            }
+            Action::Heartbeat => {
+                connection
+                    .send(OpCode::Heartbeat as u8, None, None, &json!(session.seq))
+                    .await?;
+            }
+            Action::InvalidSession(resumable) => {
+                connection
+                    .send(
+                        OpCode::InvalidSession as u8,
+                        None,
+                        None,
+                        &Value::Bool(resumable),
+                    )
+                    .await?;
+            }
+            Action::Ready => {
+                let ready = json!({
+                    "application": { "flags": 0, "id": "1" },
+                    "guilds": [],
+                    "session_id": format!("mock-session-{}", session.index),
+                    "shard": session.shard,
+                    "user": {
+                        "avatar": null,
+                        "bot": true,
+                        "discriminator": "0001",
+                        "id": "1",
+                        "mfa_enabled": false,
+                        "username": "twilight",
+                    },
+                    "v": 9,
+                });
+                let seq = session.next_seq();
+
+                connection
+                    .send(OpCode::Event as u8, Some(seq), Some("READY"), &ready)
+                    .await?;
+            }
+            Action::Receive(op) => loop {
+                match self.recv(connection, session).await? {
+                    Some(received) if received == op as u8 => break,
+                    Some(_) => {}
+                    None => return Ok(false),
+                }
+            },
+            Action::Reconnect => {
+                connection
+                    .send(OpCode::Reconnect as u8, None, None, &Value::Null)
+                    .await?;
+            }
+            Action::Resumed => {
+                let seq = session.next_seq();
+
+                connection
+                    .send(
+                        OpCode::Event as u8,
+                        Some(seq),
+                        Some("RESUMED"),
+                        &Value::Null,
+                    )
+                    .await?;
+            }
+            Action::Sleep(duration) => {
+                let sleep = time::sleep(duration);
+                futures_util::pin_mut!(sleep);
+
+                loop {
+                    let received = self.recv(connection, session);
+                    futures_util::pin_mut!(received);
+
+                    match future::select(received, &mut sleep).await {
+                        Either::Left((received, _)) => {
+                            if received?.is_none() {
+                                return Ok(false);
+                            }
+                        }
+                        Either::Right(_) => break,
+                    }
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Receive and handle the next payload from a shard, returning its opcode.
+    ///
+    /// Returns `None` if the connection has been closed.
+    async fn recv(
+        &self,
+        connection: &mut Connection,
+        session: &mut Session,
+    ) -> Result<Option<u8>, WebSocketError> {
+        let payload = match connection.recv().await.transpose()? {
+            Some(Received::Payload(payload)) => payload,
+            Some(Received::Invalid) => {
+                connection
+                    .close(
+                        CloseCode::DecodeError as u16,
+                        close_reason(CloseCode::DecodeError),
+                    )
+                    .await?;
+
+                return Ok(None);
+            }
+            Some(Received::Close) | None => return Ok(None),
+        };
+
+        if payload.op == OpCode::Heartbeat as u8 {
+            connection
+                .send(OpCode::HeartbeatAck as u8, None, None, &Value::Null)
+                .await?;
+        } else if payload.op == OpCode::Identify as u8 {
+            session.shard = payload.d.get("shard").and_then(|shard| {
+                let id = shard.get(0)?.as_u64()?;
+                let total = shard.get(1)?.as_u64()?;
+
+                Some([id, total])
+            });
+        } else if payload.op == OpCode::Resume as u8 {
+            if let Some(seq) = payload.d.get("seq").and_then(Value::as_u64) {
+                session.seq = seq;
+            }
+        }
+
+        let op = payload.op;
+
+        self.0
+            .received
+            .lock()
+            .expect("received poisoned")
+            .push(ReceivedPayload {
+                connection: session.index,
+                data: payload.d,
+                op,
+            });
+
+        Ok(Some(op))
+    }
+}
+
+/// Session of a shard connected to the mock gateway.
+#[derive(Debug)]
+struct Session {
+    /// Index of the connection.
+    index: usize,
+    seq: u64,
+    shard: Option<[u64; 2]>,
+}
+
+impl Session {
+    /// Increment the sequence of the session, returning the new sequence.
+    fn next_seq(&mut self) -> u64 {
+        self.seq += 1;
+
+        self.seq
+    }
+}
+
+/// Reason Discord sends along with a close code.
+const fn close_reason(code: CloseCode) -> &'static str {
+    match code {
+        CloseCode::UnknownError => "Unknown error.",
+        CloseCode::UnknownOpcode => "Unknown opcode.",
+        CloseCode::DecodeError => "Error while decoding payload.",
+        CloseCode::NotAuthenticated => "Not authenticated.",
+        CloseCode::AuthenticationFailed => "Authentication failed.",
+        CloseCode::AlreadyAuthenticated => "Already authenticated.",
+        CloseCode::InvalidSequence => "Invalid seq.",
+        CloseCode::RateLimited => "Rate limited.",
+        CloseCode::SessionTimedOut => "Session timed out.",
+        CloseCode::InvalidShard => "Invalid shard.",
+        CloseCode::ShardingRequired => "Sharding required.",
+        CloseCode::InvalidApiVersion => "Invalid API version.",
+        CloseCode::InvalidIntents => "Invalid intent(s).",
+        CloseCode::DisallowedIntents => "Disallowed intent(s).",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Action, MockGateway, MockGatewayError, MockGatewayErrorType, ReceivedPayload, Script,
+    };
+    use serde_json::json;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::gateway::{CloseCode, OpCode};
+
+    assert_impl_all!(MockGateway: Clone, Debug, Send, Sync);
+    assert_impl_all!(MockGatewayErrorType: Debug, Send, Sync);
+    assert_impl_all!(MockGatewayError: Error, Send, Sync);
+    assert_impl_all!(ReceivedPayload: Clone, Debug, Send, Sync);
+    assert_impl_all!(Script: Clone, Debug, Default, Send, Sync);
+
+    #[test]
+    fn test_script_actions() {
+        let script = Script::new()
+            .identify()
+            .dispatch("GUILD_ROLE_DELETE", json!({}))
+            .close(CloseCode::UnknownError);
+
+        assert!(matches!(
+            script.actions.as_slice(),
+            [
+                Action::Receive(OpCode::Identify),
+                Action::Ready,
+                Action::Dispatch { event_type, .. },
+                Action::Close(CloseCode::UnknownError),
+            ] if event_type == "GUILD_ROLE_DELETE"
+        ));
+    }
+
+    #[test]
+    fn test_new_connections() {
+        let gateway = MockGateway::new([Script::new().resume()]);
+
+        assert_eq!(0, gateway.connections());
+        assert!(gateway.received().is_empty());
+    }
+}
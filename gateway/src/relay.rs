@@ -0,0 +1,600 @@
+//! Relay the events of shards to other shards over a local gateway server.
+//!
+//! A process that owns a [`Cluster`] or [`Shard`] can re-serve the dispatch
+//! events it receives over a websocket server speaking the gateway protocol.
+//! Other shards, such as those run by developers each needing a live event
+//! stream, then connect to the relay via [`ShardBuilder::gateway_url`] instead
+//! of to Discord, without using up the bot's identify budget.
+//!
+//! Because connecting shards never reach Discord, the relay synthesizes the
+//! `Hello` and `Ready` payloads of their sessions. The `Ready` payload is the
+//! one most recently received by the relayed shard, followed by a
+//! `GUILD_CREATE` for each guild available to it, so that caches of
+//! connecting shards are populated.
+//!
+//! Connecting shards select which shard's events they receive via the shard
+//! ID they identify with. They should use a [`NoOpQueue`], since identifying
+//! with the relay isn't ratelimited. Commands sent by connecting shards other
+//! than heartbeats are not forwarded to Discord and are ignored.
+//!
+//! The relay doesn't authenticate connecting shards, so it should only be
+//! served on a local or otherwise trusted network.
+//!
+//! # Examples
+//!
+//! Relay the events of a cluster on port 5000:
+//!
+//! ```no_run
+//! use futures::StreamExt;
+//! use std::env;
+//! use tokio::net::TcpListener;
+//! use twilight_gateway::{relay::Relay, Cluster, EventTypeFlags, Intents};
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let token = env::var("DISCORD_TOKEN")?;
+//! let (cluster, mut events) = Cluster::builder(token, Intents::GUILD_MESSAGES)
+//!     .event_types(EventTypeFlags::SHARD_PAYLOAD)
+//!     .build()
+//!     .await?;
+//! cluster.up().await;
+//!
+//! let relay = Relay::new();
+//! let listener = TcpListener::bind("127.0.0.1:5000").await?;
+//! tokio::spawn({
+//!     let relay = relay.clone();
+//!
+//!     async move { relay.serve(listener).await }
+//! });
+//!
+//! while let Some((shard_id, event)) = events.next().await {
+//!     relay.process(shard_id, &event);
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! Connect to the relay from another process:
+//!
+//! ```no_run
+//! use std::{env, sync::Arc};
+//! use twilight_gateway::{queue::NoOpQueue, Intents, Shard};
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let token = env::var("DISCORD_TOKEN")?;
+//! let (shard, _events) = Shard::builder(token, Intents::GUILD_MESSAGES)
+//!     .gateway_url(Some("ws://127.0.0.1:5000".to_owned()))
+//!     .queue(Arc::new(NoOpQueue))
+//!     .build();
+//! shard.start().await?;
+//! # Ok(()) }
+//! ```
+//!
+//! [`Cluster`]: crate::Cluster
+//! [`NoOpQueue`]: crate::queue::NoOpQueue
+//! [`ShardBuilder::gateway_url`]: crate::shard::ShardBuilder::gateway_url
+//! [`Shard`]: crate::Shard
+
+use crate::{
+    server::{ClientPayload, Connection, Received},
+    Event,
+};
+use futures_util::future::{self, Either};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::broadcast::{self, error::RecvError, Receiver, Sender},
+};
+use tokio_tungstenite::tungstenite::Error as WebSocketError;
+
+/// Number of dispatch events buffered for each connection before the
+/// connection starts missing events.
+const BUFFER: usize = 1024;
+
+/// Default heartbeat interval sent to connecting shards, in milliseconds.
+const DEFAULT_HEARTBEAT_INTERVAL: u64 = 41_250;
+
+/// Serving the relay failed.
+#[derive(Debug)]
+pub struct RelayError {
+    kind: RelayErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl RelayError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &RelayErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (RelayErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for RelayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            RelayErrorType::Accepting => f.write_str("accepting a connection failed"),
+        }
+    }
+}
+
+impl Error for RelayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`RelayError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RelayErrorType {
+    /// Accepting a TCP connection from the listener failed.
+    Accepting,
+}
+
+/// Dispatch event received by a relayed shard.
+#[derive(Debug)]
+struct Dispatch {
+    data: Value,
+    event_type: String,
+    shard_id: u64,
+}
+
+/// Payload received from Discord by a relayed shard.
+#[derive(Deserialize)]
+struct UpstreamPayload {
+    #[serde(default)]
+    d: Value,
+    op: u8,
+    t: Option<String>,
+}
+
+/// State of a relayed shard used to synthesize new sessions.
+#[derive(Debug, Default)]
+struct ShardState {
+    /// Guilds available to the shard, keyed by their ID.
+    guilds: HashMap<String, Value>,
+    /// Most recently received `Ready` payload.
+    ready: Option<Value>,
+}
+
+#[derive(Debug)]
+struct RelayRef {
+    heartbeat_interval: u64,
+    /// Counter used to generate session IDs.
+    sessions: AtomicU64,
+    shards: Mutex<HashMap<u64, ShardState>>,
+    tx: Sender<Arc<Dispatch>>,
+}
+
+/// Relay of shard events to shards connected to a local gateway server.
+///
+/// Refer to the [module-level] documentation for more information.
+///
+/// [module-level]: self
+#[derive(Clone, Debug)]
+pub struct Relay(Arc<RelayRef>);
+
+impl Relay {
+    /// Create a new relay, sending connecting shards a heartbeat interval of
+    /// 41.25 seconds.
+    pub fn new() -> Self {
+        Self::with_heartbeat_interval(DEFAULT_HEARTBEAT_INTERVAL)
+    }
+
+    /// Create a new relay, sending connecting shards a heartbeat interval in
+    /// milliseconds.
+    pub fn with_heartbeat_interval(heartbeat_interval: u64) -> Self {
+        let (tx, _) = broadcast::channel(BUFFER);
+
+        Self(Arc::new(RelayRef {
+            heartbeat_interval,
+            sessions: AtomicU64::new(0),
+            shards: Mutex::new(HashMap::new()),
+            tx,
+        }))
+    }
+
+    /// Number of shards currently connected to the relay.
+    pub fn connections(&self) -> usize {
+        self.0.tx.receiver_count()
+    }
+
+    /// Process an event received by a relayed shard.
+    ///
+    /// Only [`Event::ShardPayload`] events are relayed, so the relayed shards
+    /// must be configured to emit them via [`EventTypeFlags::SHARD_PAYLOAD`].
+    ///
+    /// [`EventTypeFlags::SHARD_PAYLOAD`]: crate::EventTypeFlags::SHARD_PAYLOAD
+    pub fn process(&self, shard_id: u64, event: &Event) {
+        if let Event::ShardPayload(payload) = event {
+            self.process_payload(shard_id, &payload.bytes);
+        }
+    }
+
+    /// Process the raw bytes of a payload received by a relayed shard.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock over the state of relayed shards is poisoned.
+    pub fn process_payload(&self, shard_id: u64, bytes: &[u8]) {
+        let payload = match serde_json::from_slice::<UpstreamPayload>(bytes) {
+            Ok(payload) => payload,
+            Err(_source) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(shard_id, "relayed payload is invalid: {}", _source);
+
+                return;
+            }
+        };
+
+        let event_type = match (payload.op, payload.t) {
+            (0, Some(event_type)) => event_type,
+            _ => return,
+        };
+
+        {
+            let mut shards = self.0.shards.lock().expect("shards poisoned");
+            let shard = shards.entry(shard_id).or_default();
+
+            match event_type.as_str() {
+                "READY" => {
+                    shard.guilds.clear();
+                    shard.ready = Some(payload.d.clone());
+                }
+                "GUILD_CREATE" => {
+                    if let Some(id) = guild_id(&payload.d) {
+                        shard.guilds.insert(id, payload.d.clone());
+                    }
+                }
+                "GUILD_DELETE" => {
+                    if let Some(id) = guild_id(&payload.d) {
+                        shard.guilds.remove(&id);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Sending only fails if no shards are connected.
+        let _res = self.0.tx.send(Arc::new(Dispatch {
+            data: payload.d,
+            event_type,
+            shard_id,
+        }));
+    }
+
+    /// Serve the relay, accepting connections from a listener until accepting
+    /// fails.
+    ///
+    /// Each connection is handled in its own task.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RelayErrorType::Accepting`] error type if accepting a
+    /// connection from the listener failed.
+    pub async fn serve(&self, listener: TcpListener) -> Result<(), RelayError> {
+        loop {
+            let (stream, _address) = listener.accept().await.map_err(|source| RelayError {
+                kind: RelayErrorType::Accepting,
+                source: Some(Box::new(source)),
+            })?;
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(address = %_address, "accepted relay connection");
+
+            let relay = self.clone();
+
+            tokio::spawn(async move {
+                if let Err(_source) = relay.connection(stream).await {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!("relay connection failed: {}", _source);
+                }
+            });
+        }
+    }
+
+    /// Handle a connection until it's closed.
+    async fn connection(&self, stream: TcpStream) -> Result<(), WebSocketError> {
+        let mut connection = Connection::accept(stream).await?;
+
+        // Subscribe before saying hello so that no events are missed between
+        // the shard identifying and the `Ready` payload being sent.
+        let mut rx = self.0.tx.subscribe();
+        let mut session = Session::default();
+
+        connection
+            .send(
+                10,
+                None,
+                None,
+                &json!({ "heartbeat_interval": self.0.heartbeat_interval }),
+            )
+            .await?;
+
+        loop {
+            let next = {
+                let received = connection.recv();
+                let dispatch = recv_dispatch(&mut rx);
+                futures_util::pin_mut!(received, dispatch);
+
+                match future::select(received, dispatch).await {
+                    Either::Left((received, _)) => Either::Left(received),
+                    Either::Right((dispatch, _)) => Either::Right(dispatch),
+                }
+            };
+
+            match next {
+                Either::Left(Some(received)) => match received? {
+                    Received::Payload(payload) => {
+                        if !self
+                            .client_payload(&mut connection, &mut session, payload)
+                            .await?
+                        {
+                            return Ok(());
+                        }
+                    }
+                    Received::Invalid => {
+                        return connection.close(4002, "Decode error").await;
+                    }
+                    Received::Close => return Ok(()),
+                },
+                Either::Left(None) => return Ok(()),
+                Either::Right(Some(dispatch)) => {
+                    self.dispatch(&mut connection, &mut session, &dispatch)
+                        .await?;
+                }
+                Either::Right(None) => {
+                    return connection.close(4000, "Relay stopped").await;
+                }
+            }
+        }
+    }
+
+    /// Handle a payload sent by a connected shard, returning whether the
+    /// connection remains open.
+    async fn client_payload(
+        &self,
+        connection: &mut Connection,
+        session: &mut Session,
+        payload: ClientPayload,
+    ) -> Result<bool, WebSocketError> {
+        match payload.op {
+            // Heartbeat.
+            1 => {
+                connection.send(11, None, None, &Value::Null).await?;
+            }
+            // Identify.
+            2 => {
+                if session.identified {
+                    connection.close(4005, "Already authenticated").await?;
+
+                    return Ok(false);
+                }
+
+                let shard = payload.d.get("shard").and_then(|shard| {
+                    let id = shard.get(0)?.as_u64()?;
+                    let total = shard.get(1)?.as_u64()?;
+
+                    Some([id, total])
+                });
+
+                session.identified = true;
+                session.shard = shard;
+
+                let ready = self
+                    .0
+                    .shards
+                    .lock()
+                    .expect("shards poisoned")
+                    .get(&session.shard_id())
+                    .and_then(|state| state.ready.clone());
+
+                // If the relayed shard hasn't received a `Ready` payload yet
+                // then the session is ready once it does.
+                if let Some(ready) = ready {
+                    self.ready(connection, session, ready).await?;
+                }
+            }
+            // Resume.
+            6 => {
+                // Sessions can't be resumed since they aren't persisted, so
+                // have the shard identify instead.
+                connection.send(9, None, None, &Value::Bool(false)).await?;
+            }
+            _ => {}
+        }
+
+        Ok(true)
+    }
+
+    /// Relay a dispatch event to a connected shard if it's for the shard.
+    async fn dispatch(
+        &self,
+        connection: &mut Connection,
+        session: &mut Session,
+        dispatch: &Dispatch,
+    ) -> Result<(), WebSocketError> {
+        if !session.identified || dispatch.shard_id != session.shard_id() {
+            return Ok(());
+        }
+
+        match dispatch.event_type.as_str() {
+            "READY" if !session.ready => {
+                self.ready(connection, session, dispatch.data.clone()).await
+            }
+            // Sessions are synthesized, so the relayed shard's session events
+            // aren't relevant.
+            "READY" | "RESUMED" => Ok(()),
+            _ if session.ready => {
+                let seq = session.next_seq();
+
+                connection
+                    .send(0, Some(seq), Some(&dispatch.event_type), &dispatch.data)
+                    .await
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Send a synthesized `Ready` payload and the guilds available to the
+    /// relayed shard.
+    async fn ready(
+        &self,
+        connection: &mut Connection,
+        session: &mut Session,
+        mut ready: Value,
+    ) -> Result<(), WebSocketError> {
+        let session_id = self.0.sessions.fetch_add(1, Ordering::Relaxed);
+
+        if let Some(object) = ready.as_object_mut() {
+            object.insert(
+                "session_id".to_owned(),
+                Value::String(format!("relay-{}", session_id)),
+            );
+
+            if let Some(shard) = session.shard {
+                object.insert("shard".to_owned(), json!(shard));
+            }
+        }
+
+        session.ready = true;
+        let seq = session.next_seq();
+        connection.send(0, Some(seq), Some("READY"), &ready).await?;
+
+        let guilds = self
+            .0
+            .shards
+            .lock()
+            .expect("shards poisoned")
+            .get(&session.shard_id())
+            .map(|state| state.guilds.values().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        for guild in guilds {
+            let seq = session.next_seq();
+            connection
+                .send(0, Some(seq), Some("GUILD_CREATE"), &guild)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Relay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Session of a shard connected to the relay.
+#[derive(Debug, Default)]
+struct Session {
+    identified: bool,
+    ready: bool,
+    seq: u64,
+    shard: Option<[u64; 2]>,
+}
+
+impl Session {
+    /// Increment the sequence of the session, returning the new sequence.
+    fn next_seq(&mut self) -> u64 {
+        self.seq += 1;
+
+        self.seq
+    }
+
+    /// ID of the relayed shard whose events the session receives.
+    fn shard_id(&self) -> u64 {
+        self.shard.map_or(0, |[id, _]| id)
+    }
+}
+
+/// Receive the next dispatch event, skipping over missed events.
+///
+/// Returns `None` if the relay has been dropped.
+async fn recv_dispatch(rx: &mut Receiver<Arc<Dispatch>>) -> Option<Arc<Dispatch>> {
+    loop {
+        match rx.recv().await {
+            Ok(dispatch) => return Some(dispatch),
+            Err(RecvError::Lagged(_missed)) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(missed = _missed, "relay connection missed events");
+            }
+            Err(RecvError::Closed) => return None,
+        }
+    }
+}
+
+/// ID of a guild in a guild payload.
+fn guild_id(guild: &Value) -> Option<String> {
+    guild.get("id")?.as_str().map(ToOwned::to_owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Relay, RelayError, RelayErrorType};
+    use crate::Event;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::gateway::event::shard::Payload;
+
+    assert_impl_all!(Relay: Clone, Debug, Default, Send, Sync);
+    assert_impl_all!(RelayErrorType: Debug, Send, Sync);
+    assert_impl_all!(RelayError: Error, Send, Sync);
+
+    fn payload(json: &str) -> Event {
+        Event::ShardPayload(Payload {
+            bytes: json.as_bytes().to_vec(),
+        })
+    }
+
+    #[test]
+    fn test_process_caches_state() {
+        let relay = Relay::new();
+        relay.process(1, &payload(r#"{"op":0,"s":1,"t":"READY","d":{"v":9}}"#));
+        relay.process(
+            1,
+            &payload(r#"{"op":0,"s":2,"t":"GUILD_CREATE","d":{"id":"2"}}"#),
+        );
+        relay.process(
+            1,
+            &payload(r#"{"op":0,"s":3,"t":"GUILD_CREATE","d":{"id":"3"}}"#),
+        );
+        relay.process(
+            1,
+            &payload(r#"{"op":0,"s":4,"t":"GUILD_DELETE","d":{"id":"2"}}"#),
+        );
+        relay.process(1, &payload(r#"{"op":11,"s":null,"t":null,"d":null}"#));
+        relay.process(1, &payload("invalid"));
+
+        let shards = relay.0.shards.lock().unwrap();
+        let state = shards.get(&1).unwrap();
+        assert!(state.ready.is_some());
+        assert_eq!(1, state.guilds.len());
+        assert!(state.guilds.contains_key("3"));
+        assert!(!shards.contains_key(&0));
+    }
+}
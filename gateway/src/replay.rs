@@ -0,0 +1,709 @@
+//! Record the gateway sessions of shards and replay them offline.
+//!
+//! A [`Recorder`] writes every raw payload received by shards, along with the
+//! time it was received and the ID of the shard that received it, to a
+//! writer. A [`Replay`] reads a recording back and produces event streams
+//! equivalent to those of the shards that were recorded, which is useful for
+//! writing regression tests for caches and event handlers against realistic
+//! traffic.
+//!
+//! Recordings are newline delimited JSON, with each line being a frame
+//! containing the raw payload, the shard ID, and the number of milliseconds
+//! since the Unix epoch the payload was received at:
+//!
+//! ```json
+//! {"payload":"{\"op\":11,\"d\":null}","shard_id":0,"timestamp":1640995200000}
+//! ```
+//!
+//! # Examples
+//!
+//! Record the payloads received by a cluster to a file:
+//!
+//! ```no_run
+//! use futures::StreamExt;
+//! use std::{env, fs::File, io::BufWriter};
+//! use twilight_gateway::{replay::Recorder, Cluster, EventTypeFlags, Intents};
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let token = env::var("DISCORD_TOKEN")?;
+//! let (cluster, mut events) = Cluster::builder(token, Intents::GUILD_MESSAGES)
+//!     .event_types(EventTypeFlags::SHARD_PAYLOAD)
+//!     .build()
+//!     .await?;
+//! cluster.up().await;
+//!
+//! let mut recorder = Recorder::new(BufWriter::new(File::create("session.jsonl")?));
+//!
+//! while let Some((shard_id, event)) = events.next().await {
+//!     recorder.process(shard_id, &event)?;
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! Replay the events of shard 0 from the recording:
+//!
+//! ```no_run
+//! use futures::StreamExt;
+//! use std::{fs::File, io::BufReader};
+//! use twilight_gateway::{replay::Replay, EventTypeFlags};
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let replay = Replay::from_reader(BufReader::new(File::open("session.jsonl")?))?;
+//! let mut events = replay.shard(0, EventTypeFlags::default());
+//!
+//! while let Some(event) = events.next().await {
+//!     println!("replayed event: {:?}", event.kind());
+//! }
+//! # Ok(()) }
+//! ```
+
+use crate::{
+    cluster::{event::ShardEventsWithId, Events as ClusterEvents},
+    shard::{emitter::Emitter, json, Events as ShardEvents},
+    Event, EventTypeFlags,
+};
+use futures_util::stream::SelectAll;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{BufRead, Write},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::time::{self, Instant};
+use twilight_model::gateway::event::{shard::Connected, GatewayEvent, GatewayEventDeserializer};
+
+/// Writing a frame to a recording failed.
+#[derive(Debug)]
+pub struct RecordError {
+    kind: RecordErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl RecordError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &RecordErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (RecordErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for RecordError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            RecordErrorType::Writing => f.write_str("writing a frame to the recording failed"),
+        }
+    }
+}
+
+impl Error for RecordError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`RecordError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RecordErrorType {
+    /// Writing or flushing the writer failed.
+    Writing,
+}
+
+/// Reading a recording failed.
+#[derive(Debug)]
+pub struct ReplayError {
+    kind: ReplayErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ReplayError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ReplayErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ReplayErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ReplayErrorType::Deserializing { line } => {
+                f.write_str("frame on line ")?;
+                Display::fmt(line, f)?;
+
+                f.write_str(" of the recording is invalid")
+            }
+            ReplayErrorType::Reading => f.write_str("reading the recording failed"),
+        }
+    }
+}
+
+impl Error for ReplayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ReplayError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ReplayErrorType {
+    /// Deserializing a frame failed.
+    Deserializing {
+        /// Line number of the frame, starting from 1.
+        line: usize,
+    },
+    /// Reading from the reader failed.
+    Reading,
+}
+
+/// Raw payload received by a shard.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Frame {
+    payload: String,
+    shard_id: u64,
+    timestamp: u64,
+}
+
+impl Frame {
+    /// Raw JSON payload received by the shard.
+    pub fn payload(&self) -> &str {
+        &self.payload
+    }
+
+    /// ID of the shard that received the payload.
+    pub const fn shard_id(&self) -> u64 {
+        self.shard_id
+    }
+
+    /// Number of milliseconds since the Unix epoch the payload was received
+    /// at.
+    pub const fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
+
+/// Recorder of the raw payloads received by shards.
+///
+/// Frames are written to the writer as they're recorded, so writers such as
+/// files should be wrapped in a [`BufWriter`].
+///
+/// Refer to the [module-level] documentation for more information.
+///
+/// [`BufWriter`]: std::io::BufWriter
+/// [module-level]: self
+#[derive(Debug)]
+pub struct Recorder<W> {
+    writer: W,
+}
+
+impl<W: Write> Recorder<W> {
+    /// Create a new recorder writing frames to a writer.
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Process an event received by a recorded shard.
+    ///
+    /// Only [`Event::ShardPayload`] events are recorded, so the recorded
+    /// shards must be configured to emit them via
+    /// [`EventTypeFlags::SHARD_PAYLOAD`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordErrorType::Writing`] error type if writing the frame
+    /// failed.
+    pub fn process(&mut self, shard_id: u64, event: &Event) -> Result<(), RecordError> {
+        if let Event::ShardPayload(payload) = event {
+            self.record(shard_id, &payload.bytes)?;
+        }
+
+        Ok(())
+    }
+
+    /// Record the raw bytes of a payload received by a shard, timestamped with
+    /// the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordErrorType::Writing`] error type if writing the frame
+    /// failed.
+    pub fn record(&mut self, shard_id: u64, bytes: &[u8]) -> Result<(), RecordError> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| {
+                u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+            });
+
+        let frame = Frame {
+            payload: String::from_utf8_lossy(bytes).into_owned(),
+            shard_id,
+            timestamp,
+        };
+
+        self.write(&frame)
+    }
+
+    /// Flush the frames buffered by the writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecordErrorType::Writing`] error type if flushing the
+    /// writer failed.
+    pub fn flush(&mut self) -> Result<(), RecordError> {
+        self.writer.flush().map_err(|source| RecordError {
+            kind: RecordErrorType::Writing,
+            source: Some(Box::new(source)),
+        })
+    }
+
+    /// Consume the recorder, returning the writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write(&mut self, frame: &Frame) -> Result<(), RecordError> {
+        serde_json::to_writer(&mut self.writer, frame).map_err(|source| RecordError {
+            kind: RecordErrorType::Writing,
+            source: Some(Box::new(source)),
+        })?;
+
+        self.writer.write_all(b"\n").map_err(|source| RecordError {
+            kind: RecordErrorType::Writing,
+            source: Some(Box::new(source)),
+        })
+    }
+}
+
+/// Recording of the raw payloads received by shards, replayable as event
+/// streams.
+///
+/// Events are produced the same way as a live shard produces them from the
+/// payloads it receives, including [`Event::ShardConnected`] events when
+/// sessions are readied or resumed and [`Event::ShardPayload`] events if
+/// wanted. Events that a live shard emits because of its own actions, such as
+/// [`Event::ShardConnecting`] and [`Event::ShardIdentifying`], aren't
+/// produced. Unlike a live shard's stream, a replayed stream ends after its
+/// last frame.
+///
+/// Refer to the [module-level] documentation for more information.
+///
+/// [module-level]: self
+#[derive(Clone, Debug)]
+pub struct Replay {
+    frames: Vec<Frame>,
+    pacing: bool,
+}
+
+impl Replay {
+    /// Read a recording of frames written by a [`Recorder`].
+    ///
+    /// Empty lines are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ReplayErrorType::Deserializing`] error type if a frame is
+    /// invalid.
+    ///
+    /// Returns a [`ReplayErrorType::Reading`] error type if reading from the
+    /// reader failed.
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, ReplayError> {
+        let mut frames = Vec::new();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line.map_err(|source| ReplayError {
+                kind: ReplayErrorType::Reading,
+                source: Some(Box::new(source)),
+            })?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let frame = serde_json::from_str(&line).map_err(|source| ReplayError {
+                kind: ReplayErrorType::Deserializing { line: index + 1 },
+                source: Some(Box::new(source)),
+            })?;
+
+            frames.push(frame);
+        }
+
+        Ok(Self {
+            frames,
+            pacing: false,
+        })
+    }
+
+    /// Set whether to pace replayed events in real time.
+    ///
+    /// When enabled, the time between events matches the time between the
+    /// payloads being received in the recording. Otherwise all events are
+    /// available immediately.
+    ///
+    /// Defaults to being disabled.
+    #[must_use = "setting pacing has no effect if left unused"]
+    pub const fn pacing(mut self, pacing: bool) -> Self {
+        self.pacing = pacing;
+
+        self
+    }
+
+    /// Immutable reference to the recorded frames.
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Replay the events of a shard, as if they were from its [`Events`]
+    /// stream.
+    ///
+    /// # Panics
+    ///
+    /// Panics if pacing is enabled and this isn't called from within a Tokio
+    /// runtime.
+    ///
+    /// [`Events`]: crate::shard::Events
+    pub fn shard(&self, shard_id: u64, event_types: EventTypeFlags) -> ShardEvents {
+        let (emitter, rx) = Emitter::new(event_types);
+
+        let frames = self
+            .frames
+            .iter()
+            .filter(|frame| frame.shard_id == shard_id)
+            .cloned()
+            .collect();
+
+        let mut shards = BTreeMap::new();
+        shards.insert(shard_id, ShardReplay::new(emitter, shard_id));
+        self.play(frames, shards);
+
+        ShardEvents::new(event_types, rx)
+    }
+
+    /// Replay the events of all recorded shards, as if they were from a
+    /// cluster's [`Events`] stream.
+    ///
+    /// # Panics
+    ///
+    /// Panics if pacing is enabled and this isn't called from within a Tokio
+    /// runtime.
+    ///
+    /// [`Events`]: crate::cluster::Events
+    pub fn cluster(&self, event_types: EventTypeFlags) -> ClusterEvents {
+        let shard_ids = self
+            .frames
+            .iter()
+            .map(|frame| frame.shard_id)
+            .collect::<BTreeSet<_>>();
+
+        let mut shards = BTreeMap::new();
+        let mut streams = SelectAll::new();
+
+        for shard_id in shard_ids {
+            let (emitter, rx) = Emitter::new(event_types);
+            shards.insert(shard_id, ShardReplay::new(emitter, shard_id));
+            streams.push(ShardEventsWithId::new(
+                shard_id,
+                ShardEvents::new(event_types, rx),
+            ));
+        }
+
+        self.play(self.frames.clone(), shards);
+
+        ClusterEvents::new(streams)
+    }
+
+    /// Emit the events of frames, dropping the shards' emitters afterwards to
+    /// end their streams.
+    fn play(&self, frames: Vec<Frame>, mut shards: BTreeMap<u64, ShardReplay>) {
+        if !self.pacing {
+            for frame in &frames {
+                if let Some(shard) = shards.get_mut(&frame.shard_id) {
+                    shard.process(&frame.payload);
+                }
+            }
+
+            return;
+        }
+
+        tokio::spawn(async move {
+            let start = Instant::now();
+            let first = frames.first().map_or(0, |frame| frame.timestamp);
+
+            for frame in &frames {
+                let offset = Duration::from_millis(frame.timestamp.saturating_sub(first));
+                time::sleep_until(start + offset).await;
+
+                if let Some(shard) = shards.get_mut(&frame.shard_id) {
+                    shard.process(&frame.payload);
+                }
+            }
+        });
+    }
+}
+
+/// State of a replayed shard.
+#[derive(Debug)]
+struct ShardReplay {
+    emitter: Emitter,
+    /// Heartbeat interval of the most recent `Hello` payload, in milliseconds.
+    heartbeat_interval: u64,
+    shard_id: u64,
+}
+
+impl ShardReplay {
+    const fn new(emitter: Emitter, shard_id: u64) -> Self {
+        Self {
+            emitter,
+            heartbeat_interval: 0,
+            shard_id,
+        }
+    }
+
+    /// Emit the events of a payload in the same order as a live shard.
+    fn process(&mut self, payload: &str) {
+        self.emitter.bytes(payload.as_bytes());
+
+        let deserializer = if let Some(deserializer) = GatewayEventDeserializer::from_json(payload)
+        {
+            deserializer
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(shard_id = self.shard_id, "replayed payload has no opcode");
+
+            return;
+        };
+
+        let (op, seq, event_type) = deserializer.into_parts();
+        let mut buffer = payload.as_bytes().to_vec();
+
+        if matches!(op, 1 | 7 | 9 | 10 | 11) {
+            match json::parse_gateway_event(op, seq, event_type, &mut buffer) {
+                Ok(event) => {
+                    if let GatewayEvent::Hello(interval) = event {
+                        self.heartbeat_interval = interval;
+                    }
+
+                    self.emitter.event(Event::from(event));
+                }
+                Err(_source) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        shard_id = self.shard_id,
+                        "replayed payload failed to parse: {}",
+                        _source
+                    );
+                }
+            }
+
+            return;
+        }
+
+        if matches!(event_type, Some("READY" | "RESUMED")) {
+            self.emitter.event(Event::ShardConnected(Connected {
+                heartbeat_interval: self.heartbeat_interval,
+                shard_id: self.shard_id,
+            }));
+        }
+
+        if let Err(_source) = self.emitter.json(op, seq, event_type, &mut buffer) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                shard_id = self.shard_id,
+                "replayed payload failed to emit: {}",
+                _source
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Frame, RecordError, RecordErrorType, Recorder, Replay, ReplayError, ReplayErrorType,
+    };
+    use crate::{Event, EventTypeFlags};
+    use futures_util::stream::StreamExt;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, time::Duration};
+    use tokio::time::Instant;
+    use twilight_model::{gateway::event::shard::Payload, id::Id};
+
+    assert_impl_all!(Frame: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(RecordErrorType: Debug, Send, Sync);
+    assert_impl_all!(RecordError: Error, Send, Sync);
+    assert_impl_all!(Recorder<Vec<u8>>: Debug, Send, Sync);
+    assert_impl_all!(ReplayErrorType: Debug, Send, Sync);
+    assert_impl_all!(ReplayError: Error, Send, Sync);
+    assert_impl_all!(Replay: Clone, Debug, Send, Sync);
+
+    const HELLO: &str = r#"{"op":10,"s":null,"t":null,"d":{"heartbeat_interval":41250}}"#;
+    const READY: &str = r#"{"op":0,"s":1,"t":"READY","d":{"application":{"flags":0,"id":"100"},"guilds":[],"session_id":"abc","shard":[0,2],"user":{"avatar":null,"bot":true,"discriminator":"0001","id":"3","mfa_enabled":false,"username":"twilight"},"v":9}}"#;
+    const ROLE_DELETE: &str =
+        r#"{"op":0,"s":2,"t":"GUILD_ROLE_DELETE","d":{"guild_id":"1","role_id":"2"}}"#;
+
+    fn recording(frames: &[(u64, u64, &str)]) -> Vec<u8> {
+        let mut recorder = Recorder::new(Vec::new());
+
+        for (shard_id, timestamp, payload) in frames {
+            recorder
+                .write(&Frame {
+                    payload: (*payload).to_owned(),
+                    shard_id: *shard_id,
+                    timestamp: *timestamp,
+                })
+                .unwrap();
+        }
+
+        recorder.into_inner()
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let mut recorder = Recorder::new(Vec::new());
+        recorder.record(1, HELLO.as_bytes()).unwrap();
+        recorder
+            .process(
+                2,
+                &Event::ShardPayload(Payload {
+                    bytes: ROLE_DELETE.as_bytes().to_vec(),
+                }),
+            )
+            .unwrap();
+        recorder.process(2, &Event::GatewayReconnect).unwrap();
+
+        let bytes = recorder.into_inner();
+        let replay = Replay::from_reader(bytes.as_slice()).unwrap();
+        let frames = replay.frames();
+
+        assert_eq!(2, frames.len());
+        assert_eq!(1, frames[0].shard_id());
+        assert_eq!(HELLO, frames[0].payload());
+        assert_eq!(2, frames[1].shard_id());
+        assert_eq!(ROLE_DELETE, frames[1].payload());
+        assert!(frames[0].timestamp() <= frames[1].timestamp());
+    }
+
+    #[test]
+    fn test_from_reader_invalid() {
+        let input = format!(
+            "\n{}\nnot a frame\n",
+            String::from_utf8(recording(&[(0, 0, HELLO)])).unwrap()
+        );
+        let error = Replay::from_reader(input.as_bytes()).unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ReplayErrorType::Deserializing { line: 4 }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_shard_events() {
+        let bytes = recording(&[
+            (0, 0, HELLO),
+            (1, 1, HELLO),
+            (0, 2, READY),
+            (0, 3, ROLE_DELETE),
+        ]);
+        let replay = Replay::from_reader(bytes.as_slice()).unwrap();
+
+        let events = replay
+            .shard(0, EventTypeFlags::default() | EventTypeFlags::SHARD_PAYLOAD)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(7, events.len());
+        assert!(matches!(events[0], Event::ShardPayload(_)));
+        assert!(matches!(events[1], Event::GatewayHello(41_250)));
+        assert!(matches!(events[2], Event::ShardPayload(_)));
+        assert!(matches!(
+            &events[3],
+            Event::ShardConnected(connected)
+                if connected.heartbeat_interval == 41_250 && connected.shard_id == 0
+        ));
+        assert!(matches!(&events[4], Event::Ready(ready) if ready.session_id == "abc"));
+        assert!(matches!(events[5], Event::ShardPayload(_)));
+        assert!(matches!(
+            &events[6],
+            Event::RoleDelete(delete) if delete.role_id == Id::new(2)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_shard_events_filtered() {
+        let bytes = recording(&[(0, 0, HELLO), (0, 1, READY), (0, 2, ROLE_DELETE)]);
+        let replay = Replay::from_reader(bytes.as_slice()).unwrap();
+
+        let events = replay
+            .shard(0, EventTypeFlags::ROLE_DELETE)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(1, events.len());
+        assert!(matches!(events[0], Event::RoleDelete(_)));
+    }
+
+    #[tokio::test]
+    async fn test_cluster_events() {
+        let bytes = recording(&[(0, 0, HELLO), (1, 1, HELLO), (1, 2, ROLE_DELETE)]);
+        let replay = Replay::from_reader(bytes.as_slice()).unwrap();
+
+        let mut events = replay
+            .cluster(EventTypeFlags::default())
+            .collect::<Vec<_>>()
+            .await;
+        events.sort_by_key(|(shard_id, _)| *shard_id);
+
+        assert_eq!(3, events.len());
+        assert!(matches!(events[0], (0, Event::GatewayHello(_))));
+        assert!(matches!(events[1], (1, Event::GatewayHello(_))));
+        assert!(matches!(events[2], (1, Event::RoleDelete(_))));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_pacing() {
+        let bytes = recording(&[(0, 1_000, HELLO), (0, 6_000, ROLE_DELETE)]);
+        let replay = Replay::from_reader(bytes.as_slice()).unwrap().pacing(true);
+
+        let start = Instant::now();
+        let mut events = replay.shard(0, EventTypeFlags::default());
+
+        assert!(matches!(events.next().await, Some(Event::GatewayHello(_))));
+        assert_eq!(Duration::ZERO, start.elapsed());
+
+        assert!(matches!(events.next().await, Some(Event::RoleDelete(_))));
+        assert_eq!(Duration::from_secs(5), start.elapsed());
+
+        assert!(events.next().await.is_none());
+    }
+}
@@ -0,0 +1,219 @@
+//! Server side of gateway connections, shared by the gateway servers hosted by
+//! the library.
+//!
+//! Connections speak the JSON encoding of the gateway protocol. If the client
+//! requests `zlib-stream` transport compression via the `compress` query
+//! parameter and one of the `zlib-stock` or `zlib-simd` features is enabled,
+//! then outgoing payloads are compressed in the same way as Discord's.
+
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+    tungstenite::{
+        handshake::server::Request,
+        protocol::{frame::coding::CloseCode, CloseFrame},
+        Error as WebSocketError, Message,
+    },
+    WebSocketStream,
+};
+
+/// Payload sent by a client.
+#[derive(Debug, Deserialize)]
+pub struct ClientPayload {
+    /// Opcode of the payload.
+    pub op: u8,
+    /// Data of the payload.
+    #[serde(default)]
+    pub d: Value,
+}
+
+/// Item received over a connection.
+#[derive(Debug)]
+pub enum Received {
+    /// Connection has been closed by the client.
+    Close,
+    /// Message couldn't be decoded as a payload.
+    Invalid,
+    /// Payload sent by the client.
+    Payload(ClientPayload),
+}
+
+/// Server side of a gateway connection.
+#[derive(Debug)]
+pub struct Connection {
+    #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+    compressor: Option<compressor::Compressor>,
+    stream: WebSocketStream<TcpStream>,
+}
+
+impl Connection {
+    /// Accept a websocket connection from a client.
+    #[allow(clippy::result_large_err)]
+    pub async fn accept(stream: TcpStream) -> Result<Self, WebSocketError> {
+        let mut compress = false;
+
+        let stream = tokio_tungstenite::accept_hdr_async(stream, |request: &Request, response| {
+            compress = request.uri().query().map_or(false, |query| {
+                query.split('&').any(|pair| pair == "compress=zlib-stream")
+            });
+
+            Ok(response)
+        })
+        .await?;
+
+        #[cfg(not(any(feature = "zlib-stock", feature = "zlib-simd")))]
+        let _compress = compress;
+
+        Ok(Self {
+            #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+            compressor: compress.then(compressor::Compressor::new),
+            stream,
+        })
+    }
+
+    /// Send a payload.
+    pub async fn send(
+        &mut self,
+        op: u8,
+        seq: Option<u64>,
+        event_type: Option<&str>,
+        data: &Value,
+    ) -> Result<(), WebSocketError> {
+        let payload = json!({
+            "op": op,
+            "s": seq,
+            "t": event_type,
+            "d": data,
+        });
+
+        self.send_raw(payload.to_string()).await
+    }
+
+    /// Send a payload that has already been serialized.
+    pub async fn send_raw(&mut self, json: String) -> Result<(), WebSocketError> {
+        #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+        if let Some(compressor) = self.compressor.as_mut() {
+            let bytes = compressor.compress(json.as_bytes());
+
+            return self.stream.send(Message::Binary(bytes)).await;
+        }
+
+        self.stream.send(Message::Text(json)).await
+    }
+
+    /// Close the connection with a close code and reason.
+    pub async fn close(&mut self, code: u16, reason: &str) -> Result<(), WebSocketError> {
+        let frame = CloseFrame {
+            code: CloseCode::from(code),
+            reason: Cow::Owned(reason.to_owned()),
+        };
+
+        self.stream.close(Some(frame)).await
+    }
+
+    /// Receive the next item from the client.
+    ///
+    /// Returns `None` if the connection has ended.
+    pub async fn recv(&mut self) -> Option<Result<Received, WebSocketError>> {
+        loop {
+            let message = match self.stream.next().await? {
+                Ok(message) => message,
+                Err(source) => return Some(Err(source)),
+            };
+
+            let bytes = match &message {
+                Message::Binary(bytes) => bytes.as_slice(),
+                Message::Text(text) => text.as_bytes(),
+                Message::Close(_) => return Some(Ok(Received::Close)),
+                Message::Ping(_) | Message::Pong(_) => continue,
+            };
+
+            return Some(Ok(match serde_json::from_slice(bytes) {
+                Ok(payload) => Received::Payload(payload),
+                Err(_) => Received::Invalid,
+            }));
+        }
+    }
+}
+
+#[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
+mod compressor {
+    use flate2::{Compress, Compression, FlushCompress};
+
+    /// Compressor of a `zlib-stream` compressed connection.
+    ///
+    /// Each payload is flushed with a sync flush, ending it with the suffix
+    /// that clients look for to know that a message is complete.
+    #[derive(Debug)]
+    pub struct Compressor {
+        compress: Compress,
+    }
+
+    impl Compressor {
+        pub fn new() -> Self {
+            Self {
+                compress: Compress::new(Compression::default(), true),
+            }
+        }
+
+        pub fn compress(&mut self, input: &[u8]) -> Vec<u8> {
+            let mut output = Vec::with_capacity(input.len() / 2 + 64);
+            let before = self.compress.total_in();
+
+            loop {
+                let consumed = usize::try_from(self.compress.total_in() - before)
+                    .unwrap_or(input.len())
+                    .min(input.len());
+
+                // Compressing into a vector doesn't error; errors are only
+                // possible when the stream is corrupted via `Compress::reset`.
+                if self
+                    .compress
+                    .compress_vec(&input[consumed..], &mut output, FlushCompress::Sync)
+                    .is_err()
+                {
+                    break;
+                }
+
+                let consumed =
+                    usize::try_from(self.compress.total_in() - before).unwrap_or(input.len());
+
+                // The flush is complete once all of the input has been consumed
+                // and the output buffer wasn't filled to capacity.
+                if consumed >= input.len() && output.len() < output.capacity() {
+                    break;
+                }
+
+                output.reserve(output.capacity());
+            }
+
+            output
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Compressor;
+        use flate2::{Decompress, FlushDecompress};
+
+        #[test]
+        fn test_sync_flush_suffix() {
+            let mut compressor = Compressor::new();
+            let mut decompress = Decompress::new(true);
+
+            for input in [b"{\"op\":11}".to_vec(), vec![b'a'; 100_000]] {
+                let compressed = compressor.compress(&input);
+                assert!(compressed.ends_with(&[0x00, 0x00, 0xff, 0xff]));
+
+                let mut output = Vec::with_capacity(input.len() * 2);
+                decompress
+                    .decompress_vec(&compressed, &mut output, FlushDecompress::Sync)
+                    .unwrap();
+                assert_eq!(input, output);
+            }
+        }
+    }
+}
@@ -173,6 +173,7 @@ impl ShardBuilder {
         }
 
         Self(Config {
+            command_ratelimit_events: false,
             event_types: EventTypeFlags::default(),
             gateway_url: None,
             http_client: Arc::new(HttpClient::new(token.clone())),
@@ -194,6 +195,22 @@ impl ShardBuilder {
         Shard::new_with_config(self.0)
     }
 
+    /// Set whether to emit an [`Event::ShardCommandRatelimited`] event when a
+    /// command is delayed by the shard's command ratelimiter.
+    ///
+    /// These events aren't filtered by the [event types] and are only emitted
+    /// if enabled here.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`Event::ShardCommandRatelimited`]: crate::Event::ShardCommandRatelimited
+    /// [event types]: Self::event_types
+    pub const fn command_ratelimit_events(mut self, command_ratelimit_events: bool) -> Self {
+        self.0.command_ratelimit_events = command_ratelimit_events;
+
+        self
+    }
+
     /// Set the event types to process.
     ///
     /// This is an optimization technique; all events not included in the
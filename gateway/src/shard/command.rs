@@ -8,20 +8,74 @@ use twilight_model::gateway::payload::outgoing::{
 };
 
 mod private {
+    use super::CommandPriority;
     use serde::Serialize;
     use twilight_model::gateway::payload::outgoing::{
         identify::Identify, resume::Resume, Heartbeat, RequestGuildMembers, UpdatePresence,
         UpdateVoiceState,
     };
 
-    pub trait Sealed: Serialize {}
+    pub trait Sealed: Serialize {
+        /// Priority of the command when waiting on the shard's command
+        /// ratelimiter.
+        const PRIORITY: CommandPriority = CommandPriority::Normal;
+    }
 
     impl Sealed for Heartbeat {}
+
     impl Sealed for Identify {}
-    impl Sealed for RequestGuildMembers {}
+
+    impl Sealed for RequestGuildMembers {
+        const PRIORITY: CommandPriority = CommandPriority::Low;
+    }
+
     impl Sealed for Resume {}
+
     impl Sealed for UpdatePresence {}
-    impl Sealed for UpdateVoiceState {}
+
+    impl Sealed for UpdateVoiceState {
+        const PRIORITY: CommandPriority = CommandPriority::High;
+    }
+}
+
+/// Priority of a command waiting on a shard's command ratelimiter.
+///
+/// When the ratelimiter has no remaining budget, commands of a higher priority
+/// are sent before commands of a lower priority once it refills. Commands of
+/// the same priority are not ordered in relation to each other.
+///
+/// Commands sent via [`Shard::command`] use a default priority based on the
+/// type of command: [`UpdateVoiceState`] is sent with a [`High`] priority,
+/// [`RequestGuildMembers`] with a [`Low`] priority, and all other commands
+/// with a [`Normal`] priority. Use [`Shard::command_with_priority`] to
+/// override it.
+///
+/// [`High`]: Self::High
+/// [`Low`]: Self::Low
+/// [`Normal`]: Self::Normal
+/// [`Shard::command_with_priority`]: super::Shard::command_with_priority
+/// [`Shard::command`]: super::Shard::command
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CommandPriority {
+    /// Command is sent only once no commands of a higher priority are waiting.
+    Low = 0,
+    /// Default priority of commands.
+    Normal = 1,
+    /// Command is sent before any commands of a lower priority.
+    High = 2,
+}
+
+impl CommandPriority {
+    /// Index of the priority for use in per-priority arrays.
+    pub(crate) const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Default for CommandPriority {
+    fn default() -> Self {
+        Self::Normal
+    }
 }
 
 /// Trait marker to denote what can be provided to [`Shard::command`].
@@ -45,8 +99,9 @@ impl Command for UpdateVoiceState {}
 
 #[cfg(test)]
 mod tests {
-    use super::Command;
+    use super::{private::Sealed, Command, CommandPriority};
     use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
     use twilight_model::gateway::payload::outgoing::{
         identify::Identify, resume::Resume, Heartbeat, RequestGuildMembers, UpdatePresence,
         UpdateVoiceState,
@@ -58,4 +113,31 @@ mod tests {
     assert_impl_all!(Resume: Command);
     assert_impl_all!(UpdatePresence: Command);
     assert_impl_all!(UpdateVoiceState: Command);
+    assert_impl_all!(
+        CommandPriority: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        Ord,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_priority_order() {
+        assert!(CommandPriority::High > CommandPriority::Normal);
+        assert!(CommandPriority::Normal > CommandPriority::Low);
+        assert_eq!(CommandPriority::Normal, CommandPriority::default());
+    }
+
+    #[test]
+    fn test_voice_state_before_member_requests() {
+        assert!(<UpdateVoiceState as Sealed>::PRIORITY > <RequestGuildMembers as Sealed>::PRIORITY);
+        assert_eq!(
+            CommandPriority::Normal,
+            <UpdatePresence as Sealed>::PRIORITY
+        );
+    }
 }
@@ -15,6 +15,7 @@ use twilight_model::gateway::{
 /// [`Shard::builder`]: super::Shard::builder
 #[derive(Clone, Debug)]
 pub struct Config {
+    pub(crate) command_ratelimit_events: bool,
     pub(crate) event_types: EventTypeFlags,
     pub(crate) gateway_url: Option<Box<str>>,
     pub(crate) http_client: Arc<Client>,
@@ -31,6 +32,13 @@ pub struct Config {
 }
 
 impl Config {
+    /// Whether the shard emits [`Event::ShardCommandRatelimited`] events.
+    ///
+    /// [`Event::ShardCommandRatelimited`]: crate::Event::ShardCommandRatelimited
+    pub const fn command_ratelimit_events(&self) -> bool {
+        self.command_ratelimit_events
+    }
+
     /// Copy of the event type flags.
     pub const fn event_types(&self) -> EventTypeFlags {
         self.event_types
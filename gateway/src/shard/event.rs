@@ -43,7 +43,7 @@ pub struct Events {
 }
 
 impl Events {
-    pub(super) const fn new(event_types: EventTypeFlags, rx: UnboundedReceiver<Event>) -> Self {
+    pub(crate) const fn new(event_types: EventTypeFlags, rx: UnboundedReceiver<Event>) -> Self {
         Self { event_types, rx }
     }
 
@@ -1,6 +1,6 @@
 use super::{
     builder::ShardBuilder,
-    command::Command,
+    command::{Command, CommandPriority},
     config::Config,
     emitter::Emitter,
     event::Events,
@@ -9,7 +9,7 @@ use super::{
     raw_message::Message,
     stage::Stage,
 };
-use crate::Intents;
+use crate::{Event, Intents};
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
@@ -25,6 +25,7 @@ use tokio::{
 use tokio_tungstenite::tungstenite::protocol::{
     frame::coding::CloseCode, CloseFrame as TungsteniteCloseFrame,
 };
+use twilight_model::gateway::event::shard::CommandRatelimited;
 
 /// Sending a command failed.
 #[derive(Debug)]
@@ -59,6 +60,7 @@ impl CommandError {
 
         let new_kind = match kind {
             SendErrorType::HeartbeaterNotStarted => CommandErrorType::HeartbeaterNotStarted,
+            SendErrorType::Ratelimited => CommandErrorType::Ratelimited,
             SendErrorType::Sending => CommandErrorType::Sending,
             SendErrorType::SessionInactive => CommandErrorType::SessionInactive,
         };
@@ -76,6 +78,9 @@ impl Display for CommandError {
             CommandErrorType::HeartbeaterNotStarted => {
                 f.write_str("heartbeater task hasn't been started yet")
             }
+            CommandErrorType::Ratelimited => {
+                f.write_str("no commands remain until the ratelimiter refills")
+            }
             CommandErrorType::Sending => {
                 f.write_str("sending the message over the websocket failed")
             }
@@ -99,6 +104,10 @@ impl Error for CommandError {
 pub enum CommandErrorType {
     /// Heartbeater task has not been started yet.
     HeartbeaterNotStarted,
+    /// Shard's command ratelimiter has no remaining budget until it refills.
+    ///
+    /// Only returned by [`Shard::try_command`].
+    Ratelimited,
     /// Sending the payload over the WebSocket failed. This is indicative of a
     /// shutdown shard.
     Sending,
@@ -156,6 +165,9 @@ impl Display for SendError {
             SendErrorType::HeartbeaterNotStarted { .. } => {
                 f.write_str("heartbeater task hasn't been started yet")
             }
+            SendErrorType::Ratelimited => {
+                f.write_str("no messages remain until the ratelimiter refills")
+            }
             SendErrorType::Sending { .. } => {
                 f.write_str("sending the message over the websocket failed")
             }
@@ -178,6 +190,10 @@ impl Error for SendError {
 pub enum SendErrorType {
     /// Heartbeater task has not been started yet.
     HeartbeaterNotStarted,
+    /// Shard's command ratelimiter has no remaining budget until it refills.
+    ///
+    /// Only returned by [`Shard::try_send`].
+    Ratelimited,
     /// Sending the payload over the WebSocket failed. This is indicative of a
     /// shard that isn't properly running.
     Sending,
@@ -267,6 +283,7 @@ pub enum ShardStartErrorType {
 pub struct Information {
     id: u64,
     latency: Latency,
+    ratelimit_queued: u32,
     ratelimit_refill: Instant,
     ratelimit_requests: u32,
     session_id: Option<Box<str>>,
@@ -288,6 +305,11 @@ impl Information {
         &self.latency
     }
 
+    /// Number of commands waiting for the ratelimiter to refill.
+    pub const fn ratelimit_queued(&self) -> u32 {
+        self.ratelimit_queued
+    }
+
     /// When the ratelimiter will next refill the [`ratelimit_requests`].
     ///
     /// [`ratelimit_requests`]: Self::ratelimit_requests
@@ -398,6 +420,9 @@ pub struct Shard {
     config: Arc<Config>,
     emitter: Mutex<Option<Emitter>>,
     processor_handle: OnceCell<JoinHandle<()>>,
+    /// Emitter used to notify of commands delayed by the ratelimiter, set once
+    /// the shard has been started if command ratelimit events are enabled.
+    ratelimit_emitter: OnceCell<Emitter>,
     session: OnceCell<WatchReceiver<Arc<Session>>>,
 }
 
@@ -446,6 +471,7 @@ impl Shard {
             config,
             emitter: Mutex::new(Some(emitter)),
             processor_handle: OnceCell::new(),
+            ratelimit_emitter: OnceCell::new(),
             session: OnceCell::new(),
         };
 
@@ -522,6 +548,10 @@ impl Shard {
                 source: None,
             })?;
 
+        if self.config.command_ratelimit_events() {
+            let _emitter = self.ratelimit_emitter.set(emitter.clone());
+        }
+
         let config = Arc::clone(&self.config);
         let (processor, wrx) =
             ShardProcessor::new(config, url, emitter)
@@ -565,14 +595,16 @@ impl Shard {
     pub fn info(&self) -> Result<Information, SessionInactiveError> {
         let session = self.session()?;
 
-        let (ratelimit_requests, ratelimit_refill) = match session.ratelimit.get() {
-            Some(limiter) => (limiter.tokens(), limiter.next_refill()),
+        let (ratelimit_requests, ratelimit_refill, ratelimit_queued) = match session.ratelimit.get()
+        {
+            Some(limiter) => (limiter.tokens(), limiter.next_refill(), limiter.queued()),
             None => return Err(SessionInactiveError),
         };
 
         Ok(Information {
             id: self.config().shard()[0],
             latency: session.heartbeats.latency(),
+            ratelimit_queued,
             ratelimit_refill,
             ratelimit_requests,
             session_id: session.id(),
@@ -625,17 +657,96 @@ impl Shard {
     ///
     /// Returns a [`CommandErrorType::SessionInactive`] error type if the shard
     /// has not been started.
-    pub async fn command(&self, value: &impl Command) -> Result<(), CommandError> {
+    pub async fn command<T: Command>(&self, value: &T) -> Result<(), CommandError> {
+        self.command_with_priority(value, T::PRIORITY).await
+    }
+
+    /// Send a command over the gateway with a given priority.
+    ///
+    /// If the shard's command ratelimiter has no remaining budget, then the
+    /// command waits for it to refill, being sent before any waiting commands
+    /// of a lower priority. Refer to [`CommandPriority`] for the priorities
+    /// used by [`command`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`command`].
+    ///
+    /// [`command`]: Self::command
+    pub async fn command_with_priority(
+        &self,
+        value: &impl Command,
+        priority: CommandPriority,
+    ) -> Result<(), CommandError> {
         let json = json::to_vec(value).map_err(|source| CommandError {
             source: Some(Box::new(source)),
             kind: CommandErrorType::Serializing,
         })?;
 
-        self.send(Message::Binary(json))
+        self.send_with_priority(Message::Binary(json), priority)
             .await
             .map_err(CommandError::from_send)
     }
 
+    /// Send a command over the gateway if the shard's command ratelimiter has
+    /// remaining budget, failing otherwise instead of waiting for it to
+    /// refill.
+    ///
+    /// # Examples
+    ///
+    /// Update the presence of the shard unless doing so would wait:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::env;
+    /// use twilight_gateway::{
+    ///     shard::{CommandErrorType, Shard},
+    ///     Intents,
+    /// };
+    /// use twilight_model::gateway::{
+    ///     payload::outgoing::UpdatePresence,
+    ///     presence::{Activity, ActivityType, MinimalActivity, Status},
+    /// };
+    ///
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let (shard, _events) = Shard::new(token, Intents::empty());
+    /// shard.start().await?;
+    ///
+    /// let activity = Activity::from(MinimalActivity {
+    ///     kind: ActivityType::Playing,
+    ///     name: "twilight".to_owned(),
+    ///     url: None,
+    /// });
+    /// let request = UpdatePresence::new(Vec::from([activity]), false, None, Status::Online)?;
+    ///
+    /// if let Err(source) = shard.try_command(&request) {
+    ///     if matches!(source.kind(), CommandErrorType::Ratelimited) {
+    ///         println!("presence not updated: no commands remain");
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CommandErrorType::Ratelimited`] error type if the shard's
+    /// command ratelimiter has no remaining budget, or if commands of a higher
+    /// priority are waiting for it to refill.
+    ///
+    /// Otherwise returns the same errors as [`command`].
+    ///
+    /// [`command`]: Self::command
+    pub fn try_command<T: Command>(&self, value: &T) -> Result<(), CommandError> {
+        let json = json::to_vec(value).map_err(|source| CommandError {
+            source: Some(Box::new(source)),
+            kind: CommandErrorType::Serializing,
+        })?;
+
+        self.try_send_with_priority(Message::Binary(json), T::PRIORITY)
+            .map_err(CommandError::from_send)
+    }
+
     /// Send a raw websocket message.
     ///
     /// # Examples
@@ -692,20 +803,101 @@ impl Shard {
     ///
     /// [`shutdown`]: Self::shutdown
     pub async fn send(&self, message: Message) -> Result<(), SendError> {
+        self.send_with_priority(message, CommandPriority::default())
+            .await
+    }
+
+    /// Send a raw websocket message if the shard's command ratelimiter has
+    /// remaining budget, failing otherwise instead of waiting for it to
+    /// refill.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SendErrorType::Ratelimited`] error type if the shard's
+    /// command ratelimiter has no remaining budget, or if commands of a higher
+    /// priority are waiting for it to refill.
+    ///
+    /// Otherwise returns the same errors as [`send`].
+    ///
+    /// [`send`]: Self::send
+    pub fn try_send(&self, message: Message) -> Result<(), SendError> {
+        self.try_send_with_priority(message, CommandPriority::default())
+    }
+
+    /// Send a raw websocket message after waiting on the ratelimiter with a
+    /// given priority.
+    async fn send_with_priority(
+        &self,
+        message: Message,
+        priority: CommandPriority,
+    ) -> Result<(), SendError> {
         let session = self.session().map_err(|source| SendError {
             source: Some(Box::new(source)),
             kind: SendErrorType::SessionInactive,
         })?;
 
-        if let Some(ratelimiter) = session.ratelimit.get() {
-            ratelimiter.acquire_one().await;
-        } else {
+        let ratelimiter = session.ratelimit.get().ok_or(SendError {
+            kind: SendErrorType::HeartbeaterNotStarted,
+            source: None,
+        })?;
+
+        if !ratelimiter.try_acquire(priority) {
+            if let Some(emitter) = self.ratelimit_emitter.get() {
+                let refill_in = ratelimiter
+                    .next_refill()
+                    .saturating_duration_since(Instant::now());
+
+                emitter.event(Event::ShardCommandRatelimited(CommandRatelimited {
+                    // Include the command that is about to wait.
+                    queued: ratelimiter.queued() + 1,
+                    refill_in: refill_in.as_millis().try_into().unwrap_or(u64::MAX),
+                    shard_id: self.config().shard()[0],
+                }));
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                shard_id = self.config().shard()[0],
+                ?priority,
+                "command delayed by ratelimiter",
+            );
+
+            ratelimiter.acquire(priority).await;
+        }
+
+        Self::send_unratelimited(&session, message)
+    }
+
+    /// Send a raw websocket message if the ratelimiter has a token available
+    /// for the given priority.
+    fn try_send_with_priority(
+        &self,
+        message: Message,
+        priority: CommandPriority,
+    ) -> Result<(), SendError> {
+        let session = self.session().map_err(|source| SendError {
+            source: Some(Box::new(source)),
+            kind: SendErrorType::SessionInactive,
+        })?;
+
+        let ratelimiter = session.ratelimit.get().ok_or(SendError {
+            kind: SendErrorType::HeartbeaterNotStarted,
+            source: None,
+        })?;
+
+        if !ratelimiter.try_acquire(priority) {
             return Err(SendError {
-                kind: SendErrorType::HeartbeaterNotStarted,
+                kind: SendErrorType::Ratelimited,
                 source: None,
             });
         }
 
+        Self::send_unratelimited(&session, message)
+    }
+
+    /// Send a raw websocket message over a session, assuming that a token has
+    /// already been acquired from the ratelimiter.
+    fn send_unratelimited(session: &Session, message: Message) -> Result<(), SendError> {
         session
             .tx
             .send(message.into_tungstenite())
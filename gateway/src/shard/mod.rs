@@ -25,10 +25,10 @@ pub mod stage;
 mod builder;
 mod command;
 mod config;
-mod emitter;
+pub(crate) mod emitter;
 mod event;
 mod r#impl;
-mod json;
+pub(crate) mod json;
 mod processor;
 pub(crate) mod tls;
 
@@ -36,7 +36,7 @@ pub use self::{
     builder::{
         LargeThresholdError, LargeThresholdErrorType, ShardBuilder, ShardIdError, ShardIdErrorType,
     },
-    command::Command,
+    command::{Command, CommandPriority},
     config::Config,
     event::Events,
     processor::heartbeat::Latency,
@@ -2,6 +2,7 @@ pub mod heartbeat;
 
 mod compression;
 mod r#impl;
+mod ratelimiter;
 mod session;
 mod socket_forwarder;
 
@@ -0,0 +1,280 @@
+//! Ratelimiter for commands sent over a shard's session.
+//!
+//! Unlike a plain leaky bucket, the ratelimiter tracks how many commands of
+//! each [`CommandPriority`] are waiting for budget so that commands of a higher
+//! priority are let through first once the bucket refills.
+
+use super::super::command::CommandPriority;
+use futures_util::future;
+use std::{sync::Mutex, time::Duration};
+use tokio::{
+    sync::Notify,
+    time::{self, Instant},
+};
+
+/// Number of distinct [`CommandPriority`] variants.
+const PRIORITIES: usize = 3;
+
+/// Interval of how often the bucket refills.
+pub const REFILL_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+struct State {
+    /// Last time the bucket was refilled.
+    last_refill: Instant,
+    /// Number of tokens the bucket is refilled to.
+    max: u32,
+    /// Number of tokens remaining until the next refill.
+    tokens: u32,
+    /// Number of waiting commands, indexed by [`CommandPriority::index`].
+    waiting: [u32; PRIORITIES],
+}
+
+impl State {
+    /// Refill the bucket if one or more refill intervals have elapsed.
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+
+        if elapsed < REFILL_INTERVAL {
+            return;
+        }
+
+        // Keep refills aligned to the interval rather than to when the bucket
+        // happened to be checked.
+        let periods = elapsed.as_millis() / REFILL_INTERVAL.as_millis();
+        let periods = u32::try_from(periods).unwrap_or(u32::MAX);
+
+        self.last_refill += REFILL_INTERVAL * periods;
+        self.tokens = self.max;
+    }
+
+    /// Whether any commands of a strictly higher priority are waiting.
+    fn higher_waiting(&self, priority: CommandPriority) -> bool {
+        self.waiting[priority.index() + 1..]
+            .iter()
+            .any(|waiting| *waiting > 0)
+    }
+
+    /// Take a token if one is available and no higher priority command is
+    /// waiting for one.
+    fn take(&mut self, priority: CommandPriority) -> bool {
+        if self.tokens == 0 || self.higher_waiting(priority) {
+            return false;
+        }
+
+        self.tokens -= 1;
+
+        true
+    }
+}
+
+/// Ratelimiter for commands sent over a shard's session.
+#[derive(Debug)]
+pub struct CommandRatelimiter {
+    /// Notifier used to wake waiting commands when a waiter has finished, so
+    /// that lower priority commands may take a turn.
+    notify: Notify,
+    state: Mutex<State>,
+}
+
+impl CommandRatelimiter {
+    /// Create a new ratelimiter allotting a number of commands per refill
+    /// interval.
+    pub fn new(max: u32) -> Self {
+        Self {
+            notify: Notify::new(),
+            state: Mutex::new(State {
+                last_refill: Instant::now(),
+                max,
+                tokens: max,
+                waiting: [0; PRIORITIES],
+            }),
+        }
+    }
+
+    /// Number of commands remaining until the next refill.
+    pub fn tokens(&self) -> u32 {
+        let mut state = self.state.lock().expect("ratelimiter poisoned");
+        state.refill(Instant::now());
+
+        state.tokens
+    }
+
+    /// When the bucket will next be refilled.
+    pub fn next_refill(&self) -> Instant {
+        let mut state = self.state.lock().expect("ratelimiter poisoned");
+        state.refill(Instant::now());
+
+        state.last_refill + REFILL_INTERVAL
+    }
+
+    /// Number of commands waiting for the bucket to refill.
+    pub fn queued(&self) -> u32 {
+        self.state
+            .lock()
+            .expect("ratelimiter poisoned")
+            .waiting
+            .iter()
+            .sum()
+    }
+
+    /// Take a token without waiting, returning whether one was available.
+    ///
+    /// A token is not taken if commands of a higher priority are waiting.
+    pub fn try_acquire(&self, priority: CommandPriority) -> bool {
+        let mut state = self.state.lock().expect("ratelimiter poisoned");
+        state.refill(Instant::now());
+
+        state.take(priority)
+    }
+
+    /// Wait for and take a token.
+    ///
+    /// The future is cancellation safe: if it is dropped before completing
+    /// then no token is taken and other waiters are no longer held back by it.
+    pub async fn acquire(&self, priority: CommandPriority) {
+        if self.try_acquire(priority) {
+            return;
+        }
+
+        let _waiter = Waiter::new(self, priority);
+
+        loop {
+            // Create the notification before checking the state so that
+            // notifications sent in between aren't missed.
+            let notified = self.notify.notified();
+
+            let next_refill = {
+                let mut state = self.state.lock().expect("ratelimiter poisoned");
+                state.refill(Instant::now());
+
+                if state.take(priority) {
+                    return;
+                }
+
+                state.last_refill + REFILL_INTERVAL
+            };
+
+            let sleep = time::sleep_until(next_refill);
+            futures_util::pin_mut!(notified, sleep);
+
+            // Either another waiter has finished or the bucket has refilled,
+            // so check the state again.
+            future::select(notified, sleep).await;
+        }
+    }
+}
+
+/// Registration of a waiting command, removed when dropped.
+struct Waiter<'a> {
+    priority: CommandPriority,
+    ratelimiter: &'a CommandRatelimiter,
+}
+
+impl<'a> Waiter<'a> {
+    fn new(ratelimiter: &'a CommandRatelimiter, priority: CommandPriority) -> Self {
+        ratelimiter
+            .state
+            .lock()
+            .expect("ratelimiter poisoned")
+            .waiting[priority.index()] += 1;
+
+        Self {
+            priority,
+            ratelimiter,
+        }
+    }
+}
+
+impl Drop for Waiter<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.ratelimiter.state.lock() {
+            state.waiting[self.priority.index()] -= 1;
+        }
+
+        self.ratelimiter.notify.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandRatelimiter, REFILL_INTERVAL};
+    use crate::shard::CommandPriority;
+    use std::{sync::Arc, time::Duration};
+    use tokio::time;
+
+    #[tokio::test]
+    async fn test_try_acquire() {
+        let ratelimiter = CommandRatelimiter::new(2);
+        assert_eq!(2, ratelimiter.tokens());
+
+        assert!(ratelimiter.try_acquire(CommandPriority::Normal));
+        assert!(ratelimiter.try_acquire(CommandPriority::Low));
+        assert!(!ratelimiter.try_acquire(CommandPriority::High));
+        assert_eq!(0, ratelimiter.tokens());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_refill() {
+        let ratelimiter = CommandRatelimiter::new(1);
+        assert!(ratelimiter.try_acquire(CommandPriority::Normal));
+        assert!(!ratelimiter.try_acquire(CommandPriority::Normal));
+
+        time::advance(REFILL_INTERVAL).await;
+
+        assert_eq!(1, ratelimiter.tokens());
+        assert!(ratelimiter.try_acquire(CommandPriority::Normal));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_priority_order() {
+        let ratelimiter = Arc::new(CommandRatelimiter::new(1));
+        assert!(ratelimiter.try_acquire(CommandPriority::Normal));
+
+        let low = tokio::spawn({
+            let ratelimiter = Arc::clone(&ratelimiter);
+
+            async move { ratelimiter.acquire(CommandPriority::Low).await }
+        });
+        time::sleep(Duration::from_millis(1)).await;
+
+        let high = tokio::spawn({
+            let ratelimiter = Arc::clone(&ratelimiter);
+
+            async move { ratelimiter.acquire(CommandPriority::High).await }
+        });
+        time::sleep(Duration::from_millis(1)).await;
+
+        assert_eq!(2, ratelimiter.queued());
+
+        // Only the high priority command may take the single refilled token.
+        time::sleep(REFILL_INTERVAL).await;
+        high.await.unwrap();
+        assert_eq!(1, ratelimiter.queued());
+
+        time::sleep(REFILL_INTERVAL).await;
+        low.await.unwrap();
+        assert_eq!(0, ratelimiter.queued());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cancelled_waiter_released() {
+        let ratelimiter = Arc::new(CommandRatelimiter::new(1));
+        assert!(ratelimiter.try_acquire(CommandPriority::Normal));
+
+        let high = tokio::spawn({
+            let ratelimiter = Arc::clone(&ratelimiter);
+
+            async move { ratelimiter.acquire(CommandPriority::High).await }
+        });
+        time::sleep(Duration::from_millis(1)).await;
+        assert_eq!(1, ratelimiter.queued());
+
+        high.abort();
+        let _res = high.await;
+
+        assert_eq!(0, ratelimiter.queued());
+        time::sleep(REFILL_INTERVAL).await;
+        assert!(ratelimiter.try_acquire(CommandPriority::Low));
+    }
+}
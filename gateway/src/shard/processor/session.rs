@@ -1,8 +1,8 @@
 use super::{
     super::{json, stage::Stage},
     heartbeat::{Heartbeater, Heartbeats},
+    ratelimiter::{CommandRatelimiter, REFILL_INTERVAL},
 };
-use leaky_bucket_lite::LeakyBucket;
 use serde::ser::Serialize;
 use std::{
     error::Error,
@@ -11,7 +11,6 @@ use std::{
         atomic::{AtomicU64, AtomicU8, Ordering},
         Arc, Mutex as MutexSync,
     },
-    time::Duration,
 };
 use tokio::{
     sync::{
@@ -23,9 +22,6 @@ use tokio::{
 use tokio_tungstenite::tungstenite::{protocol::CloseFrame, Message as TungsteniteMessage};
 use twilight_model::gateway::payload::outgoing::Heartbeat;
 
-// Interval of how often the ratelimit bucket resets, in milliseconds.
-const RESET_DURATION_MILLISECONDS: u64 = 60_000;
-
 #[derive(Debug)]
 pub struct SessionSendError {
     pub(super) source: Option<Box<dyn Error + Send + Sync>>,
@@ -72,7 +68,7 @@ pub struct Session {
     pub seq: Arc<AtomicU64>,
     pub stage: AtomicU8,
     pub tx: UnboundedSender<TungsteniteMessage>,
-    pub ratelimit: OnceCell<LeakyBucket>,
+    pub ratelimit: OnceCell<CommandRatelimiter>,
 }
 
 impl Session {
@@ -127,9 +123,6 @@ impl Session {
     }
 
     pub fn set_heartbeat_interval(&self, new_heartbeat_interval: u64) {
-        // Interval of how often to refill the bucket.
-        const REFILL_INTERVAL: Duration = Duration::from_millis(RESET_DURATION_MILLISECONDS);
-
         self.heartbeat_interval
             .store(new_heartbeat_interval, Ordering::Release);
 
@@ -137,14 +130,9 @@ impl Session {
         let commands_allotted = u32::from(available_commands_per_interval(new_heartbeat_interval));
 
         // We can ignore an error if the ratelimiter has already been set.
-        let _result = self.ratelimit.set(
-            LeakyBucket::builder()
-                .max(commands_allotted)
-                .tokens(commands_allotted)
-                .refill_interval(REFILL_INTERVAL)
-                .refill_amount(commands_allotted)
-                .build(),
-        );
+        let _result = self
+            .ratelimit
+            .set(CommandRatelimiter::new(commands_allotted));
     }
 
     /// Returns the current sequence.
@@ -230,6 +218,10 @@ fn available_commands_per_interval(heartbeat_interval: u64) -> u8 {
     // <https://discord.com/developers/docs/topics/gateway#rate-limiting>
     const COMMANDS_PER_RESET: u8 = 120;
 
+    // Interval of how often the ratelimit bucket resets, in milliseconds.
+    #[allow(clippy::cast_possible_truncation)]
+    const RESET_DURATION_MILLISECONDS: u64 = REFILL_INTERVAL.as_millis() as u64;
+
     let mut heartbeats = RESET_DURATION_MILLISECONDS / heartbeat_interval;
     let remainder = RESET_DURATION_MILLISECONDS % heartbeat_interval;
 
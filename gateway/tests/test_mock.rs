@@ -0,0 +1,153 @@
+use futures_util::stream::StreamExt;
+use serde_json::json;
+use std::{sync::Arc, time::Duration};
+use tokio::{net::TcpListener, time};
+use twilight_gateway::{
+    mock::{MockGateway, Script},
+    queue::NoOpQueue,
+    shard::{Events, Shard},
+    Event, EventTypeFlags, Intents,
+};
+use twilight_model::gateway::{CloseCode, OpCode};
+
+async fn start(gateway: &MockGateway) -> (Shard, Events) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn({
+        let gateway = gateway.clone();
+
+        async move { gateway.serve(listener).await }
+    });
+
+    let (shard, events) = Shard::builder("token".to_owned(), Intents::GUILDS)
+        .event_types(EventTypeFlags::default())
+        .gateway_url(Some(format!("ws://{}", address)))
+        .queue(Arc::new(NoOpQueue))
+        .build();
+    shard.start().await.unwrap();
+
+    (shard, events)
+}
+
+async fn next_matching(events: &mut Events, f: impl Fn(&Event) -> bool) -> Event {
+    time::timeout(Duration::from_secs(10), async {
+        loop {
+            let event = events.next().await.unwrap();
+
+            if f(&event) {
+                return event;
+            }
+        }
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_identify_dispatch() {
+    let gateway = MockGateway::new([Script::new().identify().dispatch(
+        "GUILD_ROLE_DELETE",
+        json!({ "guild_id": "1", "role_id": "2" }),
+    )]);
+    let (shard, mut events) = start(&gateway).await;
+
+    let ready = next_matching(&mut events, |event| matches!(event, Event::Ready(_))).await;
+    assert!(matches!(ready, Event::Ready(ready) if ready.session_id == "mock-session-0"));
+    next_matching(&mut events, |event| matches!(event, Event::RoleDelete(_))).await;
+
+    let received = gateway.received();
+    assert_eq!(OpCode::Identify as u8, received[0].op());
+    assert_eq!(json!("Bot token"), received[0].data()["token"]);
+
+    shard.shutdown();
+}
+
+#[tokio::test]
+async fn test_resume_after_close() {
+    let gateway = MockGateway::new([
+        Script::new()
+            .identify()
+            .dispatch(
+                "GUILD_ROLE_DELETE",
+                json!({ "guild_id": "1", "role_id": "2" }),
+            )
+            .close(CloseCode::UnknownError),
+        Script::new().resume(),
+    ]);
+    let (shard, mut events) = start(&gateway).await;
+
+    let disconnected = next_matching(&mut events, |event| {
+        matches!(event, Event::ShardDisconnected(_))
+    })
+    .await;
+    assert!(matches!(
+        disconnected,
+        Event::ShardDisconnected(disconnected) if disconnected.code == Some(4000)
+    ));
+    next_matching(&mut events, |event| matches!(event, Event::Resumed)).await;
+
+    let resume = gateway
+        .received()
+        .into_iter()
+        .find(|payload| payload.op() == OpCode::Resume as u8)
+        .unwrap();
+    assert_eq!(1, resume.connection());
+    assert_eq!(json!("mock-session-0"), resume.data()["session_id"]);
+    assert_eq!(json!(2), resume.data()["seq"]);
+
+    shard.shutdown();
+}
+
+#[tokio::test]
+async fn test_reconnect_opcode() {
+    let gateway = MockGateway::new([Script::new().identify().reconnect(), Script::new().resume()]);
+    let (shard, mut events) = start(&gateway).await;
+
+    next_matching(&mut events, |event| {
+        matches!(event, Event::GatewayReconnect)
+    })
+    .await;
+    next_matching(&mut events, |event| matches!(event, Event::Resumed)).await;
+    assert_eq!(2, gateway.connections());
+
+    shard.shutdown();
+}
+
+#[tokio::test]
+async fn test_invalid_session() {
+    let gateway = MockGateway::new([
+        Script::new().identify().invalid_session(false),
+        Script::new().identify(),
+    ]);
+    let (shard, mut events) = start(&gateway).await;
+
+    next_matching(&mut events, |event| {
+        matches!(event, Event::GatewayInvalidateSession(false))
+    })
+    .await;
+    let ready = next_matching(&mut events, |event| matches!(event, Event::Ready(_))).await;
+    assert!(matches!(ready, Event::Ready(ready) if ready.session_id == "mock-session-1"));
+
+    shard.shutdown();
+}
+
+#[tokio::test]
+async fn test_fatal_close_code() {
+    let gateway = MockGateway::new([Script::new()
+        .receive(OpCode::Identify)
+        .close(CloseCode::AuthenticationFailed)]);
+    let (_shard, mut events) = start(&gateway).await;
+
+    let disconnected = next_matching(&mut events, |event| {
+        matches!(event, Event::ShardDisconnected(_))
+    })
+    .await;
+    assert!(matches!(
+        disconnected,
+        Event::ShardDisconnected(disconnected) if disconnected.code == Some(4004)
+    ));
+
+    // The shard stops without reconnecting once authentication fails.
+    time::sleep(Duration::from_secs(2)).await;
+    assert_eq!(1, gateway.connections());
+}
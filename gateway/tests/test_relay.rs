@@ -0,0 +1,83 @@
+use futures_util::stream::StreamExt;
+use std::{sync::Arc, time::Duration};
+use tokio::{net::TcpListener, time};
+use twilight_gateway::{
+    queue::NoOpQueue,
+    relay::Relay,
+    shard::{Events, Shard},
+    Event, Intents,
+};
+use twilight_model::{gateway::event::shard::Payload, id::Id};
+
+const READY: &str = r#"{"op":0,"s":1,"t":"READY","d":{
+    "application":{"flags":0,"id":"100"},
+    "guilds":[{"id":"1","unavailable":true}],
+    "session_id":"upstream",
+    "shard":[0,1],
+    "user":{"avatar":null,"bot":true,"discriminator":"0001","id":"3","mfa_enabled":false,"username":"twilight"},
+    "v":9
+}}"#;
+
+const ROLE_DELETE: &str =
+    r#"{"op":0,"s":2,"t":"GUILD_ROLE_DELETE","d":{"guild_id":"1","role_id":"2"}}"#;
+
+fn payload(json: &str) -> Event {
+    Event::ShardPayload(Payload {
+        bytes: json.as_bytes().to_vec(),
+    })
+}
+
+async fn next_matching(events: &mut Events, f: impl Fn(&Event) -> bool) -> Event {
+    time::timeout(Duration::from_secs(10), async {
+        loop {
+            let event = events.next().await.unwrap();
+
+            if f(&event) {
+                return event;
+            }
+        }
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_relay_session() {
+    let relay = Relay::new();
+    relay.process(0, &payload(READY));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn({
+        let relay = relay.clone();
+
+        async move { relay.serve(listener).await }
+    });
+
+    let (shard, mut events) = Shard::builder("token".to_owned(), Intents::GUILDS)
+        .gateway_url(Some(format!("ws://{}", address)))
+        .queue(Arc::new(NoOpQueue))
+        .build();
+    shard.start().await.unwrap();
+
+    let ready = next_matching(&mut events, |event| matches!(event, Event::Ready(_))).await;
+
+    if let Event::Ready(ready) = ready {
+        assert_eq!(Some([0, 1]), ready.shard);
+        assert_ne!("upstream", ready.session_id);
+        assert_eq!(1, ready.guilds.len());
+    }
+
+    assert_eq!(1, relay.connections());
+
+    relay.process(0, &payload(ROLE_DELETE));
+    let role_delete =
+        next_matching(&mut events, |event| matches!(event, Event::RoleDelete(_))).await;
+
+    if let Event::RoleDelete(role_delete) = role_delete {
+        assert_eq!(Id::new(1), role_delete.guild_id);
+        assert_eq!(Id::new(2), role_delete.role_id);
+    }
+
+    shard.shutdown();
+}
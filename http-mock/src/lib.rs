@@ -0,0 +1,108 @@
+//! # twilight-http-mock
+//!
+//! [![codecov badge][]][codecov link] [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-http-mock` is an in-memory fake of the Discord REST API for
+//! testing code using [`twilight-http`], part of the [`twilight-rs`]
+//! ecosystem.
+//!
+//! The [`MockServer`] serves the routes of guilds, channels, messages,
+//! members, roles, reactions, and webhooks on a local port. Clients target it
+//! via [`ClientBuilder::proxy`], so requests go through the same request and
+//! response paths, including ratelimiting, as they would with Discord.
+//! Responses carry ratelimit headers of per-route buckets, and failed
+//! requests are responded to with Discord's error codes and messages.
+//!
+//! Permissions aren't checked, and only text channels are supported.
+//!
+//! ## Features
+//!
+//! ### TLS
+//!
+//! `twilight-http-mock` has features to enable [`twilight-http`]'s TLS
+//! features, which are required to build a client. These features are
+//! mutually exclusive. `rustls-native-roots` is enabled by default.
+//!
+//! #### `native`
+//!
+//! The `native` feature enables [`twilight-http`]'s `native` feature.
+//!
+//! #### `rustls-native-roots`
+//!
+//! The `rustls-native-roots` feature enables [`twilight-http`]'s
+//! `rustls-native-roots` feature.
+//!
+//! This is enabled by default.
+//!
+//! #### `rustls-webpki-roots`
+//!
+//! The `rustls-webpki-roots` feature enables [`twilight-http`]'s
+//! `rustls-webpki-roots` feature.
+//!
+//! ## Examples
+//!
+//! Test that a message is sent to a channel:
+//!
+//! ```
+//! use twilight_http_mock::MockServer;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = MockServer::new()?;
+//! let guild_id = server.add_guild("twilight");
+//! let channel_id = server.add_channel(guild_id, "general");
+//!
+//! let client = server.client();
+//! client
+//!     .create_message(channel_id)
+//!     .content("hello")?
+//!     .exec()
+//!     .await?;
+//!
+//! assert_eq!("hello", server.messages(channel_id)[0].content);
+//! # Ok(()) }
+//! ```
+//!
+//! [`ClientBuilder::proxy`]: twilight_http::client::ClientBuilder::proxy
+//! [`twilight-http`]: https://twilight.rs/chapter_1_crates/section_2_http.html
+//! [`twilight-rs`]: https://github.com/twilight-rs/twilight
+//! [codecov badge]: https://img.shields.io/codecov/c/gh/twilight-rs/twilight?logo=codecov&style=for-the-badge&token=E9ERLJL0L2
+//! [codecov link]: https://app.codecov.io/gh/twilight-rs/twilight/
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/main/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.57+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    rustdoc::broken_intra_doc_links,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+mod ratelimit;
+mod route;
+mod server;
+mod state;
+
+pub use self::server::{MockServer, MockServerBuilder, MockServerError, MockServerErrorType};
+
+#[cfg(not(any(
+    feature = "native",
+    feature = "rustls-native-roots",
+    feature = "rustls-webpki-roots"
+)))]
+compile_error!(
+    "Either the `native`, `rustls-native-roots` or `rustls-webpki-roots` feature must be enabled."
+);
@@ -0,0 +1,205 @@
+//! Ratelimit buckets of a mock server.
+//!
+//! Requests are sorted into buckets like Discord does: by their route, with
+//! the IDs of resources replaced, except for the top-level channel, guild, or
+//! webhook ID. Each bucket allows a number of requests per period, after which
+//! requests are ratelimited until the bucket resets.
+
+use hyper::{header::HeaderValue, HeaderMap};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// Ratelimiter of a mock server.
+pub(crate) struct Ratelimiter {
+    buckets: HashMap<String, Bucket>,
+    limit: u16,
+    period: Duration,
+}
+
+/// Bucket of requests to the same route and top-level resource.
+struct Bucket {
+    remaining: u16,
+    reset_at: Instant,
+}
+
+/// Outcome of taking a request from a bucket.
+pub(crate) struct Taken {
+    /// Ratelimit headers to send with the response.
+    pub headers: HeaderMap,
+    /// Duration until the bucket resets, if the request is ratelimited.
+    pub limited: Option<Duration>,
+}
+
+impl Ratelimiter {
+    /// Create a new ratelimiter allowing a number of requests per bucket in a
+    /// period.
+    pub fn new(limit: u16, period: Duration) -> Self {
+        Self {
+            buckets: HashMap::new(),
+            limit,
+            period,
+        }
+    }
+
+    /// Take a request to a path from its bucket, returning the headers to
+    /// send with the response.
+    pub fn take(&mut self, method: &str, path: &[&str]) -> Taken {
+        let (route, major) = route(path);
+        let now = Instant::now();
+
+        let bucket = self
+            .buckets
+            .entry(format!("{} {} {}", method, route, major))
+            .or_insert(Bucket {
+                remaining: self.limit,
+                reset_at: now + self.period,
+            });
+
+        if bucket.reset_at <= now {
+            bucket.remaining = self.limit;
+            bucket.reset_at = now + self.period;
+        }
+
+        let reset_after = bucket.reset_at - now;
+        let limited = if bucket.remaining == 0 {
+            Some(reset_after)
+        } else {
+            bucket.remaining -= 1;
+
+            None
+        };
+
+        let mut hasher = DefaultHasher::new();
+        (method, &route).hash(&mut hasher);
+
+        let reset = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            + reset_after;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-ratelimit-bucket",
+            header(format!("{:016x}", hasher.finish())),
+        );
+        headers.insert("x-ratelimit-limit", HeaderValue::from(self.limit));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from(bucket.remaining));
+        headers.insert(
+            "x-ratelimit-reset",
+            header(format!("{:.3}", reset.as_secs_f64())),
+        );
+        headers.insert(
+            "x-ratelimit-reset-after",
+            header(format!("{:.3}", reset_after.as_secs_f64())),
+        );
+
+        if let Some(retry_after) = limited {
+            let secs = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+
+            headers.insert("retry-after", HeaderValue::from(secs));
+            headers.insert("x-ratelimit-scope", HeaderValue::from_static("user"));
+        }
+
+        Taken { headers, limited }
+    }
+}
+
+/// Route of a path and its top-level resource ID.
+///
+/// IDs, emojis, and tokens in the path are replaced with placeholders, except
+/// for the ID following a top-level channel, guild, or webhook segment.
+fn route<'a>(path: &[&'a str]) -> (String, &'a str) {
+    let major = match path {
+        ["channels" | "guilds" | "webhooks", id, ..] => *id,
+        _ => "",
+    };
+
+    let route = path
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            let previous = index.checked_sub(1).map(|index| path[index]);
+
+            if index == 1 && !major.is_empty() {
+                "{major}"
+            } else if previous == Some("reactions") && *segment != "@me" {
+                "{emoji}"
+            } else if index == 2 && path[0] == "webhooks" {
+                "{token}"
+            } else if segment.bytes().all(|byte| byte.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    (route, major)
+}
+
+fn header(value: String) -> HeaderValue {
+    HeaderValue::try_from(value).expect("formatted header is valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{route, Ratelimiter};
+    use std::time::Duration;
+
+    #[test]
+    fn test_route() {
+        assert_eq!(
+            ("channels/{major}/messages/{id}".to_owned(), "1"),
+            route(&["channels", "1", "messages", "2"])
+        );
+        assert_eq!(
+            (
+                "channels/{major}/messages/{id}/reactions/{emoji}/@me".to_owned(),
+                "1"
+            ),
+            route(&[
+                "channels",
+                "1",
+                "messages",
+                "2",
+                "reactions",
+                "%F0%9F%91%8D",
+                "@me"
+            ])
+        );
+        assert_eq!(
+            ("webhooks/{major}/{token}".to_owned(), "1"),
+            route(&["webhooks", "1", "token"])
+        );
+        assert_eq!(("users/@me".to_owned(), ""), route(&["users", "@me"]));
+    }
+
+    #[test]
+    fn test_take() {
+        let mut ratelimiter = Ratelimiter::new(2, Duration::from_secs(60));
+        let path = ["channels", "1", "messages"];
+
+        let first = ratelimiter.take("GET", &path);
+        assert!(first.limited.is_none());
+        assert_eq!("1", first.headers["x-ratelimit-remaining"]);
+        assert_eq!("2", first.headers["x-ratelimit-limit"]);
+
+        assert!(ratelimiter.take("GET", &path).limited.is_none());
+
+        let limited = ratelimiter.take("GET", &path);
+        assert!(limited.limited.is_some());
+        assert_eq!("60", limited.headers["retry-after"]);
+
+        // Another top-level resource is in another bucket, sharing the hash.
+        let other = ratelimiter.take("GET", &["channels", "2", "messages"]);
+        assert!(other.limited.is_none());
+        assert_eq!(
+            first.headers["x-ratelimit-bucket"],
+            other.headers["x-ratelimit-bucket"]
+        );
+    }
+}
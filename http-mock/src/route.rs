@@ -0,0 +1,911 @@
+//! Handlers of the routes of a mock server.
+
+use crate::state::{self, State};
+use hyper::{Method, StatusCode};
+use percent_encoding::percent_decode_str;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Map, Value};
+use std::{cmp::Reverse, str::FromStr};
+use twilight_model::{
+    channel::{
+        message::{Message, MessageReaction, MessageType},
+        webhook::{Webhook, WebhookType},
+        ChannelType, ReactionType, TextChannel,
+    },
+    datetime::Timestamp,
+    guild::{Permissions, Role},
+    id::{
+        marker::{
+            ChannelMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker, WebhookMarker,
+        },
+        Id,
+    },
+    user::User,
+};
+
+/// Response to a request.
+pub(crate) enum Reply {
+    /// Respond with a JSON body.
+    Json(Vec<u8>),
+    /// Respond without a body.
+    NoContent,
+}
+
+/// Failed request, responded to with a Discord error body.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Failure {
+    pub code: u64,
+    pub message: &'static str,
+    pub status: StatusCode,
+}
+
+impl Failure {
+    const fn new(status: StatusCode, code: u64, message: &'static str) -> Self {
+        Self {
+            code,
+            message,
+            status,
+        }
+    }
+
+    /// Serialize the failure as a Discord error body.
+    pub fn body(self) -> Vec<u8> {
+        serde_json::to_vec(&json!({
+            "code": self.code,
+            "message": self.message,
+        }))
+        .expect("error body is valid")
+    }
+}
+
+pub(crate) const UNAUTHORIZED: Failure =
+    Failure::new(StatusCode::UNAUTHORIZED, 0, "401: Unauthorized");
+const NOT_FOUND: Failure = Failure::new(StatusCode::NOT_FOUND, 0, "404: Not Found");
+const UNKNOWN_CHANNEL: Failure = Failure::new(StatusCode::NOT_FOUND, 10003, "Unknown Channel");
+const UNKNOWN_GUILD: Failure = Failure::new(StatusCode::NOT_FOUND, 10004, "Unknown Guild");
+const UNKNOWN_MEMBER: Failure = Failure::new(StatusCode::NOT_FOUND, 10007, "Unknown Member");
+const UNKNOWN_MESSAGE: Failure = Failure::new(StatusCode::NOT_FOUND, 10008, "Unknown Message");
+const UNKNOWN_ROLE: Failure = Failure::new(StatusCode::NOT_FOUND, 10011, "Unknown Role");
+const UNKNOWN_USER: Failure = Failure::new(StatusCode::NOT_FOUND, 10013, "Unknown User");
+const UNKNOWN_EMOJI: Failure = Failure::new(StatusCode::NOT_FOUND, 10014, "Unknown Emoji");
+const UNKNOWN_WEBHOOK: Failure = Failure::new(StatusCode::NOT_FOUND, 10015, "Unknown Webhook");
+const CANNOT_EDIT_OTHER: Failure = Failure::new(
+    StatusCode::FORBIDDEN,
+    50005,
+    "Cannot edit a message authored by another user",
+);
+const EMPTY_MESSAGE: Failure = Failure::new(
+    StatusCode::BAD_REQUEST,
+    50006,
+    "Cannot send an empty message",
+);
+const BULK_DELETE_TOO_OLD: Failure = Failure::new(
+    StatusCode::BAD_REQUEST,
+    50034,
+    "You can only bulk delete messages that are under 14 days old.",
+);
+const INVALID_WEBHOOK_TOKEN: Failure =
+    Failure::new(StatusCode::UNAUTHORIZED, 50027, "Invalid Webhook Token");
+const INVALID_FORM_BODY: Failure =
+    Failure::new(StatusCode::BAD_REQUEST, 50035, "Invalid Form Body");
+const INVALID_JSON: Failure = Failure::new(
+    StatusCode::BAD_REQUEST,
+    50109,
+    "The request body contains invalid JSON.",
+);
+
+type Result<T, E = Failure> = std::result::Result<T, E>;
+
+/// Query parameters of a request.
+pub(crate) struct Query(Vec<(String, String)>);
+
+impl Query {
+    /// Parse a query string.
+    pub fn parse(query: Option<&str>) -> Self {
+        let pairs = query
+            .unwrap_or_default()
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                let value = percent_decode_str(value).decode_utf8_lossy().into_owned();
+
+                (key.to_owned(), value)
+            })
+            .collect();
+
+        Self(pairs)
+    }
+
+    /// Parse a parameter, if present.
+    fn get<T: FromStr>(&self, key: &str) -> Result<Option<T>> {
+        self.0
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.parse().map_err(|_| INVALID_FORM_BODY))
+            .transpose()
+    }
+
+    /// Parse a limit parameter, ensuring it's within a range.
+    fn limit(&self, default: usize, max: usize) -> Result<usize> {
+        match self.get("limit")? {
+            Some(limit) if (1..=max).contains(&limit) => Ok(limit),
+            Some(_) => Err(INVALID_FORM_BODY),
+            None => Ok(default),
+        }
+    }
+}
+
+/// JSON object body of a request.
+struct Body(Map<String, Value>);
+
+impl Body {
+    fn parse(body: &[u8]) -> Result<Self> {
+        if body.is_empty() {
+            return Ok(Self(Map::new()));
+        }
+
+        serde_json::from_slice(body)
+            .map(Self)
+            .map_err(|_| INVALID_JSON)
+    }
+
+    /// Deserialize a field, if present.
+    fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        self.0
+            .get(key)
+            .map(|value| serde_json::from_value(value.clone()).map_err(|_| INVALID_FORM_BODY))
+            .transpose()
+    }
+}
+
+/// Whether a request to a path requires authorization.
+///
+/// Requests with a webhook's token are authorized by the token.
+pub(crate) fn requires_authorization(path: &[&str]) -> bool {
+    !matches!(path, ["webhooks", _, _, ..])
+}
+
+/// Handle a request, returning its reply.
+pub(crate) fn handle(
+    state: &mut State,
+    method: &Method,
+    path: &[&str],
+    query: &Query,
+    body: &[u8],
+) -> Result<Reply> {
+    match (method, path) {
+        (&Method::GET, ["users", "@me"]) => json(&state.current_user),
+        (&Method::GET, ["users", user_id]) => {
+            let user_id = id(user_id, UNKNOWN_USER)?;
+
+            json(state.users.get(&user_id).ok_or(UNKNOWN_USER)?)
+        }
+        (_, ["guilds", guild_id, rest @ ..]) => {
+            let guild_id = id(guild_id, UNKNOWN_GUILD)?;
+
+            if !state.guilds.contains_key(&guild_id) {
+                return Err(UNKNOWN_GUILD);
+            }
+
+            guild(state, method, guild_id, rest, query, body)
+        }
+        (_, ["channels", channel_id, rest @ ..]) => {
+            let channel_id = id(channel_id, UNKNOWN_CHANNEL)?;
+
+            if !state.channels.contains_key(&channel_id) {
+                return Err(UNKNOWN_CHANNEL);
+            }
+
+            channel(state, method, channel_id, rest, query, body)
+        }
+        (_, ["webhooks", webhook_id, rest @ ..]) => {
+            webhook(state, method, webhook_id, rest, query, body)
+        }
+        _ => Err(NOT_FOUND),
+    }
+}
+
+fn guild(
+    state: &mut State,
+    method: &Method,
+    guild_id: Id<GuildMarker>,
+    path: &[&str],
+    query: &Query,
+    body: &[u8],
+) -> Result<Reply> {
+    match (method, path) {
+        (&Method::GET, []) => json(&state.guilds[&guild_id]),
+        (&Method::GET, ["channels"]) => {
+            let mut channels = state
+                .channels
+                .values()
+                .filter(|channel| channel.guild_id == Some(guild_id))
+                .collect::<Vec<_>>();
+            channels.sort_by_key(|channel| (channel.position, channel.id));
+
+            json(&channels)
+        }
+        (&Method::GET, ["members"]) => {
+            let after = query.get::<u64>("after")?.unwrap_or_default();
+            let limit = query.limit(1, 1000)?;
+
+            let members = state
+                .members
+                .range((guild_id, Id::new(after.max(1)))..)
+                .take_while(|((id, _), _)| *id == guild_id)
+                .filter(|((_, user_id), _)| user_id.get() > after)
+                .map(|(_, member)| member)
+                .take(limit)
+                .collect::<Vec<_>>();
+
+            json(&members)
+        }
+        (_, ["members", user_id, rest @ ..]) => {
+            let key = (guild_id, id(user_id, UNKNOWN_MEMBER)?);
+
+            if !state.members.contains_key(&key) {
+                return Err(UNKNOWN_MEMBER);
+            }
+
+            member(state, method, key, rest, body)
+        }
+        (&Method::GET, ["roles"]) => json(&state.guilds[&guild_id].roles),
+        (&Method::POST, ["roles"]) => {
+            let body = Body::parse(body)?;
+            let id = state.next_id();
+            let guild = state.guilds.get_mut(&guild_id).expect("guild exists");
+
+            let mut role = Role {
+                color: 0,
+                hoist: false,
+                icon: None,
+                id,
+                managed: false,
+                mentionable: false,
+                name: "new role".to_owned(),
+                permissions: Permissions::empty(),
+                position: 1,
+                tags: None,
+                unicode_emoji: None,
+            };
+            update_role(&mut role, &body)?;
+
+            for role in &mut guild.roles {
+                if role.position > 0 {
+                    role.position += 1;
+                }
+            }
+
+            guild.roles.push(role.clone());
+
+            json(&role)
+        }
+        (_, ["roles", role_id]) => {
+            let role_id = id::<RoleMarker>(role_id, UNKNOWN_ROLE)?;
+            let guild = state.guilds.get_mut(&guild_id).expect("guild exists");
+            let index = guild
+                .roles
+                .iter()
+                .position(|role| role.id == role_id)
+                .ok_or(UNKNOWN_ROLE)?;
+
+            match *method {
+                Method::DELETE => {
+                    guild.roles.remove(index);
+
+                    for (_, member) in state.members.range_mut((guild_id, Id::new(1))..) {
+                        if member.guild_id != guild_id {
+                            break;
+                        }
+
+                        member.roles.retain(|id| *id != role_id);
+                    }
+
+                    Ok(Reply::NoContent)
+                }
+                Method::PATCH => {
+                    update_role(&mut guild.roles[index], &Body::parse(body)?)?;
+
+                    json(&guild.roles[index])
+                }
+                _ => Err(NOT_FOUND),
+            }
+        }
+        _ => Err(NOT_FOUND),
+    }
+}
+
+fn member(
+    state: &mut State,
+    method: &Method,
+    key: (Id<GuildMarker>, Id<UserMarker>),
+    path: &[&str],
+    body: &[u8],
+) -> Result<Reply> {
+    match (method, path) {
+        (&Method::GET, []) => json(&state.members[&key]),
+        (&Method::DELETE, []) => {
+            state.members.remove(&key);
+
+            Ok(Reply::NoContent)
+        }
+        (&Method::PATCH, []) => {
+            let body = Body::parse(body)?;
+            let roles = body.get::<Vec<Id<RoleMarker>>>("roles")?;
+
+            if let Some(roles) = &roles {
+                let guild_roles = &state.guilds[&key.0].roles;
+
+                if !roles
+                    .iter()
+                    .all(|id| guild_roles.iter().any(|role| role.id == *id))
+                {
+                    return Err(UNKNOWN_ROLE);
+                }
+            }
+
+            let member = state.members.get_mut(&key).expect("member exists");
+
+            if let Some(nick) = body.get::<Option<String>>("nick")? {
+                member.nick = nick;
+            }
+
+            if let Some(roles) = roles {
+                member.roles = roles;
+            }
+
+            if let Some(deaf) = body.get("deaf")? {
+                member.deaf = deaf;
+            }
+
+            if let Some(mute) = body.get("mute")? {
+                member.mute = mute;
+            }
+
+            if let Some(until) = body.get::<Option<Timestamp>>("communication_disabled_until")? {
+                member.communication_disabled_until = until;
+            }
+
+            json(member)
+        }
+        (&Method::PUT | &Method::DELETE, ["roles", role_id]) => {
+            let role_id = id::<RoleMarker>(role_id, UNKNOWN_ROLE)?;
+
+            if !state.guilds[&key.0]
+                .roles
+                .iter()
+                .any(|role| role.id == role_id)
+            {
+                return Err(UNKNOWN_ROLE);
+            }
+
+            let member = state.members.get_mut(&key).expect("member exists");
+            member.roles.retain(|id| *id != role_id);
+
+            if *method == Method::PUT {
+                member.roles.push(role_id);
+            }
+
+            Ok(Reply::NoContent)
+        }
+        _ => Err(NOT_FOUND),
+    }
+}
+
+fn channel(
+    state: &mut State,
+    method: &Method,
+    channel_id: Id<ChannelMarker>,
+    path: &[&str],
+    query: &Query,
+    body: &[u8],
+) -> Result<Reply> {
+    match (method, path) {
+        (&Method::GET, []) => json(&state.channels[&channel_id]),
+        (&Method::DELETE, []) => {
+            let channel = state.channels.remove(&channel_id).expect("channel exists");
+            state
+                .messages
+                .retain(|_, message| message.channel_id != channel_id);
+
+            json(&channel)
+        }
+        (&Method::GET, ["messages"]) => messages(state, channel_id, query),
+        (&Method::POST, ["messages"]) => {
+            let body = Body::parse(body)?;
+            let author = state.current_user.clone();
+            let message = create_message(state, channel_id, author, None, &body)?;
+
+            json(&message)
+        }
+        (&Method::POST, ["messages", "bulk-delete"]) => {
+            /// Maximum age in milliseconds of messages that can be bulk deleted.
+            const MAX_AGE: u64 = 14 * 24 * 60 * 60 * 1000;
+
+            let body = Body::parse(body)?;
+            let message_ids = body
+                .get::<Vec<Id<MessageMarker>>>("messages")?
+                .ok_or(INVALID_FORM_BODY)?;
+
+            if !(2..=100).contains(&message_ids.len()) {
+                return Err(INVALID_FORM_BODY);
+            }
+
+            if message_ids
+                .iter()
+                .any(|message_id| state::age_millis(*message_id) >= MAX_AGE)
+            {
+                return Err(BULK_DELETE_TOO_OLD);
+            }
+
+            // Messages that don't exist are ignored.
+            state.messages.retain(|message_id, message| {
+                message.channel_id != channel_id || !message_ids.contains(message_id)
+            });
+
+            Ok(Reply::NoContent)
+        }
+        (_, ["messages", message_id, rest @ ..]) => {
+            let message_id = id(message_id, UNKNOWN_MESSAGE)?;
+
+            match state.messages.get(&message_id) {
+                Some(message) if message.channel_id == channel_id => {}
+                _ => return Err(UNKNOWN_MESSAGE),
+            }
+
+            message(state, method, message_id, rest, query, body)
+        }
+        (&Method::GET, ["webhooks"]) => {
+            let webhooks = state
+                .webhooks
+                .values()
+                .filter(|webhook| webhook.channel_id == channel_id)
+                .collect::<Vec<_>>();
+
+            json(&webhooks)
+        }
+        (&Method::POST, ["webhooks"]) => {
+            let body = Body::parse(body)?;
+            let name = body.get::<String>("name")?.ok_or(INVALID_FORM_BODY)?;
+            let id = state.next_id();
+
+            let webhook = Webhook {
+                application_id: None,
+                avatar: None,
+                channel_id,
+                guild_id: state.channels[&channel_id].guild_id,
+                id,
+                kind: WebhookType::Incoming,
+                name: Some(name),
+                source_channel: None,
+                source_guild: None,
+                token: Some(format!("mock-token-{}", id)),
+                url: None,
+                user: Some(state.current_user.clone()),
+            };
+            state.webhooks.insert(id, webhook.clone());
+
+            json(&webhook)
+        }
+        _ => Err(NOT_FOUND),
+    }
+}
+
+/// List the messages of a channel, most recent first.
+fn messages(state: &State, channel_id: Id<ChannelMarker>, query: &Query) -> Result<Reply> {
+    let limit = query.limit(50, 100)?;
+    let in_channel = |message: &&Message| message.channel_id == channel_id;
+    let messages = state.messages.values().filter(in_channel);
+
+    let mut page = if let Some(after) = query.get::<u64>("after")? {
+        messages
+            .filter(|message| message.id.get() > after)
+            .take(limit)
+            .collect::<Vec<_>>()
+    } else if let Some(around) = query.get::<u64>("around")? {
+        let (before, after) = messages.partition::<Vec<_>, _>(|message| message.id.get() < around);
+        let mut page = after
+            .into_iter()
+            .take(limit - limit / 2)
+            .collect::<Vec<_>>();
+        page.extend(before.into_iter().rev().take(limit / 2));
+
+        page
+    } else {
+        let before = query.get::<u64>("before")?.unwrap_or(u64::MAX);
+
+        messages
+            .rev()
+            .filter(|message| message.id.get() < before)
+            .take(limit)
+            .collect()
+    };
+
+    page.sort_by_key(|message| Reverse(message.id));
+
+    json(&page)
+}
+
+fn message(
+    state: &mut State,
+    method: &Method,
+    message_id: Id<MessageMarker>,
+    path: &[&str],
+    query: &Query,
+    body: &[u8],
+) -> Result<Reply> {
+    match (method, path) {
+        (&Method::GET, []) => json(&state.messages[&message_id]),
+        (&Method::DELETE, []) => {
+            state.messages.remove(&message_id);
+            state.reactions.retain(|(id, _), _| *id != message_id);
+
+            Ok(Reply::NoContent)
+        }
+        (&Method::PATCH, []) => {
+            let body = Body::parse(body)?;
+            let current_user_id = state.current_user.id;
+            let message = state.messages.get_mut(&message_id).expect("message exists");
+
+            if message.author.id != current_user_id || message.webhook_id.is_some() {
+                return Err(CANNOT_EDIT_OTHER);
+            }
+
+            update_message(message, &body)?;
+
+            json(message)
+        }
+        (&Method::DELETE, ["reactions"]) => {
+            state.reactions.retain(|(id, _), _| *id != message_id);
+
+            if let Some(message) = state.messages.get_mut(&message_id) {
+                message.reactions.clear();
+            }
+
+            Ok(Reply::NoContent)
+        }
+        (&Method::GET, ["reactions", emoji]) => {
+            let (key, _) = emoji_key(emoji)?;
+            let after = query.get::<u64>("after")?.unwrap_or_default();
+            let limit = query.limit(25, 100)?;
+
+            let users = state
+                .reactions
+                .get(&(message_id, key))
+                .into_iter()
+                .flatten()
+                .filter(|id| id.get() > after)
+                .filter_map(|id| state.users.get(id))
+                .take(limit)
+                .collect::<Vec<_>>();
+
+            json(&users)
+        }
+        (&Method::PUT, ["reactions", emoji, "@me"]) => {
+            let user_id = state.current_user.id;
+            react(state, message_id, emoji, user_id, true)
+        }
+        (&Method::DELETE, ["reactions", emoji, "@me"]) => {
+            let user_id = state.current_user.id;
+            react(state, message_id, emoji, user_id, false)
+        }
+        (&Method::DELETE, ["reactions", emoji, user_id]) => {
+            let user_id = id(user_id, UNKNOWN_USER)?;
+            react(state, message_id, emoji, user_id, false)
+        }
+        _ => Err(NOT_FOUND),
+    }
+}
+
+/// Add or remove a user's reaction to a message.
+fn react(
+    state: &mut State,
+    message_id: Id<MessageMarker>,
+    emoji: &str,
+    user_id: Id<UserMarker>,
+    add: bool,
+) -> Result<Reply> {
+    let (key, emoji) = emoji_key(emoji)?;
+    let users = state.reactions.entry((message_id, key)).or_default();
+    let position = users.binary_search(&user_id);
+
+    let change = match (add, position) {
+        (true, Err(index)) => {
+            users.insert(index, user_id);
+
+            true
+        }
+        (false, Ok(index)) => {
+            users.remove(index);
+
+            true
+        }
+        _ => false,
+    };
+
+    let count = users.len() as u64;
+    let me = users.binary_search(&state.current_user.id).is_ok();
+    let message = state.messages.get_mut(&message_id).expect("message exists");
+
+    if change {
+        match message
+            .reactions
+            .iter()
+            .position(|reaction| reaction.emoji == emoji)
+        {
+            Some(index) if count == 0 => {
+                message.reactions.remove(index);
+            }
+            Some(index) => {
+                message.reactions[index].count = count;
+                message.reactions[index].me = me;
+            }
+            None if count > 0 => message.reactions.push(MessageReaction { count, emoji, me }),
+            None => {}
+        }
+    }
+
+    Ok(Reply::NoContent)
+}
+
+fn webhook(
+    state: &mut State,
+    method: &Method,
+    webhook_id: &str,
+    path: &[&str],
+    query: &Query,
+    body: &[u8],
+) -> Result<Reply> {
+    let webhook_id = id(webhook_id, UNKNOWN_WEBHOOK)?;
+    let webhook = state.webhooks.get(&webhook_id).ok_or(UNKNOWN_WEBHOOK)?;
+
+    if let [token, ..] = path {
+        if webhook.token.as_deref() != Some(token) {
+            return Err(INVALID_WEBHOOK_TOKEN);
+        }
+    }
+
+    match (method, path) {
+        (&Method::GET, [] | [_]) => json(webhook),
+        (&Method::DELETE, [] | [_]) => {
+            state.webhooks.remove(&webhook_id);
+
+            Ok(Reply::NoContent)
+        }
+        (&Method::POST, [_]) => {
+            let body = Body::parse(body)?;
+            let name = body
+                .get::<String>("username")?
+                .or_else(|| webhook.name.clone())
+                .unwrap_or_default();
+            let author = state::user(webhook_id.cast(), name, true);
+            let channel_id = webhook.channel_id;
+            let wait = query.get("wait")? == Some(true);
+
+            let message = create_message(state, channel_id, author, Some(webhook_id), &body)?;
+
+            if wait {
+                json(&message)
+            } else {
+                Ok(Reply::NoContent)
+            }
+        }
+        _ => Err(NOT_FOUND),
+    }
+}
+
+/// Create a message in a channel from the body of a request.
+fn create_message(
+    state: &mut State,
+    channel_id: Id<ChannelMarker>,
+    author: User,
+    webhook_id: Option<Id<WebhookMarker>>,
+    body: &Body,
+) -> Result<Message> {
+    let id = state.next_id();
+    let channel = state.channels.get_mut(&channel_id).ok_or(UNKNOWN_CHANNEL)?;
+    channel.last_message_id = Some(id);
+
+    let mut message = Message {
+        activity: None,
+        application: None,
+        application_id: None,
+        attachments: Vec::new(),
+        author,
+        channel_id,
+        components: Vec::new(),
+        content: String::new(),
+        edited_timestamp: None,
+        embeds: Vec::new(),
+        flags: None,
+        guild_id: channel.guild_id,
+        id,
+        interaction: None,
+        kind: MessageType::Regular,
+        member: None,
+        mention_channels: Vec::new(),
+        mention_everyone: false,
+        mention_roles: Vec::new(),
+        mentions: Vec::new(),
+        pinned: false,
+        reactions: Vec::new(),
+        reference: None,
+        referenced_message: None,
+        sticker_items: Vec::new(),
+        timestamp: state::now(),
+        thread: None,
+        tts: body.get("tts")?.unwrap_or_default(),
+        webhook_id,
+    };
+
+    update_message(&mut message, body)?;
+    message.edited_timestamp = None;
+
+    if message.content.is_empty() && message.embeds.is_empty() {
+        return Err(EMPTY_MESSAGE);
+    }
+
+    state.messages.insert(id, message.clone());
+
+    Ok(message)
+}
+
+/// Update the content of a message from the body of a request.
+fn update_message(message: &mut Message, body: &Body) -> Result<()> {
+    if let Some(content) = body.get::<Option<String>>("content")? {
+        message.content = content.unwrap_or_default();
+    }
+
+    if let Some(embeds) = body.get::<Option<_>>("embeds")? {
+        message.embeds = embeds.unwrap_or_default();
+    }
+
+    if let Some(components) = body.get::<Option<_>>("components")? {
+        message.components = components.unwrap_or_default();
+    }
+
+    message.edited_timestamp = Some(state::now());
+
+    Ok(())
+}
+
+/// Update a role from the body of a request.
+fn update_role(role: &mut Role, body: &Body) -> Result<()> {
+    if let Some(name) = body.get("name")? {
+        role.name = name;
+    }
+
+    if let Some(color) = body.get("color")? {
+        role.color = color;
+    }
+
+    if let Some(hoist) = body.get("hoist")? {
+        role.hoist = hoist;
+    }
+
+    if let Some(mentionable) = body.get("mentionable")? {
+        role.mentionable = mentionable;
+    }
+
+    if let Some(permissions) = body.get("permissions")? {
+        role.permissions = permissions;
+    }
+
+    Ok(())
+}
+
+/// Parse an emoji in a route into the key its reactions are stored by and its
+/// reaction type.
+///
+/// Custom emojis are in the format `name:id`, while unicode emojis are percent
+/// encoded.
+fn emoji_key(emoji: &str) -> Result<(String, ReactionType)> {
+    let key = percent_decode_str(emoji)
+        .decode_utf8()
+        .map_err(|_| UNKNOWN_EMOJI)?
+        .into_owned();
+
+    let reaction = if let Some((name, id)) = key.split_once(':') {
+        ReactionType::Custom {
+            animated: false,
+            id: self::id(id, UNKNOWN_EMOJI)?,
+            name: Some(name.to_owned()),
+        }
+    } else {
+        ReactionType::Unicode { name: key.clone() }
+    };
+
+    Ok((key, reaction))
+}
+
+/// Parse an ID in a route, failing with an error if it's invalid.
+fn id<T>(segment: &str, failure: Failure) -> Result<Id<T>> {
+    segment
+        .parse::<u64>()
+        .ok()
+        .and_then(Id::new_checked)
+        .ok_or(failure)
+}
+
+/// Serialize a model as a JSON reply.
+///
+/// Returns a result so that handlers can reply with it directly.
+#[allow(clippy::unnecessary_wraps)]
+fn json(value: &impl Serialize) -> Result<Reply> {
+    Ok(Reply::Json(
+        serde_json::to_vec(value).expect("models are serializable"),
+    ))
+}
+
+/// Create a text channel in a guild.
+pub(crate) const fn text_channel(
+    id: Id<ChannelMarker>,
+    guild_id: Id<GuildMarker>,
+    name: String,
+    position: i64,
+) -> TextChannel {
+    TextChannel {
+        guild_id: Some(guild_id),
+        id,
+        kind: ChannelType::GuildText,
+        last_message_id: None,
+        last_pin_timestamp: None,
+        name,
+        nsfw: false,
+        parent_id: None,
+        permission_overwrites: Vec::new(),
+        position,
+        rate_limit_per_user: None,
+        topic: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{emoji_key, requires_authorization, Query};
+    use twilight_model::{channel::ReactionType, id::Id};
+
+    #[test]
+    fn test_query() {
+        let query = Query::parse(Some("after=5&limit=10&wait=true"));
+
+        assert_eq!(Some(5), query.get::<u64>("after").unwrap());
+        assert_eq!(Some(true), query.get::<bool>("wait").unwrap());
+        assert_eq!(None, query.get::<u64>("before").unwrap());
+        assert_eq!(10, query.limit(1, 100).unwrap());
+        assert!(query.limit(1, 5).is_err());
+    }
+
+    #[test]
+    fn test_emoji_key() {
+        assert_eq!(
+            (
+                "\u{1f44d}".to_owned(),
+                ReactionType::Unicode {
+                    name: "\u{1f44d}".to_owned()
+                }
+            ),
+            emoji_key("%F0%9F%91%8D").unwrap()
+        );
+        assert_eq!(
+            (
+                "twilight:123".to_owned(),
+                ReactionType::Custom {
+                    animated: false,
+                    id: Id::new(123),
+                    name: Some("twilight".to_owned())
+                }
+            ),
+            emoji_key("twilight:123").unwrap()
+        );
+        assert!(emoji_key("twilight:abc").is_err());
+    }
+
+    #[test]
+    fn test_requires_authorization() {
+        assert!(requires_authorization(&["users", "@me"]));
+        assert!(requires_authorization(&["webhooks", "1"]));
+        assert!(!requires_authorization(&["webhooks", "1", "token"]));
+    }
+}
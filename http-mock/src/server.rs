@@ -0,0 +1,504 @@
+//! Mock server and its builder.
+
+use crate::{
+    ratelimit::Ratelimiter,
+    route::{self, Query, Reply},
+    state::State,
+};
+use hyper::{
+    body,
+    header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE},
+    service::{make_service_fn, service_fn},
+    Body, HeaderMap, Request, Response, Server, StatusCode,
+};
+use std::{
+    convert::Infallible,
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    net::{SocketAddr, TcpListener},
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+use tokio::task::JoinHandle;
+use twilight_http::Client;
+use twilight_model::{
+    channel::{message::Message, webhook::Webhook},
+    guild::{Guild, Member, Permissions, Role},
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker, RoleMarker, UserMarker},
+        Id,
+    },
+    user::User,
+};
+
+/// Starting a [`MockServer`] failed.
+#[derive(Debug)]
+pub struct MockServerError {
+    kind: MockServerErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl MockServerError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &MockServerErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (MockServerErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for MockServerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            MockServerErrorType::Binding => f.write_str("failed to bind to a local port"),
+        }
+    }
+}
+
+impl Error for MockServerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`MockServerError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MockServerErrorType {
+    /// Binding to a local port failed.
+    Binding,
+}
+
+/// State shared between the server and its handle.
+struct Shared {
+    ratelimiter: Mutex<Ratelimiter>,
+    state: Mutex<State>,
+    token: Option<Box<str>>,
+}
+
+/// Builder to configure and start a [`MockServer`].
+#[derive(Debug)]
+#[must_use = "the server is only started when built"]
+pub struct MockServerBuilder {
+    limit: u16,
+    period: Duration,
+    token: Option<Box<str>>,
+}
+
+impl MockServerBuilder {
+    /// Create a new builder to start a [`MockServer`].
+    pub const fn new() -> Self {
+        Self {
+            limit: 50,
+            period: Duration::from_secs(1),
+            token: None,
+        }
+    }
+
+    /// Start the server on a local port.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MockServerErrorType::Binding`] error type if binding to a
+    /// local port failed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not called within a Tokio runtime.
+    pub fn build(self) -> Result<MockServer, MockServerError> {
+        let binding = |source: Box<dyn Error + Send + Sync>| MockServerError {
+            kind: MockServerErrorType::Binding,
+            source: Some(source),
+        };
+
+        let listener =
+            TcpListener::bind(("127.0.0.1", 0)).map_err(|source| binding(Box::new(source)))?;
+        listener
+            .set_nonblocking(true)
+            .map_err(|source| binding(Box::new(source)))?;
+        let addr = listener
+            .local_addr()
+            .map_err(|source| binding(Box::new(source)))?;
+
+        let shared = Arc::new(Shared {
+            ratelimiter: Mutex::new(Ratelimiter::new(self.limit, self.period)),
+            state: Mutex::new(State::new()),
+            token: self.token.clone(),
+        });
+
+        let service_shared = Arc::clone(&shared);
+        let make_service = make_service_fn(move |_| {
+            let shared = Arc::clone(&service_shared);
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    handle(Arc::clone(&shared), request)
+                }))
+            }
+        });
+
+        let server = Server::from_tcp(listener)
+            .map_err(|source| binding(Box::new(source)))?
+            .serve(make_service);
+
+        let handle = tokio::spawn(async move {
+            let _res = server.await;
+        });
+
+        Ok(MockServer {
+            addr,
+            handle,
+            shared,
+            token: self.token,
+        })
+    }
+
+    /// Set the number of requests allowed per ratelimit bucket in a period.
+    ///
+    /// Requests are sorted into buckets by their route and top-level channel,
+    /// guild, or webhook ID. Requests exceeding the limit are responded to
+    /// with a ratelimited response until the bucket resets.
+    ///
+    /// Defaults to 50 requests per second.
+    pub const fn ratelimit(mut self, limit: u16, period: Duration) -> Self {
+        self.limit = limit;
+        self.period = period;
+
+        self
+    }
+
+    /// Set the token that requests must be authorized with.
+    ///
+    /// Requests with another token are responded to with an unauthorized
+    /// response. By default requests with any token are accepted.
+    pub fn token(mut self, token: String) -> Self {
+        self.token = Some(token.into_boxed_str());
+
+        self
+    }
+}
+
+impl Default for MockServerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// In-memory fake of the Discord REST API, served on a local port.
+///
+/// Resources are created with the server's methods, such as
+/// [`add_guild`] and [`add_channel`], and can then be read and modified
+/// through requests made by a [`Client`] targeting the server, created via
+/// [`client`]. The resources can be inspected after requests have been made.
+///
+/// The server is stopped when dropped.
+///
+/// [`add_channel`]: Self::add_channel
+/// [`add_guild`]: Self::add_guild
+/// [`client`]: Self::client
+pub struct MockServer {
+    addr: SocketAddr,
+    handle: JoinHandle<()>,
+    shared: Arc<Shared>,
+    token: Option<Box<str>>,
+}
+
+impl MockServer {
+    /// Create a new builder to configure and start a server.
+    pub const fn builder() -> MockServerBuilder {
+        MockServerBuilder::new()
+    }
+
+    /// Start a new server with the default configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MockServerErrorType::Binding`] error type if binding to a
+    /// local port failed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not called within a Tokio runtime.
+    pub fn new() -> Result<Self, MockServerError> {
+        Self::builder().build()
+    }
+
+    /// Local address the server is listening on.
+    pub const fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Create a client sending its requests to the server.
+    ///
+    /// The client is authorized with the server's token, if set.
+    pub fn client(&self) -> Client {
+        let token = self.token.as_deref().unwrap_or("mock-token").to_owned();
+
+        Client::builder()
+            .proxy(self.addr.to_string(), true)
+            .token(token)
+            .build()
+    }
+
+    /// Current user, which is the author of messages created by the client.
+    pub fn current_user(&self) -> User {
+        self.state().current_user.clone()
+    }
+
+    /// Add a text channel to a guild, returning its ID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the guild doesn't exist.
+    pub fn add_channel(&self, guild_id: Id<GuildMarker>, name: &str) -> Id<ChannelMarker> {
+        let mut state = self.state();
+        assert!(state.guilds.contains_key(&guild_id), "guild doesn't exist");
+
+        let id = state.next_id();
+        let position = state
+            .channels
+            .values()
+            .filter(|channel| channel.guild_id == Some(guild_id))
+            .count();
+        let channel = route::text_channel(
+            id,
+            guild_id,
+            name.to_owned(),
+            i64::try_from(position).unwrap_or(i64::MAX),
+        );
+        state.channels.insert(id, channel);
+
+        id
+    }
+
+    /// Add a guild owned by the current user, returning its ID.
+    ///
+    /// The current user is a member of the guild, and the guild has an
+    /// `@everyone` role with the same ID as the guild.
+    pub fn add_guild(&self, name: &str) -> Id<GuildMarker> {
+        self.state().create_guild(name)
+    }
+
+    /// Add a user as a member of a guild.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the guild or user doesn't exist.
+    pub fn add_member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
+        let mut state = self.state();
+        assert!(state.guilds.contains_key(&guild_id), "guild doesn't exist");
+        assert!(state.create_member(guild_id, user_id), "user doesn't exist");
+    }
+
+    /// Add a role to a guild, returning its ID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the guild doesn't exist.
+    pub fn add_role(&self, guild_id: Id<GuildMarker>, name: &str) -> Id<RoleMarker> {
+        let mut state = self.state();
+        let id = state.next_id();
+        let guild = state
+            .guilds
+            .get_mut(&guild_id)
+            .expect("guild doesn't exist");
+        let position = guild.roles.len();
+
+        guild.roles.push(Role {
+            color: 0,
+            hoist: false,
+            icon: None,
+            id,
+            managed: false,
+            mentionable: false,
+            name: name.to_owned(),
+            permissions: Permissions::empty(),
+            position: i64::try_from(position).unwrap_or(i64::MAX),
+            tags: None,
+            unicode_emoji: None,
+        });
+
+        id
+    }
+
+    /// Add a user, returning its ID.
+    pub fn add_user(&self, name: &str) -> Id<UserMarker> {
+        self.state().create_user(name.to_owned())
+    }
+
+    /// Guild by its ID, if it exists.
+    pub fn guild(&self, guild_id: Id<GuildMarker>) -> Option<Guild> {
+        self.state().guilds.get(&guild_id).cloned()
+    }
+
+    /// Member of a guild, if they exist.
+    pub fn member(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> Option<Member> {
+        self.state().members.get(&(guild_id, user_id)).cloned()
+    }
+
+    /// Message by its ID, if it exists.
+    pub fn message(&self, message_id: Id<MessageMarker>) -> Option<Message> {
+        self.state().messages.get(&message_id).cloned()
+    }
+
+    /// Messages in a channel, most recent first.
+    pub fn messages(&self, channel_id: Id<ChannelMarker>) -> Vec<Message> {
+        self.state()
+            .messages
+            .values()
+            .rev()
+            .filter(|message| message.channel_id == channel_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Webhooks of a channel.
+    pub fn webhooks(&self, channel_id: Id<ChannelMarker>) -> Vec<Webhook> {
+        self.state()
+            .webhooks
+            .values()
+            .filter(|webhook| webhook.channel_id == channel_id)
+            .cloned()
+            .collect()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.shared.state.lock().expect("state poisoned")
+    }
+}
+
+impl Debug for MockServer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("MockServer")
+            .field("addr", &self.addr)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// Handle a request to the server.
+async fn handle(shared: Arc<Shared>, request: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let (parts, body) = request.into_parts();
+    let body = body::to_bytes(body).await.unwrap_or_default();
+
+    let path = parts.uri.path();
+    let path = match path.strip_prefix("/api/v") {
+        Some(rest) => rest.split_once('/').map_or("", |(_, path)| path),
+        None => "",
+    };
+    let segments = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+
+    if route::requires_authorization(&segments) && !authorized(&shared, &parts.headers) {
+        return Ok(failure(route::UNAUTHORIZED));
+    }
+
+    let taken = shared
+        .ratelimiter
+        .lock()
+        .expect("ratelimiter poisoned")
+        .take(parts.method.as_str(), &segments);
+
+    if let Some(retry_after) = taken.limited {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "global": false,
+            "message": "You are being rate limited.",
+            "retry_after": retry_after.as_secs_f64(),
+        }))
+        .expect("ratelimited body is valid");
+
+        let mut response = json(StatusCode::TOO_MANY_REQUESTS, body);
+        response.headers_mut().extend(taken.headers);
+
+        return Ok(response);
+    }
+
+    let query = Query::parse(parts.uri.query());
+    let reply = {
+        let mut state = shared.state.lock().expect("state poisoned");
+
+        route::handle(&mut state, &parts.method, &segments, &query, &body)
+    };
+
+    let mut response = match reply {
+        Ok(Reply::Json(body)) => json(StatusCode::OK, body),
+        Ok(Reply::NoContent) => {
+            let mut response = Response::new(Body::empty());
+            *response.status_mut() = StatusCode::NO_CONTENT;
+
+            response
+        }
+        Err(source) => failure(source),
+    };
+    response.headers_mut().extend(taken.headers);
+
+    Ok(response)
+}
+
+/// Whether a request is authorized with the server's token, if any.
+fn authorized(shared: &Shared, headers: &HeaderMap) -> bool {
+    let value = match headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+    {
+        Some(value) => value,
+        None => return false,
+    };
+
+    let token = value
+        .strip_prefix("Bot ")
+        .or_else(|| value.strip_prefix("Bearer "))
+        .unwrap_or(value);
+
+    !token.is_empty()
+        && shared
+            .token
+            .as_deref()
+            .map_or(true, |expected| expected == token)
+}
+
+fn failure(failure: route::Failure) -> Response<Body> {
+    json(failure.status, failure.body())
+}
+
+fn json(status: StatusCode, body: Vec<u8>) -> Response<Body> {
+    let mut response = Response::new(Body::from(body));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MockServer, MockServerBuilder, MockServerError, MockServerErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(MockServerBuilder: Debug, Default, Send, Sync);
+    assert_impl_all!(MockServerErrorType: Debug, Send, Sync);
+    assert_impl_all!(MockServerError: Error, Send, Sync);
+    assert_impl_all!(MockServer: Debug, Send, Sync);
+}
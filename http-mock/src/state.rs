@@ -0,0 +1,202 @@
+//! Resources stored by a mock server.
+
+use serde_json::json;
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use twilight_model::{
+    channel::{message::Message, webhook::Webhook, TextChannel},
+    datetime::Timestamp,
+    guild::{Guild, Member},
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker, WebhookMarker},
+        Id,
+    },
+    user::User,
+};
+
+/// Milliseconds since the Unix epoch of the first second of 2015, the epoch
+/// of snowflakes.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// Resources of a mock server.
+///
+/// Resources are stored as the models returned by Discord and serialized as
+/// they are when responding to requests.
+pub(crate) struct State {
+    pub channels: HashMap<Id<ChannelMarker>, TextChannel>,
+    pub current_user: User,
+    pub guilds: HashMap<Id<GuildMarker>, Guild>,
+    last_id: u64,
+    pub members: BTreeMap<(Id<GuildMarker>, Id<UserMarker>), Member>,
+    pub messages: BTreeMap<Id<MessageMarker>, Message>,
+    /// Users that have reacted to messages, by the message and the emoji in
+    /// the format used in routes.
+    pub reactions: HashMap<(Id<MessageMarker>, String), Vec<Id<UserMarker>>>,
+    pub users: HashMap<Id<UserMarker>, User>,
+    pub webhooks: HashMap<Id<WebhookMarker>, Webhook>,
+}
+
+impl State {
+    /// Create a new state, with a bot user as the current user.
+    pub fn new() -> Self {
+        let mut state = Self {
+            channels: HashMap::new(),
+            current_user: user(Id::new(1), "Mock".to_owned(), true),
+            guilds: HashMap::new(),
+            last_id: 0,
+            members: BTreeMap::new(),
+            messages: BTreeMap::new(),
+            reactions: HashMap::new(),
+            users: HashMap::new(),
+            webhooks: HashMap::new(),
+        };
+
+        let current_user = user(state.next_id(), "Mock".to_owned(), true);
+        state.users.insert(current_user.id, current_user.clone());
+        state.current_user = current_user;
+
+        state
+    }
+
+    /// Generate a new ID, created at the current time.
+    ///
+    /// IDs are strictly increasing, even if generated within the same
+    /// millisecond.
+    pub fn next_id<T>(&mut self) -> Id<T> {
+        let millis = now_millis().saturating_sub(DISCORD_EPOCH);
+        let id = (millis << 22).max(self.last_id + 1);
+        self.last_id = id;
+
+        Id::new(id)
+    }
+
+    /// Create a new guild owned by the current user, with the current user as
+    /// a member.
+    pub fn create_guild(&mut self, name: &str) -> Id<GuildMarker> {
+        let id = self.next_id();
+        let owner_id = self.current_user.id;
+
+        let guild = serde_json::from_value(json!({
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "application_id": null,
+            "banner": null,
+            "default_message_notifications": 0,
+            "description": null,
+            "discovery_splash": null,
+            "emojis": [],
+            "explicit_content_filter": 0,
+            "features": [],
+            "icon": null,
+            "id": id,
+            "large": false,
+            "mfa_level": 0,
+            "name": name,
+            "nsfw_level": 0,
+            "owner_id": owner_id,
+            "preferred_locale": "en-US",
+            "premium_progress_bar_enabled": false,
+            "roles": [{
+                "color": 0,
+                "hoist": false,
+                "id": id,
+                "managed": false,
+                "mentionable": false,
+                "name": "@everyone",
+                "permissions": "1071698660929",
+                "position": 0,
+            }],
+            "rules_channel_id": null,
+            "splash": null,
+            "system_channel_flags": 0,
+            "system_channel_id": null,
+            "vanity_url_code": null,
+            "verification_level": 0,
+        }))
+        .expect("guild is valid");
+
+        self.guilds.insert(id, guild);
+        self.create_member(id, owner_id);
+
+        id
+    }
+
+    /// Create a new member of a guild, if the user exists.
+    pub fn create_member(&mut self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) -> bool {
+        let user = match self.users.get(&user_id) {
+            Some(user) => user.clone(),
+            None => return false,
+        };
+
+        self.members.insert(
+            (guild_id, user_id),
+            Member {
+                avatar: None,
+                communication_disabled_until: None,
+                deaf: false,
+                guild_id,
+                joined_at: now(),
+                mute: false,
+                nick: None,
+                pending: false,
+                premium_since: None,
+                roles: Vec::new(),
+                user,
+            },
+        );
+
+        true
+    }
+
+    /// Create a new user.
+    pub fn create_user(&mut self, name: String) -> Id<UserMarker> {
+        let id = self.next_id();
+        self.users.insert(id, user(id, name, false));
+
+        id
+    }
+}
+
+/// Create a user.
+pub(crate) const fn user(id: Id<UserMarker>, name: String, bot: bool) -> User {
+    User {
+        accent_color: None,
+        avatar: None,
+        banner: None,
+        bot,
+        discriminator: 1,
+        email: None,
+        flags: None,
+        id,
+        locale: None,
+        mfa_enabled: None,
+        name,
+        premium_type: None,
+        public_flags: None,
+        system: None,
+        verified: None,
+    }
+}
+
+/// Current time as a timestamp.
+pub(crate) fn now() -> Timestamp {
+    #[allow(clippy::cast_possible_wrap)]
+    let micros = (now_millis() * 1000) as i64;
+
+    Timestamp::from_micros(micros).expect("current time is valid")
+}
+
+/// Milliseconds elapsed since an ID was created.
+pub fn age_millis<T>(id: Id<T>) -> u64 {
+    now_millis().saturating_sub((id.get() >> 22) + DISCORD_EPOCH)
+}
+
+fn now_millis() -> u64 {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+
+    u64::try_from(since_epoch.as_millis()).unwrap_or(u64::MAX)
+}
@@ -0,0 +1,611 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use twilight_http::{
+    api_error::{ApiError, ErrorCode},
+    client::{Middleware, ResponseInfo, WebhookClient},
+    error::ErrorType,
+    request::{
+        channel::{
+            message::{PurgeErrorType, PurgeOutcome},
+            reaction::RequestReactionType,
+        },
+        Method, Request,
+    },
+    routing::Path,
+    Client,
+};
+use twilight_http_mock::MockServer;
+use twilight_http_ratelimiting::{
+    headers::RatelimitHeaders,
+    ticket::{TicketDeadlineError, TicketDeadlineErrorType},
+};
+use twilight_model::id::Id;
+
+/// Discord error code of the error returned by a request, if any.
+fn error_code(error: &twilight_http::Error) -> Option<ErrorCode> {
+    match error.kind() {
+        ErrorType::Response { error, .. } => error.error_code(),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn test_messages() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::new()?;
+    let guild_id = server.add_guild("twilight");
+    let channel_id = server.add_channel(guild_id, "general");
+    let client = server.client();
+
+    for content in ["one", "two", "three"] {
+        client
+            .create_message(channel_id)
+            .content(content)?
+            .exec()
+            .await?;
+    }
+
+    let messages = client
+        .channel_messages(channel_id)
+        .limit(2)?
+        .exec()
+        .await?
+        .models()
+        .await?;
+    let contents = messages
+        .iter()
+        .map(|message| message.content.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(["three", "two"], contents.as_slice());
+    assert_eq!(server.current_user().id, messages[0].author.id);
+
+    let older = client
+        .channel_messages(channel_id)
+        .before(messages[1].id)
+        .exec()
+        .await?
+        .models()
+        .await?;
+    assert_eq!(1, older.len());
+    assert_eq!("one", older[0].content);
+
+    let updated = client
+        .update_message(channel_id, older[0].id)
+        .content(Some("edited"))?
+        .exec()
+        .await?
+        .model()
+        .await?;
+    assert_eq!("edited", updated.content);
+    assert!(updated.edited_timestamp.is_some());
+
+    client.delete_message(channel_id, updated.id).exec().await?;
+    assert!(server.message(updated.id).is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_purge() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::new()?;
+    let guild_id = server.add_guild("twilight");
+    let channel_id = server.add_channel(guild_id, "general");
+    let client = server.client();
+
+    let mut message_ids = Vec::new();
+
+    for content in ["one", "two", "three", "four"] {
+        let message = client
+            .create_message(channel_id)
+            .content(content)?
+            .exec()
+            .await?
+            .model()
+            .await?;
+        message_ids.push(message.id);
+    }
+
+    // Old enough that it can't be bulk deleted, and doesn't exist.
+    let old_id = Id::new(1);
+    let ids = [message_ids[0], message_ids[1], message_ids[0], old_id];
+    let report = client.purge_messages(channel_id, &ids).exec().await?;
+
+    assert!(!report.is_complete());
+    assert_eq!(3, report.outcomes().len());
+    assert!(matches!(report.outcomes()[0].1, PurgeOutcome::BulkDeleted));
+    assert!(matches!(report.outcomes()[1].1, PurgeOutcome::BulkDeleted));
+    let failed = report.failed().collect::<Vec<_>>();
+    assert_eq!(1, failed.len());
+    assert_eq!(old_id, failed[0].0);
+    assert_eq!(Some(ErrorCode::UnknownMessage), error_code(failed[0].1));
+
+    let report = client
+        .channel_messages(channel_id)
+        .purge(|message| message.content == "four")
+        .exec()
+        .await?;
+    assert!(report.is_complete());
+    assert_eq!(
+        [message_ids[3]].as_ref(),
+        report.deleted().collect::<Vec<_>>()
+    );
+    assert!(matches!(report.outcomes()[0].1, PurgeOutcome::Deleted));
+
+    let remaining = server.messages(channel_id);
+    assert_eq!(1, remaining.len());
+    assert_eq!("three", remaining[0].content);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_purge_paginated() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::builder()
+        .ratelimit(1000, Duration::from_secs(1))
+        .build()?;
+    let guild_id = server.add_guild("twilight");
+    let channel_id = server.add_channel(guild_id, "general");
+    let client = server.client();
+
+    // The matching message is on the second page of messages.
+    let target = client
+        .create_message(channel_id)
+        .content("target")?
+        .exec()
+        .await?
+        .model()
+        .await?;
+
+    for _ in 0..100 {
+        client
+            .create_message(channel_id)
+            .content("other")?
+            .exec()
+            .await?;
+    }
+
+    let report = client
+        .channel_messages(channel_id)
+        .limit(1)?
+        .purge(|message| message.content == "target")
+        .exec()
+        .await?;
+    assert_eq!([target.id].as_ref(), report.deleted().collect::<Vec<_>>());
+    assert_eq!(100, server.messages(channel_id).len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_purge_unauthorized() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::builder().token("secret".to_owned()).build()?;
+    let guild_id = server.add_guild("twilight");
+    let channel_id = server.add_channel(guild_id, "general");
+
+    let mut message_ids = Vec::new();
+
+    for content in ["one", "two"] {
+        let message = server
+            .client()
+            .create_message(channel_id)
+            .content(content)?
+            .exec()
+            .await?
+            .model()
+            .await?;
+        message_ids.push(message.id);
+    }
+
+    let client = Client::builder()
+        .proxy(server.addr().to_string(), true)
+        .token("wrong".to_owned())
+        .build();
+
+    // The failed bulk delete isn't retried one message at a time.
+    let error = client
+        .purge_messages(channel_id, &message_ids)
+        .exec()
+        .await
+        .unwrap_err();
+    let (kind, source) = error.into_parts();
+    assert!(matches!(
+        kind,
+        PurgeErrorType::Deleting { report } if report.outcomes().is_empty()
+    ));
+    let source = source.unwrap().downcast::<twilight_http::Error>().unwrap();
+    assert!(matches!(
+        source.kind(),
+        ErrorType::Response { status, .. } if status.raw() == 401
+    ));
+    assert_eq!(2, server.messages(channel_id).len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_members_and_roles() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::new()?;
+    let guild_id = server.add_guild("twilight");
+    let user_id = server.add_user("member");
+    server.add_member(guild_id, user_id);
+    let role_id = server.add_role(guild_id, "moderator");
+    let client = server.client();
+
+    client
+        .update_guild_member(guild_id, user_id)
+        .nick(Some("nickname"))?
+        .exec()
+        .await?;
+    client
+        .add_guild_member_role(guild_id, user_id, role_id)
+        .exec()
+        .await?;
+
+    let member = server.member(guild_id, user_id).unwrap();
+    assert_eq!(Some("nickname"), member.nick.as_deref());
+    assert_eq!([role_id].as_ref(), member.roles.as_slice());
+
+    let members = client
+        .guild_members(guild_id)
+        .limit(10)?
+        .exec()
+        .await?
+        .models()
+        .await?;
+    assert_eq!(2, members.len());
+
+    let error = client
+        .add_guild_member_role(guild_id, user_id, Id::new(1))
+        .exec()
+        .await
+        .unwrap_err();
+    assert_eq!(Some(ErrorCode::UnknownRole), error_code(&error));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reactions() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::new()?;
+    let guild_id = server.add_guild("twilight");
+    let channel_id = server.add_channel(guild_id, "general");
+    let client = server.client();
+
+    let message = client
+        .create_message(channel_id)
+        .content("react")?
+        .exec()
+        .await?
+        .model()
+        .await?;
+
+    let emoji = RequestReactionType::Unicode { name: "👍" };
+    client
+        .create_reaction(channel_id, message.id, &emoji)
+        .exec()
+        .await?;
+
+    let users = client
+        .reactions(channel_id, message.id, &emoji)
+        .exec()
+        .await?
+        .models()
+        .await?;
+    assert_eq!(1, users.len());
+    assert_eq!(server.current_user().id, users[0].id);
+
+    let reactions = server.message(message.id).unwrap().reactions;
+    assert_eq!(1, reactions.len());
+    assert!(reactions[0].me);
+
+    client
+        .delete_current_user_reaction(channel_id, message.id, &emoji)
+        .exec()
+        .await?;
+    assert!(server.message(message.id).unwrap().reactions.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_webhooks() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::new()?;
+    let guild_id = server.add_guild("twilight");
+    let channel_id = server.add_channel(guild_id, "general");
+    let client = server.client();
+
+    let webhook = client
+        .create_webhook(channel_id, "hook")
+        .exec()
+        .await?
+        .model()
+        .await?;
+    let token = webhook.token.as_deref().unwrap();
+    assert_eq!(1, server.webhooks(channel_id).len());
+
+    let message = client
+        .execute_webhook(webhook.id, token)
+        .content("from a webhook")?
+        .wait()
+        .exec()
+        .await?
+        .model()
+        .await?;
+    assert_eq!(Some(webhook.id), message.webhook_id);
+    assert_eq!("hook", message.author.name);
+
+    let error = client
+        .execute_webhook(webhook.id, "invalid")
+        .content("denied")?
+        .exec()
+        .await
+        .unwrap_err();
+    assert_eq!(Some(ErrorCode::InvalidWebhookToken), error_code(&error));
+    assert_eq!(1, server.messages(channel_id).len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_webhook_client() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::new()?;
+    let guild_id = server.add_guild("twilight");
+    let channel_id = server.add_channel(guild_id, "general");
+
+    let webhook = server
+        .client()
+        .create_webhook(channel_id, "hook")
+        .exec()
+        .await?
+        .model()
+        .await?;
+
+    let client = Client::builder()
+        .proxy(server.addr().to_string(), true)
+        .build();
+    let webhook_client =
+        WebhookClient::with_client(client, webhook.id, webhook.token.clone().unwrap());
+
+    let message = webhook_client
+        .execute()
+        .content("without a bot token")?
+        .username("renamed")
+        .wait()
+        .exec()
+        .await?
+        .model()
+        .await?;
+    assert_eq!(Some(webhook.id), message.webhook_id);
+    assert_eq!("renamed", message.author.name);
+    assert_eq!(1, server.messages(channel_id).len());
+
+    let fetched = webhook_client.webhook().exec().await?.model().await?;
+    assert_eq!(webhook.id, fetched.id);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unknown_resources() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::new()?;
+    let client = server.client();
+
+    let error = client.channel(Id::new(1)).exec().await.unwrap_err();
+    assert_eq!(Some(ErrorCode::UnknownChannel), error_code(&error));
+
+    let error = client.guild(Id::new(1)).exec().await.unwrap_err();
+    assert_eq!(Some(ErrorCode::UnknownGuild), error_code(&error));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unauthorized() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::builder().token("secret".to_owned()).build()?;
+    let guild_id = server.add_guild("twilight");
+
+    let client = Client::builder()
+        .proxy(server.addr().to_string(), true)
+        .token("wrong".to_owned())
+        .build();
+    let error = client.guild(guild_id).exec().await.unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorType::Response { status, .. } if status.raw() == 401
+    ));
+
+    // The client remembers that its token is invalid.
+    let error = client.guild(guild_id).exec().await.unwrap_err();
+    assert!(matches!(error.kind(), ErrorType::Unauthorized));
+
+    server.client().guild(guild_id).exec().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ticket_deadline() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::builder()
+        .ratelimit(1, Duration::from_secs(60))
+        .build()?;
+    let guild_id = server.add_guild("twilight");
+    let client = server.client();
+
+    client.guild(guild_id).exec().await?;
+
+    // Allow the ratelimiter to process the response's headers.
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    // The bucket is exhausted for longer than the deadline, so the request
+    // fails without waiting.
+    let started_at = Instant::now();
+    let mut request = client.guild(guild_id).exec();
+    assert!(request.set_ticket_deadline(started_at + Duration::from_secs(3)));
+    let error = request.await.unwrap_err();
+    assert!(started_at.elapsed() < Duration::from_secs(1));
+
+    assert!(matches!(error.kind(), ErrorType::RatelimiterTicket));
+    let source = error.into_source().unwrap();
+    let source = source.downcast::<TicketDeadlineError>().unwrap();
+    assert!(matches!(
+        source.kind(),
+        TicketDeadlineErrorType::Exhausted { .. }
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_request_limit() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::new()?;
+    let guild_id = server.add_guild("twilight");
+    let channel_id = server.add_channel(guild_id, "general");
+    let webhook = server
+        .client()
+        .create_webhook(channel_id, "hook")
+        .exec()
+        .await?
+        .model()
+        .await?;
+
+    let client = Client::builder()
+        .proxy(server.addr().to_string(), true)
+        .invalid_request_limit(2)
+        .build();
+
+    for _ in 0..2 {
+        let error = client
+            .execute_webhook(webhook.id, "invalid")
+            .content("denied")?
+            .exec()
+            .await
+            .unwrap_err();
+        assert_eq!(Some(ErrorCode::InvalidWebhookToken), error_code(&error));
+    }
+
+    assert_eq!(2, client.invalid_request_count());
+
+    // Further requests are refused, even if they would be valid.
+    let error = client
+        .execute_webhook(webhook.id, webhook.token.as_deref().unwrap())
+        .content("refused")?
+        .exec()
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorType::InvalidRequestLimitReached
+    ));
+    assert!(server.messages(channel_id).is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ratelimits() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::builder()
+        .ratelimit(1, Duration::from_secs(60))
+        .build()?;
+    let guild_id = server.add_guild("twilight");
+
+    let client = Client::builder()
+        .proxy(server.addr().to_string(), true)
+        .ratelimiter(None)
+        .token("token".to_owned())
+        .build();
+
+    let response = client.guild(guild_id).exec().await?;
+    let headers = response.headers().collect::<Vec<_>>();
+    assert!(headers.contains(&("x-ratelimit-limit", b"1".as_ref())));
+    assert!(headers.contains(&("x-ratelimit-remaining", b"0".as_ref())));
+
+    let error = client.guild(guild_id).exec().await.unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorType::Response {
+            error: ApiError::Ratelimited(ratelimited),
+            ..
+        } if !ratelimited.global && ratelimited.retry_after > 0.0
+    ));
+
+    // Other routes have their own buckets.
+    client.guild_channels(guild_id).exec().await?;
+
+    Ok(())
+}
+
+/// Method, ratelimit path, status, and remaining ratelimit of a response.
+type Record = (Method, Path, Option<u16>, Option<u64>);
+
+/// Middleware recording the requests and responses it sees.
+#[derive(Clone, Debug, Default)]
+struct Recorder {
+    requests: Arc<Mutex<Vec<String>>>,
+    responses: Arc<Mutex<Vec<Record>>>,
+}
+
+impl Middleware for Recorder {
+    fn before_request(&self, request: &mut Request) {
+        self.requests
+            .lock()
+            .unwrap()
+            .push(request.path().to_owned());
+    }
+
+    fn after_response(&self, response: &ResponseInfo<'_>) {
+        let remaining = match response.ratelimit_headers() {
+            Some(Ok(RatelimitHeaders::Present(present))) => Some(present.remaining()),
+            _ => None,
+        };
+
+        self.responses.lock().unwrap().push((
+            response.method(),
+            response.ratelimit_path().clone(),
+            response.status().map(|status| status.raw()),
+            remaining,
+        ));
+    }
+}
+
+#[tokio::test]
+async fn test_middleware() -> Result<(), Box<dyn std::error::Error>> {
+    let server = MockServer::builder()
+        .ratelimit(5, Duration::from_secs(60))
+        .build()?;
+    let guild_id = server.add_guild("twilight");
+    let recorder = Recorder::default();
+
+    let client = Client::builder()
+        .proxy(server.addr().to_string(), true)
+        .token("token".to_owned())
+        .middleware(Box::new(recorder.clone()))
+        .build();
+
+    client.guild(guild_id).exec().await?;
+    client.guild(Id::new(1)).exec().await.unwrap_err();
+
+    let path = format!("guilds/{}", guild_id);
+    assert_eq!(
+        [path, "guilds/1".to_owned()].as_ref(),
+        recorder.requests.lock().unwrap().as_slice()
+    );
+
+    let responses = recorder.responses.lock().unwrap();
+    assert_eq!(2, responses.len());
+    assert_eq!(
+        (
+            Method::Get,
+            Path::GuildsId(guild_id.get()),
+            Some(200),
+            Some(4)
+        ),
+        responses[0]
+    );
+    assert_eq!(
+        (Method::Get, Path::GuildsId(1), Some(404), Some(4)),
+        responses[1]
+    );
+
+    Ok(())
+}
@@ -3,10 +3,14 @@
 //! consumed by the [`BucketQueueTask`] that manages the ratelimit for the bucket
 //! and respects the global ratelimit.
 
-use super::GlobalLockPair;
+use super::{
+    global::GlobalLimiter,
+    snapshot::{BucketSnapshot, WaitStatistics},
+    Buckets, GlobalLockPair,
+};
 use crate::{headers::RatelimitHeaders, request::Path, ticket::TicketNotifier};
+use futures_util::FutureExt;
 use std::{
-    collections::HashMap,
     sync::{
         atomic::{AtomicU64, Ordering},
         Arc, Mutex,
@@ -37,6 +41,8 @@ pub enum TimeRemaining {
 /// A generic version not specific to this ratelimiter is [`crate::Bucket`].
 #[derive(Debug)]
 pub struct Bucket {
+    /// Bucket hash reported by Discord, if known.
+    pub hash: Mutex<Option<String>>,
     /// Total number of tickets allotted in a cycle.
     pub limit: AtomicU64,
     /// Path this ratelimit applies to.
@@ -49,18 +55,22 @@ pub struct Bucket {
     pub reset_after: AtomicU64,
     /// When the bucket's ratelimit refresh countdown started.
     pub started_at: Mutex<Option<Instant>>,
+    /// Statistics of the time requests waited for a ticket.
+    pub wait: Mutex<WaitStatistics>,
 }
 
 impl Bucket {
     /// Create a new bucket for the specified [`Path`].
     pub fn new(path: Path) -> Self {
         Self {
+            hash: Mutex::new(None),
             limit: AtomicU64::new(u64::max_value()),
             path,
             queue: BucketQueue::default(),
             remaining: AtomicU64::new(u64::max_value()),
             reset_after: AtomicU64::new(u64::max_value()),
             started_at: Mutex::new(None),
+            wait: Mutex::new(WaitStatistics::default()),
         }
     }
 
@@ -81,6 +91,20 @@ impl Bucket {
         self.reset_after.load(Ordering::Relaxed)
     }
 
+    /// Snapshot of the bucket's current state.
+    pub fn snapshot(&self) -> BucketSnapshot {
+        BucketSnapshot {
+            hash: self.hash.lock().expect("bucket poisoned").clone(),
+            limit: self.limit(),
+            path: self.path.clone(),
+            queued: self.queue.len(),
+            remaining: self.remaining(),
+            reset_after: Duration::from_millis(self.reset_after()),
+            started_at: *self.started_at.lock().expect("bucket poisoned"),
+            wait: *self.wait.lock().expect("bucket poisoned"),
+        }
+    }
+
     /// Time remaining until this bucket will reset.
     pub fn time_remaining(&self) -> TimeRemaining {
         let reset_after = self.reset_after();
@@ -145,23 +169,59 @@ impl Bucket {
 /// Queue of ratelimit requests for a bucket.
 #[derive(Debug)]
 pub struct BucketQueue {
-    /// Receiver for the ratelimit requests.
-    rx: AsyncMutex<UnboundedReceiver<TicketNotifier>>,
-    /// Sender for the ratelimit requests.
-    tx: UnboundedSender<TicketNotifier>,
+    /// Number of ratelimit requests in the queue.
+    len: AtomicU64,
+    /// Receiver for the ratelimit requests and when they were queued.
+    rx: AsyncMutex<UnboundedReceiver<(Instant, TicketNotifier)>>,
+    /// Sender for the ratelimit requests and when they were queued.
+    tx: UnboundedSender<(Instant, TicketNotifier)>,
 }
 
 impl BucketQueue {
+    /// Number of ratelimit requests in the queue.
+    pub fn len(&self) -> u64 {
+        self.len.load(Ordering::Relaxed)
+    }
+
     /// Add a new ratelimit request to the queue.
     pub fn push(&self, tx: TicketNotifier) {
-        let _sent = self.tx.send(tx);
+        self.len.fetch_add(1, Ordering::Relaxed);
+
+        if self.tx.send((Instant::now(), tx)).is_err() {
+            self.len.fetch_sub(1, Ordering::Relaxed);
+        }
     }
 
-    /// Receive the first incoming ratelimit request.
-    pub async fn pop(&self, timeout_duration: Duration) -> Option<TicketNotifier> {
+    /// Receive the first incoming ratelimit request and when it was queued.
+    pub async fn pop(&self, timeout_duration: Duration) -> Option<(Instant, TicketNotifier)> {
         let mut rx = self.rx.lock().await;
+        let popped = timeout(timeout_duration, rx.recv()).await.ok().flatten();
+
+        if popped.is_some() {
+            self.len.fetch_sub(1, Ordering::Relaxed);
+        }
 
-        timeout(timeout_duration, rx.recv()).await.ok().flatten()
+        popped
+    }
+
+    /// Move the queued ratelimit requests into another queue, keeping when
+    /// they were queued.
+    ///
+    /// Requests are only moved if the queue isn't being popped from.
+    pub fn drain_into(&self, other: &Self) {
+        let mut rx = match self.rx.try_lock() {
+            Ok(rx) => rx,
+            Err(_) => return,
+        };
+
+        while let Some(request) = rx.recv().now_or_never().flatten() {
+            self.len.fetch_sub(1, Ordering::Relaxed);
+            other.len.fetch_add(1, Ordering::Relaxed);
+
+            if other.tx.send(request).is_err() {
+                other.len.fetch_sub(1, Ordering::Relaxed);
+            }
+        }
     }
 }
 
@@ -170,6 +230,7 @@ impl Default for BucketQueue {
         let (tx, rx) = mpsc::unbounded_channel();
 
         Self {
+            len: AtomicU64::new(0),
             rx: AsyncMutex::new(rx),
             tx,
         }
@@ -183,9 +244,13 @@ pub(super) struct BucketQueueTask {
     /// The [`Bucket`] managed by this task.
     bucket: Arc<Bucket>,
     /// All buckets managed by the associated [`super::InMemoryRatelimiter`].
-    buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+    buckets: Arc<Mutex<Buckets>>,
     /// Global ratelimit data.
     global: Arc<GlobalLockPair>,
+    /// Pacing of requests to the global ratelimit.
+    global_limiter: Arc<GlobalLimiter>,
+    /// Time to wait for a request before removing the idle bucket.
+    idle_timeout: Duration,
     /// The [`Path`] this [`Bucket`] belongs to.
     path: Path,
 }
@@ -197,14 +262,18 @@ impl BucketQueueTask {
     /// Create a new task to manage the ratelimit for a [`Bucket`].
     pub fn new(
         bucket: Arc<Bucket>,
-        buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+        buckets: Arc<Mutex<Buckets>>,
         global: Arc<GlobalLockPair>,
+        global_limiter: Arc<GlobalLimiter>,
+        idle_timeout: Duration,
         path: Path,
     ) -> Self {
         Self {
             bucket,
             buckets,
             global,
+            global_limiter,
+            idle_timeout,
             path,
         }
     }
@@ -215,22 +284,63 @@ impl BucketQueueTask {
         #[cfg(feature = "tracing")]
         let span = tracing::debug_span!("background queue task", path=?self.path);
 
-        while let Some(queue_tx) = self.next().await {
+        loop {
+            let (queued_at, queue_tx) = match self.next().await {
+                Some(next) => next,
+                None if self.try_remove() => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(parent: &span, "bucket appears finished, removed");
+
+                    return;
+                }
+                // A request was queued, such as by a path merging into this
+                // bucket, while timing out.
+                None => continue,
+            };
+
             if self.global.is_locked() {
                 self.global.0.lock().await;
             }
 
+            // Only spend global budget on requests that are still waiting.
+            if queue_tx.is_canceled() {
+                continue;
+            }
+
+            if GlobalLimiter::applies_to(&self.path) {
+                self.global_limiter.acquire().await;
+            }
+
             let ticket_headers = if let Some(ticket_headers) = queue_tx.available() {
                 ticket_headers
             } else {
                 continue;
             };
 
+            self.bucket
+                .wait
+                .lock()
+                .expect("bucket poisoned")
+                .record(queued_at.elapsed());
+
             #[cfg(feature = "tracing")]
             tracing::debug!(parent: &span, "starting to wait for response headers",);
 
             match timeout(Self::WAIT, ticket_headers).await {
-                Ok(Ok(Some(headers))) => self.handle_headers(&headers).await,
+                Ok(Ok(Some(headers))) => {
+                    if self.handle_headers(&headers).await {
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(parent: &span, "bucket merged, removing");
+
+                        // Requests were moved to the shared bucket.
+                        self.buckets
+                            .lock()
+                            .expect("ratelimit buckets poisoned")
+                            .remove(&self.bucket);
+
+                        return;
+                    }
+                }
                 Ok(Ok(None)) => {
                     #[cfg(feature = "tracing")]
                     tracing::debug!(parent: &span, "request aborted");
@@ -245,34 +355,57 @@ impl BucketQueueTask {
                 }
             }
         }
+    }
 
-        #[cfg(feature = "tracing")]
-        tracing::debug!(parent: &span, "bucket appears finished, removing");
+    /// Remove the bucket if no requests are queued in it.
+    ///
+    /// Returns whether the bucket was removed.
+    fn try_remove(&self) -> bool {
+        let mut buckets = self.buckets.lock().expect("ratelimit buckets poisoned");
+
+        if self.bucket.queue.len() > 0 {
+            return false;
+        }
+
+        buckets.remove(&self.bucket);
 
-        self.buckets
-            .lock()
-            .expect("ratelimit buckets poisoned")
-            .remove(&self.path);
+        true
     }
 
-    /// Update the bucket's ratelimit state.
-    async fn handle_headers(&self, headers: &RatelimitHeaders) {
-        let ratelimits = match headers {
+    /// Update the ratelimit state of the bucket, or of the shared bucket if
+    /// the bucket's hash is shared with another path's bucket.
+    ///
+    /// Returns whether the bucket was merged into another path's bucket.
+    async fn handle_headers(&self, headers: &RatelimitHeaders) -> bool {
+        let (bucket, ratelimits) = match headers {
             RatelimitHeaders::GlobalLimited(global_limited) => {
                 self.lock_global(Duration::from_secs(global_limited.retry_after()))
                     .await;
 
-                None
+                (Arc::clone(&self.bucket), None)
             }
-            RatelimitHeaders::None => return,
+            RatelimitHeaders::None => return false,
             RatelimitHeaders::Present(present) => {
-                Some((present.limit(), present.remaining(), present.reset_after()))
+                let bucket = match present.bucket() {
+                    Some(hash) => self
+                        .buckets
+                        .lock()
+                        .expect("ratelimit buckets poisoned")
+                        .learn(&self.path, hash, &self.bucket),
+                    None => Arc::clone(&self.bucket),
+                };
+
+                let ratelimits = (present.limit(), present.remaining(), present.reset_after());
+
+                (bucket, Some(ratelimits))
             }
         };
 
         #[cfg(feature = "tracing")]
-        tracing::debug!(path=?self.path, "updating bucket");
-        self.bucket.update(ratelimits);
+        tracing::debug!(path=?bucket.path, "updating bucket");
+        bucket.update(ratelimits);
+
+        !Arc::ptr_eq(&bucket, &self.bucket)
     }
 
     /// Lock the global ratelimit for a specified duration.
@@ -287,14 +420,14 @@ impl BucketQueueTask {
         drop(lock);
     }
 
-    /// Get the next [`TicketNotifier`] in the queue.
-    async fn next(&self) -> Option<TicketNotifier> {
+    /// Get the next [`TicketNotifier`] in the queue and when it was queued.
+    async fn next(&self) -> Option<(Instant, TicketNotifier)> {
         #[cfg(feature = "tracing")]
         tracing::debug!(path=?self.path, "starting to get next in queue");
 
         self.wait_if_needed().await;
 
-        self.bucket.queue.pop(Self::WAIT).await
+        self.bucket.queue.pop(self.idle_timeout).await
     }
 
     /// Wait for this bucket to refresh if it isn't ready yet.
@@ -0,0 +1,175 @@
+//! Proactive pacing of requests to the global ratelimit used by the
+//! [`super::InMemoryRatelimiter`] internally.
+
+use crate::request::Path;
+use std::{sync::Mutex, time::Duration};
+use tokio::time::{self, Instant};
+
+/// Number of requests per second Discord allows by default.
+pub const DEFAULT_LIMIT: u64 = 50;
+
+/// Prefix of interaction tokens, which is `interaction:` encoded in base64.
+const INTERACTION_TOKEN_PREFIX: &str = "aW50ZXJhY3Rpb246";
+
+/// Duration of a window in which up to the limit of requests may be sent.
+const WINDOW: Duration = Duration::from_secs(1);
+
+/// Paces requests to a number per second, preventing the global ratelimit
+/// from being hit in the first place.
+#[derive(Debug)]
+pub struct GlobalLimiter {
+    /// Number of requests allowed per window, or 0 if requests aren't paced.
+    limit: u64,
+    /// Current window.
+    window: Mutex<Window>,
+}
+
+/// Window in which up to the limit of requests may be sent.
+#[derive(Debug)]
+struct Window {
+    /// Number of requests sent within the window.
+    used: u64,
+    /// When the window started.
+    started_at: Instant,
+}
+
+impl GlobalLimiter {
+    /// Create a new limiter allowing a number of requests per second.
+    ///
+    /// A limit of 0 disables pacing.
+    pub fn new(limit: u64) -> Self {
+        Self {
+            limit,
+            window: Mutex::new(Window {
+                used: 0,
+                started_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Whether requests to a path count towards the global ratelimit.
+    ///
+    /// Discord exempts interaction endpoints from the global ratelimit. This
+    /// includes responding to an interaction and its original response and
+    /// follow-up messages, which share webhook paths but are authorized by the
+    /// interaction's token.
+    pub fn applies_to(path: &Path) -> bool {
+        match path {
+            Path::InteractionCallback(_) => false,
+            Path::WebhooksIdToken(_, token) | Path::WebhooksIdTokenMessagesId(_, token) => {
+                !token.starts_with(INTERACTION_TOKEN_PREFIX)
+            }
+            _ => true,
+        }
+    }
+
+    /// Number of requests that may be sent in the current window, or `None`
+    /// if requests aren't paced.
+    pub fn remaining(&self) -> Option<u64> {
+        if self.limit == 0 {
+            return None;
+        }
+
+        let window = self.window.lock().expect("global window poisoned");
+
+        if window.started_at.elapsed() >= WINDOW {
+            return Some(self.limit);
+        }
+
+        Some(self.limit.saturating_sub(window.used))
+    }
+
+    /// Wait until a request may be sent, counting it towards the current
+    /// window.
+    pub async fn acquire(&self) {
+        if self.limit == 0 {
+            return;
+        }
+
+        loop {
+            let wait = {
+                let mut window = self.window.lock().expect("global window poisoned");
+                let elapsed = window.started_at.elapsed();
+
+                if elapsed >= WINDOW {
+                    window.started_at = Instant::now();
+                    window.used = 0;
+                }
+
+                if window.used < self.limit {
+                    window.used += 1;
+
+                    return;
+                }
+
+                WINDOW.saturating_sub(elapsed)
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                milliseconds = %wait.as_millis(),
+                "waiting for global request budget to refresh",
+            );
+
+            time::sleep(wait).await;
+        }
+    }
+}
+
+impl Default for GlobalLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_LIMIT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GlobalLimiter, WINDOW};
+    use crate::request::Path;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use tokio::time::Instant;
+
+    assert_impl_all!(GlobalLimiter: Debug, Default, Send, Sync);
+
+    #[tokio::test(start_paused = true)]
+    async fn test_acquire() {
+        let limiter = GlobalLimiter::new(2);
+        assert_eq!(Some(2), limiter.remaining());
+
+        let started_at = Instant::now();
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(Some(0), limiter.remaining());
+        assert!(started_at.elapsed() < WINDOW);
+
+        limiter.acquire().await;
+        assert!(started_at.elapsed() >= WINDOW);
+        assert_eq!(Some(1), limiter.remaining());
+    }
+
+    #[tokio::test]
+    async fn test_disabled() {
+        let limiter = GlobalLimiter::new(0);
+        assert!(limiter.remaining().is_none());
+        limiter.acquire().await;
+        assert!(limiter.remaining().is_none());
+    }
+
+    #[test]
+    fn test_applies_to() {
+        assert!(GlobalLimiter::applies_to(&Path::ChannelsIdMessages(1)));
+        assert!(!GlobalLimiter::applies_to(&Path::InteractionCallback(1)));
+        assert!(GlobalLimiter::applies_to(&Path::WebhooksIdToken(
+            1,
+            "token".to_owned()
+        )));
+        assert!(!GlobalLimiter::applies_to(&Path::WebhooksIdToken(
+            1,
+            "aW50ZXJhY3Rpb246MTox".to_owned()
+        )));
+        assert!(!GlobalLimiter::applies_to(
+            &Path::WebhooksIdTokenMessagesId(1, "aW50ZXJhY3Rpb246MTox".to_owned())
+        ));
+    }
+}
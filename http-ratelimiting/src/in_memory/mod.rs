@@ -1,18 +1,29 @@
 //! In-memory based default [`Ratelimiter`] implementation used in `twilight-http`.
 
 mod bucket;
+mod global;
+mod snapshot;
 
-use self::bucket::{Bucket, BucketQueueTask};
+pub use self::snapshot::{BucketSnapshot, WaitStatistics};
+
+use self::{
+    bucket::{Bucket, BucketQueueTask},
+    global::GlobalLimiter,
+};
 use super::{
     ticket::{self, TicketNotifier},
     Bucket as InfoBucket, Ratelimiter,
 };
 use crate::{
-    request::Path, GetBucketFuture, GetTicketFuture, HasBucketFuture, IsGloballyLockedFuture,
+    request::Path, GetBucketFuture, GetGlobalRemainingFuture, GetTicketFuture, HasBucketFuture,
+    IsGloballyLockedFuture,
 };
 use futures_util::future;
 use std::{
-    collections::hash_map::{Entry, HashMap},
+    collections::{
+        hash_map::{Entry, HashMap},
+        HashSet,
+    },
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc, Mutex,
@@ -45,6 +56,117 @@ impl GlobalLockPair {
     }
 }
 
+/// Key of a [`Bucket`] within the [`InMemoryRatelimiter`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum BucketKey {
+    /// Bucket shared by all paths reporting a bucket hash for a resource.
+    Hash {
+        /// Bucket hash reported by Discord.
+        hash: String,
+        /// ID of the resource the paths operate on.
+        major_parameter: Option<u64>,
+    },
+    /// Bucket of a path whose bucket hash isn't known yet.
+    Path(Path),
+}
+
+/// Buckets of the [`InMemoryRatelimiter`] and the bucket hashes learned from
+/// responses.
+#[derive(Debug, Default)]
+struct Buckets {
+    /// Mapping of [`BucketKey`]s to their associated [`Bucket`]s.
+    ///
+    /// A bucket may be present under both its path and its bucket hash.
+    buckets: HashMap<BucketKey, Arc<Bucket>>,
+    /// Bucket hashes that paths were last reported to have.
+    hashes: HashMap<Path, String>,
+}
+
+impl Buckets {
+    /// Get the bucket a path's requests are currently queued in, if any.
+    fn get(&self, path: &Path) -> Option<&Arc<Bucket>> {
+        self.buckets.get(&self.key(path))
+    }
+
+    /// Key of the bucket for a path.
+    fn key(&self, path: &Path) -> BucketKey {
+        match self.hashes.get(path) {
+            Some(hash) => BucketKey::Hash {
+                hash: hash.clone(),
+                major_parameter: path.major_parameter(),
+            },
+            None => BucketKey::Path(path.clone()),
+        }
+    }
+
+    /// Record the bucket hash reported for a path, sharing the bucket with
+    /// other paths reporting the same hash, and return the shared bucket.
+    ///
+    /// If no other path of the resource has reported the hash yet then the
+    /// path's current bucket becomes the shared bucket. Otherwise, requests
+    /// queued in the path's current bucket are moved to the shared bucket.
+    fn learn(&mut self, path: &Path, hash: &str, bucket: &Arc<Bucket>) -> Arc<Bucket> {
+        if self.hashes.get(path).map(String::as_str) != Some(hash) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?path, hash, "learned bucket hash");
+
+            self.hashes.insert(path.clone(), hash.to_owned());
+        }
+
+        let key = BucketKey::Hash {
+            hash: hash.to_owned(),
+            major_parameter: path.major_parameter(),
+        };
+
+        let shared = self
+            .buckets
+            .entry(key)
+            .or_insert_with(|| Arc::clone(bucket));
+
+        shared
+            .hash
+            .lock()
+            .expect("bucket poisoned")
+            .get_or_insert_with(|| hash.to_owned());
+
+        if !Arc::ptr_eq(bucket, shared) {
+            // Requests for the path are now queued in the shared bucket, so
+            // none are queued in the current bucket after these.
+            bucket.queue.drain_into(&shared.queue);
+        }
+
+        Arc::clone(shared)
+    }
+
+    /// Remove all entries of a bucket, forgetting the bucket hashes of the
+    /// paths that shared it.
+    fn remove(&mut self, bucket: &Arc<Bucket>) {
+        let mut removed = Vec::new();
+
+        self.buckets.retain(|key, existing| {
+            let retain = !Arc::ptr_eq(existing, bucket);
+
+            if !retain {
+                removed.push(key.clone());
+            }
+
+            retain
+        });
+
+        self.hashes.retain(|path, hash| {
+            !removed.iter().any(|key| {
+                matches!(
+                    key,
+                    BucketKey::Hash {
+                        hash: removed_hash,
+                        major_parameter,
+                    } if removed_hash == hash && *major_parameter == path.major_parameter()
+                )
+            })
+        });
+    }
+}
+
 /// Default ratelimiter implementation used in twilight that
 /// stores ratelimit information in an in-memory mapping.
 ///
@@ -53,13 +175,26 @@ impl GlobalLockPair {
 /// your own [`Ratelimiter`] that uses a shared storage backend
 /// or use the [HTTP proxy].
 ///
+/// Buckets are initially tracked per [`Path`]. Once a response reports the
+/// path's bucket hash, requests to the path are queued in a bucket shared by
+/// all paths reporting the same hash for the same [major parameter].
+///
+/// Buckets that haven't had a request queued for an [idle timeout] are
+/// removed.
+///
+/// [idle timeout]: InMemoryRatelimiterBuilder::idle_timeout
+/// [major parameter]: Path::major_parameter
 /// [HTTP proxy]: https://twilight.rs/chapter_2_multi-serviced_approach.html#http-proxy-ratelimiting
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct InMemoryRatelimiter {
-    /// Mapping of [`Path`]s to their associated [`Bucket`]s.
-    buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+    /// Buckets and the bucket hashes of paths.
+    buckets: Arc<Mutex<Buckets>>,
     /// Global ratelimit data.
     global: Arc<GlobalLockPair>,
+    /// Pacing of requests to the global ratelimit.
+    global_limiter: Arc<GlobalLimiter>,
+    /// Time to wait for a request before removing an idle bucket.
+    idle_timeout: Duration,
 }
 
 impl InMemoryRatelimiter {
@@ -67,17 +202,97 @@ impl InMemoryRatelimiter {
     ///
     /// This is used by HTTP client to queue requests in order to avoid
     /// hitting the API's ratelimits.
+    ///
+    /// Requests are paced to Discord's default global ratelimit of 50
+    /// requests per second.
     #[must_use]
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Create a new builder to configure an in-memory ratelimiter.
+    #[must_use]
+    pub const fn builder() -> InMemoryRatelimiterBuilder {
+        InMemoryRatelimiterBuilder::new()
+    }
+
+    /// Create a new in-memory ratelimiter pacing requests to a global
+    /// ratelimit of a number of requests per second.
+    ///
+    /// Large bots may have a higher global ratelimit than the default of 50
+    /// requests per second. A limit of 0 disables pacing, in which case the
+    /// global ratelimit is only respected once Discord reports it as
+    /// exhausted.
+    ///
+    /// Requests to interaction endpoints are exempt from the global
+    /// ratelimit.
+    ///
+    /// This is a shorthand for configuring a [builder] with
+    /// [`InMemoryRatelimiterBuilder::global_limit`].
+    ///
+    /// [builder]: Self::builder
+    #[must_use]
+    pub fn with_global_limit(requests_per_second: u64) -> Self {
+        Self::builder().global_limit(requests_per_second).build()
+    }
+
+    /// Snapshot of the current state of every bucket.
+    ///
+    /// This is useful for monitoring how close to their ratelimits buckets
+    /// are, how many requests are waiting in them, and how long requests wait
+    /// for a ticket.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_http_ratelimiting::InMemoryRatelimiter;
+    ///
+    /// let ratelimiter = InMemoryRatelimiter::new();
+    ///
+    /// for bucket in ratelimiter.snapshot() {
+    ///     println!(
+    ///         "{:?}: {}/{} remaining, {} queued",
+    ///         bucket.path(),
+    ///         bucket.remaining(),
+    ///         bucket.limit(),
+    ///         bucket.queued(),
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock over the buckets is poisoned.
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<BucketSnapshot> {
+        // Clone the buckets out so that the lock isn't held while snapshotting
+        // each bucket.
+        let buckets = self
+            .buckets
+            .lock()
+            .expect("buckets poisoned")
+            .buckets
+            .values()
+            .map(Arc::clone)
+            .collect::<Vec<_>>();
+        let mut seen = HashSet::with_capacity(buckets.len());
+
+        // Shared buckets are present under multiple keys.
+        buckets
+            .iter()
+            .filter(|bucket| seen.insert(Arc::as_ptr(bucket)))
+            .map(|bucket| bucket.snapshot())
+            .collect()
+    }
+
     /// Get the [`Bucket`] for a [`Path`] and queue for a [`TicketNotifier`]
     /// to be notified when a request may be performed.
     fn entry(&self, path: Path, tx: TicketNotifier) -> (Arc<Bucket>, bool) {
         let mut buckets = self.buckets.lock().expect("buckets poisoned");
 
-        match buckets.entry(path.clone()) {
+        let key = buckets.key(&path);
+
+        match buckets.buckets.entry(key) {
             Entry::Occupied(bucket) => {
                 #[cfg(feature = "tracing")]
                 tracing::debug!("got existing bucket: {:?}", path);
@@ -106,6 +321,85 @@ impl InMemoryRatelimiter {
     }
 }
 
+impl Default for InMemoryRatelimiter {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Builder to configure an [`InMemoryRatelimiter`].
+#[derive(Debug)]
+pub struct InMemoryRatelimiterBuilder {
+    /// Number of requests per second to pace requests to.
+    global_limit: u64,
+    /// Time to wait for a request before removing an idle bucket.
+    idle_timeout: Duration,
+}
+
+impl InMemoryRatelimiterBuilder {
+    /// Default time to wait for a request before removing an idle bucket.
+    const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Create a new builder to configure an [`InMemoryRatelimiter`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            global_limit: global::DEFAULT_LIMIT,
+            idle_timeout: Self::IDLE_TIMEOUT,
+        }
+    }
+
+    /// Build the [`InMemoryRatelimiter`].
+    #[must_use]
+    pub fn build(self) -> InMemoryRatelimiter {
+        InMemoryRatelimiter {
+            buckets: Arc::default(),
+            global: Arc::default(),
+            global_limiter: Arc::new(GlobalLimiter::new(self.global_limit)),
+            idle_timeout: self.idle_timeout,
+        }
+    }
+
+    /// Set the number of requests per second to pace requests to, respecting
+    /// the global ratelimit.
+    ///
+    /// Large bots may have a higher global ratelimit than the default of 50
+    /// requests per second. A limit of 0 disables pacing, in which case the
+    /// global ratelimit is only respected once Discord reports it as
+    /// exhausted.
+    ///
+    /// Requests to interaction endpoints are exempt from the global
+    /// ratelimit.
+    #[must_use]
+    pub const fn global_limit(mut self, requests_per_second: u64) -> Self {
+        self.global_limit = requests_per_second;
+
+        self
+    }
+
+    /// Set the time to wait for a request to be queued in a bucket before
+    /// removing the idle bucket.
+    ///
+    /// Removing idle buckets keeps memory usage low, but the ratelimit of a
+    /// removed bucket is unknown until a response to a new request reports
+    /// it. Buckets aren't removed while waiting for their ratelimit to
+    /// refresh.
+    ///
+    /// Defaults to 10 seconds.
+    #[must_use]
+    pub const fn idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+
+        self
+    }
+}
+
+impl Default for InMemoryRatelimiterBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Ratelimiter for InMemoryRatelimiter {
     fn bucket(&self, path: &Path) -> GetBucketFuture {
         self.buckets
@@ -127,6 +421,10 @@ impl Ratelimiter for InMemoryRatelimiter {
             )
     }
 
+    fn global_remaining(&self) -> GetGlobalRemainingFuture {
+        Box::pin(future::ok(self.global_limiter.remaining()))
+    }
+
     fn globally_locked(&self) -> IsGloballyLockedFuture {
         Box::pin(future::ok(self.global.is_locked()))
     }
@@ -136,7 +434,8 @@ impl Ratelimiter for InMemoryRatelimiter {
             .buckets
             .lock()
             .expect("buckets poisoned")
-            .contains_key(path);
+            .get(path)
+            .is_some();
 
         Box::pin(future::ok(has))
     }
@@ -154,6 +453,8 @@ impl Ratelimiter for InMemoryRatelimiter {
                     bucket,
                     Arc::clone(&self.buckets),
                     Arc::clone(&self.global),
+                    Arc::clone(&self.global_limiter),
+                    self.idle_timeout,
                     path,
                 )
                 .run(),
@@ -163,3 +464,289 @@ impl Ratelimiter for InMemoryRatelimiter {
         Box::pin(future::ok(rx))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{InMemoryRatelimiter, InMemoryRatelimiterBuilder};
+    use crate::{
+        headers::RatelimitHeaders,
+        request::Path,
+        ticket::{TicketDeadlineError, TicketDeadlineErrorType},
+        GenericError, Ratelimiter,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        time::{Duration, Instant},
+    };
+    use tokio::time;
+
+    assert_impl_all!(InMemoryRatelimiter: Clone, Debug, Default, Ratelimiter, Send, Sync);
+    assert_impl_all!(InMemoryRatelimiterBuilder: Debug, Default, Send, Sync);
+
+    fn headers(bucket: &str, remaining: &str) -> Result<RatelimitHeaders, GenericError> {
+        let headers = [
+            ("x-ratelimit-bucket", bucket.as_bytes()),
+            ("x-ratelimit-limit", b"5".as_ref()),
+            ("x-ratelimit-remaining", remaining.as_bytes()),
+            ("x-ratelimit-reset", b"1000000000".as_ref()),
+            ("x-ratelimit-reset-after", b"60".as_ref()),
+        ];
+
+        Ok(RatelimitHeaders::from_pairs(headers.into_iter())?)
+    }
+
+    /// Send a request to a path, responding with ratelimit headers.
+    async fn request(
+        ratelimiter: &InMemoryRatelimiter,
+        path: Path,
+        headers: RatelimitHeaders,
+    ) -> Result<(), GenericError> {
+        let sender = ratelimiter.wait_for_ticket(path).await?;
+        let _sent = sender.headers(Some(headers));
+
+        // Allow the bucket's queue task to process the headers.
+        time::sleep(Duration::from_millis(50)).await;
+
+        Ok(())
+    }
+
+    async fn remaining(ratelimiter: &InMemoryRatelimiter, path: &Path) -> Option<u64> {
+        ratelimiter
+            .bucket(path)
+            .await
+            .unwrap()
+            .map(|bucket| bucket.remaining())
+    }
+
+    #[tokio::test]
+    async fn test_shared_bucket() -> Result<(), GenericError> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let pins = Path::ChannelsIdPins(1);
+        let typing = Path::ChannelsIdTyping(1);
+        let other_channel = Path::ChannelsIdTyping(2);
+
+        request(&ratelimiter, pins.clone(), headers("abc", "4")?).await?;
+        request(&ratelimiter, typing.clone(), headers("abc", "3")?).await?;
+        request(&ratelimiter, other_channel.clone(), headers("abc", "4")?).await?;
+
+        // The bucket of the path first reporting the hash is shared, and is
+        // updated by the responses of the other paths.
+        assert_eq!(Some(3), remaining(&ratelimiter, &pins).await);
+        assert_eq!(Some(3), remaining(&ratelimiter, &typing).await);
+
+        request(&ratelimiter, typing.clone(), headers("abc", "2")?).await?;
+        assert_eq!(Some(2), remaining(&ratelimiter, &pins).await);
+        assert_eq!(Some(2), remaining(&ratelimiter, &typing).await);
+
+        // Paths of other resources don't share the bucket.
+        assert_eq!(Some(4), remaining(&ratelimiter, &other_channel).await);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_shared_bucket_queued() -> Result<(), GenericError> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let pins = Path::ChannelsIdPins(1);
+        let typing = Path::ChannelsIdTyping(1);
+
+        request(&ratelimiter, pins.clone(), headers("abc", "4")?).await?;
+
+        // Queue a request behind the path's first request, before the path's
+        // bucket hash is known.
+        let first = ratelimiter.wait_for_ticket(typing.clone()).await?;
+        let second = ratelimiter.ticket(typing.clone()).await?;
+        let _sent = first.headers(Some(headers("abc", "3")?));
+
+        // The queued request is moved to the shared bucket.
+        let second = time::timeout(Duration::from_secs(1), second).await??;
+        let _sent = second.headers(Some(headers("abc", "2")?));
+        time::sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(Some(2), remaining(&ratelimiter, &pins).await);
+        assert_eq!(1, ratelimiter.snapshot().len());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unknown_hash() -> Result<(), GenericError> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let path = Path::ChannelsIdPins(1);
+        assert!(!ratelimiter.has(&path).await.unwrap());
+
+        request(&ratelimiter, path.clone(), RatelimitHeaders::None).await?;
+        assert!(ratelimiter.has(&path).await.unwrap());
+        assert!(!ratelimiter.has(&Path::ChannelsIdTyping(1)).await.unwrap());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshot() -> Result<(), GenericError> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        assert!(ratelimiter.snapshot().is_empty());
+
+        request(&ratelimiter, Path::ChannelsIdPins(1), headers("abc", "4")?).await?;
+        request(
+            &ratelimiter,
+            Path::ChannelsIdTyping(1),
+            headers("abc", "3")?,
+        )
+        .await?;
+        request(
+            &ratelimiter,
+            Path::ChannelsIdTyping(1),
+            headers("abc", "2")?,
+        )
+        .await?;
+
+        // The bucket of the path merged into the shared bucket is removed.
+        let snapshot = ratelimiter.snapshot();
+        assert_eq!(1, snapshot.len());
+
+        let shared = &snapshot[0];
+        assert_eq!(Some("abc"), shared.hash());
+        assert_eq!(&Path::ChannelsIdPins(1), shared.path());
+        assert_eq!(5, shared.limit());
+        assert_eq!(2, shared.remaining());
+        assert_eq!(Duration::from_secs(60), shared.reset_after());
+        assert_eq!(0, shared.queued());
+        assert_eq!(2, shared.wait().count());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_idle_timeout() -> Result<(), GenericError> {
+        let ratelimiter = InMemoryRatelimiter::builder()
+            .idle_timeout(Duration::from_millis(100))
+            .build();
+        let path = Path::ChannelsIdPins(1);
+
+        request(&ratelimiter, path.clone(), RatelimitHeaders::None).await?;
+        assert!(ratelimiter.has(&path).await?);
+
+        time::sleep(Duration::from_millis(200)).await;
+        assert!(!ratelimiter.has(&path).await?);
+        assert!(ratelimiter.snapshot().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_idle_timeout_forgets_hashes() -> Result<(), GenericError> {
+        let ratelimiter = InMemoryRatelimiter::builder()
+            .idle_timeout(Duration::from_millis(300))
+            .build();
+
+        request(&ratelimiter, Path::ChannelsIdPins(1), headers("abc", "4")?).await?;
+        request(
+            &ratelimiter,
+            Path::ChannelsIdTyping(1),
+            headers("abc", "3")?,
+        )
+        .await?;
+        assert_eq!(
+            2,
+            ratelimiter
+                .buckets
+                .lock()
+                .expect("buckets poisoned")
+                .hashes
+                .len()
+        );
+
+        time::sleep(Duration::from_millis(500)).await;
+        assert!(ratelimiter
+            .buckets
+            .lock()
+            .expect("buckets poisoned")
+            .hashes
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_deadline_elapsed_ticket_skipped() -> Result<(), GenericError> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let path = Path::ChannelsIdPins(1);
+
+        let sender = ratelimiter.wait_for_ticket(path.clone()).await?;
+
+        let deadline = Instant::now() + Duration::from_millis(100);
+        assert!(ratelimiter
+            .wait_for_ticket_before(path.clone(), deadline)
+            .await
+            .is_err());
+        drop(sender);
+
+        // The ticket given up on at its deadline doesn't hold up the bucket
+        // until the response timeout.
+        time::timeout(Duration::from_secs(1), ratelimiter.wait_for_ticket(path)).await??;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dropped_ticket_global() -> Result<(), GenericError> {
+        let ratelimiter = InMemoryRatelimiter::with_global_limit(2);
+        let path = Path::ChannelsIdPins(1);
+
+        let first = ratelimiter.wait_for_ticket(path.clone()).await?;
+        let second = ratelimiter.ticket(path).await?;
+        drop(second);
+        let _sent = first.headers(None);
+        time::sleep(Duration::from_millis(50)).await;
+
+        // The dropped ticket doesn't spend global budget.
+        assert_eq!(Some(1), ratelimiter.global_remaining().await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_deadline_exhausted() -> Result<(), GenericError> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let path = Path::ChannelsIdPins(1);
+        request(&ratelimiter, path.clone(), headers("abc", "0")?).await?;
+
+        let started_at = Instant::now();
+        let error = ratelimiter
+            .wait_for_ticket_before(path, started_at + Duration::from_secs(3))
+            .await
+            .unwrap_err();
+        assert!(started_at.elapsed() < Duration::from_secs(1));
+
+        let error = error.downcast::<TicketDeadlineError>().unwrap();
+        assert!(matches!(
+            error.kind(),
+            TicketDeadlineErrorType::Exhausted { time_remaining }
+                if *time_remaining > Duration::from_secs(3)
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_deadline_elapsed() -> Result<(), GenericError> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let path = Path::ChannelsIdPins(1);
+
+        // Hold a ticket so that the next request has to wait for its response.
+        let _sender = ratelimiter.wait_for_ticket(path.clone()).await?;
+
+        let deadline = Instant::now() + Duration::from_millis(100);
+        let error = ratelimiter
+            .wait_for_ticket_before(path, deadline)
+            .await
+            .unwrap_err();
+
+        let error = error.downcast::<TicketDeadlineError>().unwrap();
+        assert!(matches!(error.kind(), TicketDeadlineErrorType::Elapsed));
+
+        Ok(())
+    }
+}
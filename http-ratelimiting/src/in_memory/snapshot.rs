@@ -0,0 +1,168 @@
+//! Snapshots of the state of the buckets of the [`super::InMemoryRatelimiter`].
+
+use crate::request::Path;
+use std::time::{Duration, Instant};
+
+/// State of a bucket of the [`InMemoryRatelimiter`] at a point in time.
+///
+/// Retrieved via [`InMemoryRatelimiter::snapshot`].
+///
+/// [`InMemoryRatelimiter`]: super::InMemoryRatelimiter
+/// [`InMemoryRatelimiter::snapshot`]: super::InMemoryRatelimiter::snapshot
+#[derive(Clone, Debug)]
+pub struct BucketSnapshot {
+    /// Bucket hash reported by Discord, if known.
+    pub(super) hash: Option<String>,
+    /// Total number of tickets allotted in a cycle.
+    pub(super) limit: u64,
+    /// Path the bucket was created for.
+    pub(super) path: Path,
+    /// Number of requests waiting for a ticket.
+    pub(super) queued: u64,
+    /// Number of tickets remaining.
+    pub(super) remaining: u64,
+    /// Duration after [`Self::started_at`] time the bucket will refresh.
+    pub(super) reset_after: Duration,
+    /// When the bucket's ratelimit refresh countdown started.
+    pub(super) started_at: Option<Instant>,
+    /// Statistics of the time requests waited for a ticket.
+    pub(super) wait: WaitStatistics,
+}
+
+impl BucketSnapshot {
+    /// Bucket hash reported by Discord, if a response has reported it.
+    ///
+    /// Paths reporting the same hash for the same [major parameter] share the
+    /// bucket.
+    ///
+    /// [major parameter]: Path::major_parameter
+    #[must_use]
+    pub fn hash(&self) -> Option<&str> {
+        self.hash.as_deref()
+    }
+
+    /// Total number of tickets allotted in a cycle.
+    #[must_use]
+    pub const fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Path the bucket was created for.
+    #[must_use]
+    pub const fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of requests waiting for a ticket.
+    #[must_use]
+    pub const fn queued(&self) -> u64 {
+        self.queued
+    }
+
+    /// Number of tickets remaining.
+    #[must_use]
+    pub const fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Duration after the [`started_at`] time the bucket will refresh.
+    ///
+    /// [`started_at`]: Self::started_at
+    #[must_use]
+    pub const fn reset_after(&self) -> Duration {
+        self.reset_after
+    }
+
+    /// When the bucket's ratelimit refresh countdown started.
+    #[must_use]
+    pub const fn started_at(&self) -> Option<Instant> {
+        self.started_at
+    }
+
+    /// How long until the bucket will refresh.
+    ///
+    /// May return `None` if the refresh timer has not been started yet or
+    /// the bucket has already refreshed.
+    #[must_use]
+    pub fn time_remaining(&self) -> Option<Duration> {
+        let reset_at = self.started_at? + self.reset_after;
+
+        reset_at.checked_duration_since(Instant::now())
+    }
+
+    /// Statistics of the time requests waited for a ticket.
+    #[must_use]
+    pub const fn wait(&self) -> WaitStatistics {
+        self.wait
+    }
+}
+
+/// Statistics of the time requests waited in a bucket's queue for a ticket.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct WaitStatistics {
+    /// Number of requests that received a ticket.
+    count: u64,
+    /// Longest time a request waited.
+    max: Duration,
+    /// Total time requests waited.
+    total: Duration,
+}
+
+impl WaitStatistics {
+    /// Number of requests that received a ticket.
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Longest time a request waited.
+    #[must_use]
+    pub const fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Average time a request waited, if any have received a ticket.
+    #[must_use]
+    pub fn mean(&self) -> Option<Duration> {
+        let count = u32::try_from(self.count).unwrap_or(u32::MAX);
+
+        (count > 0).then(|| self.total / count)
+    }
+
+    /// Total time requests waited.
+    #[must_use]
+    pub const fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// Record the time a request waited for a ticket.
+    pub(super) fn record(&mut self, wait: Duration) {
+        self.count += 1;
+        self.max = self.max.max(wait);
+        self.total += wait;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BucketSnapshot, WaitStatistics};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+
+    assert_impl_all!(BucketSnapshot: Clone, Debug, Send, Sync);
+    assert_impl_all!(WaitStatistics: Clone, Copy, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_wait_statistics() {
+        let mut wait = WaitStatistics::default();
+        assert!(wait.mean().is_none());
+
+        wait.record(Duration::from_millis(100));
+        wait.record(Duration::from_millis(300));
+
+        assert_eq!(2, wait.count());
+        assert_eq!(Duration::from_millis(300), wait.max());
+        assert_eq!(Some(Duration::from_millis(200)), wait.mean());
+        assert_eq!(Duration::from_millis(400), wait.total());
+    }
+}
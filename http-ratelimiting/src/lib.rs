@@ -52,8 +52,8 @@ pub use self::{
     request::{Method, Path},
 };
 
-use self::ticket::{TicketReceiver, TicketSender};
-use futures_util::FutureExt;
+use self::ticket::{TicketDeadlineError, TicketDeadlineErrorType, TicketReceiver, TicketSender};
+use futures_util::{future, FutureExt};
 use std::{
     error::Error,
     fmt::Debug,
@@ -119,6 +119,10 @@ pub type GenericError = Box<dyn Error + Send + Sync>;
 pub type GetBucketFuture =
     Pin<Box<dyn Future<Output = Result<Option<Bucket>, GenericError>> + Send + 'static>>;
 
+/// Future returned by [`Ratelimiter::global_remaining`].
+pub type GetGlobalRemainingFuture =
+    Pin<Box<dyn Future<Output = Result<Option<u64>, GenericError>> + Send + 'static>>;
+
 /// Future returned by [`Ratelimiter::globally_locked`].
 pub type IsGloballyLockedFuture =
     Pin<Box<dyn Future<Output = Result<bool, GenericError>> + Send + 'static>>;
@@ -155,6 +159,15 @@ pub trait Ratelimiter: Debug + Send + Sync {
     /// Retrieve the basic information of the bucket for a given path.
     fn bucket(&self, path: &Path) -> GetBucketFuture;
 
+    /// Number of requests that may currently be sent before the global
+    /// ratelimit is exhausted.
+    ///
+    /// Returns `None` if the ratelimiter doesn't proactively pace requests to
+    /// the global ratelimit, which is the default.
+    fn global_remaining(&self) -> GetGlobalRemainingFuture {
+        Box::pin(future::ok(None))
+    }
+
     /// Whether the ratelimiter is currently globally locked.
     fn globally_locked(&self) -> IsGloballyLockedFuture;
 
@@ -180,4 +193,48 @@ pub trait Ratelimiter: Debug + Send + Sync {
             }
         }))
     }
+
+    /// Retrieve a ticket to send a request, giving up at a deadline.
+    ///
+    /// If the path's bucket is exhausted and refreshes after the deadline
+    /// then this fails immediately, instead of waiting for the deadline.
+    /// This is useful for requests with a time budget, such as responding to
+    /// an interaction within 3 seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TicketDeadlineError`] of type
+    /// [`TicketDeadlineErrorType::Exhausted`] if the bucket refreshes after
+    /// the deadline.
+    ///
+    /// Returns a [`TicketDeadlineError`] of type
+    /// [`TicketDeadlineErrorType::Elapsed`] if the deadline elapses while
+    /// waiting for a ticket.
+    fn wait_for_ticket_before(&self, path: Path, deadline: Instant) -> WaitForTicketFuture {
+        let bucket = self.bucket(&path);
+        let ticket = self.ticket(path);
+
+        Box::pin(async move {
+            if let Some(bucket) = bucket.await? {
+                if let Some(time_remaining) = bucket.time_remaining() {
+                    if bucket.remaining() == 0 && Instant::now() + time_remaining > deadline {
+                        return Err(Box::new(TicketDeadlineError {
+                            kind: TicketDeadlineErrorType::Exhausted { time_remaining },
+                            source: None,
+                        }) as GenericError);
+                    }
+                }
+            }
+
+            let rx = ticket.await?;
+
+            match tokio::time::timeout_at(deadline.into(), rx).await {
+                Ok(result) => result.map_err(From::from),
+                Err(source) => Err(Box::new(TicketDeadlineError {
+                    kind: TicketDeadlineErrorType::Elapsed,
+                    source: Some(Box::new(source)),
+                }) as GenericError),
+            }
+        })
+    }
 }
@@ -250,6 +250,12 @@ pub enum Path {
     UsersId,
     /// Operating on the user's application information.
     OauthApplicationsMe,
+    /// Operating on the current `OAuth2` authorization.
+    OauthMe,
+    /// Operating on `OAuth2` access tokens.
+    OauthToken,
+    /// Operating on the revocation of `OAuth2` access tokens.
+    OauthTokenRevoke,
     /// Operating on the user's connections.
     UsersIdConnections,
     /// Operating on the user's private channels.
@@ -270,6 +276,108 @@ pub enum Path {
     WebhooksIdTokenMessagesId(u64, String),
 }
 
+impl Path {
+    /// ID of the resource the path's ratelimits are scoped to, such as a
+    /// channel, guild, or webhook.
+    ///
+    /// Discord shares a ratelimit bucket between paths reporting the same
+    /// bucket hash only if they operate on the same resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_http_ratelimiting::Path;
+    ///
+    /// assert_eq!(Some(123), Path::ChannelsIdMessages(123).major_parameter());
+    /// assert!(Path::Gateway.major_parameter().is_none());
+    /// ```
+    #[allow(clippy::too_many_lines)]
+    #[must_use]
+    pub const fn major_parameter(&self) -> Option<u64> {
+        match self {
+            Path::ApplicationCommand(id)
+            | Path::ApplicationCommandId(id)
+            | Path::ApplicationGuildCommand(id)
+            | Path::ApplicationGuildCommandId(id)
+            | Path::ChannelsId(id)
+            | Path::ChannelsIdInvites(id)
+            | Path::ChannelsIdMessages(id)
+            | Path::ChannelsIdMessagesBulkDelete(id)
+            | Path::ChannelsIdMessagesId(_, id)
+            | Path::ChannelsIdMessagesIdCrosspost(id)
+            | Path::ChannelsIdMessagesIdReactions(id)
+            | Path::ChannelsIdMessagesIdReactionsUserIdType(id)
+            | Path::ChannelsIdMessagesIdThreads(id)
+            | Path::ChannelsIdPermissionsOverwriteId(id)
+            | Path::ChannelsIdPins(id)
+            | Path::ChannelsIdPinsMessageId(id)
+            | Path::ChannelsIdRecipients(id)
+            | Path::ChannelsIdThreadMembers(id)
+            | Path::ChannelsIdThreads(id)
+            | Path::ChannelsIdTyping(id)
+            | Path::ChannelsIdWebhooks(id)
+            | Path::ChannelsIdFollowers(id)
+            | Path::GuildsId(id)
+            | Path::GuildsIdBans(id)
+            | Path::GuildsIdBansId(id)
+            | Path::GuildsIdAuditLogs(id)
+            | Path::GuildsIdBansUserId(id)
+            | Path::GuildsIdChannels(id)
+            | Path::GuildsIdWidget(id)
+            | Path::GuildsIdEmojis(id)
+            | Path::GuildsIdEmojisId(id)
+            | Path::GuildsIdIntegrations(id)
+            | Path::GuildsIdIntegrationsId(id)
+            | Path::GuildsIdIntegrationsIdSync(id)
+            | Path::GuildsIdInvites(id)
+            | Path::GuildsIdMembers(id)
+            | Path::GuildsIdMembersId(id)
+            | Path::GuildsIdMembersIdRolesId(id)
+            | Path::GuildsIdMembersMeNick(id)
+            | Path::GuildsIdMembersSearch(id)
+            | Path::GuildsIdPreview(id)
+            | Path::GuildsIdPrune(id)
+            | Path::GuildsIdRegions(id)
+            | Path::GuildsIdRoles(id)
+            | Path::GuildsIdRolesId(id)
+            | Path::GuildsIdScheduledEvents(id)
+            | Path::GuildsIdScheduledEventsId(id)
+            | Path::GuildsIdScheduledEventsIdUsers(id)
+            | Path::GuildsIdStickers(id)
+            | Path::GuildsIdTemplates(id)
+            | Path::GuildsIdTemplatesCode(id, _)
+            | Path::GuildsIdThreads(id)
+            | Path::GuildsIdVanityUrl(id)
+            | Path::GuildsIdVoiceStates(id)
+            | Path::GuildsIdWelcomeScreen(id)
+            | Path::GuildsIdWebhooks(id)
+            | Path::InteractionCallback(id)
+            | Path::WebhooksId(id)
+            | Path::WebhooksIdToken(id, _)
+            | Path::WebhooksIdTokenMessagesId(id, _) => Some(*id),
+            Path::Gateway
+            | Path::GatewayBot
+            | Path::Guilds
+            | Path::InvitesCode
+            | Path::StageInstances
+            | Path::StickerPacks
+            | Path::Stickers
+            | Path::UsersId
+            | Path::OauthApplicationsMe
+            | Path::OauthMe
+            | Path::OauthToken
+            | Path::OauthTokenRevoke
+            | Path::UsersIdConnections
+            | Path::UsersIdChannels
+            | Path::UsersIdGuilds
+            | Path::UsersIdGuildsId
+            | Path::UsersIdGuildsIdMember
+            | Path::VoiceRegions
+            | Path::GuildsTemplatesCode(_) => None,
+        }
+    }
+}
+
 impl FromStr for Path {
     type Err = PathParseError;
 
@@ -408,6 +516,9 @@ impl FromStr for Path {
             ["sticker-packs"] => StickerPacks,
             ["stickers", _] => Stickers,
             ["oauth2", "applications", "@me"] => OauthApplicationsMe,
+            ["oauth2", "@me"] => OauthMe,
+            ["oauth2", "token"] => OauthToken,
+            ["oauth2", "token", "revoke"] => OauthTokenRevoke,
             ["users", _] => UsersId,
             ["users", _, "connections"] => UsersIdConnections,
             ["users", _, "channels"] => UsersIdChannels,
@@ -473,6 +584,11 @@ mod tests {
         assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
         assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
         assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
+        assert_eq!(Path::OauthToken, Path::from_str("/oauth2/token")?);
+        assert_eq!(
+            Path::OauthTokenRevoke,
+            Path::from_str("/oauth2/token/revoke")?
+        );
 
         Ok(())
     }
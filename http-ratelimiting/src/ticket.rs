@@ -27,6 +27,15 @@
 //! sent to the ratelimiter via [`TicketSender::headers`]. This completes the
 //! cycle.
 //!
+//! ## Canceling
+//!
+//! Consumers may drop the [`TicketReceiver`] or [`TicketSender`] at any time,
+//! such as when a request is canceled. A dropped receiver's ticket is skipped
+//! and a dropped sender's ticket is returned to the bucket without waiting
+//! for a response, so the next ticket is approved immediately. Consumers with
+//! a time budget may use [`Ratelimiter::wait_for_ticket_before`] to stop
+//! waiting at a deadline.
+//!
 //! # Ratelimiter
 //!
 //! ## 1. Initializing a ticket's channels
@@ -55,17 +64,96 @@
 //! headers to complete the cycle.
 //!
 //! [`Ratelimiter::ticket`]: super::Ratelimiter::ticket
+//! [`Ratelimiter::wait_for_ticket_before`]: super::Ratelimiter::wait_for_ticket_before
 //! [`Ratelimiter`]: super::Ratelimiter
 //! [must be parsed]: super::headers
 
 use crate::headers::RatelimitHeaders;
 use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
     future::Future,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
 use tokio::sync::oneshot::{self, error::RecvError, Receiver, Sender};
 
+/// Ticket couldn't be acquired before a deadline.
+///
+/// Returned by [`Ratelimiter::wait_for_ticket_before`].
+///
+/// [`Ratelimiter::wait_for_ticket_before`]: super::Ratelimiter::wait_for_ticket_before
+#[derive(Debug)]
+pub struct TicketDeadlineError {
+    /// Type of error that occurred.
+    pub(super) kind: TicketDeadlineErrorType,
+    /// Original error leading up to this one.
+    pub(super) source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl TicketDeadlineError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &TicketDeadlineErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        TicketDeadlineErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for TicketDeadlineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            TicketDeadlineErrorType::Elapsed => {
+                f.write_str("deadline elapsed while waiting for a ticket")
+            }
+            TicketDeadlineErrorType::Exhausted { time_remaining } => {
+                f.write_str("bucket is exhausted and refreshes in ")?;
+                Display::fmt(&time_remaining.as_millis(), f)?;
+
+                f.write_str("ms, after the deadline")
+            }
+        }
+    }
+}
+
+impl Error for TicketDeadlineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`TicketDeadlineError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TicketDeadlineErrorType {
+    /// Deadline elapsed while waiting for a ticket.
+    Elapsed,
+    /// Bucket is exhausted and refreshes after the deadline.
+    Exhausted {
+        /// Time until the bucket refreshes.
+        time_remaining: Duration,
+    },
+}
+
 /// Receiver to wait for the headers sent by the API consumer.
 ///
 /// You must poll the future in order to process the headers. If the future
@@ -105,6 +193,13 @@ impl TicketNotifier {
 
         Some(TicketHeaders(rx))
     }
+
+    /// Whether the consumer has dropped their [`TicketReceiver`] half, in
+    /// which case the ticket is considered canceled.
+    #[must_use]
+    pub fn is_canceled(&self) -> bool {
+        self.0.is_closed()
+    }
 }
 
 /// Channel receiver to wait for availability of a ratelimit ticket.
@@ -134,6 +229,10 @@ impl Future for TicketReceiver {
 /// [`headers`] to the ratelimiter backend. If a response results in an
 /// error - such as a server error or request cancellation - send `None`.
 ///
+/// Dropping the sender without sending headers, such as when a request is
+/// canceled before it's sent, returns the ticket to the ratelimiter
+/// immediately.
+///
 /// [`headers`]: Self::headers
 #[derive(Debug)]
 pub struct TicketSender(Sender<Option<RatelimitHeaders>>);
@@ -173,3 +272,13 @@ pub fn channel() -> (TicketNotifier, TicketReceiver) {
 
     (TicketNotifier(tx), TicketReceiver(rx))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{TicketDeadlineError, TicketDeadlineErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(TicketDeadlineError: Error, Send, Sync);
+    assert_impl_all!(TicketDeadlineErrorType: Debug, Send, Sync);
+}
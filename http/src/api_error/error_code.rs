@@ -0,0 +1,897 @@
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+};
+
+/// Discord JSON error code returned in an API error response.
+///
+/// Error codes are compared by their [raw value], so an [`Unknown`] error code
+/// with the value of a known error code is equal to the known error code.
+///
+/// Refer to [Discord Docs/JSON Error Codes] for more information.
+///
+/// [`Unknown`]: Self::Unknown
+/// [raw value]: Self::code
+/// [Discord Docs/JSON Error Codes]: https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// General error, such as an internal server error.
+    GeneralError,
+    /// Unknown account.
+    UnknownAccount,
+    /// Unknown application.
+    UnknownApplication,
+    /// Unknown channel.
+    UnknownChannel,
+    /// Unknown guild.
+    UnknownGuild,
+    /// Unknown integration.
+    UnknownIntegration,
+    /// Unknown invite.
+    UnknownInvite,
+    /// Unknown member.
+    UnknownMember,
+    /// Unknown message.
+    UnknownMessage,
+    /// Unknown permission overwrite.
+    UnknownPermissionOverwrite,
+    /// Unknown provider.
+    UnknownProvider,
+    /// Unknown role.
+    UnknownRole,
+    /// Unknown token.
+    UnknownToken,
+    /// Unknown user.
+    UnknownUser,
+    /// Unknown emoji.
+    UnknownEmoji,
+    /// Unknown webhook.
+    UnknownWebhook,
+    /// Unknown webhook service.
+    UnknownWebhookService,
+    /// Unknown session.
+    UnknownSession,
+    /// Unknown ban.
+    UnknownBan,
+    /// Unknown SKU.
+    UnknownSku,
+    /// Unknown store listing.
+    UnknownStoreListing,
+    /// Unknown entitlement.
+    UnknownEntitlement,
+    /// Unknown build.
+    UnknownBuild,
+    /// Unknown lobby.
+    UnknownLobby,
+    /// Unknown branch.
+    UnknownBranch,
+    /// Unknown store directory layout.
+    UnknownStoreDirectoryLayout,
+    /// Unknown redistributable.
+    UnknownRedistributable,
+    /// Unknown gift code.
+    UnknownGiftCode,
+    /// Unknown stream.
+    UnknownStream,
+    /// Unknown premium server subscribe cooldown.
+    UnknownPremiumServerSubscribeCooldown,
+    /// Unknown guild template.
+    UnknownGuildTemplate,
+    /// Unknown discoverable server category.
+    UnknownDiscoverableServerCategory,
+    /// Unknown sticker.
+    UnknownSticker,
+    /// Unknown interaction.
+    UnknownInteraction,
+    /// Unknown application command.
+    UnknownApplicationCommand,
+    /// Unknown voice state.
+    UnknownVoiceState,
+    /// Unknown application command permissions.
+    UnknownApplicationCommandPermissions,
+    /// Unknown stage instance.
+    UnknownStageInstance,
+    /// Unknown guild member verification form.
+    UnknownGuildMemberVerificationForm,
+    /// Unknown guild welcome screen.
+    UnknownGuildWelcomeScreen,
+    /// Unknown guild scheduled event.
+    UnknownGuildScheduledEvent,
+    /// Unknown guild scheduled event user.
+    UnknownGuildScheduledEventUser,
+    /// Bots cannot use this endpoint.
+    BotsCannotUseEndpoint,
+    /// Only bots can use this endpoint.
+    OnlyBotsCanUseEndpoint,
+    /// Explicit content cannot be sent to the desired recipients.
+    ExplicitContentCannotBeSent,
+    /// Not authorized to perform this action on this application.
+    ApplicationActionUnauthorized,
+    /// Action cannot be performed due to the slowmode ratelimit.
+    SlowmodeRatelimited,
+    /// Only the owner of this account can perform this action.
+    AccountOwnerOnly,
+    /// Message cannot be edited due to announcement ratelimits.
+    AnnouncementRatelimited,
+    /// Channel has hit the write ratelimit.
+    ChannelWriteRatelimited,
+    /// Server has hit the write ratelimit.
+    ServerWriteRatelimited,
+    /// Stage topic, server name, server description, or channel names contain words that are not allowed.
+    DisallowedWords,
+    /// Guild premium subscription level is too low.
+    GuildPremiumTierTooLow,
+    /// Maximum number of guilds reached.
+    MaximumGuilds,
+    /// Maximum number of friends reached.
+    MaximumFriends,
+    /// Maximum number of pins reached for the channel.
+    MaximumPins,
+    /// Maximum number of recipients reached.
+    MaximumRecipients,
+    /// Maximum number of guild roles reached.
+    MaximumRoles,
+    /// Maximum number of webhooks reached.
+    MaximumWebhooks,
+    /// Maximum number of emojis reached.
+    MaximumEmojis,
+    /// Maximum number of reactions reached.
+    MaximumReactions,
+    /// Maximum number of guild channels reached.
+    MaximumChannels,
+    /// Maximum number of attachments in a message reached.
+    MaximumAttachments,
+    /// Maximum number of invites reached.
+    MaximumInvites,
+    /// Maximum number of animated emojis reached.
+    MaximumAnimatedEmojis,
+    /// Maximum number of server members reached.
+    MaximumMembers,
+    /// Maximum number of server categories reached.
+    MaximumCategories,
+    /// Guild already has a template.
+    GuildTemplateExists,
+    /// Maximum number of thread participants reached.
+    MaximumThreadParticipants,
+    /// Maximum number of bans for non-guild members exceeded.
+    MaximumNonMemberBans,
+    /// Maximum number of ban fetches reached.
+    MaximumBanFetches,
+    /// Maximum number of uncompleted guild scheduled events reached.
+    MaximumUncompletedScheduledEvents,
+    /// Maximum number of stickers reached.
+    MaximumStickers,
+    /// Maximum number of prune requests reached.
+    MaximumPruneRequests,
+    /// Maximum number of guild widget settings updates reached.
+    MaximumWidgetUpdates,
+    /// Maximum number of edits to messages older than one hour reached.
+    MaximumOldMessageEdits,
+    /// Unauthorized; a valid token must be provided.
+    Unauthorized,
+    /// Account must be verified to perform this action.
+    AccountVerificationRequired,
+    /// Direct messages are being opened too fast.
+    OpeningDirectMessagesTooFast,
+    /// Sending messages has been temporarily disabled.
+    SendMessagesDisabled,
+    /// Request entity is too large.
+    RequestEntityTooLarge,
+    /// Feature has been temporarily disabled server-side.
+    FeatureDisabled,
+    /// User is banned from this guild.
+    UserBanned,
+    /// Target user is not connected to voice.
+    TargetUserNotInVoice,
+    /// Message has already been crossposted.
+    AlreadyCrossposted,
+    /// Application command with that name already exists.
+    ApplicationCommandExists,
+    /// Interaction has already been acknowledged.
+    InteractionAcknowledged,
+    /// Missing access.
+    MissingAccess,
+    /// Invalid account type.
+    InvalidAccountType,
+    /// Cannot execute action on a direct message channel.
+    DirectMessageChannel,
+    /// Guild widget is disabled.
+    GuildWidgetDisabled,
+    /// Cannot edit a message authored by another user.
+    MessageByOtherUser,
+    /// Cannot send an empty message.
+    EmptyMessage,
+    /// Cannot send messages to this user.
+    CannotMessageUser,
+    /// Cannot send messages in a non-text channel.
+    NonTextChannel,
+    /// Channel verification level is too high to gain access.
+    ChannelVerificationLevelTooHigh,
+    /// `OAuth2` application does not have a bot.
+    OauthApplicationWithoutBot,
+    /// `OAuth2` application limit reached.
+    OauthApplicationLimit,
+    /// Invalid `OAuth2` state.
+    InvalidOauthState,
+    /// Missing permissions to perform the action.
+    MissingPermissions,
+    /// Invalid authentication token provided.
+    InvalidToken,
+    /// Note is too long.
+    NoteTooLong,
+    /// Too few or too many messages to delete provided.
+    InvalidBulkDeleteCount,
+    /// Message can only be pinned to the channel it was sent in.
+    PinInOtherChannel,
+    /// Invite code is either invalid or taken.
+    InvalidInviteCode,
+    /// Cannot execute action on a system message.
+    SystemMessage,
+    /// Cannot execute action on this channel type.
+    InvalidChannelType,
+    /// Invalid `OAuth2` access token provided.
+    InvalidOauthAccessToken,
+    /// Missing required `OAuth2` scope.
+    MissingOauthScope,
+    /// Invalid webhook token provided.
+    InvalidWebhookToken,
+    /// Invalid role.
+    InvalidRole,
+    /// Invalid recipients.
+    InvalidRecipients,
+    /// Message provided was too old to bulk delete.
+    BulkDeleteMessageTooOld,
+    /// Invalid form body or content type provided.
+    InvalidFormBody,
+    /// Invite was accepted to a guild the application's bot is not in.
+    InviteAcceptedWithoutBot,
+    /// Invalid API version provided.
+    InvalidApiVersion,
+    /// Uploaded file exceeds the maximum size.
+    FileTooLarge,
+    /// Invalid file uploaded.
+    InvalidFile,
+    /// Cannot self-redeem this gift.
+    CannotSelfRedeemGift,
+    /// Invalid guild.
+    InvalidGuild,
+    /// Invalid message type.
+    InvalidMessageType,
+    /// Payment source required to redeem gift.
+    PaymentSourceRequired,
+    /// Cannot delete a channel required for community guilds.
+    CommunityChannelRequired,
+    /// Invalid sticker sent.
+    InvalidSticker,
+    /// Operation was performed on an archived thread.
+    ArchivedThread,
+    /// Invalid thread notification settings.
+    InvalidThreadNotificationSettings,
+    /// `before` value is earlier than the thread creation date.
+    BeforeThreadCreation,
+    /// Community server channels must be text channels.
+    CommunityChannelNotText,
+    /// Server is not available in your location.
+    ServerUnavailableInLocation,
+    /// Server needs monetization enabled to perform this action.
+    MonetizationRequired,
+    /// Server needs more boosts to perform this action.
+    MoreBoostsRequired,
+    /// Request body contains invalid JSON.
+    InvalidJson,
+    /// Two factor authentication is required for this operation.
+    TwoFactorRequired,
+    /// No users with the Discord tag exist.
+    NoUsersWithTag,
+    /// Reaction was blocked.
+    ReactionBlocked,
+    /// API resource is currently overloaded.
+    ApiOverloaded,
+    /// Stage is already open.
+    StageAlreadyOpen,
+    /// Cannot reply without permission to read message history.
+    ReplyWithoutReadHistory,
+    /// Thread has already been created for this message.
+    ThreadAlreadyCreated,
+    /// Thread is locked.
+    ThreadLocked,
+    /// Maximum number of active threads reached.
+    MaximumActiveThreads,
+    /// Maximum number of active announcement threads reached.
+    MaximumActiveAnnouncementThreads,
+    /// Invalid JSON for uploaded Lottie file.
+    InvalidLottieJson,
+    /// Uploaded Lotties cannot contain rasterized images such as PNG or JPEG.
+    RasterizedLottie,
+    /// Sticker maximum framerate exceeded.
+    StickerFramerateExceeded,
+    /// Sticker frame count exceeds the maximum of 1000 frames.
+    StickerFrameCountExceeded,
+    /// Lottie animation maximum dimensions exceeded.
+    LottieDimensionsExceeded,
+    /// Sticker frame rate is either too small or too large.
+    InvalidStickerFramerate,
+    /// Sticker animation duration exceeds the maximum of 5 seconds.
+    StickerDurationExceeded,
+    /// Cannot update a finished event.
+    FinishedEvent,
+    /// Failed to create the stage needed for a stage event.
+    StageEventCreationFailed,
+    /// Error code not known to Twilight.
+    Unknown {
+        /// Raw error code.
+        code: u64,
+    },
+}
+
+impl ErrorCode {
+    /// Raw numerical value of the error code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_http::api_error::ErrorCode;
+    ///
+    /// assert_eq!(50013, ErrorCode::MissingPermissions.code());
+    /// assert_eq!(1, ErrorCode::Unknown { code: 1 }.code());
+    /// ```
+    #[allow(clippy::too_many_lines)]
+    pub const fn code(self) -> u64 {
+        match self {
+            Self::GeneralError => 0,
+            Self::UnknownAccount => 10001,
+            Self::UnknownApplication => 10002,
+            Self::UnknownChannel => 10003,
+            Self::UnknownGuild => 10004,
+            Self::UnknownIntegration => 10005,
+            Self::UnknownInvite => 10006,
+            Self::UnknownMember => 10007,
+            Self::UnknownMessage => 10008,
+            Self::UnknownPermissionOverwrite => 10009,
+            Self::UnknownProvider => 10010,
+            Self::UnknownRole => 10011,
+            Self::UnknownToken => 10012,
+            Self::UnknownUser => 10013,
+            Self::UnknownEmoji => 10014,
+            Self::UnknownWebhook => 10015,
+            Self::UnknownWebhookService => 10016,
+            Self::UnknownSession => 10020,
+            Self::UnknownBan => 10026,
+            Self::UnknownSku => 10027,
+            Self::UnknownStoreListing => 10028,
+            Self::UnknownEntitlement => 10029,
+            Self::UnknownBuild => 10030,
+            Self::UnknownLobby => 10031,
+            Self::UnknownBranch => 10032,
+            Self::UnknownStoreDirectoryLayout => 10033,
+            Self::UnknownRedistributable => 10036,
+            Self::UnknownGiftCode => 10038,
+            Self::UnknownStream => 10049,
+            Self::UnknownPremiumServerSubscribeCooldown => 10050,
+            Self::UnknownGuildTemplate => 10057,
+            Self::UnknownDiscoverableServerCategory => 10059,
+            Self::UnknownSticker => 10060,
+            Self::UnknownInteraction => 10062,
+            Self::UnknownApplicationCommand => 10063,
+            Self::UnknownVoiceState => 10065,
+            Self::UnknownApplicationCommandPermissions => 10066,
+            Self::UnknownStageInstance => 10067,
+            Self::UnknownGuildMemberVerificationForm => 10068,
+            Self::UnknownGuildWelcomeScreen => 10069,
+            Self::UnknownGuildScheduledEvent => 10070,
+            Self::UnknownGuildScheduledEventUser => 10071,
+            Self::BotsCannotUseEndpoint => 20001,
+            Self::OnlyBotsCanUseEndpoint => 20002,
+            Self::ExplicitContentCannotBeSent => 20009,
+            Self::ApplicationActionUnauthorized => 20012,
+            Self::SlowmodeRatelimited => 20016,
+            Self::AccountOwnerOnly => 20018,
+            Self::AnnouncementRatelimited => 20022,
+            Self::ChannelWriteRatelimited => 20028,
+            Self::ServerWriteRatelimited => 20029,
+            Self::DisallowedWords => 20031,
+            Self::GuildPremiumTierTooLow => 20035,
+            Self::MaximumGuilds => 30001,
+            Self::MaximumFriends => 30002,
+            Self::MaximumPins => 30003,
+            Self::MaximumRecipients => 30004,
+            Self::MaximumRoles => 30005,
+            Self::MaximumWebhooks => 30007,
+            Self::MaximumEmojis => 30008,
+            Self::MaximumReactions => 30010,
+            Self::MaximumChannels => 30013,
+            Self::MaximumAttachments => 30015,
+            Self::MaximumInvites => 30016,
+            Self::MaximumAnimatedEmojis => 30018,
+            Self::MaximumMembers => 30019,
+            Self::MaximumCategories => 30030,
+            Self::GuildTemplateExists => 30031,
+            Self::MaximumThreadParticipants => 30033,
+            Self::MaximumNonMemberBans => 30035,
+            Self::MaximumBanFetches => 30037,
+            Self::MaximumUncompletedScheduledEvents => 30038,
+            Self::MaximumStickers => 30039,
+            Self::MaximumPruneRequests => 30040,
+            Self::MaximumWidgetUpdates => 30042,
+            Self::MaximumOldMessageEdits => 30046,
+            Self::Unauthorized => 40001,
+            Self::AccountVerificationRequired => 40002,
+            Self::OpeningDirectMessagesTooFast => 40003,
+            Self::SendMessagesDisabled => 40004,
+            Self::RequestEntityTooLarge => 40005,
+            Self::FeatureDisabled => 40006,
+            Self::UserBanned => 40007,
+            Self::TargetUserNotInVoice => 40032,
+            Self::AlreadyCrossposted => 40033,
+            Self::ApplicationCommandExists => 40041,
+            Self::InteractionAcknowledged => 40060,
+            Self::MissingAccess => 50001,
+            Self::InvalidAccountType => 50002,
+            Self::DirectMessageChannel => 50003,
+            Self::GuildWidgetDisabled => 50004,
+            Self::MessageByOtherUser => 50005,
+            Self::EmptyMessage => 50006,
+            Self::CannotMessageUser => 50007,
+            Self::NonTextChannel => 50008,
+            Self::ChannelVerificationLevelTooHigh => 50009,
+            Self::OauthApplicationWithoutBot => 50010,
+            Self::OauthApplicationLimit => 50011,
+            Self::InvalidOauthState => 50012,
+            Self::MissingPermissions => 50013,
+            Self::InvalidToken => 50014,
+            Self::NoteTooLong => 50015,
+            Self::InvalidBulkDeleteCount => 50016,
+            Self::PinInOtherChannel => 50019,
+            Self::InvalidInviteCode => 50020,
+            Self::SystemMessage => 50021,
+            Self::InvalidChannelType => 50024,
+            Self::InvalidOauthAccessToken => 50025,
+            Self::MissingOauthScope => 50026,
+            Self::InvalidWebhookToken => 50027,
+            Self::InvalidRole => 50028,
+            Self::InvalidRecipients => 50033,
+            Self::BulkDeleteMessageTooOld => 50034,
+            Self::InvalidFormBody => 50035,
+            Self::InviteAcceptedWithoutBot => 50036,
+            Self::InvalidApiVersion => 50041,
+            Self::FileTooLarge => 50045,
+            Self::InvalidFile => 50046,
+            Self::CannotSelfRedeemGift => 50054,
+            Self::InvalidGuild => 50055,
+            Self::InvalidMessageType => 50068,
+            Self::PaymentSourceRequired => 50070,
+            Self::CommunityChannelRequired => 50074,
+            Self::InvalidSticker => 50081,
+            Self::ArchivedThread => 50083,
+            Self::InvalidThreadNotificationSettings => 50084,
+            Self::BeforeThreadCreation => 50085,
+            Self::CommunityChannelNotText => 50086,
+            Self::ServerUnavailableInLocation => 50095,
+            Self::MonetizationRequired => 50097,
+            Self::MoreBoostsRequired => 50101,
+            Self::InvalidJson => 50109,
+            Self::TwoFactorRequired => 60003,
+            Self::NoUsersWithTag => 80004,
+            Self::ReactionBlocked => 90001,
+            Self::ApiOverloaded => 130_000,
+            Self::StageAlreadyOpen => 150_006,
+            Self::ReplyWithoutReadHistory => 160_002,
+            Self::ThreadAlreadyCreated => 160_004,
+            Self::ThreadLocked => 160_005,
+            Self::MaximumActiveThreads => 160_006,
+            Self::MaximumActiveAnnouncementThreads => 160_007,
+            Self::InvalidLottieJson => 170_001,
+            Self::RasterizedLottie => 170_002,
+            Self::StickerFramerateExceeded => 170_003,
+            Self::StickerFrameCountExceeded => 170_004,
+            Self::LottieDimensionsExceeded => 170_005,
+            Self::InvalidStickerFramerate => 170_006,
+            Self::StickerDurationExceeded => 170_007,
+            Self::FinishedEvent => 180_000,
+            Self::StageEventCreationFailed => 180_002,
+            Self::Unknown { code } => code,
+        }
+    }
+}
+
+impl Eq for ErrorCode {}
+
+impl Hash for ErrorCode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code().hash(state);
+    }
+}
+
+impl PartialEq for ErrorCode {
+    fn eq(&self, other: &Self) -> bool {
+        self.code() == other.code()
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.code(), f)
+    }
+}
+
+impl From<u64> for ErrorCode {
+    #[allow(clippy::too_many_lines)]
+    fn from(code: u64) -> Self {
+        match code {
+            0 => Self::GeneralError,
+            10001 => Self::UnknownAccount,
+            10002 => Self::UnknownApplication,
+            10003 => Self::UnknownChannel,
+            10004 => Self::UnknownGuild,
+            10005 => Self::UnknownIntegration,
+            10006 => Self::UnknownInvite,
+            10007 => Self::UnknownMember,
+            10008 => Self::UnknownMessage,
+            10009 => Self::UnknownPermissionOverwrite,
+            10010 => Self::UnknownProvider,
+            10011 => Self::UnknownRole,
+            10012 => Self::UnknownToken,
+            10013 => Self::UnknownUser,
+            10014 => Self::UnknownEmoji,
+            10015 => Self::UnknownWebhook,
+            10016 => Self::UnknownWebhookService,
+            10020 => Self::UnknownSession,
+            10026 => Self::UnknownBan,
+            10027 => Self::UnknownSku,
+            10028 => Self::UnknownStoreListing,
+            10029 => Self::UnknownEntitlement,
+            10030 => Self::UnknownBuild,
+            10031 => Self::UnknownLobby,
+            10032 => Self::UnknownBranch,
+            10033 => Self::UnknownStoreDirectoryLayout,
+            10036 => Self::UnknownRedistributable,
+            10038 => Self::UnknownGiftCode,
+            10049 => Self::UnknownStream,
+            10050 => Self::UnknownPremiumServerSubscribeCooldown,
+            10057 => Self::UnknownGuildTemplate,
+            10059 => Self::UnknownDiscoverableServerCategory,
+            10060 => Self::UnknownSticker,
+            10062 => Self::UnknownInteraction,
+            10063 => Self::UnknownApplicationCommand,
+            10065 => Self::UnknownVoiceState,
+            10066 => Self::UnknownApplicationCommandPermissions,
+            10067 => Self::UnknownStageInstance,
+            10068 => Self::UnknownGuildMemberVerificationForm,
+            10069 => Self::UnknownGuildWelcomeScreen,
+            10070 => Self::UnknownGuildScheduledEvent,
+            10071 => Self::UnknownGuildScheduledEventUser,
+            20001 => Self::BotsCannotUseEndpoint,
+            20002 => Self::OnlyBotsCanUseEndpoint,
+            20009 => Self::ExplicitContentCannotBeSent,
+            20012 => Self::ApplicationActionUnauthorized,
+            20016 => Self::SlowmodeRatelimited,
+            20018 => Self::AccountOwnerOnly,
+            20022 => Self::AnnouncementRatelimited,
+            20028 => Self::ChannelWriteRatelimited,
+            20029 => Self::ServerWriteRatelimited,
+            20031 => Self::DisallowedWords,
+            20035 => Self::GuildPremiumTierTooLow,
+            30001 => Self::MaximumGuilds,
+            30002 => Self::MaximumFriends,
+            30003 => Self::MaximumPins,
+            30004 => Self::MaximumRecipients,
+            30005 => Self::MaximumRoles,
+            30007 => Self::MaximumWebhooks,
+            30008 => Self::MaximumEmojis,
+            30010 => Self::MaximumReactions,
+            30013 => Self::MaximumChannels,
+            30015 => Self::MaximumAttachments,
+            30016 => Self::MaximumInvites,
+            30018 => Self::MaximumAnimatedEmojis,
+            30019 => Self::MaximumMembers,
+            30030 => Self::MaximumCategories,
+            30031 => Self::GuildTemplateExists,
+            30033 => Self::MaximumThreadParticipants,
+            30035 => Self::MaximumNonMemberBans,
+            30037 => Self::MaximumBanFetches,
+            30038 => Self::MaximumUncompletedScheduledEvents,
+            30039 => Self::MaximumStickers,
+            30040 => Self::MaximumPruneRequests,
+            30042 => Self::MaximumWidgetUpdates,
+            30046 => Self::MaximumOldMessageEdits,
+            40001 => Self::Unauthorized,
+            40002 => Self::AccountVerificationRequired,
+            40003 => Self::OpeningDirectMessagesTooFast,
+            40004 => Self::SendMessagesDisabled,
+            40005 => Self::RequestEntityTooLarge,
+            40006 => Self::FeatureDisabled,
+            40007 => Self::UserBanned,
+            40032 => Self::TargetUserNotInVoice,
+            40033 => Self::AlreadyCrossposted,
+            40041 => Self::ApplicationCommandExists,
+            40060 => Self::InteractionAcknowledged,
+            50001 => Self::MissingAccess,
+            50002 => Self::InvalidAccountType,
+            50003 => Self::DirectMessageChannel,
+            50004 => Self::GuildWidgetDisabled,
+            50005 => Self::MessageByOtherUser,
+            50006 => Self::EmptyMessage,
+            50007 => Self::CannotMessageUser,
+            50008 => Self::NonTextChannel,
+            50009 => Self::ChannelVerificationLevelTooHigh,
+            50010 => Self::OauthApplicationWithoutBot,
+            50011 => Self::OauthApplicationLimit,
+            50012 => Self::InvalidOauthState,
+            50013 => Self::MissingPermissions,
+            50014 => Self::InvalidToken,
+            50015 => Self::NoteTooLong,
+            50016 => Self::InvalidBulkDeleteCount,
+            50019 => Self::PinInOtherChannel,
+            50020 => Self::InvalidInviteCode,
+            50021 => Self::SystemMessage,
+            50024 => Self::InvalidChannelType,
+            50025 => Self::InvalidOauthAccessToken,
+            50026 => Self::MissingOauthScope,
+            50027 => Self::InvalidWebhookToken,
+            50028 => Self::InvalidRole,
+            50033 => Self::InvalidRecipients,
+            50034 => Self::BulkDeleteMessageTooOld,
+            50035 => Self::InvalidFormBody,
+            50036 => Self::InviteAcceptedWithoutBot,
+            50041 => Self::InvalidApiVersion,
+            50045 => Self::FileTooLarge,
+            50046 => Self::InvalidFile,
+            50054 => Self::CannotSelfRedeemGift,
+            50055 => Self::InvalidGuild,
+            50068 => Self::InvalidMessageType,
+            50070 => Self::PaymentSourceRequired,
+            50074 => Self::CommunityChannelRequired,
+            50081 => Self::InvalidSticker,
+            50083 => Self::ArchivedThread,
+            50084 => Self::InvalidThreadNotificationSettings,
+            50085 => Self::BeforeThreadCreation,
+            50086 => Self::CommunityChannelNotText,
+            50095 => Self::ServerUnavailableInLocation,
+            50097 => Self::MonetizationRequired,
+            50101 => Self::MoreBoostsRequired,
+            50109 => Self::InvalidJson,
+            60003 => Self::TwoFactorRequired,
+            80004 => Self::NoUsersWithTag,
+            90001 => Self::ReactionBlocked,
+            130_000 => Self::ApiOverloaded,
+            150_006 => Self::StageAlreadyOpen,
+            160_002 => Self::ReplyWithoutReadHistory,
+            160_004 => Self::ThreadAlreadyCreated,
+            160_005 => Self::ThreadLocked,
+            160_006 => Self::MaximumActiveThreads,
+            160_007 => Self::MaximumActiveAnnouncementThreads,
+            170_001 => Self::InvalidLottieJson,
+            170_002 => Self::RasterizedLottie,
+            170_003 => Self::StickerFramerateExceeded,
+            170_004 => Self::StickerFrameCountExceeded,
+            170_005 => Self::LottieDimensionsExceeded,
+            170_006 => Self::InvalidStickerFramerate,
+            170_007 => Self::StickerDurationExceeded,
+            180_000 => Self::FinishedEvent,
+            180_002 => Self::StageEventCreationFailed,
+            code => Self::Unknown { code },
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u64::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ErrorCode;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        ErrorCode: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    const MAP: &[(ErrorCode, u64)] = &[
+        (ErrorCode::GeneralError, 0),
+        (ErrorCode::UnknownAccount, 10001),
+        (ErrorCode::UnknownApplication, 10002),
+        (ErrorCode::UnknownChannel, 10003),
+        (ErrorCode::UnknownGuild, 10004),
+        (ErrorCode::UnknownIntegration, 10005),
+        (ErrorCode::UnknownInvite, 10006),
+        (ErrorCode::UnknownMember, 10007),
+        (ErrorCode::UnknownMessage, 10008),
+        (ErrorCode::UnknownPermissionOverwrite, 10009),
+        (ErrorCode::UnknownProvider, 10010),
+        (ErrorCode::UnknownRole, 10011),
+        (ErrorCode::UnknownToken, 10012),
+        (ErrorCode::UnknownUser, 10013),
+        (ErrorCode::UnknownEmoji, 10014),
+        (ErrorCode::UnknownWebhook, 10015),
+        (ErrorCode::UnknownWebhookService, 10016),
+        (ErrorCode::UnknownSession, 10020),
+        (ErrorCode::UnknownBan, 10026),
+        (ErrorCode::UnknownSku, 10027),
+        (ErrorCode::UnknownStoreListing, 10028),
+        (ErrorCode::UnknownEntitlement, 10029),
+        (ErrorCode::UnknownBuild, 10030),
+        (ErrorCode::UnknownLobby, 10031),
+        (ErrorCode::UnknownBranch, 10032),
+        (ErrorCode::UnknownStoreDirectoryLayout, 10033),
+        (ErrorCode::UnknownRedistributable, 10036),
+        (ErrorCode::UnknownGiftCode, 10038),
+        (ErrorCode::UnknownStream, 10049),
+        (ErrorCode::UnknownPremiumServerSubscribeCooldown, 10050),
+        (ErrorCode::UnknownGuildTemplate, 10057),
+        (ErrorCode::UnknownDiscoverableServerCategory, 10059),
+        (ErrorCode::UnknownSticker, 10060),
+        (ErrorCode::UnknownInteraction, 10062),
+        (ErrorCode::UnknownApplicationCommand, 10063),
+        (ErrorCode::UnknownVoiceState, 10065),
+        (ErrorCode::UnknownApplicationCommandPermissions, 10066),
+        (ErrorCode::UnknownStageInstance, 10067),
+        (ErrorCode::UnknownGuildMemberVerificationForm, 10068),
+        (ErrorCode::UnknownGuildWelcomeScreen, 10069),
+        (ErrorCode::UnknownGuildScheduledEvent, 10070),
+        (ErrorCode::UnknownGuildScheduledEventUser, 10071),
+        (ErrorCode::BotsCannotUseEndpoint, 20001),
+        (ErrorCode::OnlyBotsCanUseEndpoint, 20002),
+        (ErrorCode::ExplicitContentCannotBeSent, 20009),
+        (ErrorCode::ApplicationActionUnauthorized, 20012),
+        (ErrorCode::SlowmodeRatelimited, 20016),
+        (ErrorCode::AccountOwnerOnly, 20018),
+        (ErrorCode::AnnouncementRatelimited, 20022),
+        (ErrorCode::ChannelWriteRatelimited, 20028),
+        (ErrorCode::ServerWriteRatelimited, 20029),
+        (ErrorCode::DisallowedWords, 20031),
+        (ErrorCode::GuildPremiumTierTooLow, 20035),
+        (ErrorCode::MaximumGuilds, 30001),
+        (ErrorCode::MaximumFriends, 30002),
+        (ErrorCode::MaximumPins, 30003),
+        (ErrorCode::MaximumRecipients, 30004),
+        (ErrorCode::MaximumRoles, 30005),
+        (ErrorCode::MaximumWebhooks, 30007),
+        (ErrorCode::MaximumEmojis, 30008),
+        (ErrorCode::MaximumReactions, 30010),
+        (ErrorCode::MaximumChannels, 30013),
+        (ErrorCode::MaximumAttachments, 30015),
+        (ErrorCode::MaximumInvites, 30016),
+        (ErrorCode::MaximumAnimatedEmojis, 30018),
+        (ErrorCode::MaximumMembers, 30019),
+        (ErrorCode::MaximumCategories, 30030),
+        (ErrorCode::GuildTemplateExists, 30031),
+        (ErrorCode::MaximumThreadParticipants, 30033),
+        (ErrorCode::MaximumNonMemberBans, 30035),
+        (ErrorCode::MaximumBanFetches, 30037),
+        (ErrorCode::MaximumUncompletedScheduledEvents, 30038),
+        (ErrorCode::MaximumStickers, 30039),
+        (ErrorCode::MaximumPruneRequests, 30040),
+        (ErrorCode::MaximumWidgetUpdates, 30042),
+        (ErrorCode::MaximumOldMessageEdits, 30046),
+        (ErrorCode::Unauthorized, 40001),
+        (ErrorCode::AccountVerificationRequired, 40002),
+        (ErrorCode::OpeningDirectMessagesTooFast, 40003),
+        (ErrorCode::SendMessagesDisabled, 40004),
+        (ErrorCode::RequestEntityTooLarge, 40005),
+        (ErrorCode::FeatureDisabled, 40006),
+        (ErrorCode::UserBanned, 40007),
+        (ErrorCode::TargetUserNotInVoice, 40032),
+        (ErrorCode::AlreadyCrossposted, 40033),
+        (ErrorCode::ApplicationCommandExists, 40041),
+        (ErrorCode::InteractionAcknowledged, 40060),
+        (ErrorCode::MissingAccess, 50001),
+        (ErrorCode::InvalidAccountType, 50002),
+        (ErrorCode::DirectMessageChannel, 50003),
+        (ErrorCode::GuildWidgetDisabled, 50004),
+        (ErrorCode::MessageByOtherUser, 50005),
+        (ErrorCode::EmptyMessage, 50006),
+        (ErrorCode::CannotMessageUser, 50007),
+        (ErrorCode::NonTextChannel, 50008),
+        (ErrorCode::ChannelVerificationLevelTooHigh, 50009),
+        (ErrorCode::OauthApplicationWithoutBot, 50010),
+        (ErrorCode::OauthApplicationLimit, 50011),
+        (ErrorCode::InvalidOauthState, 50012),
+        (ErrorCode::MissingPermissions, 50013),
+        (ErrorCode::InvalidToken, 50014),
+        (ErrorCode::NoteTooLong, 50015),
+        (ErrorCode::InvalidBulkDeleteCount, 50016),
+        (ErrorCode::PinInOtherChannel, 50019),
+        (ErrorCode::InvalidInviteCode, 50020),
+        (ErrorCode::SystemMessage, 50021),
+        (ErrorCode::InvalidChannelType, 50024),
+        (ErrorCode::InvalidOauthAccessToken, 50025),
+        (ErrorCode::MissingOauthScope, 50026),
+        (ErrorCode::InvalidWebhookToken, 50027),
+        (ErrorCode::InvalidRole, 50028),
+        (ErrorCode::InvalidRecipients, 50033),
+        (ErrorCode::BulkDeleteMessageTooOld, 50034),
+        (ErrorCode::InvalidFormBody, 50035),
+        (ErrorCode::InviteAcceptedWithoutBot, 50036),
+        (ErrorCode::InvalidApiVersion, 50041),
+        (ErrorCode::FileTooLarge, 50045),
+        (ErrorCode::InvalidFile, 50046),
+        (ErrorCode::CannotSelfRedeemGift, 50054),
+        (ErrorCode::InvalidGuild, 50055),
+        (ErrorCode::InvalidMessageType, 50068),
+        (ErrorCode::PaymentSourceRequired, 50070),
+        (ErrorCode::CommunityChannelRequired, 50074),
+        (ErrorCode::InvalidSticker, 50081),
+        (ErrorCode::ArchivedThread, 50083),
+        (ErrorCode::InvalidThreadNotificationSettings, 50084),
+        (ErrorCode::BeforeThreadCreation, 50085),
+        (ErrorCode::CommunityChannelNotText, 50086),
+        (ErrorCode::ServerUnavailableInLocation, 50095),
+        (ErrorCode::MonetizationRequired, 50097),
+        (ErrorCode::MoreBoostsRequired, 50101),
+        (ErrorCode::InvalidJson, 50109),
+        (ErrorCode::TwoFactorRequired, 60003),
+        (ErrorCode::NoUsersWithTag, 80004),
+        (ErrorCode::ReactionBlocked, 90001),
+        (ErrorCode::ApiOverloaded, 130_000),
+        (ErrorCode::StageAlreadyOpen, 150_006),
+        (ErrorCode::ReplyWithoutReadHistory, 160_002),
+        (ErrorCode::ThreadAlreadyCreated, 160_004),
+        (ErrorCode::ThreadLocked, 160_005),
+        (ErrorCode::MaximumActiveThreads, 160_006),
+        (ErrorCode::MaximumActiveAnnouncementThreads, 160_007),
+        (ErrorCode::InvalidLottieJson, 170_001),
+        (ErrorCode::RasterizedLottie, 170_002),
+        (ErrorCode::StickerFramerateExceeded, 170_003),
+        (ErrorCode::StickerFrameCountExceeded, 170_004),
+        (ErrorCode::LottieDimensionsExceeded, 170_005),
+        (ErrorCode::InvalidStickerFramerate, 170_006),
+        (ErrorCode::StickerDurationExceeded, 170_007),
+        (ErrorCode::FinishedEvent, 180_000),
+        (ErrorCode::StageEventCreationFailed, 180_002),
+    ];
+
+    #[test]
+    fn test_variants() {
+        for (kind, code) in MAP {
+            assert_eq!(*code, kind.code());
+            assert_eq!(*kind, ErrorCode::from(*code));
+            serde_test::assert_tokens(kind, &[Token::U64(*code)]);
+        }
+    }
+
+    #[test]
+    fn test_unknown() {
+        let kind = ErrorCode::from(12_345);
+        assert_eq!(ErrorCode::Unknown { code: 12_345 }, kind);
+        assert_eq!(12_345, kind.code());
+        serde_test::assert_tokens(&kind, &[Token::U64(12_345)]);
+    }
+
+    #[test]
+    fn test_unknown_known_code() {
+        assert_eq!(
+            ErrorCode::UnknownMessage,
+            ErrorCode::Unknown { code: 10008 }
+        );
+        assert_ne!(
+            ErrorCode::UnknownChannel,
+            ErrorCode::Unknown { code: 10008 }
+        );
+    }
+}
@@ -0,0 +1,404 @@
+use serde::{
+    de::Deserializer,
+    ser::{SerializeMap, Serializer},
+    Deserialize, Serialize,
+};
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+    slice::Iter,
+};
+
+/// Error for a single field of a request body that was invalid.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct FieldError {
+    /// Code of the error, such as `BASE_TYPE_MAX_LENGTH`.
+    pub code: String,
+    /// Human readable message provided by the API.
+    pub message: String,
+    /// Path to the field, with each segment joined by a period.
+    ///
+    /// Array indices are segments, so the title of the second embed of a
+    /// message is at `embeds.1.title`. Errors about the body as a whole have an
+    /// empty path.
+    #[serde(skip)]
+    pub path: String,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if !self.path.is_empty() {
+            f.write_str(&self.path)?;
+            f.write_str(": ")?;
+        }
+
+        f.write_str(&self.message)
+    }
+}
+
+/// Errors for the invalid fields of a request body.
+///
+/// Discord returns these as a nested object mirroring the structure of the
+/// request body, which is flattened into a list of errors each knowing the
+/// [path] of its field.
+///
+/// [path]: FieldError::path
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FieldErrors(pub(super) Vec<FieldError>);
+
+impl FieldErrors {
+    /// Errors of the field at a path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_http::api_error::ApiError;
+    ///
+    /// let body = r#"{
+    ///     "code": 50035,
+    ///     "message": "Invalid Form Body",
+    ///     "errors": {
+    ///         "embeds": {
+    ///             "0": {
+    ///                 "title": {
+    ///                     "_errors": [{
+    ///                         "code": "BASE_TYPE_MAX_LENGTH",
+    ///                         "message": "Must be 256 or fewer in length."
+    ///                     }]
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }"#;
+    ///
+    /// let error = serde_json::from_str::<ApiError>(body)?;
+    /// let errors = error.field_errors().expect("has field errors");
+    ///
+    /// let title = errors.get("embeds.0.title").next().expect("title invalid");
+    /// assert_eq!("BASE_TYPE_MAX_LENGTH", title.code);
+    /// # Ok(()) }
+    /// ```
+    pub fn get<'a>(&'a self, path: &'a str) -> impl Iterator<Item = &'a FieldError> + 'a {
+        self.0.iter().filter(move |error| error.path == path)
+    }
+
+    /// Whether there are no errors.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterator over all of the errors.
+    pub fn iter(&self) -> Iter<'_, FieldError> {
+        self.0.iter()
+    }
+
+    /// Number of errors.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Consume the errors, returning them as a list.
+    pub fn into_vec(self) -> Vec<FieldError> {
+        self.0
+    }
+}
+
+impl Display for FieldErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for (idx, error) in self.0.iter().enumerate() {
+            if idx != 0 {
+                f.write_str(", ")?;
+            }
+
+            Display::fmt(error, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> IntoIterator for &'a FieldErrors {
+    type IntoIter = Iter<'a, FieldError>;
+    type Item = &'a FieldError;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for FieldErrors {
+    type IntoIter = std::vec::IntoIter<FieldError>;
+    type Item = FieldError;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Key of the list of errors within an object of the nested errors.
+const ERRORS_KEY: &str = "_errors";
+
+impl<'de> Deserialize<'de> for FieldErrors {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // The errors are buffered so that values of an unrecognized shape can
+        // be ignored rather than failing to deserialize the whole API error.
+        let value = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        collect(&mut errors, "", value);
+
+        Ok(Self(errors))
+    }
+}
+
+/// Append the errors of an object of the nested errors and of its children.
+///
+/// Values that aren't objects, and errors that aren't objects with a code and
+/// message, are ignored.
+fn collect(errors: &mut Vec<FieldError>, path: &str, value: Value) {
+    let map = match value {
+        Value::Object(map) => map,
+        _ => return,
+    };
+
+    for (key, value) in map {
+        if key == ERRORS_KEY {
+            if let Value::Array(list) = value {
+                errors.extend(
+                    list.into_iter()
+                        .filter_map(|error| serde_json::from_value::<FieldError>(error).ok())
+                        .map(|mut error| {
+                            error.path = path.to_owned();
+
+                            error
+                        }),
+                );
+            }
+
+            continue;
+        }
+
+        if path.is_empty() {
+            collect(errors, &key, value);
+        } else {
+            collect(errors, &format!("{}.{}", path, key), value);
+        }
+    }
+}
+
+impl Serialize for FieldErrors {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut root = Node::default();
+
+        for error in &self.0 {
+            let mut node = &mut root;
+
+            if !error.path.is_empty() {
+                for segment in error.path.split('.') {
+                    node = node.children.entry(segment).or_default();
+                }
+            }
+
+            node.errors.push(error);
+        }
+
+        root.serialize(serializer)
+    }
+}
+
+/// Object of the nested errors, used when serializing.
+#[derive(Default)]
+struct Node<'a> {
+    children: BTreeMap<&'a str, Node<'a>>,
+    errors: Vec<&'a FieldError>,
+}
+
+impl Serialize for Node<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = self.children.len() + usize::from(!self.errors.is_empty());
+        let mut map = serializer.serialize_map(Some(len))?;
+
+        for (key, child) in &self.children {
+            map.serialize_entry(key, child)?;
+        }
+
+        if !self.errors.is_empty() {
+            map.serialize_entry(ERRORS_KEY, &self.errors)?;
+        }
+
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldError, FieldErrors};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(FieldError: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(
+        FieldErrors: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    fn error(path: &str, code: &str, message: &str) -> FieldError {
+        FieldError {
+            code: code.to_owned(),
+            message: message.to_owned(),
+            path: path.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_nested() {
+        let value = FieldErrors(vec![
+            error(
+                "content",
+                "BASE_TYPE_MAX_LENGTH",
+                "Must be 2000 or fewer in length.",
+            ),
+            error(
+                "embeds.0.title",
+                "BASE_TYPE_REQUIRED",
+                "This field is required",
+            ),
+            error(
+                "embeds.0.title",
+                "BASE_TYPE_MAX_LENGTH",
+                "Must be 256 or fewer in length.",
+            ),
+        ]);
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Map { len: Some(2) },
+                Token::Str("content"),
+                Token::Map { len: Some(1) },
+                Token::Str("_errors"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "FieldError",
+                    len: 2,
+                },
+                Token::Str("code"),
+                Token::Str("BASE_TYPE_MAX_LENGTH"),
+                Token::Str("message"),
+                Token::Str("Must be 2000 or fewer in length."),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::MapEnd,
+                Token::Str("embeds"),
+                Token::Map { len: Some(1) },
+                Token::Str("0"),
+                Token::Map { len: Some(1) },
+                Token::Str("title"),
+                Token::Map { len: Some(1) },
+                Token::Str("_errors"),
+                Token::Seq { len: Some(2) },
+                Token::Struct {
+                    name: "FieldError",
+                    len: 2,
+                },
+                Token::Str("code"),
+                Token::Str("BASE_TYPE_REQUIRED"),
+                Token::Str("message"),
+                Token::Str("This field is required"),
+                Token::StructEnd,
+                Token::Struct {
+                    name: "FieldError",
+                    len: 2,
+                },
+                Token::Str("code"),
+                Token::Str("BASE_TYPE_MAX_LENGTH"),
+                Token::Str("message"),
+                Token::Str("Must be 256 or fewer in length."),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::MapEnd,
+                Token::MapEnd,
+                Token::MapEnd,
+                Token::MapEnd,
+            ],
+        );
+
+        assert_eq!(2, value.get("embeds.0.title").count());
+        assert_eq!(0, value.get("embeds.0").count());
+        assert_eq!(
+            "content: Must be 2000 or fewer in length., \
+            embeds.0.title: This field is required, \
+            embeds.0.title: Must be 256 or fewer in length.",
+            value.to_string()
+        );
+    }
+
+    #[test]
+    fn test_root() {
+        let value = FieldErrors(vec![error(
+            "",
+            "DICT_TYPE_CONVERT",
+            "Only dictionaries may be used in a DictType",
+        )]);
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Map { len: Some(1) },
+                Token::Str("_errors"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "FieldError",
+                    len: 2,
+                },
+                Token::Str("code"),
+                Token::Str("DICT_TYPE_CONVERT"),
+                Token::Str("message"),
+                Token::Str("Only dictionaries may be used in a DictType"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_unrecognized_ignored() -> Result<(), serde_json::Error> {
+        let value = serde_json::from_str::<FieldErrors>(
+            r#"{
+                "content": {
+                    "_errors": [
+                        {"code": "BASE_TYPE_REQUIRED", "message": "This field is required"},
+                        "unrecognized",
+                        {"code": 1}
+                    ]
+                },
+                "embeds": ["unrecognized"],
+                "flags": 1,
+                "nonce": {"_errors": "unrecognized"}
+            }"#,
+        )?;
+
+        assert_eq!(
+            FieldErrors(vec![error(
+                "content",
+                "BASE_TYPE_REQUIRED",
+                "This field is required"
+            )]),
+            value
+        );
+
+        Ok(())
+    }
+}
@@ -1,3 +1,11 @@
+mod error_code;
+mod field_error;
+
+pub use self::{
+    error_code::ErrorCode,
+    field_error::{FieldError, FieldErrors},
+};
+
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
@@ -12,6 +20,24 @@ pub enum ApiError {
     Message(MessageApiError),
 }
 
+impl ApiError {
+    /// Typed JSON error code of the error, if it has one.
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        match self {
+            Self::General(inner) => Some(inner.error_code()),
+            Self::Message(_) | Self::Ratelimited(_) => None,
+        }
+    }
+
+    /// Errors for the invalid fields of the request body, if there are any.
+    pub const fn field_errors(&self) -> Option<&FieldErrors> {
+        match self {
+            Self::General(inner) => inner.errors.as_ref(),
+            Self::Message(_) | Self::Ratelimited(_) => None,
+        }
+    }
+}
+
 impl Display for ApiError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
@@ -26,16 +52,34 @@ impl Display for ApiError {
 #[non_exhaustive]
 pub struct GeneralApiError {
     pub code: u64,
+    /// Errors for the invalid fields of the request body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub errors: Option<FieldErrors>,
     pub message: String,
 }
 
+impl GeneralApiError {
+    /// Typed JSON error code of the error.
+    pub fn error_code(&self) -> ErrorCode {
+        ErrorCode::from(self.code)
+    }
+}
+
 impl Display for GeneralApiError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.write_str("Error code ")?;
         Display::fmt(&self.code, f)?;
         f.write_str(": ")?;
 
-        f.write_str(&self.message)
+        f.write_str(&self.message)?;
+
+        if let Some(errors) = self.errors.as_ref().filter(|errors| !errors.is_empty()) {
+            f.write_str(" (")?;
+            Display::fmt(errors, f)?;
+            f.write_str(")")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -131,7 +175,8 @@ impl PartialEq for RatelimitedApiError {
 #[cfg(test)]
 mod tests {
     use super::{
-        ApiError, GeneralApiError, MessageApiError, MessageApiErrorEmbedField, RatelimitedApiError,
+        ApiError, ErrorCode, FieldError, FieldErrors, GeneralApiError, MessageApiError,
+        MessageApiErrorEmbedField, RatelimitedApiError,
     };
     use serde_test::Token;
 
@@ -139,6 +184,7 @@ mod tests {
     fn test_api_error_deser() {
         let expected = GeneralApiError {
             code: 10001,
+            errors: None,
             message: "Unknown account".to_owned(),
         };
 
@@ -158,6 +204,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_api_error_field_errors() {
+        let expected = ApiError::General(GeneralApiError {
+            code: 50035,
+            errors: Some(FieldErrors(vec![FieldError {
+                code: "BASE_TYPE_MAX_LENGTH".to_owned(),
+                message: "Must be 2000 or fewer in length.".to_owned(),
+                path: "content".to_owned(),
+            }])),
+            message: "Invalid Form Body".to_owned(),
+        });
+
+        serde_test::assert_tokens(
+            &expected,
+            &[
+                Token::Struct {
+                    name: "GeneralApiError",
+                    len: 3,
+                },
+                Token::Str("code"),
+                Token::U64(50035),
+                Token::Str("errors"),
+                Token::Some,
+                Token::Map { len: Some(1) },
+                Token::Str("content"),
+                Token::Map { len: Some(1) },
+                Token::Str("_errors"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "FieldError",
+                    len: 2,
+                },
+                Token::Str("code"),
+                Token::Str("BASE_TYPE_MAX_LENGTH"),
+                Token::Str("message"),
+                Token::Str("Must be 2000 or fewer in length."),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::MapEnd,
+                Token::MapEnd,
+                Token::Str("message"),
+                Token::Str("Invalid Form Body"),
+                Token::StructEnd,
+            ],
+        );
+
+        assert_eq!(Some(ErrorCode::InvalidFormBody), expected.error_code());
+        assert_eq!(1, expected.field_errors().map_or(0, FieldErrors::len));
+        assert_eq!(
+            "Error code 50035: Invalid Form Body (content: Must be 2000 or fewer in length.)",
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn test_api_error_field_errors_unrecognized() -> Result<(), serde_json::Error> {
+        let error = serde_json::from_str::<ApiError>(
+            r#"{"code": 50035, "errors": {"content": "unrecognized"}, "message": "Invalid Form Body"}"#,
+        )?;
+
+        assert_eq!(Some(ErrorCode::InvalidFormBody), error.error_code());
+        assert_eq!(Some(0), error.field_errors().map(FieldErrors::len));
+
+        Ok(())
+    }
+
     #[test]
     fn test_api_error_message() {
         let expected = ApiError::Message(MessageApiError {
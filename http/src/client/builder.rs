@@ -1,4 +1,7 @@
-use super::Client;
+use super::{
+    invalid_request::{self, InvalidRequests},
+    transport, Client, Middleware, TokenManager, Transport,
+};
 use hyper::header::HeaderMap;
 use std::{
     sync::{atomic::AtomicBool, Arc},
@@ -11,12 +14,17 @@ use twilight_model::channel::message::allowed_mentions::AllowedMentions;
 /// A builder for [`Client`].
 pub struct ClientBuilder {
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
+    invalid_request_limit: Option<u32>,
+    invalid_request_warnings: Vec<u32>,
+    middleware: Vec<Box<dyn Middleware>>,
     pub(crate) proxy: Option<Box<str>>,
     pub(crate) ratelimiter: Option<Box<dyn Ratelimiter>>,
     remember_invalid_token: bool,
     pub(crate) default_headers: Option<HeaderMap>,
     pub(crate) timeout: Duration,
     pub(crate) token: Option<Box<str>>,
+    token_manager: Option<TokenManager>,
+    transport: Option<Box<dyn Transport>>,
     pub(crate) use_http: bool,
 }
 
@@ -28,37 +36,9 @@ impl ClientBuilder {
 
     /// Build the [`Client`].
     pub fn build(self) -> Client {
-        #[cfg(not(feature = "trust-dns"))]
-        let mut http_connector = hyper::client::HttpConnector::new();
-        #[cfg(feature = "trust-dns")]
-        let mut http_connector = hyper_trust_dns::new_trust_dns_http_connector();
-
-        http_connector.enforce_http(false);
-
-        #[cfg(feature = "rustls-native-roots")]
-        let connector = hyper_rustls::HttpsConnectorBuilder::new()
-            .with_native_roots()
-            .https_or_http()
-            .enable_http1()
-            .enable_http2()
-            .wrap_connector(http_connector);
-
-        #[cfg(all(feature = "rustls-webpki-roots", not(feature = "rustls-native-roots")))]
-        let connector = hyper_rustls::HttpsConnectorBuilder::new()
-            .with_webpki_roots()
-            .https_or_http()
-            .enable_http1()
-            .enable_http2()
-            .wrap_connector(http_connector);
-
-        #[cfg(all(
-            feature = "hyper-tls",
-            not(feature = "rustls-native-roots"),
-            not(feature = "rustls-webpki-roots")
-        ))]
-        let connector = hyper_tls::HttpsConnector::new_with_connector(http_connector);
-
-        let http = hyper::client::Builder::default().build(connector);
+        let http = self
+            .transport
+            .map_or_else(|| Arc::new(transport::default()) as _, Arc::from);
 
         let token_invalidated = if self.remember_invalid_token {
             Some(Arc::new(AtomicBool::new(false)))
@@ -69,11 +49,17 @@ impl ClientBuilder {
         Client {
             http,
             default_headers: self.default_headers,
+            invalid_requests: Arc::new(InvalidRequests::new(
+                self.invalid_request_limit,
+                self.invalid_request_warnings.into(),
+            )),
+            middleware: self.middleware.into(),
             proxy: self.proxy,
-            ratelimiter: self.ratelimiter,
+            ratelimiter: self.ratelimiter.map(Arc::from),
             timeout: self.timeout,
             token_invalidated,
             token: self.token,
+            token_manager: self.token_manager.map(Arc::new),
             default_allowed_mentions: self.default_allowed_mentions,
             use_http: self.use_http,
         }
@@ -87,6 +73,50 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the number of invalid requests in the past 10 minutes at which the
+    /// client refuses to send further requests.
+    ///
+    /// Discord temporarily bans the IP address of clients making 10,000
+    /// invalid requests, which are requests responded to with a status of
+    /// 401, 403, or 429, within 10 minutes. Once the limit is reached, requests
+    /// return an [`ErrorType::InvalidRequestLimitReached`] error until enough
+    /// invalid requests have left the window.
+    ///
+    /// Defaults to not refusing requests.
+    ///
+    /// [`ErrorType::InvalidRequestLimitReached`]: crate::error::ErrorType::InvalidRequestLimitReached
+    pub const fn invalid_request_limit(mut self, limit: u32) -> Self {
+        self.invalid_request_limit = Some(limit);
+
+        self
+    }
+
+    /// Set the numbers of invalid requests in the past 10 minutes at which to
+    /// emit a warning.
+    ///
+    /// Warnings are emitted via `tracing`, and require the `tracing` feature.
+    /// Refer to [`invalid_request_limit`] for what invalid requests are.
+    ///
+    /// Defaults to warning at 5,000, 8,000, and 9,000 invalid requests.
+    ///
+    /// [`invalid_request_limit`]: Self::invalid_request_limit
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn invalid_request_warnings(mut self, thresholds: Vec<u32>) -> Self {
+        self.invalid_request_warnings = thresholds;
+
+        self
+    }
+
+    /// Add a middleware to call before sending requests and after receiving
+    /// their responses.
+    ///
+    /// Refer to [`Middleware`] for the order middleware is called in.
+    pub fn middleware(mut self, middleware: Box<dyn Middleware>) -> Self {
+        self.middleware.push(middleware);
+
+        self
+    }
+
     /// Set the proxy to use for all HTTP(S) requests.
     ///
     /// **Note** that this isn't currently a traditional proxy, but is for
@@ -137,6 +167,18 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the transport to send requests with.
+    ///
+    /// If this method is not called at all then a hyper client with a
+    /// connector using the enabled TLS feature will be created by
+    /// [`ClientBuilder::build`]. Refer to [`Transport`] for more information.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn transport(mut self, transport: Box<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+
+        self
+    }
+
     /// Set a group headers which are sent in every request.
     pub fn default_headers(mut self, headers: HeaderMap) -> Self {
         self.default_headers.replace(headers);
@@ -172,6 +214,20 @@ impl ClientBuilder {
 
         self
     }
+
+    /// Set a manager of a bearer token to authorize requests with.
+    ///
+    /// The manager's access token takes precedence over a token set via
+    /// [`token`], and is refreshed before it expires. Refer to
+    /// [`TokenManager`] for more information.
+    ///
+    /// [`token`]: Self::token
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn token_manager(mut self, token_manager: TokenManager) -> Self {
+        self.token_manager = Some(token_manager);
+
+        self
+    }
 }
 
 impl Default for ClientBuilder {
@@ -179,11 +235,16 @@ impl Default for ClientBuilder {
         Self {
             default_allowed_mentions: None,
             default_headers: None,
+            invalid_request_limit: None,
+            invalid_request_warnings: invalid_request::DEFAULT_WARNINGS.to_vec(),
+            middleware: Vec::new(),
             proxy: None,
             ratelimiter: Some(Box::new(InMemoryRatelimiter::default())),
             remember_invalid_token: true,
             timeout: Duration::from_secs(10),
             token: None,
+            token_manager: None,
+            transport: None,
             use_http: false,
         }
     }
@@ -1,10 +1,12 @@
 use crate::{
+    client::InteractionResponder,
     request::application::{
         command::{
             CreateGlobalCommand, CreateGuildCommand, DeleteGlobalCommand, DeleteGuildCommand,
             GetCommandPermissions, GetGlobalCommand, GetGlobalCommands, GetGuildCommand,
             GetGuildCommandPermissions, GetGuildCommands, SetCommandPermissions, SetGlobalCommands,
-            SetGuildCommands, UpdateCommandPermissions, UpdateGlobalCommand, UpdateGuildCommand,
+            SetGuildCommands, SyncCommands, UpdateCommandPermissions, UpdateGlobalCommand,
+            UpdateGuildCommand,
         },
         interaction::{
             CreateFollowupMessage, DeleteFollowupMessage, DeleteOriginalResponse,
@@ -18,6 +20,7 @@ use twilight_model::{
     application::{
         callback::InteractionResponse,
         command::{permissions::CommandPermissions, Command},
+        interaction::Interaction,
     },
     id::{
         marker::{ApplicationMarker, CommandMarker, GuildMarker, InteractionMarker, MessageMarker},
@@ -86,6 +89,16 @@ impl<'a> InteractionClient<'a> {
         InteractionCallback::new(self.client, interaction_id, interaction_token, response)
     }
 
+    /// Create a responder to an interaction received now.
+    ///
+    /// The responder defers the interaction if a handler takes too long and
+    /// routes responses to the interaction callback, original response, or
+    /// followup messages. Refer to [`InteractionResponder`] for more
+    /// information.
+    pub fn responder(&self, interaction: &Interaction) -> InteractionResponder<'a> {
+        InteractionResponder::new(self.client, self.application_id, interaction)
+    }
+
     /// Get the original message, by its token.
     pub const fn get_interaction_original(
         &'a self,
@@ -223,6 +236,26 @@ impl<'a> InteractionClient<'a> {
         SetGuildCommands::new(self.client, self.application_id, guild_id, commands)
     }
 
+    /// Sync the global commands with declared commands, creating, updating,
+    /// and deleting only the commands that differ.
+    ///
+    /// Refer to [`SyncCommands`] for more information.
+    pub const fn sync_global_commands(&'a self, commands: &'a [Command]) -> SyncCommands<'a> {
+        SyncCommands::new(self.client, self.application_id, None, commands)
+    }
+
+    /// Sync the commands of a guild with declared commands, creating,
+    /// updating, and deleting only the commands that differ.
+    ///
+    /// Refer to [`SyncCommands`] for more information.
+    pub const fn sync_guild_commands(
+        &'a self,
+        guild_id: Id<GuildMarker>,
+        commands: &'a [Command],
+    ) -> SyncCommands<'a> {
+        SyncCommands::new(self.client, self.application_id, Some(guild_id), commands)
+    }
+
     /// Create a new global command.
     pub const fn create_global_command(&'a self) -> CreateGlobalCommand<'a> {
         CreateGlobalCommand::new(self.client, self.application_id)
@@ -0,0 +1,173 @@
+use super::TransportFuture;
+use hyper::StatusCode;
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Number of invalid requests in the [window] after which Discord bans the
+/// client's IP address for a period of time.
+///
+/// [window]: WINDOW
+#[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+pub const BAN_THRESHOLD: u32 = 10_000;
+
+/// Thresholds at which warnings are emitted by default.
+pub const DEFAULT_WARNINGS: [u32; 3] = [5_000, 8_000, 9_000];
+
+/// Rolling window in which invalid requests are counted.
+const WINDOW: Duration = Duration::from_secs(10 * 60);
+
+/// Value of the ratelimit scope header of ratelimits not counting towards the
+/// invalid request limit.
+const SHARED_SCOPE: &[u8] = b"shared";
+
+/// Tracker of the invalid responses received in the past [window].
+///
+/// Responses with a status of 401, 403, or 429 are invalid, except for 429s
+/// caused by a shared ratelimit.
+///
+/// [window]: WINDOW
+#[derive(Debug)]
+pub(super) struct InvalidRequests {
+    /// Number of invalid requests at which further requests are refused.
+    limit: Option<u32>,
+    /// When each invalid response in the window was received.
+    responses: Mutex<VecDeque<Instant>>,
+    /// Numbers of invalid requests at which to emit a warning.
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    warnings: Box<[u32]>,
+}
+
+impl InvalidRequests {
+    /// Create a new tracker.
+    pub fn new(limit: Option<u32>, warnings: Box<[u32]>) -> Self {
+        Self {
+            limit,
+            responses: Mutex::new(VecDeque::new()),
+            warnings,
+        }
+    }
+
+    /// Number of invalid responses received in the past window.
+    pub fn count(&self) -> u32 {
+        self.count_at(Instant::now())
+    }
+
+    /// Whether further requests must be refused.
+    pub fn is_limited(&self) -> bool {
+        self.limit.map_or(false, |limit| self.count() >= limit)
+    }
+
+    /// Number of invalid responses received in the window ending at a time.
+    fn count_at(&self, now: Instant) -> u32 {
+        let mut responses = self.responses.lock().expect("invalid requests poisoned");
+        prune(&mut responses, now);
+
+        u32::try_from(responses.len()).unwrap_or(u32::MAX)
+    }
+
+    /// Record an invalid response received at a time, returning the new
+    /// count.
+    fn record_at(&self, now: Instant) -> u32 {
+        let count = {
+            let mut responses = self.responses.lock().expect("invalid requests poisoned");
+            prune(&mut responses, now);
+            responses.push_back(now);
+
+            u32::try_from(responses.len()).unwrap_or(u32::MAX)
+        };
+
+        #[cfg(feature = "tracing")]
+        if self.warnings.contains(&count) {
+            tracing::warn!(
+                count,
+                ban_threshold = BAN_THRESHOLD,
+                "received {} invalid responses in the past 10 minutes",
+                count,
+            );
+        }
+
+        count
+    }
+}
+
+/// Remove responses received before the window ending at a time.
+fn prune(responses: &mut VecDeque<Instant>, now: Instant) {
+    while responses.front().map_or(false, |received| {
+        now.saturating_duration_since(*received) >= WINDOW
+    }) {
+        responses.pop_front();
+    }
+}
+
+/// Wrap the future of a request sent by the transport, recording its response
+/// if it's invalid.
+pub(super) fn track(
+    invalid_requests: Arc<InvalidRequests>,
+    future: TransportFuture,
+) -> TransportFuture {
+    Box::pin(async move {
+        let result = future.await;
+
+        if let Ok(response) = &result {
+            let invalid = match response.status() {
+                StatusCode::FORBIDDEN | StatusCode::UNAUTHORIZED => true,
+                StatusCode::TOO_MANY_REQUESTS => response
+                    .headers()
+                    .get("x-ratelimit-scope")
+                    .map_or(true, |scope| scope.as_bytes() != SHARED_SCOPE),
+                _ => false,
+            };
+
+            if invalid {
+                invalid_requests.record_at(Instant::now());
+            }
+        }
+
+        result
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidRequests, WINDOW};
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        time::{Duration, Instant},
+    };
+
+    assert_impl_all!(InvalidRequests: Debug, Send, Sync);
+
+    #[test]
+    fn test_window() {
+        let invalid_requests = InvalidRequests::new(None, Box::new([]));
+        let start = Instant::now();
+
+        assert_eq!(1, invalid_requests.record_at(start));
+        assert_eq!(
+            2,
+            invalid_requests.record_at(start + Duration::from_secs(1))
+        );
+        assert_eq!(
+            2,
+            invalid_requests.count_at(start + Duration::from_secs(599))
+        );
+        assert_eq!(1, invalid_requests.count_at(start + WINDOW));
+        assert_eq!(0, invalid_requests.count_at(start + WINDOW * 2));
+        assert!(!invalid_requests.is_limited());
+    }
+
+    #[test]
+    fn test_limit() {
+        let invalid_requests = InvalidRequests::new(Some(2), Box::new([1]));
+        let now = Instant::now();
+
+        invalid_requests.record_at(now);
+        assert!(!invalid_requests.is_limited());
+        invalid_requests.record_at(now);
+        assert!(invalid_requests.is_limited());
+    }
+}
@@ -0,0 +1,435 @@
+use super::TransportFuture;
+use crate::{
+    request::{Method, Request},
+    response::StatusCode,
+    routing::Path,
+};
+use hyper::{header::HeaderMap, Body, Response};
+use std::{fmt::Debug, sync::Arc, time::Duration};
+use tokio::time::Instant;
+use twilight_http_ratelimiting::headers::{HeaderParsingError, RatelimitHeaders};
+
+/// Hooks called around every request sent by the [`Client`].
+///
+/// Middleware is added via [`ClientBuilder::middleware`]. When the client is
+/// given a request, [`before_request`] is called on each middleware in the
+/// order they were added, allowing them to modify the request. The future
+/// sending the request is then passed to [`wrap_send`] on each middleware, so
+/// that the first middleware's wrapper is the outermost. Once a response is
+/// received, [`after_response`] is called on each middleware in reverse order
+/// with information about the request and its response.
+///
+/// [`before_request`] is called once, before waiting for a ratelimit ticket,
+/// while the other hooks are called when the request is sent. Time spent
+/// waiting for a ticket is therefore not observed by [`wrap_send`] and
+/// [`after_response`].
+///
+/// All hooks default to doing nothing.
+///
+/// # Examples
+///
+/// Add an audit log reason to every request that doesn't already have one:
+///
+/// ```
+/// use hyper::header::{HeaderName, HeaderValue};
+/// use twilight_http::{client::Middleware, request::Request, Client};
+///
+/// #[derive(Debug)]
+/// struct DefaultReason;
+///
+/// impl Middleware for DefaultReason {
+///     fn before_request(&self, request: &mut Request) {
+///         request
+///             .headers_mut()
+///             .entry(HeaderName::from_static("x-audit-log-reason"))
+///             .or_insert(HeaderValue::from_static("automated%20action"));
+///     }
+/// }
+///
+/// let client = Client::builder()
+///     .token("my token".to_owned())
+///     .middleware(Box::new(DefaultReason))
+///     .build();
+/// ```
+///
+/// [`Client`]: super::Client
+/// [`ClientBuilder::middleware`]: super::ClientBuilder::middleware
+/// [`after_response`]: Self::after_response
+/// [`before_request`]: Self::before_request
+/// [`wrap_send`]: Self::wrap_send
+pub trait Middleware: Debug + Send + Sync {
+    /// Called when the client is given a request, allowing it to be modified.
+    fn before_request(&self, _request: &mut Request) {}
+
+    /// Called once all middleware have modified a request, wrapping the future
+    /// sending it.
+    ///
+    /// The wrapped future resolves once the response is received and the
+    /// [`after_response`] hooks have been called. This may be used to
+    /// instrument the request, such as with a [`tracing`] span.
+    ///
+    /// Defaults to returning the future unchanged.
+    ///
+    /// [`after_response`]: Self::after_response
+    /// [`tracing`]: https://docs.rs/tracing
+    fn wrap_send(&self, _request: &RequestInfo<'_>, future: TransportFuture) -> TransportFuture {
+        future
+    }
+
+    /// Called after a response to a request is received or sending the
+    /// request failed.
+    ///
+    /// This isn't called if the request times out or its future is dropped
+    /// before receiving a response.
+    fn after_response(&self, _response: &ResponseInfo<'_>) {}
+}
+
+/// Information about a request, provided to [`Middleware::wrap_send`].
+#[derive(Debug)]
+pub struct RequestInfo<'a> {
+    method: Method,
+    path: &'a str,
+    ratelimit_path: &'a Path,
+}
+
+impl RequestInfo<'_> {
+    /// Method of the request.
+    pub const fn method(&self) -> Method {
+        self.method
+    }
+
+    /// Path and query of the request's URL.
+    pub const fn path(&self) -> &str {
+        self.path
+    }
+
+    /// Path used for ratelimiting the request.
+    pub const fn ratelimit_path(&self) -> &Path {
+        self.ratelimit_path
+    }
+}
+
+/// Information about a request and its response, provided to
+/// [`Middleware::after_response`].
+#[derive(Debug)]
+pub struct ResponseInfo<'a> {
+    elapsed: Duration,
+    method: Method,
+    path: &'a str,
+    ratelimit_path: &'a Path,
+    response: Option<&'a Response<Body>>,
+}
+
+impl<'a> ResponseInfo<'a> {
+    /// Time between sending the request and receiving the response.
+    ///
+    /// This doesn't include time spent waiting for a ratelimit ticket.
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Headers of the response, if one was received.
+    pub fn headers(&self) -> Option<&'a HeaderMap> {
+        self.response.map(Response::headers)
+    }
+
+    /// Method of the request.
+    pub const fn method(&self) -> Method {
+        self.method
+    }
+
+    /// Path and query of the request's URL.
+    pub const fn path(&self) -> &str {
+        self.path
+    }
+
+    /// Path used for ratelimiting the request.
+    pub const fn ratelimit_path(&self) -> &Path {
+        self.ratelimit_path
+    }
+
+    /// Parse the ratelimit headers of the response, if one was received.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HeaderParsingError`] if the headers are malformed.
+    pub fn ratelimit_headers(&self) -> Option<Result<RatelimitHeaders, HeaderParsingError>> {
+        self.headers().map(ratelimit_headers)
+    }
+
+    /// Status code of the response, if one was received.
+    pub fn status(&self) -> Option<StatusCode> {
+        self.response
+            .map(|response| StatusCode::new(response.status().as_u16()))
+    }
+}
+
+/// Parse the ratelimit headers of a response.
+fn ratelimit_headers(headers: &HeaderMap) -> Result<RatelimitHeaders, HeaderParsingError> {
+    RatelimitHeaders::from_pairs(
+        headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_bytes())),
+    )
+}
+
+/// Wrap the future of a request sent by the transport, calling the
+/// [`Middleware::after_response`] hooks once it resolves and then wrapping it
+/// with each [`Middleware::wrap_send`].
+pub(super) fn wrap(
+    middleware: &Arc<[Box<dyn Middleware>]>,
+    method: Method,
+    path: &str,
+    ratelimit_path: &Path,
+    future: TransportFuture,
+) -> TransportFuture {
+    let future = after_response(
+        Arc::clone(middleware),
+        method,
+        path.to_owned(),
+        ratelimit_path.clone(),
+        future,
+    );
+
+    let info = RequestInfo {
+        method,
+        path,
+        ratelimit_path,
+    };
+
+    middleware.iter().rev().fold(future, |future, middleware| {
+        middleware.wrap_send(&info, future)
+    })
+}
+
+/// Wrap the future of a request sent by the transport, calling the
+/// [`Middleware::after_response`] hooks once it resolves.
+fn after_response(
+    middleware: Arc<[Box<dyn Middleware>]>,
+    method: Method,
+    path: String,
+    ratelimit_path: Path,
+    future: TransportFuture,
+) -> TransportFuture {
+    Box::pin(async move {
+        let started = Instant::now();
+        let result = future.await;
+
+        let info = ResponseInfo {
+            elapsed: started.elapsed(),
+            method,
+            path: &path,
+            ratelimit_path: &ratelimit_path,
+            response: result.as_ref().ok(),
+        };
+
+        for middleware in middleware.iter().rev() {
+            middleware.after_response(&info);
+        }
+
+        result
+    })
+}
+
+/// Middleware emitting a [`tracing`] span for every request.
+///
+/// A debug-level span named `request` is created with the request's method,
+/// path, and ratelimit path when the request is sent, and events emitted
+/// while the request is in flight are within it. Once a response is
+/// received, the response's status, the time the request took, and the
+/// remaining ratelimit of the bucket are recorded in the span and an event is
+/// emitted within it.
+///
+/// [`tracing`]: https://docs.rs/tracing
+#[cfg(feature = "tracing")]
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct TracingMiddleware;
+
+#[cfg(feature = "tracing")]
+impl TracingMiddleware {
+    /// Create a new tracing middleware.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Middleware for TracingMiddleware {
+    fn wrap_send(&self, request: &RequestInfo<'_>, future: TransportFuture) -> TransportFuture {
+        use tracing::{field, Instrument};
+
+        let span = tracing::debug_span!(
+            "request",
+            method = %request.method().to_http(),
+            path = request.path(),
+            ratelimit_path = ?request.ratelimit_path(),
+            status = field::Empty,
+            elapsed = field::Empty,
+            remaining = field::Empty,
+        );
+
+        Box::pin(async move {
+            let started = Instant::now();
+            let result = future.instrument(span.clone()).await;
+            let response = result.as_ref().ok();
+
+            let remaining = match response.map(|response| ratelimit_headers(response.headers())) {
+                Some(Ok(RatelimitHeaders::Present(present))) => Some(present.remaining()),
+                _ => None,
+            };
+
+            span.record(
+                "status",
+                field::debug(response.map(|response| response.status().as_u16())),
+            );
+            span.record("elapsed", field::debug(started.elapsed()));
+            span.record("remaining", field::debug(remaining));
+
+            span.in_scope(|| {
+                if response.is_some() {
+                    tracing::debug!("received response");
+                } else {
+                    tracing::debug!("request failed");
+                }
+            });
+
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Middleware, RequestInfo, ResponseInfo};
+    use crate::{
+        client::{Client, TransportFuture},
+        request::Request,
+        response::StatusCode,
+        test::Recorder,
+    };
+    use hyper::header::{HeaderName, HeaderValue};
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{
+        error::Error,
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    };
+
+    assert_obj_safe!(Middleware);
+    assert_impl_all!(RequestInfo<'_>: Debug, Send, Sync);
+    assert_impl_all!(ResponseInfo<'_>: Debug, Send, Sync);
+
+    #[cfg(feature = "tracing")]
+    assert_impl_all!(super::TracingMiddleware: Debug, Default, Middleware, Send, Sync);
+
+    /// Middleware logging the hooks called on it and setting a header.
+    #[derive(Debug)]
+    struct Logger {
+        log: Arc<Mutex<Vec<String>>>,
+        name: &'static str,
+    }
+
+    impl Logger {
+        fn push(&self, entry: String) {
+            self.log.lock().unwrap().push(entry);
+        }
+    }
+
+    impl Middleware for Logger {
+        fn before_request(&self, request: &mut Request) {
+            self.push(format!("{} before {}", self.name, request.path()));
+
+            request.headers_mut().insert(
+                HeaderName::from_static("x-audit-log-reason"),
+                HeaderValue::from_static(self.name),
+            );
+        }
+
+        fn wrap_send(&self, request: &RequestInfo<'_>, future: TransportFuture) -> TransportFuture {
+            self.push(format!("{} wrap {}", self.name, request.path()));
+
+            let log = Arc::clone(&self.log);
+            let name = self.name;
+
+            Box::pin(async move {
+                let result = future.await;
+                log.lock().unwrap().push(format!("{} unwrap", name));
+
+                result
+            })
+        }
+
+        fn after_response(&self, response: &ResponseInfo<'_>) {
+            self.push(format!(
+                "{} after {} {:?}",
+                self.name,
+                response.path(),
+                response.status().map(StatusCode::raw),
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hooks() -> Result<(), Box<dyn Error>> {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let recorder = Recorder::ok(r#"{"url":"wss://gateway.discord.gg"}"#);
+        let requests = recorder.requests();
+        let client = Client::builder()
+            .ratelimiter(None)
+            .middleware(Box::new(Logger {
+                log: Arc::clone(&log),
+                name: "first",
+            }))
+            .middleware(Box::new(Logger {
+                log: Arc::clone(&log),
+                name: "second",
+            }))
+            .transport(Box::new(recorder))
+            .build();
+
+        client.gateway().exec().await?;
+
+        assert_eq!(
+            [
+                "first before gateway",
+                "second before gateway",
+                "second wrap gateway",
+                "first wrap gateway",
+                "second after gateway Some(200)",
+                "first after gateway Some(200)",
+                "second unwrap",
+                "first unwrap",
+            ]
+            .as_ref(),
+            log.lock().unwrap().as_slice()
+        );
+
+        // The header set by the last middleware is sent.
+        let requests = requests.lock().unwrap();
+        assert_eq!(1, requests.len());
+        assert_eq!(Some("second"), requests[0].header("x-audit-log-reason"));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_tracing_middleware() -> Result<(), Box<dyn Error>> {
+        let recorder = Recorder::ok("{}");
+        let requests = recorder.requests();
+        let client = Client::builder()
+            .ratelimiter(None)
+            .middleware(Box::new(super::TracingMiddleware::new()))
+            .transport(Box::new(recorder))
+            .build();
+
+        client
+            .channel(twilight_model::id::Id::new(1))
+            .exec()
+            .await?;
+        assert_eq!("/api/v9/channels/1", requests.lock().unwrap()[0].path());
+
+        Ok(())
+    }
+}
@@ -1,7 +1,31 @@
 mod builder;
 mod interaction;
+mod invalid_request;
+mod middleware;
+mod oauth;
+mod responder;
+mod token_manager;
+mod transport;
+mod webhook;
+
+use self::invalid_request::InvalidRequests;
+
+pub use self::{
+    builder::ClientBuilder,
+    interaction::InteractionClient,
+    middleware::{Middleware, RequestInfo, ResponseInfo},
+    oauth::OauthClient,
+    responder::{
+        InteractionResponder, InteractionResponderError, InteractionResponderErrorType,
+        InteractionResponderState, CALLBACK_WINDOW, DEFAULT_DEADLINE, TOKEN_LIFETIME,
+    },
+    token_manager::{TokenManager, TokenManagerError, TokenManagerErrorType},
+    transport::{Transport, TransportFuture},
+    webhook::{WebhookClient, WebhookClientError, WebhookClientErrorType},
+};
 
-pub use self::{builder::ClientBuilder, interaction::InteractionClient};
+#[cfg(feature = "tracing")]
+pub use self::middleware::TracingMiddleware;
 
 #[allow(deprecated)]
 use crate::{
@@ -11,7 +35,7 @@ use crate::{
             invite::{CreateInvite, DeleteInvite, GetChannelInvites, GetInvite},
             message::{
                 CreateMessage, CrosspostMessage, DeleteMessage, DeleteMessages, GetChannelMessages,
-                GetMessage, UpdateMessage,
+                GetMessage, PurgeMessages, UpdateMessage,
             },
             reaction::{
                 delete_reaction::TargetUser, CreateReaction, DeleteAllReaction, DeleteAllReactions,
@@ -56,6 +80,7 @@ use crate::{
             GetGuildWelcomeScreen, GetGuildWidget, UpdateCurrentMember, UpdateGuild,
             UpdateGuildChannelPositions, UpdateGuildWelcomeScreen, UpdateGuildWidget,
         },
+        oauth::GetCurrentAuthorizationInformation,
         scheduled_event::{
             CreateGuildScheduledEvent, DeleteGuildScheduledEvent, GetGuildScheduledEvent,
             GetGuildScheduledEventUsers, GetGuildScheduledEvents, UpdateGuildScheduledEvent,
@@ -70,13 +95,12 @@ use crate::{
             GetCurrentUserGuildMember, GetCurrentUserGuilds, GetUser, LeaveGuild,
             UpdateCurrentUser,
         },
-        GetGateway, GetUserApplicationInfo, GetVoiceRegions, Method, Request,
+        AttachmentStream, GetGateway, GetUserApplicationInfo, GetVoiceRegions, Method, Request,
     },
     response::ResponseFuture,
     API_VERSION,
 };
 use hyper::{
-    client::Client as HyperClient,
     header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
     Body,
 };
@@ -106,15 +130,14 @@ use twilight_validate::{
     channel::ChannelValidationError, request::ValidationError, sticker::StickerValidationError,
 };
 
-#[cfg(feature = "hyper-rustls")]
-type HttpsConnector<T> = hyper_rustls::HttpsConnector<T>;
-#[cfg(all(feature = "hyper-tls", not(feature = "hyper-rustls")))]
-type HttpsConnector<T> = hyper_tls::HttpsConnector<T>;
-
-#[cfg(feature = "trust-dns")]
-type HttpConnector = hyper_trust_dns::TrustDnsHttpConnector;
-#[cfg(not(feature = "trust-dns"))]
-type HttpConnector = hyper::client::HttpConnector;
+/// Value of the user agent header sent with requests.
+const USER_AGENT_VALUE: &str = concat!(
+    "DiscordBot (",
+    env!("CARGO_PKG_HOMEPAGE"),
+    ", ",
+    env!("CARGO_PKG_VERSION"),
+    ") Twilight-rs",
+);
 
 /// Twilight's http client.
 ///
@@ -143,6 +166,10 @@ type HttpConnector = hyper::client::HttpConnector;
 /// # Ok(()) }
 /// ```
 ///
+/// Bearer tokens may be obtained via the `OAuth2` flows of an
+/// [`OauthClient`], and kept fresh by configuring a [`TokenManager`] via
+/// [`ClientBuilder::token_manager`].
+///
 /// # Using the client in multiple tasks
 ///
 /// To use a client instance in multiple tasks, consider wrapping it in an
@@ -156,6 +183,14 @@ type HttpConnector = hyper::client::HttpConnector;
 /// new token. The client will no longer execute requests in order to
 /// prevent API bans and will always return [`ErrorType::Unauthorized`].
 ///
+/// # Invalid requests
+///
+/// Discord temporarily bans the IP address of clients making too many invalid
+/// requests. The client counts the invalid requests it made in the past 10
+/// minutes, available via [`invalid_request_count`], and may be configured to
+/// refuse to send requests before being banned via
+/// [`ClientBuilder::invalid_request_limit`].
+///
 /// # Examples
 ///
 /// Create a client called `client`:
@@ -186,15 +221,19 @@ type HttpConnector = hyper::client::HttpConnector;
 /// All the examples on this page assume you have already created a client, and have named it
 /// `client`.
 ///
+/// [`invalid_request_count`]: Self::invalid_request_count
 /// [here]: https://discord.com/developers/applications
 #[derive(Debug)]
 pub struct Client {
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
     default_headers: Option<HeaderMap>,
-    http: HyperClient<HttpsConnector<HttpConnector>, Body>,
+    http: Arc<dyn Transport>,
+    invalid_requests: Arc<InvalidRequests>,
+    middleware: Arc<[Box<dyn Middleware>]>,
     proxy: Option<Box<str>>,
-    ratelimiter: Option<Box<dyn Ratelimiter>>,
+    ratelimiter: Option<Arc<dyn Ratelimiter>>,
     timeout: Duration,
+    token_manager: Option<Arc<TokenManager>>,
     /// Whether the token has been invalidated.
     ///
     /// Whether an invalid token is tracked can be configured via
@@ -222,10 +261,19 @@ impl Client {
     ///
     /// If the initial token provided is not prefixed with `Bot `, it will be, and this method
     /// reflects that.
+    ///
+    /// If a [`TokenManager`] is configured then requests are authorized with
+    /// its access token instead.
     pub fn token(&self) -> Option<&str> {
         self.token.as_deref()
     }
 
+    /// Retrieve an immutable reference to the token manager used by the
+    /// client, if one is configured.
+    pub fn token_manager(&self) -> Option<&TokenManager> {
+        self.token_manager.as_deref()
+    }
+
     /// Create an interface for using interactions.
     ///
     /// An application ID is required to be passed in to use interactions. The
@@ -272,11 +320,35 @@ impl Client {
         InteractionClient::new(self, application_id)
     }
 
+    /// Create an interface for the `OAuth2` flows of an application.
+    ///
+    /// Refer to [`OauthClient`] for more information.
+    pub const fn oauth<'a>(
+        &'a self,
+        application_id: Id<ApplicationMarker>,
+        client_secret: &'a str,
+    ) -> OauthClient<'a> {
+        OauthClient::new(self, application_id, client_secret)
+    }
+
+    /// Get information about the authorization of the client's bearer token.
+    pub const fn current_authorization(&self) -> GetCurrentAuthorizationInformation<'_> {
+        GetCurrentAuthorizationInformation::new(self)
+    }
+
     /// Get the default [`AllowedMentions`] for sent messages.
     pub fn default_allowed_mentions(&self) -> Option<AllowedMentions> {
         self.default_allowed_mentions.clone()
     }
 
+    /// Number of invalid requests made in the past 10 minutes.
+    ///
+    /// Refer to [`ClientBuilder::invalid_request_limit`] for what invalid
+    /// requests are.
+    pub fn invalid_request_count(&self) -> u32 {
+        self.invalid_requests.count()
+    }
+
     /// Get the Ratelimiter used by the client internally.
     ///
     /// This will return `None` only if ratelimit handling
@@ -1274,6 +1346,21 @@ impl Client {
         DeleteMessages::new(self, channel_id, message_ids)
     }
 
+    /// Delete any number of messages in a channel by their IDs, regardless of
+    /// their age.
+    ///
+    /// Messages are bulk deleted where possible and otherwise deleted one at a
+    /// time. Refer to [`PurgeMessages`] for more information.
+    ///
+    /// To delete messages matching a filter, use [`GetChannelMessages::purge`].
+    pub const fn purge_messages<'a>(
+        &'a self,
+        channel_id: Id<ChannelMarker>,
+        message_ids: &'a [Id<MessageMarker>],
+    ) -> PurgeMessages<'a> {
+        PurgeMessages::new(self, channel_id, message_ids)
+    }
+
     /// Update a message by [`Id<ChannelMarker>`] and [`Id<MessageMarker>`].
     ///
     /// You can pass `None` to any of the methods to remove the associated field.
@@ -2278,6 +2365,26 @@ impl Client {
         CreateGuildSticker::new(self, guild_id, name, description, tags, file)
     }
 
+    /// Creates a sticker in a guild, streaming the contents of its file, and
+    /// returns the created sticker.
+    ///
+    /// Refer to [`AttachmentStream`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StickerValidationError`] if the name, description, or tags
+    /// are invalid.
+    pub fn create_guild_sticker_stream<'a>(
+        &'a self,
+        guild_id: Id<GuildMarker>,
+        name: &'a str,
+        description: &'a str,
+        tags: &'a str,
+        file: AttachmentStream,
+    ) -> Result<CreateGuildSticker<'_>, StickerValidationError> {
+        CreateGuildSticker::new_stream(self, guild_id, name, description, tags, file)
+    }
+
     /// Updates a sticker in a guild, and returns the updated sticker.
     ///
     /// # Examples
@@ -2340,6 +2447,14 @@ impl Client {
         DeleteGuildSticker::new(self, guild_id, sticker_id)
     }
 
+    /// Full URL of an API path.
+    fn url(&self, path: &str) -> String {
+        let protocol = if self.use_http { "http" } else { "https" };
+        let host = self.proxy.as_deref().unwrap_or("discord.com");
+
+        format!("{}://{}/api/v{}/{}", protocol, host, API_VERSION, path)
+    }
+
     /// Execute a request, returning a future resolving to a [`Response`].
     ///
     /// # Errors
@@ -2356,14 +2471,9 @@ impl Client {
     }
 
     #[allow(clippy::too_many_lines)]
-    fn try_request<T>(&self, request: Request) -> Result<ResponseFuture<T>, Error> {
-        if let Some(token_invalidated) = self.token_invalidated.as_ref() {
-            if token_invalidated.load(Ordering::Relaxed) {
-                return Err(Error {
-                    kind: ErrorType::Unauthorized,
-                    source: None,
-                });
-            }
+    fn try_request<T>(&self, mut request: Request) -> Result<ResponseFuture<T>, Error> {
+        for middleware in self.middleware.iter() {
+            middleware.before_request(&mut request);
         }
 
         let Request {
@@ -2376,16 +2486,36 @@ impl Client {
             use_authorization_token,
         } = request;
 
-        let protocol = if self.use_http { "http" } else { "https" };
-        let host = self.proxy.as_deref().unwrap_or("discord.com");
+        // Requests authorized by a token manager refresh an invalidated token
+        // instead.
+        let token_manager = self
+            .token_manager
+            .as_ref()
+            .filter(|_| use_authorization_token);
 
-        let url = format!("{}://{}/api/v{}/{}", protocol, host, API_VERSION, path);
+        if let Some(token_invalidated) = self.token_invalidated.as_ref() {
+            if token_manager.is_none() && token_invalidated.load(Ordering::Relaxed) {
+                return Err(Error {
+                    kind: ErrorType::Unauthorized,
+                    source: None,
+                });
+            }
+        }
+
+        if self.invalid_requests.is_limited() {
+            return Err(Error {
+                kind: ErrorType::InvalidRequestLimitReached,
+                source: None,
+            });
+        }
+
+        let url = self.url(&path);
         #[cfg(feature = "tracing")]
         tracing::debug!("URL: {:?}", url);
 
         let mut builder = hyper::Request::builder().method(method.to_http()).uri(&url);
 
-        if use_authorization_token {
+        if use_authorization_token && token_manager.is_none() {
             if let Some(token) = &self.token {
                 let value = HeaderValue::from_str(token).map_err(|source| {
                     #[allow(clippy::borrow_interior_mutable_const)]
@@ -2403,13 +2533,7 @@ impl Client {
             }
         }
 
-        let user_agent = HeaderValue::from_static(concat!(
-            "DiscordBot (",
-            env!("CARGO_PKG_HOMEPAGE"),
-            ", ",
-            env!("CARGO_PKG_VERSION"),
-            ") Twilight-rs",
-        ));
+        let user_agent = HeaderValue::from_static(USER_AGENT_VALUE);
 
         if let Some(headers) = builder.headers_mut() {
             if let Some(form) = &form {
@@ -2448,16 +2572,14 @@ impl Client {
         }
 
         let req = if let Some(form) = form {
-            let form_bytes = form.build();
+            let (form_body, form_length) = form.into_body();
             if let Some(headers) = builder.headers_mut() {
-                headers.insert(CONTENT_LENGTH, HeaderValue::from(form_bytes.len()));
+                headers.insert(CONTENT_LENGTH, HeaderValue::from(form_length));
             };
-            builder
-                .body(Body::from(form_bytes))
-                .map_err(|source| Error {
-                    kind: ErrorType::BuildingRequest,
-                    source: Some(Box::new(source)),
-                })?
+            builder.body(form_body).map_err(|source| Error {
+                kind: ErrorType::BuildingRequest,
+                source: Some(Box::new(source)),
+            })?
         } else if let Some(bytes) = body {
             builder.body(Body::from(bytes)).map_err(|source| Error {
                 kind: ErrorType::BuildingRequest,
@@ -2479,7 +2601,24 @@ impl Client {
             })?
         };
 
-        let inner = self.http.request(req);
+        let inner = if let Some(token_manager) = token_manager {
+            token_manager.send(
+                Arc::clone(&self.http),
+                self.url("oauth2/token"),
+                self.token_invalidated.as_ref().map(Arc::clone),
+                req,
+            )
+        } else {
+            self.http.send(req)
+        };
+
+        let inner = invalid_request::track(Arc::clone(&self.invalid_requests), inner);
+
+        let inner = if self.middleware.is_empty() {
+            inner
+        } else {
+            middleware::wrap(&self.middleware, method, &path, &ratelimit_path, inner)
+        };
 
         // For requests that don't use an authorization token we don't need to
         // remember whether the token is invalid. This may be for requests such
@@ -2494,12 +2633,11 @@ impl Client {
         // due to move semantics in both cases.
         #[allow(clippy::option_if_let_else)]
         if let Some(ratelimiter) = self.ratelimiter.as_ref() {
-            let tx_future = ratelimiter.wait_for_ticket(ratelimit_path);
-
             Ok(ResponseFuture::ratelimit(
                 None,
                 invalid_token,
-                tx_future,
+                Arc::clone(ratelimiter),
+                ratelimit_path,
                 self.timeout,
                 inner,
             ))
@@ -0,0 +1,107 @@
+use crate::{
+    request::oauth::{ClientCredentials, ExchangeCode, RefreshToken, RevokeToken},
+    Client,
+};
+use twilight_model::id::{marker::ApplicationMarker, Id};
+
+/// Client interface for the `OAuth2` flows of an application.
+///
+/// Requests are authorized by the application's ID and secret instead of
+/// the client's token.
+///
+/// # Examples
+///
+/// Exchange an authorization code for an access token, and then use the
+/// access token:
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// let client = Client::builder().build();
+/// let client_secret = env::var("CLIENT_SECRET")?;
+///
+/// let token = client
+///     .oauth(Id::new(123), &client_secret)
+///     .exchange_code("code", "https://example.com/callback")
+///     .exec()
+///     .await?
+///     .model()
+///     .await?;
+///
+/// let user_client = Client::new(format!("Bearer {}", token.access_token));
+/// let user = user_client.current_user().exec().await?.model().await?;
+///
+/// println!("authorized by {}", user.name);
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct OauthClient<'a> {
+    application_id: Id<ApplicationMarker>,
+    client: &'a Client,
+    client_secret: &'a str,
+}
+
+impl<'a> OauthClient<'a> {
+    /// Create a new interface for the `OAuth2` flows of an application.
+    pub(super) const fn new(
+        client: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        client_secret: &'a str,
+    ) -> Self {
+        Self {
+            application_id,
+            client,
+            client_secret,
+        }
+    }
+
+    /// Grant an access token for the application's owner via the client
+    /// credentials flow.
+    ///
+    /// Bot applications are granted the `applications.commands.update`
+    /// scope, allowing them to update their commands.
+    pub const fn client_credentials(&self, scopes: &'a [&'a str]) -> ClientCredentials<'a> {
+        ClientCredentials::new(self.client, self.application_id, self.client_secret, scopes)
+    }
+
+    /// Exchange an authorization code for an access token.
+    ///
+    /// The redirect URI must match the one used in the authorization URL.
+    pub const fn exchange_code(&self, code: &'a str, redirect_uri: &'a str) -> ExchangeCode<'a> {
+        ExchangeCode::new(
+            self.client,
+            self.application_id,
+            self.client_secret,
+            code,
+            redirect_uri,
+        )
+    }
+
+    /// Refresh an access token via its refresh token.
+    pub const fn refresh_token(&self, refresh_token: &'a str) -> RefreshToken<'a> {
+        RefreshToken::new(
+            self.client,
+            self.application_id,
+            self.client_secret,
+            refresh_token,
+        )
+    }
+
+    /// Revoke an access token or refresh token.
+    pub const fn revoke_token(&self, token: &'a str) -> RevokeToken<'a> {
+        RevokeToken::new(self.client, self.application_id, self.client_secret, token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OauthClient;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(OauthClient<'_>: Debug, Send, Sync);
+}
@@ -0,0 +1,937 @@
+use crate::{
+    error::Error,
+    request::application::interaction::{
+        CreateFollowupMessage, InteractionCallback, UpdateOriginalResponse,
+    },
+    response::Response,
+    Client,
+};
+use futures_util::pin_mut;
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    future::Future,
+    time::Duration,
+};
+use tokio::time::{self, Instant};
+use twilight_model::{
+    application::{
+        callback::{CallbackData, InteractionResponse},
+        interaction::Interaction,
+    },
+    channel::{message::MessageFlags, Message},
+    id::{
+        marker::{ApplicationMarker, InteractionMarker},
+        Id,
+    },
+};
+use twilight_validate::message::{
+    components as validate_components, content as validate_content, embeds as validate_embeds,
+    MessageValidationError,
+};
+
+/// Time after receiving an interaction within which the initial response must
+/// be sent.
+pub const CALLBACK_WINDOW: Duration = Duration::from_secs(3);
+
+/// Default time after which a handler run by the responder is deferred.
+///
+/// Leaves some margin before the [callback window] ends for the deferral to
+/// reach Discord.
+///
+/// [callback window]: CALLBACK_WINDOW
+pub const DEFAULT_DEADLINE: Duration = Duration::from_secs(2);
+
+/// Time after receiving an interaction for which its token is valid.
+pub const TOKEN_LIFETIME: Duration = Duration::from_secs(15 * 60);
+
+/// Responding to an interaction with an [`InteractionResponder`] failed.
+#[derive(Debug)]
+pub struct InteractionResponderError {
+    kind: InteractionResponderErrorType,
+    source: Option<Box<dyn StdError + Send + Sync>>,
+}
+
+impl InteractionResponderError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &InteractionResponderErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn StdError + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        InteractionResponderErrorType,
+        Option<Box<dyn StdError + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+
+    /// Create an error of a type without a source.
+    const fn new(kind: InteractionResponderErrorType) -> Self {
+        Self { kind, source: None }
+    }
+
+    /// Create an error of type [`Requesting`].
+    ///
+    /// [`Requesting`]: InteractionResponderErrorType::Requesting
+    fn requesting(source: Error) -> Self {
+        Self {
+            kind: InteractionResponderErrorType::Requesting,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    /// Create an error of type [`Validation`].
+    ///
+    /// [`Validation`]: InteractionResponderErrorType::Validation
+    fn validation(source: MessageValidationError) -> Self {
+        Self {
+            kind: InteractionResponderErrorType::Validation,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for InteractionResponderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            InteractionResponderErrorType::CallbackWindowElapsed => {
+                f.write_str("initial interaction response window has elapsed")
+            }
+            InteractionResponderErrorType::Requesting => {
+                f.write_str("failed to send the interaction response")
+            }
+            InteractionResponderErrorType::TokenExpired => {
+                f.write_str("interaction token has expired")
+            }
+            InteractionResponderErrorType::Validation => {
+                f.write_str("interaction response is invalid")
+            }
+        }
+    }
+}
+
+impl StdError for InteractionResponderError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn StdError + 'static))
+    }
+}
+
+/// Type of [`InteractionResponderError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum InteractionResponderErrorType {
+    /// Interaction hasn't been responded to within the [callback window].
+    ///
+    /// [callback window]: CALLBACK_WINDOW
+    CallbackWindowElapsed,
+    /// Request sending the response failed.
+    ///
+    /// The source is an [`Error`] and may be inspected for the response.
+    ///
+    /// [`Error`]: crate::Error
+    Requesting,
+    /// Interaction token has outlived its [lifetime].
+    ///
+    /// [lifetime]: TOKEN_LIFETIME
+    TokenExpired,
+    /// Response contents are invalid.
+    ///
+    /// The source is a [`MessageValidationError`].
+    Validation,
+}
+
+/// State of the response to an interaction.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum InteractionResponderState {
+    /// Interaction hasn't been responded to yet.
+    Pending,
+    /// Interaction has been deferred, and the next response edits the
+    /// original response.
+    Deferred,
+    /// Interaction has been responded to, and further responses are sent as
+    /// followup messages.
+    Responded,
+}
+
+/// Responder to a received interaction.
+///
+/// Routes each response to the endpoint matching the state of the interaction:
+///
+/// - the first response is sent as the [interaction callback];
+/// - a response after a deferral [edits the original response];
+/// - further responses are sent as [followup messages].
+///
+/// Handlers run via [`run`] are automatically deferred if they haven't
+/// completed before the [deadline], so that slow handlers don't miss the
+/// [callback window]. The expiry of the interaction's token after its
+/// [lifetime] is tracked from when the responder was created, so it should be
+/// created as soon as the interaction is received.
+///
+/// Deferrals are sent as a [`DeferredChannelMessageWithSource`], so the
+/// eventual response is a new message, unless the responder [updates] the
+/// message a component is attached to, in which case they're sent as a
+/// [`DeferredUpdateMessage`]. Responders are not suitable for [`Ping`]s or
+/// [autocomplete interactions], which can't be deferred.
+///
+/// Created via [`InteractionClient::responder`].
+///
+/// # Examples
+///
+/// Respond to a command, deferring if computing the response takes too long:
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let interaction: twilight_model::application::interaction::Interaction = unimplemented!();
+/// use std::{env, time::Duration};
+/// use twilight_http::Client;
+/// use twilight_model::{application::callback::CallbackData, id::Id};
+///
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+/// let interaction_client = client.interaction(Id::new(1));
+///
+/// let mut responder = interaction_client
+///     .responder(&interaction)
+///     .deadline(Duration::from_millis(1500));
+///
+/// let content = responder
+///     .run(async {
+///         // Expensive work.
+///         "pong".to_owned()
+///     })
+///     .await?;
+///
+/// responder
+///     .respond(CallbackData {
+///         allowed_mentions: None,
+///         components: None,
+///         content: Some(content),
+///         embeds: None,
+///         flags: None,
+///         tts: None,
+///     })
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`DeferredChannelMessageWithSource`]: InteractionResponse::DeferredChannelMessageWithSource
+/// [`DeferredUpdateMessage`]: InteractionResponse::DeferredUpdateMessage
+/// [`InteractionClient::responder`]: super::InteractionClient::responder
+/// [`Ping`]: Interaction::Ping
+/// [`run`]: Self::run
+/// [autocomplete interactions]: Interaction::ApplicationCommandAutocomplete
+/// [callback window]: CALLBACK_WINDOW
+/// [deadline]: Self::deadline
+/// [edits the original response]: super::InteractionClient::update_interaction_original
+/// [followup messages]: super::InteractionClient::create_followup_message
+/// [interaction callback]: super::InteractionClient::interaction_callback
+/// [lifetime]: TOKEN_LIFETIME
+/// [updates]: Self::update
+#[derive(Debug)]
+#[must_use = "responders do nothing unless used to respond"]
+pub struct InteractionResponder<'a> {
+    application_id: Id<ApplicationMarker>,
+    client: &'a Client,
+    deadline: Duration,
+    ephemeral: bool,
+    id: Id<InteractionMarker>,
+    received_at: Instant,
+    state: InteractionResponderState,
+    token: String,
+    update: bool,
+}
+
+impl<'a> InteractionResponder<'a> {
+    /// Create a new responder to an interaction received now.
+    pub(super) fn new(
+        client: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        interaction: &Interaction,
+    ) -> Self {
+        Self {
+            application_id,
+            client,
+            deadline: DEFAULT_DEADLINE,
+            ephemeral: false,
+            id: interaction.id(),
+            received_at: Instant::now(),
+            state: InteractionResponderState::Pending,
+            token: interaction.token().to_owned(),
+            update: matches!(interaction, Interaction::MessageComponent(_)),
+        }
+    }
+
+    /// Set the time after receiving the interaction at which handlers run by
+    /// [`run`] are deferred.
+    ///
+    /// Defaults to [`DEFAULT_DEADLINE`].
+    ///
+    /// [`run`]: Self::run
+    pub const fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = deadline;
+
+        self
+    }
+
+    /// Set whether deferrals are ephemeral, in which case the eventual
+    /// response is only visible to the user who invoked the interaction.
+    ///
+    /// Defaults to `false`.
+    pub const fn ephemeral(mut self, ephemeral: bool) -> Self {
+        self.ephemeral = ephemeral;
+
+        self
+    }
+
+    /// Set whether the initial response updates the message a component is
+    /// attached to instead of creating a new message.
+    ///
+    /// Deferrals are then sent as a [`DeferredUpdateMessage`] and the
+    /// interaction callback as an [`UpdateMessage`]. This is only valid for
+    /// [message component interactions].
+    ///
+    /// Defaults to `true` for message component interactions and `false`
+    /// otherwise.
+    ///
+    /// [`DeferredUpdateMessage`]: InteractionResponse::DeferredUpdateMessage
+    /// [`UpdateMessage`]: InteractionResponse::UpdateMessage
+    /// [message component interactions]: Interaction::MessageComponent
+    pub const fn update(mut self, update: bool) -> Self {
+        self.update = update;
+
+        self
+    }
+
+    /// When the interaction's token expires.
+    pub fn expires_at(&self) -> Instant {
+        self.received_at + TOKEN_LIFETIME
+    }
+
+    /// ID of the interaction.
+    pub const fn id(&self) -> Id<InteractionMarker> {
+        self.id
+    }
+
+    /// Whether the interaction's token has expired.
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at()
+    }
+
+    /// State of the response to the interaction.
+    pub const fn state(&self) -> InteractionResponderState {
+        self.state
+    }
+
+    /// Token of the interaction.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Defer the response to the interaction.
+    ///
+    /// Does nothing if the interaction has already been deferred or responded
+    /// to. The deferral is [ephemeral] unless the responder [updates] the
+    /// component's message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`CallbackWindowElapsed`] if the interaction
+    /// is pending and the [callback window] has elapsed.
+    ///
+    /// Returns an error of type [`Requesting`] if the request failed.
+    ///
+    /// [`CallbackWindowElapsed`]: InteractionResponderErrorType::CallbackWindowElapsed
+    /// [`Requesting`]: InteractionResponderErrorType::Requesting
+    /// [callback window]: CALLBACK_WINDOW
+    /// [ephemeral]: Self::ephemeral
+    /// [updates]: Self::update
+    pub async fn defer(&mut self) -> Result<(), InteractionResponderError> {
+        if self.state != InteractionResponderState::Pending {
+            return Ok(());
+        }
+
+        self.callback(&self.deferral_response()).await?;
+        self.state = InteractionResponderState::Deferred;
+
+        Ok(())
+    }
+
+    /// Mark the interaction as deferred without sending the deferral,
+    /// returning it so that it can be sent as the initial response by other
+    /// means.
+    ///
+    /// This is useful when the initial response is sent as the response to a
+    /// request received by an interactions endpoint. The deferral is built the
+    /// same way as by [`defer`].
+    ///
+    /// Returns `None` if the interaction has already been deferred or
+    /// responded to.
+    ///
+    /// [`defer`]: Self::defer
+    pub fn deferral(&mut self) -> Option<InteractionResponse> {
+        if self.state != InteractionResponderState::Pending {
+            return None;
+        }
+
+        self.state = InteractionResponderState::Deferred;
+
+        Some(self.deferral_response())
+    }
+
+    /// Respond to the interaction.
+    ///
+    /// If the interaction is pending the response is sent as the interaction
+    /// callback, [updating] the component's message if configured to, if it
+    /// has been deferred the original response is edited, and
+    /// otherwise a followup message is created. Returns the response of the
+    /// request creating or editing a message, if one was sent.
+    ///
+    /// Editing the original response can't change whether it's ephemeral, so
+    /// the [`EPHEMERAL`] flag is only respected by the interaction callback
+    /// and followup messages. If the responder [updates] the component's
+    /// message and has been deferred, an ephemeral or text-to-speech response
+    /// is sent as a followup message instead of editing the component's
+    /// message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`CallbackWindowElapsed`] if the interaction
+    /// is pending and the [callback window] has elapsed.
+    ///
+    /// Returns an error of type [`TokenExpired`] if the interaction's token
+    /// has expired.
+    ///
+    /// Returns an error of type [`Requesting`] if the request failed.
+    ///
+    /// Returns an error of type [`Validation`] if the contents of the response
+    /// are invalid.
+    ///
+    /// [`CallbackWindowElapsed`]: InteractionResponderErrorType::CallbackWindowElapsed
+    /// [`EPHEMERAL`]: MessageFlags::EPHEMERAL
+    /// [`Requesting`]: InteractionResponderErrorType::Requesting
+    /// [`TokenExpired`]: InteractionResponderErrorType::TokenExpired
+    /// [`Validation`]: InteractionResponderErrorType::Validation
+    /// [callback window]: CALLBACK_WINDOW
+    /// [updates]: Self::update
+    /// [updating]: Self::update
+    pub async fn respond(
+        &mut self,
+        data: CallbackData,
+    ) -> Result<Option<Response<Message>>, InteractionResponderError> {
+        match self.state {
+            InteractionResponderState::Pending => {
+                Self::validate(&data)?;
+
+                let response = if self.update {
+                    InteractionResponse::UpdateMessage(data)
+                } else {
+                    InteractionResponse::ChannelMessageWithSource(data)
+                };
+
+                self.callback(&response).await?;
+                self.state = InteractionResponderState::Responded;
+
+                Ok(None)
+            }
+            InteractionResponderState::Deferred => {
+                let ephemeral = data
+                    .flags
+                    .map_or(false, |flags| flags.contains(MessageFlags::EPHEMERAL));
+
+                // An edit can't apply either to the component's message.
+                let response = if self.update && (ephemeral || data.tts.unwrap_or_default()) {
+                    self.followup(&data).await?
+                } else {
+                    self.update_original(&data).await?
+                };
+
+                self.state = InteractionResponderState::Responded;
+
+                Ok(Some(response))
+            }
+            InteractionResponderState::Responded => self.followup(&data).await.map(Some),
+        }
+    }
+
+    /// Run a handler to completion, deferring the interaction if the handler
+    /// hasn't completed before the [deadline].
+    ///
+    /// The handler's output is returned so that it may be used to
+    /// [`respond`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`CallbackWindowElapsed`] if the deadline is
+    /// after the [callback window] and the handler didn't complete within it.
+    ///
+    /// Returns an error of type [`Requesting`] if deferring the interaction
+    /// failed.
+    ///
+    /// [`CallbackWindowElapsed`]: InteractionResponderErrorType::CallbackWindowElapsed
+    /// [`Requesting`]: InteractionResponderErrorType::Requesting
+    /// [`respond`]: Self::respond
+    /// [callback window]: CALLBACK_WINDOW
+    /// [deadline]: Self::deadline
+    pub async fn run<F: Future>(
+        &mut self,
+        handler: F,
+    ) -> Result<F::Output, InteractionResponderError> {
+        pin_mut!(handler);
+
+        if self.state == InteractionResponderState::Pending {
+            let deadline = self.received_at + self.deadline;
+
+            if let Ok(output) = time::timeout_at(deadline, &mut handler).await {
+                return Ok(output);
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(interaction_id = %self.id, "deferring interaction after deadline");
+
+            self.defer().await?;
+        }
+
+        Ok(handler.await)
+    }
+
+    /// Send the interaction callback if it's within the callback window.
+    ///
+    /// Waiting for a ratelimiter ticket is bounded by the end of the callback
+    /// window, after which the callback would be rejected anyway.
+    async fn callback(
+        &self,
+        response: &InteractionResponse,
+    ) -> Result<(), InteractionResponderError> {
+        let window_end = self.received_at + CALLBACK_WINDOW;
+
+        if Instant::now() >= window_end {
+            return Err(InteractionResponderError::new(
+                InteractionResponderErrorType::CallbackWindowElapsed,
+            ));
+        }
+
+        let mut future =
+            InteractionCallback::new(self.client, self.id, &self.token, response).exec();
+        future.set_ticket_deadline(window_end.into_std());
+
+        future
+            .await
+            .map_err(InteractionResponderError::requesting)?;
+
+        Ok(())
+    }
+
+    /// Deferral sent as the interaction callback.
+    fn deferral_response(&self) -> InteractionResponse {
+        if self.update {
+            InteractionResponse::DeferredUpdateMessage
+        } else {
+            InteractionResponse::DeferredChannelMessageWithSource(CallbackData {
+                allowed_mentions: None,
+                components: None,
+                content: None,
+                embeds: None,
+                flags: self.ephemeral.then(|| MessageFlags::EPHEMERAL),
+                tts: None,
+            })
+        }
+    }
+
+    /// Create a followup message.
+    async fn followup(
+        &self,
+        data: &CallbackData,
+    ) -> Result<Response<Message>, InteractionResponderError> {
+        self.check_expiry()?;
+
+        let mut request = CreateFollowupMessage::new(self.client, self.application_id, &self.token)
+            .ephemeral(
+                data.flags
+                    .map_or(false, |flags| flags.contains(MessageFlags::EPHEMERAL)),
+            )
+            .tts(data.tts.unwrap_or_default());
+
+        if let Some(allowed_mentions) = &data.allowed_mentions {
+            request = request.allowed_mentions(allowed_mentions);
+        }
+
+        if let Some(components) = &data.components {
+            request = request
+                .components(components)
+                .map_err(InteractionResponderError::validation)?;
+        }
+
+        if let Some(content) = &data.content {
+            request = request
+                .content(content)
+                .map_err(InteractionResponderError::validation)?;
+        }
+
+        if let Some(embeds) = &data.embeds {
+            request = request
+                .embeds(embeds)
+                .map_err(InteractionResponderError::validation)?;
+        }
+
+        request
+            .exec()
+            .await
+            .map_err(InteractionResponderError::requesting)
+    }
+
+    /// Edit the original response of a deferred interaction.
+    async fn update_original(
+        &self,
+        data: &CallbackData,
+    ) -> Result<Response<Message>, InteractionResponderError> {
+        self.check_expiry()?;
+
+        let mut request =
+            UpdateOriginalResponse::new(self.client, self.application_id, &self.token);
+
+        if let Some(allowed_mentions) = &data.allowed_mentions {
+            request = request.allowed_mentions(allowed_mentions.clone());
+        }
+
+        if let Some(components) = &data.components {
+            request = request
+                .components(Some(components))
+                .map_err(InteractionResponderError::validation)?;
+        }
+
+        if let Some(content) = &data.content {
+            request = request
+                .content(Some(content))
+                .map_err(InteractionResponderError::validation)?;
+        }
+
+        if let Some(embeds) = &data.embeds {
+            request = request
+                .embeds(Some(embeds))
+                .map_err(InteractionResponderError::validation)?;
+        }
+
+        request
+            .exec()
+            .await
+            .map_err(InteractionResponderError::requesting)
+    }
+
+    /// Validate the contents of an interaction callback.
+    fn validate(data: &CallbackData) -> Result<(), InteractionResponderError> {
+        if let Some(components) = &data.components {
+            validate_components(components).map_err(InteractionResponderError::validation)?;
+        }
+
+        if let Some(content) = &data.content {
+            validate_content(content).map_err(InteractionResponderError::validation)?;
+        }
+
+        if let Some(embeds) = &data.embeds {
+            validate_embeds(embeds).map_err(InteractionResponderError::validation)?;
+        }
+
+        Ok(())
+    }
+
+    /// Return an error if the interaction's token has expired.
+    fn check_expiry(&self) -> Result<(), InteractionResponderError> {
+        if self.is_expired() {
+            return Err(InteractionResponderError::new(
+                InteractionResponderErrorType::TokenExpired,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        InteractionResponder, InteractionResponderError, InteractionResponderErrorType,
+        InteractionResponderState, CALLBACK_WINDOW, TOKEN_LIFETIME,
+    };
+    use crate::{
+        client::Client,
+        test::{Recorder, Requests},
+    };
+    use hyper::Method;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, hash::Hash, time::Duration};
+    use tokio::time;
+    use twilight_model::{
+        application::{
+            callback::{CallbackData, InteractionResponse},
+            interaction::{Interaction, InteractionType},
+        },
+        channel::message::MessageFlags,
+        id::Id,
+    };
+
+    assert_impl_all!(InteractionResponder<'_>: Debug, Send, Sync);
+    assert_impl_all!(InteractionResponderError: Error, Send, Sync);
+    assert_impl_all!(InteractionResponderErrorType: Debug, Send, Sync);
+    assert_impl_all!(InteractionResponderState: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    fn client() -> (Client, Requests) {
+        let recorder = Recorder::ok("{}");
+        let requests = recorder.requests();
+        let client = Client::builder()
+            .ratelimiter(None)
+            .transport(Box::new(recorder))
+            .build();
+
+        (client, requests)
+    }
+
+    fn interaction() -> Interaction {
+        Interaction::Ping(Box::new(twilight_model::application::interaction::Ping {
+            application_id: Id::new(1),
+            id: Id::new(2),
+            kind: InteractionType::Ping,
+            token: "token".to_owned(),
+        }))
+    }
+
+    fn content(content: &str) -> CallbackData {
+        CallbackData {
+            allowed_mentions: None,
+            components: None,
+            content: Some(content.to_owned()),
+            embeds: None,
+            flags: None,
+            tts: None,
+        }
+    }
+
+    /// Methods and paths of the recorded requests.
+    fn routes(requests: &Requests) -> Vec<(Method, String)> {
+        requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|request| (request.method.clone(), request.path().to_owned()))
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_respond_routing() -> Result<(), Box<dyn Error>> {
+        let (client, requests) = client();
+        let interaction_client = client.interaction(Id::new(1));
+        let mut responder = interaction_client.responder(&interaction());
+
+        assert_eq!(InteractionResponderState::Pending, responder.state());
+        assert!(responder.respond(content("one")).await?.is_none());
+        assert_eq!(InteractionResponderState::Responded, responder.state());
+        assert!(responder.respond(content("two")).await?.is_some());
+
+        assert_eq!(
+            [
+                (
+                    Method::POST,
+                    "/api/v9/interactions/2/token/callback".to_owned()
+                ),
+                (Method::POST, "/api/v9/webhooks/1/token".to_owned()),
+            ]
+            .as_ref(),
+            routes(&requests).as_slice()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_run_defers() -> Result<(), Box<dyn Error>> {
+        let (client, requests) = client();
+        let interaction_client = client.interaction(Id::new(1));
+        let mut responder = interaction_client
+            .responder(&interaction())
+            .deadline(Duration::from_secs(1))
+            .ephemeral(true);
+
+        let output = responder
+            .run(async {
+                time::sleep(Duration::from_secs(5)).await;
+
+                "slow"
+            })
+            .await?;
+        assert_eq!("slow", output);
+        assert_eq!(InteractionResponderState::Deferred, responder.state());
+
+        responder.respond(content("edited")).await?;
+        assert_eq!(InteractionResponderState::Responded, responder.state());
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(2, requests.len());
+        assert_eq!("/api/v9/interactions/2/token/callback", requests[0].path());
+        assert!(requests[0].body().contains(r#""type":5"#));
+        assert!(requests[0].body().contains(r#""flags":64"#));
+        assert_eq!(Method::PATCH, requests[1].method);
+        assert_eq!(
+            "/api/v9/webhooks/1/token/messages/@original",
+            requests[1].path()
+        );
+        assert_eq!(r#"{"content":"edited"}"#, requests[1].body());
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_update() -> Result<(), Box<dyn Error>> {
+        let (client, requests) = client();
+        let interaction_client = client.interaction(Id::new(1));
+        let mut responder = interaction_client
+            .responder(&interaction())
+            .ephemeral(true)
+            .update(true);
+
+        responder.defer().await?;
+        assert_eq!(InteractionResponderState::Deferred, responder.state());
+
+        let mut responder = interaction_client.responder(&interaction()).update(true);
+        responder.respond(content("updated")).await?;
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(2, requests.len());
+        assert_eq!(r#"{"type":6}"#, requests[0].body());
+        assert!(requests[1].body().contains(r#""type":7"#));
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_deferred_update_followup() -> Result<(), Box<dyn Error>> {
+        let (client, requests) = client();
+        let interaction_client = client.interaction(Id::new(1));
+        let mut responder = interaction_client.responder(&interaction()).update(true);
+        responder.defer().await?;
+
+        // Ephemeral data can't be applied by editing the component's message.
+        let mut data = content("hidden");
+        data.flags = Some(MessageFlags::EPHEMERAL);
+        responder.respond(data).await?;
+
+        assert_eq!(
+            [
+                (
+                    Method::POST,
+                    "/api/v9/interactions/2/token/callback".to_owned()
+                ),
+                (Method::POST, "/api/v9/webhooks/1/token".to_owned()),
+            ]
+            .as_ref(),
+            routes(&requests).as_slice()
+        );
+        assert!(requests.lock().unwrap()[1].body().contains(r#""flags":64"#));
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_deferral() -> Result<(), Box<dyn Error>> {
+        let (client, requests) = client();
+        let interaction_client = client.interaction(Id::new(1));
+        let mut responder = interaction_client.responder(&interaction()).ephemeral(true);
+
+        assert!(matches!(
+            responder.deferral(),
+            Some(InteractionResponse::DeferredChannelMessageWithSource(
+                CallbackData {
+                    flags: Some(MessageFlags::EPHEMERAL),
+                    ..
+                }
+            ))
+        ));
+        assert_eq!(InteractionResponderState::Deferred, responder.state());
+        assert!(responder.deferral().is_none());
+
+        responder.respond(content("done")).await?;
+
+        assert_eq!(
+            [(
+                Method::PATCH,
+                "/api/v9/webhooks/1/token/messages/@original".to_owned()
+            )]
+            .as_ref(),
+            routes(&requests).as_slice()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_respond_validation() {
+        let (client, requests) = client();
+        let interaction_client = client.interaction(Id::new(1));
+        let mut responder = interaction_client.responder(&interaction());
+
+        let error = responder
+            .respond(content(&"a".repeat(2001)))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            InteractionResponderErrorType::Validation
+        ));
+        assert_eq!(InteractionResponderState::Pending, responder.state());
+        assert!(requests.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_run_fast_handler() -> Result<(), Box<dyn Error>> {
+        let (client, requests) = client();
+        let interaction_client = client.interaction(Id::new(1));
+        let mut responder = interaction_client.responder(&interaction());
+
+        responder.run(async {}).await?;
+        assert_eq!(InteractionResponderState::Pending, responder.state());
+        assert!(requests.lock().unwrap().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_expiry() -> Result<(), Box<dyn Error>> {
+        let (client, requests) = client();
+        let interaction_client = client.interaction(Id::new(1));
+        let mut responder = interaction_client.responder(&interaction());
+
+        time::advance(CALLBACK_WINDOW).await;
+        let error = responder.defer().await.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            InteractionResponderErrorType::CallbackWindowElapsed
+        ));
+
+        let mut responder = interaction_client.responder(&interaction());
+        responder.defer().await?;
+        assert!(!responder.is_expired());
+
+        time::advance(TOKEN_LIFETIME).await;
+        assert!(responder.is_expired());
+        let error = responder.respond(content("late")).await.unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            InteractionResponderErrorType::TokenExpired
+        ));
+        assert_eq!(1, requests.lock().unwrap().len());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,602 @@
+use super::{Transport, TransportFuture, USER_AGENT_VALUE};
+use crate::{request::oauth::form_body, response::StatusCode};
+use hyper::{
+    body,
+    header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE, USER_AGENT},
+    Body, Method, Request,
+};
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    time::{Duration, Instant},
+};
+use tokio::sync::Mutex as AsyncMutex;
+use twilight_model::{
+    id::{marker::ApplicationMarker, Id},
+    oauth::AccessToken,
+};
+
+/// Refreshing the access token of a [`TokenManager`] failed.
+///
+/// Requests failing because of this are returned as an
+/// [`ErrorType::RequestError`] error type with this as the source.
+///
+/// [`ErrorType::RequestError`]: crate::error::ErrorType::RequestError
+#[derive(Debug)]
+pub struct TokenManagerError {
+    kind: TokenManagerErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl TokenManagerError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &TokenManagerErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (TokenManagerErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    fn requesting(source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self {
+            kind: TokenManagerErrorType::Requesting,
+            source: Some(source.into()),
+        }
+    }
+}
+
+impl Display for TokenManagerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            TokenManagerErrorType::Deserializing { .. } => {
+                f.write_str("failed to deserialize the granted access token")
+            }
+            TokenManagerErrorType::RefreshTokenMissing => {
+                f.write_str("access token has expired and has no refresh token")
+            }
+            TokenManagerErrorType::Requesting => f.write_str("failed to request an access token"),
+            TokenManagerErrorType::Response { status, .. } => {
+                f.write_str("requesting an access token failed with status code ")?;
+
+                Display::fmt(status, f)
+            }
+        }
+    }
+}
+
+impl Error for TokenManagerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`TokenManagerError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TokenManagerErrorType {
+    /// Response body couldn't be deserialized as an access token.
+    Deserializing {
+        /// Raw response body.
+        body: Vec<u8>,
+    },
+    /// Access token has expired or been invalidated, but wasn't granted a
+    /// refresh token to refresh it with.
+    RefreshTokenMissing,
+    /// Sending the request for an access token failed.
+    Requesting,
+    /// Request for an access token was responded to with an error.
+    ///
+    /// This may occur if the refresh token has been revoked.
+    Response {
+        /// Raw response body.
+        body: Vec<u8>,
+        /// Status code of the response.
+        status: StatusCode,
+    },
+}
+
+/// How new access tokens are granted.
+enum Grant {
+    /// Request a new token via the client credentials flow.
+    ClientCredentials { scope: Box<str> },
+    /// Refresh the token via its refresh token.
+    RefreshToken,
+}
+
+/// Access token currently in use.
+struct Granted {
+    access_token: Box<str>,
+    expires_at: Instant,
+    refresh_token: Option<Box<str>>,
+}
+
+impl Granted {
+    fn new(token: AccessToken) -> Self {
+        Self {
+            access_token: token.access_token.into_boxed_str(),
+            expires_at: Instant::now() + Duration::from_secs(token.expires_in),
+            refresh_token: token.refresh_token.map(String::into_boxed_str),
+        }
+    }
+}
+
+/// Manager of a bearer token, refreshing it before it expires.
+///
+/// When configured via [`ClientBuilder::token_manager`], requests made by the
+/// client are authorized with the manager's access token. Before a request is
+/// sent the token is refreshed if it expires within the [refresh window], or
+/// if a previous request was responded to with an Unauthorized response
+/// status; a successful refresh clears the client's [`ErrorType::Unauthorized`]
+/// state. Concurrent requests wait for a single refresh.
+///
+/// Tokens granted via the authorization code flow are refreshed via their
+/// refresh token, while tokens granted via the client credentials flow are
+/// requested anew.
+///
+/// Refresh requests bypass the client's ratelimiter. If a refresh fails then
+/// the request is failed with an [`ErrorType::RequestError`] error type with
+/// a [`TokenManagerError`] as its source.
+///
+/// # Examples
+///
+/// Make requests as the application's owner via the client credentials flow:
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_http::{client::TokenManager, Client};
+/// use twilight_model::id::Id;
+///
+/// let manager = TokenManager::client_credentials(
+///     Id::new(123),
+///     env::var("CLIENT_SECRET")?,
+///     &["identify", "applications.commands.update"],
+/// );
+/// let client = Client::builder().token_manager(manager).build();
+///
+/// let user = client.current_user().exec().await?.model().await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`ClientBuilder::token_manager`]: super::ClientBuilder::token_manager
+/// [`ErrorType::RequestError`]: crate::error::ErrorType::RequestError
+/// [`ErrorType::Unauthorized`]: crate::error::ErrorType::Unauthorized
+/// [refresh window]: Self::refresh_before
+pub struct TokenManager {
+    application_id: Id<ApplicationMarker>,
+    client_secret: Box<str>,
+    grant: Grant,
+    refresh_before: Duration,
+    /// Lock held while refreshing the token, so that concurrent requests
+    /// refresh it once.
+    refreshing: AsyncMutex<()>,
+    token: Mutex<Option<Granted>>,
+}
+
+impl TokenManager {
+    /// Create a new manager of a granted access token, refreshing it via its
+    /// refresh token.
+    ///
+    /// Use this for tokens granted via the authorization code flow, such as
+    /// by [`OauthClient::exchange_code`].
+    ///
+    /// [`OauthClient::exchange_code`]: super::OauthClient::exchange_code
+    pub fn new(
+        application_id: Id<ApplicationMarker>,
+        client_secret: String,
+        token: AccessToken,
+    ) -> Self {
+        Self {
+            application_id,
+            client_secret: client_secret.into_boxed_str(),
+            grant: Grant::RefreshToken,
+            refresh_before: Duration::from_secs(60),
+            refreshing: AsyncMutex::new(()),
+            token: Mutex::new(Some(Granted::new(token))),
+        }
+    }
+
+    /// Create a new manager requesting access tokens with scopes via the
+    /// client credentials flow.
+    ///
+    /// The first token is requested before the first request is sent.
+    pub fn client_credentials(
+        application_id: Id<ApplicationMarker>,
+        client_secret: String,
+        scopes: &[&str],
+    ) -> Self {
+        Self {
+            application_id,
+            client_secret: client_secret.into_boxed_str(),
+            grant: Grant::ClientCredentials {
+                scope: scopes.join(" ").into_boxed_str(),
+            },
+            refresh_before: Duration::from_secs(60),
+            refreshing: AsyncMutex::new(()),
+            token: Mutex::new(None),
+        }
+    }
+
+    /// Set how long before the access token expires that it is refreshed.
+    ///
+    /// Defaults to 60 seconds.
+    pub const fn refresh_before(mut self, refresh_before: Duration) -> Self {
+        self.refresh_before = refresh_before;
+
+        self
+    }
+
+    /// Current access token, if one has been granted.
+    pub fn access_token(&self) -> Option<String> {
+        self.token()
+            .as_ref()
+            .map(|token| token.access_token.to_string())
+    }
+
+    /// Current refresh token, if one has been granted.
+    ///
+    /// Refresh tokens are replaced when refreshed, so the current refresh
+    /// token should be persisted in place of the initial one.
+    pub fn refresh_token(&self) -> Option<String> {
+        self.token()
+            .as_ref()
+            .and_then(|token| token.refresh_token.as_deref())
+            .map(ToOwned::to_owned)
+    }
+
+    /// Send a request authorized with the access token, refreshing it first
+    /// if needed.
+    pub(super) fn send(
+        self: &Arc<Self>,
+        transport: Arc<dyn Transport>,
+        token_url: String,
+        invalid_token: Option<Arc<AtomicBool>>,
+        mut request: Request<Body>,
+    ) -> TransportFuture {
+        let manager = Arc::clone(self);
+
+        Box::pin(async move {
+            let access_token = manager
+                .fresh_token(&*transport, &token_url, invalid_token.as_deref())
+                .await?;
+            let value = HeaderValue::try_from(format!("Bearer {}", access_token))?;
+            request.headers_mut().insert(AUTHORIZATION, value);
+
+            transport.send(request).await
+        })
+    }
+
+    /// Current access token, refreshing it first if needed.
+    async fn fresh_token(
+        &self,
+        transport: &dyn Transport,
+        token_url: &str,
+        invalid_token: Option<&AtomicBool>,
+    ) -> Result<Box<str>, TokenManagerError> {
+        if let Some(access_token) = self.current(invalid_token) {
+            return Ok(access_token);
+        }
+
+        let _refreshing = self.refreshing.lock().await;
+
+        // Another request may have refreshed the token while waiting.
+        if let Some(access_token) = self.current(invalid_token) {
+            return Ok(access_token);
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("refreshing access token");
+
+        let token = Granted::new(self.request(transport, token_url).await?);
+        let access_token = token.access_token.clone();
+        *self.token() = Some(token);
+
+        if let Some(invalid_token) = invalid_token {
+            invalid_token.store(false, Ordering::Relaxed);
+        }
+
+        Ok(access_token)
+    }
+
+    /// Current access token, if it doesn't need to be refreshed.
+    fn current(&self, invalid_token: Option<&AtomicBool>) -> Option<Box<str>> {
+        if invalid_token.map_or(false, |invalid| invalid.load(Ordering::Relaxed)) {
+            return None;
+        }
+
+        self.token()
+            .as_ref()
+            .filter(|token| token.expires_at > Instant::now() + self.refresh_before)
+            .map(|token| token.access_token.clone())
+    }
+
+    /// Request a new access token.
+    async fn request(
+        &self,
+        transport: &dyn Transport,
+        token_url: &str,
+    ) -> Result<AccessToken, TokenManagerError> {
+        let client_id = self.application_id.to_string();
+
+        let body = match &self.grant {
+            Grant::ClientCredentials { scope } => form_body(&[
+                ("client_id", &client_id),
+                ("client_secret", &self.client_secret),
+                ("grant_type", "client_credentials"),
+                ("scope", scope),
+            ]),
+            Grant::RefreshToken => {
+                let refresh_token = self
+                    .token()
+                    .as_ref()
+                    .and_then(|token| token.refresh_token.clone())
+                    .ok_or(TokenManagerError {
+                        kind: TokenManagerErrorType::RefreshTokenMissing,
+                        source: None,
+                    })?;
+
+                form_body(&[
+                    ("client_id", &client_id),
+                    ("client_secret", &self.client_secret),
+                    ("grant_type", "refresh_token"),
+                    ("refresh_token", &refresh_token),
+                ])
+            }
+        };
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(token_url)
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .header(USER_AGENT, USER_AGENT_VALUE)
+            .body(Body::from(body))
+            .map_err(TokenManagerError::requesting)?;
+
+        let response = transport
+            .send(request)
+            .await
+            .map_err(TokenManagerError::requesting)?;
+        let status = response.status();
+        let bytes = body::to_bytes(response.into_body())
+            .await
+            .map_err(TokenManagerError::requesting)?;
+
+        if !status.is_success() {
+            return Err(TokenManagerError {
+                kind: TokenManagerErrorType::Response {
+                    body: bytes.to_vec(),
+                    status: StatusCode::new(status.as_u16()),
+                },
+                source: None,
+            });
+        }
+
+        crate::json::from_bytes(&bytes).map_err(|source| TokenManagerError {
+            kind: TokenManagerErrorType::Deserializing {
+                body: bytes.to_vec(),
+            },
+            source: Some(Box::new(source)),
+        })
+    }
+
+    fn token(&self) -> MutexGuard<'_, Option<Granted>> {
+        self.token.lock().expect("token poisoned")
+    }
+}
+
+impl Debug for TokenManager {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("TokenManager")
+            .field("application_id", &self.application_id)
+            .field("refresh_before", &self.refresh_before)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TokenManager, TokenManagerError, TokenManagerErrorType};
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        test::{Recorder, Requests},
+    };
+    use hyper::StatusCode;
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::Debug,
+        sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    };
+    use twilight_model::{id::Id, oauth::AccessToken};
+
+    assert_impl_all!(TokenManager: Debug, Send, Sync);
+    assert_impl_all!(TokenManagerErrorType: Debug, Send, Sync);
+    assert_impl_all!(TokenManagerError: Error, Send, Sync);
+
+    /// Create a transport granting numbered access tokens, responding to the
+    /// first other request as unauthorized if configured to.
+    fn oauth(unauthorized: bool) -> Recorder {
+        let granted = AtomicUsize::new(0);
+        let unauthorized = AtomicBool::new(unauthorized);
+
+        Recorder::new(move |request| {
+            if is_token(request.path()) {
+                let granted = granted.fetch_add(1, Ordering::Relaxed) + 1;
+                let body = format!(
+                    r#"{{"access_token":"access-{0}","expires_in":3600,"refresh_token":"refresh-{0}","scope":"identify","token_type":"Bearer"}}"#,
+                    granted
+                );
+
+                return (StatusCode::OK, body);
+            }
+
+            if unauthorized.swap(false, Ordering::Relaxed) {
+                return (
+                    StatusCode::UNAUTHORIZED,
+                    r#"{"code":0,"message":"401: Unauthorized"}"#.to_owned(),
+                );
+            }
+
+            (StatusCode::OK, r#"{"url":"wss://gateway"}"#.to_owned())
+        })
+    }
+
+    fn is_token(path: &str) -> bool {
+        path.ends_with("oauth2/token")
+    }
+
+    /// Bodies of token requests and the authorization of other requests.
+    fn log(requests: &Requests) -> Vec<String> {
+        requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|request| {
+                if is_token(request.path()) {
+                    request.body().to_owned()
+                } else {
+                    request
+                        .header("authorization")
+                        .unwrap_or_default()
+                        .to_owned()
+                }
+            })
+            .collect()
+    }
+
+    fn token(expires_in: u64) -> AccessToken {
+        AccessToken {
+            access_token: "access-0".to_owned(),
+            expires_in,
+            refresh_token: Some("refresh-0".to_owned()),
+            scope: "identify".to_owned(),
+            token_type: "Bearer".to_owned(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_credentials() -> Result<(), Box<dyn Error>> {
+        let recorder = oauth(false);
+        let requests = recorder.requests();
+        let manager =
+            TokenManager::client_credentials(Id::new(1), "secret".to_owned(), &["identify"]);
+        let client = Client::builder()
+            .ratelimiter(None)
+            .token_manager(manager)
+            .transport(Box::new(recorder))
+            .build();
+
+        client.gateway().exec().await?;
+        client.gateway().exec().await?;
+
+        assert_eq!(
+            [
+                "client_id=1&client_secret=secret&grant_type=client_credentials&scope=identify",
+                "Bearer access-1",
+                "Bearer access-1",
+            ]
+            .as_ref(),
+            log(&requests).as_slice()
+        );
+        assert_eq!(
+            Some("access-1"),
+            client.token_manager().unwrap().access_token().as_deref()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refresh_expiring() -> Result<(), Box<dyn Error>> {
+        let recorder = oauth(false);
+        let requests = recorder.requests();
+        let manager = TokenManager::new(Id::new(1), "secret".to_owned(), token(30));
+        let client = Client::builder()
+            .ratelimiter(None)
+            .token_manager(manager)
+            .transport(Box::new(recorder))
+            .build();
+
+        client.gateway().exec().await?;
+
+        assert_eq!(
+            [
+                "client_id=1&client_secret=secret&grant_type=refresh_token&refresh_token=refresh-0",
+                "Bearer access-1",
+            ]
+            .as_ref(),
+            log(&requests).as_slice()
+        );
+        assert_eq!(
+            Some("refresh-1"),
+            client.token_manager().unwrap().refresh_token().as_deref()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refresh_unauthorized() -> Result<(), Box<dyn Error>> {
+        let recorder = oauth(true);
+        let requests = recorder.requests();
+        let manager = TokenManager::new(Id::new(1), "secret".to_owned(), token(3600));
+        let client = Client::builder()
+            .ratelimiter(None)
+            .token_manager(manager)
+            .transport(Box::new(recorder))
+            .build();
+
+        let error = client.gateway().exec().await.unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Response { status, .. } if status.raw() == 401));
+
+        // Instead of failing as unauthorized, the token is refreshed.
+        client.gateway().exec().await?;
+        client.gateway().exec().await?;
+
+        let log = log(&requests);
+        assert_eq!(4, log.len());
+        assert_eq!("Bearer access-0", log[0]);
+        assert!(log[1].contains("grant_type=refresh_token"));
+        assert_eq!(["Bearer access-1", "Bearer access-1"].as_ref(), &log[2..]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_missing() {
+        let mut token = token(0);
+        token.refresh_token = None;
+
+        let client = Client::builder()
+            .token_manager(TokenManager::new(Id::new(1), "secret".to_owned(), token))
+            .transport(Box::new(oauth(false)))
+            .build();
+
+        let (kind, source) = client.gateway().exec().await.unwrap_err().into_parts();
+        assert!(matches!(kind, ErrorType::RequestError));
+
+        let source = source.unwrap().downcast::<TokenManagerError>().unwrap();
+        assert!(matches!(
+            source.kind(),
+            TokenManagerErrorType::RefreshTokenMissing
+        ));
+    }
+}
@@ -0,0 +1,194 @@
+use hyper::{
+    client::{connect::Connect, Client as HyperClient},
+    Body, Request, Response,
+};
+use std::{error::Error, fmt::Debug, future::Future, pin::Pin};
+
+#[cfg(feature = "hyper-rustls")]
+type HttpsConnector<T> = hyper_rustls::HttpsConnector<T>;
+#[cfg(all(feature = "hyper-tls", not(feature = "hyper-rustls")))]
+type HttpsConnector<T> = hyper_tls::HttpsConnector<T>;
+
+#[cfg(feature = "trust-dns")]
+type HttpConnector = hyper_trust_dns::TrustDnsHttpConnector;
+#[cfg(not(feature = "trust-dns"))]
+type HttpConnector = hyper::client::HttpConnector;
+
+/// Future resolving to the response to a request sent by a [`Transport`].
+pub type TransportFuture =
+    Pin<Box<dyn Future<Output = Result<Response<Body>, Box<dyn Error + Send + Sync>>> + Send>>;
+
+/// Transport sending requests built by the [`Client`] and returning their
+/// responses.
+///
+/// The client's headers, ratelimiting, timeout, and response handling are
+/// applied regardless of the transport in use; the transport is only
+/// responsible for sending a request and streaming back its response. This
+/// makes it possible to route requests through a custom connection pool or
+/// middleware, or to return mocked responses in tests.
+///
+/// This is implemented for hyper's [`Client`][`HyperClient`] with any
+/// connector. By default a hyper client with a connector using the enabled TLS
+/// feature is used.
+///
+/// Errors returned by the transport are exposed as the source of an
+/// [`ErrorType::RequestError`] error type.
+///
+/// # Examples
+///
+/// Respond to every request with an empty list:
+///
+/// ```
+/// use hyper::{Body, Request, Response};
+/// use twilight_http::client::{Client, Transport, TransportFuture};
+///
+/// #[derive(Debug)]
+/// struct EmptyList;
+///
+/// impl Transport for EmptyList {
+///     fn send(&self, _: Request<Body>) -> TransportFuture {
+///         Box::pin(async { Ok(Response::new(Body::from("[]"))) })
+///     }
+/// }
+///
+/// let client = Client::builder()
+///     .token("my token".to_owned())
+///     .transport(Box::new(EmptyList))
+///     .build();
+/// ```
+///
+/// [`Client`]: super::Client
+/// [`ErrorType::RequestError`]: crate::error::ErrorType::RequestError
+pub trait Transport: Debug + Send + Sync {
+    /// Send a request, returning a future resolving to its response.
+    fn send(&self, request: Request<Body>) -> TransportFuture;
+}
+
+impl<C: Connect + Clone + Send + Sync + 'static> Transport for HyperClient<C, Body> {
+    fn send(&self, request: Request<Body>) -> TransportFuture {
+        let future = self.request(request);
+
+        Box::pin(async move { future.await.map_err(From::from) })
+    }
+}
+
+/// Create the default transport: a hyper client with a connector using the
+/// enabled TLS feature.
+pub(super) fn default() -> HyperClient<HttpsConnector<HttpConnector>, Body> {
+    #[cfg(not(feature = "trust-dns"))]
+    let mut http_connector = hyper::client::HttpConnector::new();
+    #[cfg(feature = "trust-dns")]
+    let mut http_connector = hyper_trust_dns::new_trust_dns_http_connector();
+
+    http_connector.enforce_http(false);
+
+    #[cfg(feature = "rustls-native-roots")]
+    let connector = hyper_rustls::HttpsConnectorBuilder::new()
+        .with_native_roots()
+        .https_or_http()
+        .enable_http1()
+        .enable_http2()
+        .wrap_connector(http_connector);
+
+    #[cfg(all(feature = "rustls-webpki-roots", not(feature = "rustls-native-roots")))]
+    let connector = hyper_rustls::HttpsConnectorBuilder::new()
+        .with_webpki_roots()
+        .https_or_http()
+        .enable_http1()
+        .enable_http2()
+        .wrap_connector(http_connector);
+
+    #[cfg(all(
+        feature = "hyper-tls",
+        not(feature = "rustls-native-roots"),
+        not(feature = "rustls-webpki-roots")
+    ))]
+    let connector = hyper_tls::HttpsConnector::new_with_connector(http_connector);
+
+    hyper::client::Builder::default().build(connector)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Transport, TransportFuture};
+    use crate::{client::Client, error::ErrorType, test::Recorder};
+    use hyper::{
+        client::{Client as HyperClient, HttpConnector},
+        Body, Request, StatusCode,
+    };
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::io::{Error as IoError, ErrorKind};
+    use twilight_model::id::Id;
+
+    assert_obj_safe!(Transport);
+    assert_impl_all!(HyperClient<HttpConnector, Body>: Transport);
+    assert_impl_all!(TransportFuture: Send);
+
+    #[derive(Debug)]
+    struct Failing;
+
+    impl Transport for Failing {
+        fn send(&self, _: Request<Body>) -> TransportFuture {
+            Box::pin(async { Err(IoError::new(ErrorKind::ConnectionRefused, "refused").into()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_transport() {
+        let recorder = Recorder::ok(r#"{"url":"wss://gateway.discord.gg"}"#);
+        let requests = recorder.requests();
+        let client = Client::builder()
+            .token("token".to_owned())
+            .transport(Box::new(recorder))
+            .build();
+
+        let info = client
+            .gateway()
+            .exec()
+            .await
+            .unwrap()
+            .model()
+            .await
+            .unwrap();
+
+        assert_eq!("wss://gateway.discord.gg", info.url);
+        let requests = requests.lock().unwrap();
+        assert_eq!(1, requests.len());
+        assert_eq!("https://discord.com/api/v9/gateway", requests[0].uri);
+        assert_eq!(Some("Bot token"), requests[0].header("authorization"));
+    }
+
+    #[tokio::test]
+    async fn test_custom_transport_response_error() {
+        let client = Client::builder()
+            .ratelimiter(None)
+            .transport(Box::new(Recorder::new(|_| {
+                (
+                    StatusCode::NOT_FOUND,
+                    r#"{"code":10003,"message":"Unknown Channel"}"#.to_owned(),
+                )
+            })))
+            .build();
+
+        let error = client.channel(Id::new(1)).exec().await.unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response { status, .. } if status.raw() == 404
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_transport_error() {
+        let client = Client::builder().transport(Box::new(Failing)).build();
+        let (kind, source) = client
+            .channel(Id::new(1))
+            .exec()
+            .await
+            .unwrap_err()
+            .into_parts();
+
+        assert!(matches!(kind, ErrorType::RequestError));
+        assert!(source.unwrap().downcast::<IoError>().is_ok());
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{
+    request::channel::webhook::{
+        DeleteWebhookMessage, ExecuteWebhook, GetWebhook, GetWebhookMessage, UpdateWebhookMessage,
+        UpdateWebhookWithToken,
+    },
+    Client,
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::id::{
+    marker::{ChannelMarker, MessageMarker, WebhookMarker},
+    Id,
+};
+use twilight_util::link::webhook;
+
+/// Error when creating a [`WebhookClient`] from a URL.
+#[derive(Debug)]
+pub struct WebhookClientError {
+    kind: WebhookClientErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl WebhookClientError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &WebhookClientErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (WebhookClientErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for WebhookClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            WebhookClientErrorType::Parsing => f.write_str("webhook url is invalid"),
+            WebhookClientErrorType::TokenMissing => f.write_str("webhook url has no token"),
+        }
+    }
+}
+
+impl Error for WebhookClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`WebhookClientError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum WebhookClientErrorType {
+    /// URL isn't a valid webhook URL.
+    Parsing,
+    /// URL doesn't contain the webhook's token.
+    TokenMissing,
+}
+
+/// Lightweight client for using a single webhook.
+///
+/// Webhooks are authorized by their token, so no bot token is needed. The
+/// client has its own ratelimiter, which only tracks the ratelimits of the
+/// webhook's requests.
+///
+/// Requests are created with the same request builders as the [`Client`]. If
+/// a [thread ID] is set then messages are executed in, and retrieved from,
+/// that thread.
+///
+/// # Examples
+///
+/// Execute a webhook from its URL:
+///
+/// ```no_run
+/// use twilight_http::client::WebhookClient;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let url = "https://discord.com/api/webhooks/1/token";
+/// let webhook = WebhookClient::from_url(url)?;
+///
+/// let message = webhook
+///     .execute()
+///     .content("hello from a webhook")?
+///     .wait()
+///     .exec()
+///     .await?
+///     .model()
+///     .await?;
+///
+/// webhook
+///     .update_message(message.id)
+///     .content(Some("edited"))?
+///     .exec()
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [thread ID]: Self::thread_id
+#[derive(Debug)]
+pub struct WebhookClient {
+    client: Client,
+    id: Id<WebhookMarker>,
+    thread_id: Option<Id<ChannelMarker>>,
+    token: String,
+}
+
+impl WebhookClient {
+    /// Create a new client for a webhook by its ID and token.
+    pub fn new(id: Id<WebhookMarker>, token: String) -> Self {
+        Self::with_client(Client::builder().build(), id, token)
+    }
+
+    /// Create a new client for a webhook from its URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Parsing`] if the URL isn't a valid webhook
+    /// URL.
+    ///
+    /// Returns an error of type [`TokenMissing`] if the URL doesn't contain the
+    /// webhook's token.
+    ///
+    /// [`Parsing`]: WebhookClientErrorType::Parsing
+    /// [`TokenMissing`]: WebhookClientErrorType::TokenMissing
+    pub fn from_url(url: &str) -> Result<Self, WebhookClientError> {
+        let (id, token) = webhook::parse(url).map_err(|source| WebhookClientError {
+            kind: WebhookClientErrorType::Parsing,
+            source: Some(Box::new(source)),
+        })?;
+
+        let token = token.ok_or(WebhookClientError {
+            kind: WebhookClientErrorType::TokenMissing,
+            source: None,
+        })?;
+
+        Ok(Self::new(id, token.to_owned()))
+    }
+
+    /// Create a new client for a webhook using a configured HTTP client.
+    ///
+    /// This is useful for configuring the client's proxy, timeout, or
+    /// transport via a [`ClientBuilder`]. The client's token is not used.
+    ///
+    /// [`ClientBuilder`]: super::ClientBuilder
+    pub const fn with_client(client: Client, id: Id<WebhookMarker>, token: String) -> Self {
+        Self {
+            client,
+            id,
+            thread_id: None,
+            token,
+        }
+    }
+
+    /// Set the thread to execute and retrieve messages in.
+    pub const fn thread_id(mut self, thread_id: Id<ChannelMarker>) -> Self {
+        self.thread_id = Some(thread_id);
+
+        self
+    }
+
+    /// HTTP client used to send requests.
+    pub const fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// ID of the webhook.
+    pub const fn id(&self) -> Id<WebhookMarker> {
+        self.id
+    }
+
+    /// Token of the webhook.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Execute the webhook, sending a message to its channel.
+    ///
+    /// Refer to [`Client::execute_webhook`] for more information.
+    pub fn execute(&self) -> ExecuteWebhook<'_> {
+        let request = self.client.execute_webhook(self.id, &self.token);
+
+        match self.thread_id {
+            Some(thread_id) => request.thread_id(thread_id),
+            None => request,
+        }
+    }
+
+    /// Get a message executed by the webhook.
+    pub fn message(&self, message_id: Id<MessageMarker>) -> GetWebhookMessage<'_> {
+        let request = self
+            .client
+            .webhook_message(self.id, &self.token, message_id);
+
+        match self.thread_id {
+            Some(thread_id) => request.thread_id(thread_id),
+            None => request,
+        }
+    }
+
+    /// Update a message executed by the webhook.
+    ///
+    /// Refer to [`Client::update_webhook_message`] for more information.
+    pub fn update_message(&self, message_id: Id<MessageMarker>) -> UpdateWebhookMessage<'_> {
+        let request = self
+            .client
+            .update_webhook_message(self.id, &self.token, message_id);
+
+        match self.thread_id {
+            Some(thread_id) => request.thread_id(thread_id),
+            None => request,
+        }
+    }
+
+    /// Delete a message executed by the webhook.
+    pub fn delete_message(&self, message_id: Id<MessageMarker>) -> DeleteWebhookMessage<'_> {
+        let request = self
+            .client
+            .delete_webhook_message(self.id, &self.token, message_id);
+
+        match self.thread_id {
+            Some(thread_id) => request.thread_id(thread_id),
+            None => request,
+        }
+    }
+
+    /// Get the webhook.
+    pub fn webhook(&self) -> GetWebhook<'_> {
+        self.client.webhook(self.id).token(&self.token)
+    }
+
+    /// Update the webhook's name or avatar.
+    pub fn update(&self) -> UpdateWebhookWithToken<'_> {
+        self.client.update_webhook_with_token(self.id, &self.token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WebhookClient, WebhookClientError, WebhookClientErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(WebhookClient: Debug, Send, Sync);
+    assert_impl_all!(WebhookClientError: Error, Send, Sync);
+    assert_impl_all!(WebhookClientErrorType: Debug, Send, Sync);
+
+    #[test]
+    fn test_from_url() -> Result<(), Box<dyn Error>> {
+        let webhook = WebhookClient::from_url("https://discord.com/api/webhooks/1/token")?;
+        assert_eq!(Id::new(1), webhook.id());
+        assert_eq!("token", webhook.token());
+        assert!(webhook.client().token().is_none());
+
+        assert!(matches!(
+            WebhookClient::from_url("https://discord.com/api/webhooks/1")
+                .unwrap_err()
+                .kind(),
+            WebhookClientErrorType::TokenMissing
+        ));
+        assert!(matches!(
+            WebhookClient::from_url("https://discord.com/api/channels/1")
+                .unwrap_err()
+                .kind(),
+            WebhookClientErrorType::Parsing
+        ));
+        assert!(matches!(
+            WebhookClient::from_url("https://discord.com/api/webhooks/a/token")
+                .unwrap_err()
+                .kind(),
+            WebhookClientErrorType::Parsing
+        ));
+
+        for url in [
+            "https://discordapp.com/api/webhooks/1/token",
+            "https://canary.discord.com/api/webhooks/1/token",
+            "https://ptb.discord.com/api/webhooks/1/token",
+            "https://discord.com/api/v10/webhooks/1/token",
+            "https://discord.com/api/webhooks/1/token?thread_id=2",
+        ] {
+            let webhook = WebhookClient::from_url(url)?;
+            assert_eq!(Id::new(1), webhook.id());
+            assert_eq!("token", webhook.token());
+        }
+
+        Ok(())
+    }
+}
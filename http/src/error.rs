@@ -42,6 +42,9 @@ impl Error {
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            ErrorType::AttachmentStreamConsumed => {
+                f.write_str("attachment stream has already been used by another request")
+            }
             ErrorType::BuildingRequest => f.write_str("failed to build the request"),
             ErrorType::ChunkingResponse => f.write_str("Chunking the response failed"),
             ErrorType::CreatingHeader { name, .. } => {
@@ -50,6 +53,9 @@ impl Display for Error {
 
                 f.write_str(" failed")
             }
+            ErrorType::InvalidRequestLimitReached => {
+                f.write_str("too many invalid requests have been made in the past 10 minutes")
+            }
             ErrorType::Json => f.write_str("Given value couldn't be serialized"),
             ErrorType::Parsing { body, .. } => {
                 f.write_str("Response body couldn't be deserialized: ")?;
@@ -95,11 +101,24 @@ impl StdError for Error {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ErrorType {
+    /// Streamed attachment has already been used by another request.
+    ///
+    /// Streams can only be sent once, so a new stream must be created for
+    /// each request.
+    AttachmentStreamConsumed,
     BuildingRequest,
     ChunkingResponse,
     CreatingHeader {
         name: String,
     },
+    /// Too many invalid requests have been made in the past 10 minutes.
+    ///
+    /// Requests are refused until enough invalid requests have left the
+    /// window, to prevent Discord from banning the client's IP address.
+    /// Refer to [`ClientBuilder::invalid_request_limit`] for more information.
+    ///
+    /// [`ClientBuilder::invalid_request_limit`]: crate::client::ClientBuilder::invalid_request_limit
+    InvalidRequestLimitReached,
     Json,
     Parsing {
         body: Vec<u8>,
@@ -143,6 +143,9 @@ pub mod routing;
 
 mod json;
 
+#[cfg(test)]
+mod test;
+
 /// Discord API version used by this crate.
 pub const API_VERSION: u8 = 9;
 
@@ -6,8 +6,10 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::collections::BTreeMap;
 use twilight_model::{
     application::command::{Command, CommandOption, CommandType},
+    guild::Permissions,
     id::{marker::ApplicationMarker, Id},
 };
 use twilight_validate::command::{
@@ -26,10 +28,14 @@ use twilight_validate::command::{
 #[must_use = "requests must be configured and executed"]
 pub struct CreateGlobalChatInputCommand<'a> {
     application_id: Id<ApplicationMarker>,
+    default_member_permissions: Option<Permissions>,
     default_permission: Option<bool>,
     description: &'a str,
+    description_localizations: Option<&'a BTreeMap<String, String>>,
+    dm_permission: Option<bool>,
     http: &'a Client,
     name: &'a str,
+    name_localizations: Option<&'a BTreeMap<String, String>>,
     options: Option<&'a [CommandOption]>,
 }
 
@@ -46,10 +52,14 @@ impl<'a> CreateGlobalChatInputCommand<'a> {
 
         Ok(Self {
             application_id,
+            default_member_permissions: None,
             default_permission: None,
             description,
+            description_localizations: None,
+            dm_permission: None,
             http,
             name,
+            name_localizations: None,
             options: None,
         })
     }
@@ -76,6 +86,16 @@ impl<'a> CreateGlobalChatInputCommand<'a> {
         Ok(self)
     }
 
+    /// Default permissions required for a member to run the command.
+    ///
+    /// Setting this [`Permissions::empty()`] will prohibit anyone from running
+    /// the command, except for guild administrators.
+    pub const fn default_member_permissions(mut self, default: Permissions) -> Self {
+        self.default_member_permissions = Some(default);
+
+        self
+    }
+
     /// Whether the command is enabled by default when the app is added to a guild.
     pub const fn default_permission(mut self, default: bool) -> Self {
         self.default_permission = Some(default);
@@ -83,6 +103,56 @@ impl<'a> CreateGlobalChatInputCommand<'a> {
         self
     }
 
+    /// Set the localization dictionary for the command description.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`DescriptionInvalid`] if a localized
+    /// description is invalid.
+    ///
+    /// [`DescriptionInvalid`]: twilight_validate::command::CommandValidationErrorType::DescriptionInvalid
+    pub fn description_localizations(
+        mut self,
+        localizations: &'a BTreeMap<String, String>,
+    ) -> Result<Self, CommandValidationError> {
+        localizations.values().try_for_each(validate_description)?;
+
+        self.description_localizations = Some(localizations);
+
+        Ok(self)
+    }
+
+    /// Set whether the command is available in DMs.
+    ///
+    /// By default, commands are visible in DMs.
+    pub const fn dm_permission(mut self, dm_permission: bool) -> Self {
+        self.dm_permission = Some(dm_permission);
+
+        self
+    }
+
+    /// Set the localization dictionary for the command name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`NameLengthInvalid`] or
+    /// [`NameCharacterInvalid`] if a localized name is invalid.
+    ///
+    /// [`NameCharacterInvalid`]: twilight_validate::command::CommandValidationErrorType::NameCharacterInvalid
+    /// [`NameLengthInvalid`]: twilight_validate::command::CommandValidationErrorType::NameLengthInvalid
+    pub fn name_localizations(
+        mut self,
+        localizations: &'a BTreeMap<String, String>,
+    ) -> Result<Self, CommandValidationError> {
+        localizations
+            .values()
+            .try_for_each(validate_chat_input_name)?;
+
+        self.name_localizations = Some(localizations);
+
+        Ok(self)
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -103,10 +173,14 @@ impl TryIntoRequest for CreateGlobalChatInputCommand<'_> {
         })
         .json(&CommandBorrowed {
             application_id: Some(self.application_id),
+            default_member_permissions: self.default_member_permissions,
             default_permission: self.default_permission,
             description: Some(self.description),
+            description_localizations: self.description_localizations,
+            dm_permission: self.dm_permission,
             kind: CommandType::ChatInput,
             name: self.name,
+            name_localizations: self.name_localizations,
             options: self.options,
         })
         .map(RequestBuilder::build)
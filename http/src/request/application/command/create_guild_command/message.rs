@@ -6,8 +6,10 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::collections::BTreeMap;
 use twilight_model::{
     application::command::{Command, CommandType},
+    guild::Permissions,
     id::{
         marker::{ApplicationMarker, GuildMarker},
         Id,
@@ -25,10 +27,12 @@ use twilight_validate::command::{name as validate_name, CommandValidationError};
 #[must_use = "requests must be configured and executed"]
 pub struct CreateGuildMessageCommand<'a> {
     application_id: Id<ApplicationMarker>,
+    default_member_permissions: Option<Permissions>,
     default_permission: Option<bool>,
     guild_id: Id<GuildMarker>,
     http: &'a Client,
     name: &'a str,
+    name_localizations: Option<&'a BTreeMap<String, String>>,
 }
 
 impl<'a> CreateGuildMessageCommand<'a> {
@@ -42,13 +46,25 @@ impl<'a> CreateGuildMessageCommand<'a> {
 
         Ok(Self {
             application_id,
+            default_member_permissions: None,
             default_permission: None,
             guild_id,
             http,
             name,
+            name_localizations: None,
         })
     }
 
+    /// Default permissions required for a member to run the command.
+    ///
+    /// Setting this [`Permissions::empty()`] will prohibit anyone from running
+    /// the command, except for guild administrators.
+    pub const fn default_member_permissions(mut self, default: Permissions) -> Self {
+        self.default_member_permissions = Some(default);
+
+        self
+    }
+
     /// Whether the command is enabled by default when the app is added to a
     /// guild.
     pub const fn default_permission(mut self, default: bool) -> Self {
@@ -57,6 +73,25 @@ impl<'a> CreateGuildMessageCommand<'a> {
         self
     }
 
+    /// Set the localization dictionary for the command name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`NameLengthInvalid`] if a localized name is
+    /// invalid.
+    ///
+    /// [`NameLengthInvalid`]: twilight_validate::command::CommandValidationErrorType::NameLengthInvalid
+    pub fn name_localizations(
+        mut self,
+        localizations: &'a BTreeMap<String, String>,
+    ) -> Result<Self, CommandValidationError> {
+        localizations.values().try_for_each(validate_name)?;
+
+        self.name_localizations = Some(localizations);
+
+        Ok(self)
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -78,10 +113,14 @@ impl TryIntoRequest for CreateGuildMessageCommand<'_> {
         })
         .json(&CommandBorrowed {
             application_id: Some(self.application_id),
+            default_member_permissions: self.default_member_permissions,
             default_permission: self.default_permission,
             description: None,
+            description_localizations: None,
+            dm_permission: None,
             kind: CommandType::Message,
             name: self.name,
+            name_localizations: self.name_localizations,
             options: None,
         })
         .map(RequestBuilder::build)
@@ -0,0 +1,1164 @@
+use super::{CommandBorrowed, GetGlobalCommands, GetGuildCommands};
+use crate::{
+    client::Client,
+    error::Error,
+    request::Request,
+    response::{marker::EmptyBody, DeserializeBodyError},
+    routing::Route,
+};
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+};
+use twilight_model::{
+    application::command::{Command, CommandOption, CommandOptionChoice, CommandType},
+    guild::Permissions,
+    id::{
+        marker::{ApplicationMarker, CommandMarker, GuildMarker},
+        Id,
+    },
+};
+
+/// Future resolving to the report of a command sync.
+pub type SyncCommandsFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<SyncReport, SyncCommandsError>> + Send + 'a>>;
+
+/// Retrieving the existing commands to sync with failed.
+#[derive(Debug)]
+pub struct SyncCommandsError {
+    kind: SyncCommandsErrorType,
+    source: Option<Box<dyn StdError + Send + Sync>>,
+}
+
+impl SyncCommandsError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &SyncCommandsErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn StdError + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        SyncCommandsErrorType,
+        Option<Box<dyn StdError + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for SyncCommandsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            SyncCommandsErrorType::Deserializing => f.write_str("failed to deserialize commands"),
+            SyncCommandsErrorType::Requesting => f.write_str("failed to request commands"),
+        }
+    }
+}
+
+impl StdError for SyncCommandsError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn StdError + 'static))
+    }
+}
+
+/// Type of [`SyncCommandsError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SyncCommandsErrorType {
+    /// Response body of the existing commands could not be deserialized.
+    Deserializing,
+    /// Request for the existing commands failed.
+    ///
+    /// The source is an [`Error`] and may be inspected for the response.
+    ///
+    /// [`Error`]: crate::Error
+    Requesting,
+}
+
+/// Change to a field of a command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CommandChange {
+    /// Default permissions required for a member to run the command changed.
+    DefaultMemberPermissions {
+        /// Existing permissions.
+        old: Option<Permissions>,
+        /// Declared permissions.
+        new: Option<Permissions>,
+    },
+    /// Whether the command is enabled by default changed.
+    DefaultPermission {
+        /// Existing value.
+        old: bool,
+        /// Declared value.
+        new: bool,
+    },
+    /// Description of the command changed.
+    Description {
+        /// Existing description.
+        old: String,
+        /// Declared description.
+        new: String,
+    },
+    /// Localizations of the description of the command changed.
+    DescriptionLocalizations {
+        /// Existing localizations.
+        old: Option<BTreeMap<String, String>>,
+        /// Declared localizations.
+        new: Option<BTreeMap<String, String>>,
+    },
+    /// Whether the command is available in DMs changed.
+    DmPermission {
+        /// Existing value.
+        old: bool,
+        /// Declared value.
+        new: bool,
+    },
+    /// Localizations of the name of the command changed.
+    NameLocalizations {
+        /// Existing localizations.
+        old: Option<BTreeMap<String, String>>,
+        /// Declared localizations.
+        new: Option<BTreeMap<String, String>>,
+    },
+    /// Option was added.
+    OptionAdded {
+        /// Path of the option.
+        path: String,
+    },
+    /// Fields of an option changed.
+    ///
+    /// Changes of the nested options of subcommands and subcommand groups are
+    /// reported separately.
+    OptionChanged {
+        /// Path of the option.
+        path: String,
+    },
+    /// Option was removed.
+    OptionRemoved {
+        /// Path of the option.
+        path: String,
+    },
+    /// Order of options changed.
+    OptionsReordered {
+        /// Path of the subcommand or subcommand group containing the options,
+        /// or `None` for the command's top-level options.
+        path: Option<String>,
+    },
+}
+
+/// Difference between a declared command and the existing commands.
+///
+/// Commands are matched by their [type] and name. Paths of options are the
+/// names of the subcommands and subcommand groups containing the option and
+/// the option's name joined by spaces, such as `"set value"` for the `value`
+/// option of the `set` subcommand.
+///
+/// [type]: Command::kind
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum CommandDiff {
+    /// Command is declared but doesn't exist.
+    Added {
+        /// Declared command.
+        command: Command,
+    },
+    /// Command exists but its fields differ from the declaration.
+    Changed {
+        /// Declared command.
+        command: Command,
+        /// ID of the existing command.
+        id: Id<CommandMarker>,
+        /// Changes to the existing command's fields.
+        changes: Vec<CommandChange>,
+    },
+    /// Command exists but isn't declared.
+    Removed {
+        /// Existing command.
+        command: Command,
+        /// ID of the existing command.
+        id: Id<CommandMarker>,
+    },
+}
+
+impl CommandDiff {
+    /// Compute the differences between declared and existing global
+    /// commands.
+    ///
+    /// Commands that are unchanged are omitted, as are existing commands
+    /// without an ID, which can't be updated or deleted. Differences are
+    /// ordered by the declared commands, followed by the removed existing
+    /// commands.
+    pub fn between(declared: &[Command], existing: &[Command]) -> Vec<Self> {
+        Self::diff(declared, existing, true)
+    }
+
+    /// Compute the differences between declared and existing guild commands.
+    ///
+    /// This is the same as [`between`], except that whether commands are
+    /// available in DMs isn't compared, as it only applies to global
+    /// commands.
+    ///
+    /// [`between`]: Self::between
+    pub fn between_guild(declared: &[Command], existing: &[Command]) -> Vec<Self> {
+        Self::diff(declared, existing, false)
+    }
+
+    /// Compute the differences between declared and existing commands,
+    /// comparing their DM permissions if configured to.
+    fn diff(declared: &[Command], existing: &[Command], dm_permission: bool) -> Vec<Self> {
+        let mut diffs = Vec::new();
+
+        for command in declared {
+            match existing.iter().find(|other| same_command(command, other)) {
+                Some(other) => {
+                    let changes = command_changes(other, command, dm_permission);
+
+                    if changes.is_empty() {
+                        continue;
+                    }
+
+                    // Commands returned by the API always have an ID.
+                    if let Some(id) = other.id {
+                        diffs.push(Self::Changed {
+                            command: command.clone(),
+                            id,
+                            changes,
+                        });
+                    }
+                }
+                None => diffs.push(Self::Added {
+                    command: command.clone(),
+                }),
+            }
+        }
+
+        diffs.extend(
+            existing
+                .iter()
+                .filter(|other| !declared.iter().any(|command| same_command(command, other)))
+                .filter_map(|other| {
+                    other.id.map(|id| Self::Removed {
+                        command: other.clone(),
+                        id,
+                    })
+                }),
+        );
+
+        diffs
+    }
+
+    /// Declared or, if removed, existing command.
+    pub const fn command(&self) -> &Command {
+        match self {
+            Self::Added { command }
+            | Self::Changed { command, .. }
+            | Self::Removed { command, .. } => command,
+        }
+    }
+}
+
+/// Outcome of applying the difference of a command in a sync.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SyncOutcome {
+    /// Difference was applied.
+    Applied,
+    /// Applying the difference failed.
+    Failed(Error),
+    /// Difference wasn't applied because the sync is a dry run.
+    Skipped,
+}
+
+/// Report of the differences found in a sync and the outcome of applying each.
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    outcomes: Vec<(CommandDiff, SyncOutcome)>,
+}
+
+impl SyncReport {
+    /// Differences found between the declared and existing commands.
+    pub fn diffs(&self) -> impl Iterator<Item = &CommandDiff> + '_ {
+        self.outcomes.iter().map(|(diff, _)| diff)
+    }
+
+    /// Differences that failed to be applied and their errors.
+    pub fn failed(&self) -> impl Iterator<Item = (&CommandDiff, &Error)> + '_ {
+        self.outcomes
+            .iter()
+            .filter_map(|(diff, outcome)| match outcome {
+                SyncOutcome::Failed(error) => Some((diff, error)),
+                _ => None,
+            })
+    }
+
+    /// Whether every difference was applied.
+    ///
+    /// Dry runs are only complete if there were no differences.
+    pub fn is_complete(&self) -> bool {
+        self.outcomes
+            .iter()
+            .all(|(_, outcome)| matches!(outcome, SyncOutcome::Applied))
+    }
+
+    /// Whether the declared commands matched the existing commands.
+    pub fn is_unchanged(&self) -> bool {
+        self.outcomes.is_empty()
+    }
+
+    /// Differences and the outcome of applying each.
+    pub fn outcomes(&self) -> &[(CommandDiff, SyncOutcome)] {
+        &self.outcomes
+    }
+
+    /// Consume the report, returning the differences and the outcome of
+    /// applying each.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_outcomes(self) -> Vec<(CommandDiff, SyncOutcome)> {
+        self.outcomes
+    }
+}
+
+#[derive(Serialize)]
+struct UpdateCommandFields<'a> {
+    default_member_permissions: Option<Permissions>,
+    default_permission: bool,
+    description: &'a str,
+    description_localizations: Option<&'a BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dm_permission: Option<bool>,
+    name: &'a str,
+    name_localizations: Option<&'a BTreeMap<String, String>>,
+    options: &'a [CommandOption],
+}
+
+/// Sync the commands of an application with declared commands.
+///
+/// The existing global or guild commands are retrieved and compared with the
+/// declared commands, and only the commands that differ are created, updated,
+/// or deleted. Unlike [`SetGlobalCommands`] and [`SetGuildCommands`] this
+/// reports what changed, and unchanged commands keep their IDs and
+/// permissions.
+///
+/// Applying the differences doesn't stop on failure; the outcome of each
+/// difference is returned in a [`SyncReport`]. When configured as a
+/// [dry run] the differences are only computed.
+///
+/// Whether commands are available in DMs only applies to global commands, so
+/// it's ignored when syncing guild commands.
+///
+/// The declared [`Command`]s have an [associated builder] in the
+/// [`twilight-util`] crate.
+///
+/// # Examples
+///
+/// Print what would change without applying it:
+///
+/// ```no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let commands = Vec::new();
+/// let client = Client::new("my token".to_owned());
+/// let interaction_client = client.interaction(Id::new(1));
+///
+/// let report = interaction_client
+///     .sync_global_commands(&commands)
+///     .dry_run(true)
+///     .exec()
+///     .await?;
+///
+/// for diff in report.diffs() {
+///     println!("{:?}", diff);
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`SetGlobalCommands`]: super::SetGlobalCommands
+/// [`SetGuildCommands`]: super::SetGuildCommands
+/// [`twilight-util`]: https://docs.rs/twilight-util/latest/index.html
+/// [associated builder]: https://docs.rs/twilight-util/latest/builder/command/struct.CommandBuilder.html
+/// [dry run]: Self::dry_run
+#[must_use = "requests must be configured and executed"]
+pub struct SyncCommands<'a> {
+    application_id: Id<ApplicationMarker>,
+    commands: &'a [Command],
+    dry_run: bool,
+    guild_id: Option<Id<GuildMarker>>,
+    http: &'a Client,
+}
+
+impl<'a> SyncCommands<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        guild_id: Option<Id<GuildMarker>>,
+        commands: &'a [Command],
+    ) -> Self {
+        Self {
+            application_id,
+            commands,
+            dry_run: false,
+            guild_id,
+            http,
+        }
+    }
+
+    /// Set whether to only compute the differences without applying them.
+    ///
+    /// Defaults to `false`.
+    pub const fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+
+        self
+    }
+
+    /// Execute the sync, returning a future resolving to a report of the
+    /// differences and the outcome of applying each.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Requesting`] if requesting the existing
+    /// commands failed.
+    ///
+    /// Returns an error of type [`Deserializing`] if the existing commands
+    /// could not be deserialized.
+    ///
+    /// [`Deserializing`]: SyncCommandsErrorType::Deserializing
+    /// [`Requesting`]: SyncCommandsErrorType::Requesting
+    pub fn exec(self) -> SyncCommandsFuture<'a> {
+        Box::pin(async move {
+            let response = match self.guild_id {
+                Some(guild_id) => {
+                    GetGuildCommands::new(self.http, self.application_id, guild_id)
+                        .with_localizations(true)
+                        .exec()
+                        .await
+                }
+                None => {
+                    GetGlobalCommands::new(self.http, self.application_id)
+                        .with_localizations(true)
+                        .exec()
+                        .await
+                }
+            };
+
+            let existing = response
+                .map_err(|source| SyncCommandsError {
+                    kind: SyncCommandsErrorType::Requesting,
+                    source: Some(Box::new(source)),
+                })?
+                .models()
+                .await
+                .map_err(|source: DeserializeBodyError| SyncCommandsError {
+                    kind: SyncCommandsErrorType::Deserializing,
+                    source: Some(Box::new(source)),
+                })?;
+
+            let diffs = if self.guild_id.is_some() {
+                CommandDiff::between_guild(self.commands, &existing)
+            } else {
+                CommandDiff::between(self.commands, &existing)
+            };
+            let mut outcomes = Vec::with_capacity(diffs.len());
+
+            for diff in diffs {
+                let outcome = if self.dry_run {
+                    SyncOutcome::Skipped
+                } else {
+                    match self.apply(&diff).await {
+                        Ok(()) => SyncOutcome::Applied,
+                        Err(source) => SyncOutcome::Failed(source),
+                    }
+                };
+
+                outcomes.push((diff, outcome));
+            }
+
+            Ok(SyncReport { outcomes })
+        })
+    }
+
+    /// Send the request applying a difference.
+    async fn apply(&self, diff: &CommandDiff) -> Result<(), Error> {
+        let application_id = self.application_id.get();
+        let guild_id = self.guild_id.map(Id::get);
+
+        let request = match diff {
+            CommandDiff::Added { command } => {
+                let route = match guild_id {
+                    Some(guild_id) => Route::CreateGuildCommand {
+                        application_id,
+                        guild_id,
+                    },
+                    None => Route::CreateGlobalCommand { application_id },
+                };
+
+                Request::builder(&route)
+                    .json(&CommandBorrowed {
+                        application_id: Some(self.application_id),
+                        default_member_permissions: command.default_member_permissions,
+                        default_permission: command.default_permission,
+                        description: (command.kind == CommandType::ChatInput)
+                            .then(|| command.description.as_str()),
+                        description_localizations: command.description_localizations.as_ref(),
+                        dm_permission: command.dm_permission.filter(|_| guild_id.is_none()),
+                        kind: command.kind,
+                        name: &command.name,
+                        name_localizations: command.name_localizations.as_ref(),
+                        options: Some(&command.options),
+                    })?
+                    .build()
+            }
+            CommandDiff::Changed { command, id, .. } => {
+                let route = match guild_id {
+                    Some(guild_id) => Route::UpdateGuildCommand {
+                        application_id,
+                        command_id: id.get(),
+                        guild_id,
+                    },
+                    None => Route::UpdateGlobalCommand {
+                        application_id,
+                        command_id: id.get(),
+                    },
+                };
+
+                Request::builder(&route)
+                    .json(&UpdateCommandFields {
+                        default_member_permissions: command.default_member_permissions,
+                        default_permission: command.default_permission.unwrap_or(true),
+                        description: &command.description,
+                        description_localizations: command.description_localizations.as_ref(),
+                        // DM permissions only apply to global commands.
+                        dm_permission: guild_id
+                            .is_none()
+                            .then(|| command.dm_permission.unwrap_or(true)),
+                        name: &command.name,
+                        name_localizations: command.name_localizations.as_ref(),
+                        options: &command.options,
+                    })?
+                    .build()
+            }
+            CommandDiff::Removed { id, .. } => {
+                let command_id = id.get();
+                let route = match guild_id {
+                    Some(guild_id) => Route::DeleteGuildCommand {
+                        application_id,
+                        command_id,
+                        guild_id,
+                    },
+                    None => Route::DeleteGlobalCommand {
+                        application_id,
+                        command_id,
+                    },
+                };
+
+                Request::from_route(&route)
+            }
+        };
+
+        self.http.request::<EmptyBody>(request).await?;
+
+        Ok(())
+    }
+}
+
+/// Whether two commands are the same command.
+fn same_command(command: &Command, other: &Command) -> bool {
+    command.kind == other.kind && command.name == other.name
+}
+
+/// Changes of the fields of an existing command to match a declared command,
+/// including their DM permissions if configured to.
+fn command_changes(
+    existing: &Command,
+    declared: &Command,
+    dm_permission: bool,
+) -> Vec<CommandChange> {
+    let mut changes = Vec::new();
+
+    if existing.default_member_permissions != declared.default_member_permissions {
+        changes.push(CommandChange::DefaultMemberPermissions {
+            old: existing.default_member_permissions,
+            new: declared.default_member_permissions,
+        });
+    }
+
+    let old = existing.default_permission.unwrap_or(true);
+    let new = declared.default_permission.unwrap_or(true);
+
+    if old != new {
+        changes.push(CommandChange::DefaultPermission { old, new });
+    }
+
+    if existing.description != declared.description {
+        changes.push(CommandChange::Description {
+            old: existing.description.clone(),
+            new: declared.description.clone(),
+        });
+    }
+
+    if !same_localizations(
+        existing.description_localizations.as_ref(),
+        declared.description_localizations.as_ref(),
+    ) {
+        changes.push(CommandChange::DescriptionLocalizations {
+            old: existing.description_localizations.clone(),
+            new: declared.description_localizations.clone(),
+        });
+    }
+
+    let old = existing.dm_permission.unwrap_or(true);
+    let new = declared.dm_permission.unwrap_or(true);
+
+    if dm_permission && old != new {
+        changes.push(CommandChange::DmPermission { old, new });
+    }
+
+    if !same_localizations(
+        existing.name_localizations.as_ref(),
+        declared.name_localizations.as_ref(),
+    ) {
+        changes.push(CommandChange::NameLocalizations {
+            old: existing.name_localizations.clone(),
+            new: declared.name_localizations.clone(),
+        });
+    }
+
+    option_changes(None, &existing.options, &declared.options, &mut changes);
+
+    changes
+}
+
+/// Whether two localization dictionaries are the same, where no dictionary is
+/// the same as an empty one.
+fn same_localizations(
+    localizations: Option<&BTreeMap<String, String>>,
+    other: Option<&BTreeMap<String, String>>,
+) -> bool {
+    let is_empty = |localizations: Option<&BTreeMap<String, String>>| {
+        localizations.map_or(true, BTreeMap::is_empty)
+    };
+
+    localizations == other || (is_empty(localizations) && is_empty(other))
+}
+
+/// Replace an empty localization dictionary with no dictionary.
+fn normalize_localizations(localizations: &mut Option<BTreeMap<String, String>>) {
+    if localizations.as_ref().map_or(false, BTreeMap::is_empty) {
+        *localizations = None;
+    }
+}
+
+/// Copy of an option where empty localization dictionaries of the option and
+/// its choices are replaced with no dictionary, so that options can be
+/// compared like [`same_localizations`] compares localizations.
+fn normalized_option(option: &CommandOption) -> CommandOption {
+    let mut option = option.clone();
+
+    let (description_localizations, name_localizations, choices) = match &mut option {
+        CommandOption::SubCommand(data) | CommandOption::SubCommandGroup(data) => (
+            &mut data.description_localizations,
+            &mut data.name_localizations,
+            None,
+        ),
+        CommandOption::String(data) => (
+            &mut data.description_localizations,
+            &mut data.name_localizations,
+            Some(&mut data.choices),
+        ),
+        CommandOption::Integer(data) | CommandOption::Number(data) => (
+            &mut data.description_localizations,
+            &mut data.name_localizations,
+            Some(&mut data.choices),
+        ),
+        CommandOption::Boolean(data)
+        | CommandOption::User(data)
+        | CommandOption::Role(data)
+        | CommandOption::Mentionable(data) => (
+            &mut data.description_localizations,
+            &mut data.name_localizations,
+            None,
+        ),
+        CommandOption::Channel(data) => (
+            &mut data.description_localizations,
+            &mut data.name_localizations,
+            None,
+        ),
+    };
+
+    normalize_localizations(description_localizations);
+    normalize_localizations(name_localizations);
+
+    for choice in choices.into_iter().flatten() {
+        match choice {
+            CommandOptionChoice::String {
+                name_localizations, ..
+            }
+            | CommandOptionChoice::Int {
+                name_localizations, ..
+            }
+            | CommandOptionChoice::Number {
+                name_localizations, ..
+            } => normalize_localizations(name_localizations),
+        }
+    }
+
+    option
+}
+
+/// Push the changes of existing options to match declared options.
+fn option_changes(
+    parent: Option<&str>,
+    existing: &[CommandOption],
+    declared: &[CommandOption],
+    changes: &mut Vec<CommandChange>,
+) {
+    let path = |option: &CommandOption| match parent {
+        Some(parent) => format!("{} {}", parent, option.name()),
+        None => option.name().to_owned(),
+    };
+
+    for option in existing {
+        if !declared.iter().any(|other| other.name() == option.name()) {
+            changes.push(CommandChange::OptionRemoved { path: path(option) });
+        }
+    }
+
+    for option in declared {
+        let other = if let Some(other) = existing.iter().find(|other| other.name() == option.name())
+        {
+            other
+        } else {
+            changes.push(CommandChange::OptionAdded { path: path(option) });
+
+            continue;
+        };
+
+        match (other, option) {
+            (CommandOption::SubCommand(old), CommandOption::SubCommand(new))
+            | (CommandOption::SubCommandGroup(old), CommandOption::SubCommandGroup(new)) => {
+                let path = path(option);
+
+                if old.description != new.description
+                    || !same_localizations(
+                        old.description_localizations.as_ref(),
+                        new.description_localizations.as_ref(),
+                    )
+                    || !same_localizations(
+                        old.name_localizations.as_ref(),
+                        new.name_localizations.as_ref(),
+                    )
+                {
+                    changes.push(CommandChange::OptionChanged { path: path.clone() });
+                }
+
+                option_changes(Some(&path), &old.options, &new.options, changes);
+            }
+            _ if normalized_option(other) != normalized_option(option) => {
+                changes.push(CommandChange::OptionChanged { path: path(option) })
+            }
+            _ => {}
+        }
+    }
+
+    let same_names = existing.len() == declared.len()
+        && declared
+            .iter()
+            .all(|option| existing.iter().any(|other| other.name() == option.name()));
+    let same_order = existing
+        .iter()
+        .zip(declared)
+        .all(|(other, option)| other.name() == option.name());
+
+    if same_names && !same_order {
+        changes.push(CommandChange::OptionsReordered {
+            path: parent.map(ToOwned::to_owned),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CommandChange, CommandDiff, SyncCommands, SyncCommandsError, SyncCommandsErrorType,
+        SyncOutcome, SyncReport,
+    };
+    use crate::{
+        client::Client,
+        test::{Recorder, Requests},
+    };
+    use hyper::{Method, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{collections::BTreeMap, error::Error, fmt::Debug};
+    use twilight_model::{
+        application::command::{
+            BaseCommandOptionData, Command, CommandOption, CommandType, OptionsCommandOptionData,
+        },
+        guild::Permissions,
+        id::Id,
+    };
+
+    assert_impl_all!(CommandChange: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(CommandDiff: Clone, Debug, PartialEq, Send, Sync);
+    assert_impl_all!(SyncCommands<'_>: Send, Sync);
+    assert_impl_all!(SyncCommandsError: Error, Send, Sync);
+    assert_impl_all!(SyncCommandsErrorType: Debug, Send, Sync);
+    assert_impl_all!(SyncOutcome: Debug, Send, Sync);
+    assert_impl_all!(SyncReport: Debug, Default, Send, Sync);
+
+    /// Create a client whose requests for the existing commands are
+    /// responded to with a list of commands.
+    fn client(existing: &[Command]) -> (Client, Requests) {
+        let body = serde_json::to_string(existing).unwrap();
+        let recorder = Recorder::new(move |request| {
+            if request.method == Method::GET {
+                (StatusCode::OK, body.clone())
+            } else {
+                (StatusCode::OK, "{}".to_owned())
+            }
+        });
+        let requests = recorder.requests();
+        let client = Client::builder()
+            .ratelimiter(None)
+            .transport(Box::new(recorder))
+            .build();
+
+        (client, requests)
+    }
+
+    /// Methods and paths of the recorded requests applying differences.
+    fn applied(requests: &Requests) -> Vec<(Method, String)> {
+        requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|request| request.method != Method::GET)
+            .map(|request| (request.method.clone(), request.path().to_owned()))
+            .collect()
+    }
+
+    fn command(name: &str, options: Vec<CommandOption>) -> Command {
+        Command {
+            application_id: None,
+            default_member_permissions: None,
+            default_permission: None,
+            description: "description".to_owned(),
+            description_localizations: None,
+            dm_permission: None,
+            guild_id: None,
+            id: None,
+            kind: CommandType::ChatInput,
+            name: name.to_owned(),
+            name_localizations: None,
+            options,
+            version: Id::new(1),
+        }
+    }
+
+    fn existing(id: u64, mut command: Command) -> Command {
+        command.application_id = Some(Id::new(1));
+        command.id = Some(Id::new(id));
+
+        command
+    }
+
+    fn boolean(name: &str, required: bool) -> CommandOption {
+        CommandOption::Boolean(BaseCommandOptionData {
+            description: "option".to_owned(),
+            description_localizations: None,
+            name: name.to_owned(),
+            name_localizations: None,
+            required,
+        })
+    }
+
+    fn subcommand(name: &str, options: Vec<CommandOption>) -> CommandOption {
+        CommandOption::SubCommand(OptionsCommandOptionData {
+            description: "subcommand".to_owned(),
+            description_localizations: None,
+            name: name.to_owned(),
+            name_localizations: None,
+            options,
+        })
+    }
+
+    #[test]
+    fn test_unchanged() {
+        let declared = [command("ping", vec![boolean("loud", false)])];
+        let mut remote = existing(2, declared[0].clone());
+        remote.default_permission = Some(true);
+
+        assert!(CommandDiff::between(&declared, &[remote]).is_empty());
+    }
+
+    #[test]
+    fn test_added_and_removed() {
+        let declared = [command("ping", Vec::new())];
+        let mut user = existing(2, command("ping", Vec::new()));
+        user.kind = CommandType::User;
+
+        let diffs = CommandDiff::between(&declared, &[user.clone()]);
+
+        assert_eq!(
+            [
+                CommandDiff::Added {
+                    command: declared[0].clone()
+                },
+                CommandDiff::Removed {
+                    command: user,
+                    id: Id::new(2),
+                },
+            ]
+            .as_ref(),
+            diffs.as_slice()
+        );
+    }
+
+    #[test]
+    fn test_changed() {
+        let mut declared = command(
+            "settings",
+            vec![
+                subcommand("set", vec![boolean("value", true), boolean("new", false)]),
+                boolean("global", false),
+            ],
+        );
+        declared.default_permission = Some(false);
+        declared.description = "new description".to_owned();
+
+        let remote = existing(
+            2,
+            command(
+                "settings",
+                vec![
+                    boolean("global", false),
+                    subcommand("set", vec![boolean("value", false), boolean("old", false)]),
+                ],
+            ),
+        );
+
+        let diffs = CommandDiff::between(&[declared.clone()], &[remote]);
+
+        assert_eq!(
+            [CommandDiff::Changed {
+                command: declared,
+                id: Id::new(2),
+                changes: vec![
+                    CommandChange::DefaultPermission {
+                        old: true,
+                        new: false
+                    },
+                    CommandChange::Description {
+                        old: "description".to_owned(),
+                        new: "new description".to_owned(),
+                    },
+                    CommandChange::OptionRemoved {
+                        path: "set old".to_owned()
+                    },
+                    CommandChange::OptionChanged {
+                        path: "set value".to_owned()
+                    },
+                    CommandChange::OptionAdded {
+                        path: "set new".to_owned()
+                    },
+                    CommandChange::OptionsReordered { path: None },
+                ],
+            }]
+            .as_ref(),
+            diffs.as_slice()
+        );
+    }
+
+    #[test]
+    fn test_localizations_changed() {
+        let mut declared = command("settings", vec![subcommand("set", Vec::new())]);
+        declared.name_localizations = Some(BTreeMap::from([(
+            "de".to_owned(),
+            "einstellungen".to_owned(),
+        )]));
+
+        if let CommandOption::SubCommand(data) = &mut declared.options[0] {
+            data.description_localizations =
+                Some(BTreeMap::from([("de".to_owned(), "Setzen".to_owned())]));
+        }
+
+        let mut remote = existing(2, command("settings", vec![subcommand("set", Vec::new())]));
+        remote.description_localizations = Some(BTreeMap::new());
+
+        let diffs = CommandDiff::between(&[declared.clone()], &[remote]);
+
+        assert_eq!(
+            [CommandDiff::Changed {
+                command: declared.clone(),
+                id: Id::new(2),
+                changes: vec![
+                    CommandChange::NameLocalizations {
+                        old: None,
+                        new: declared.name_localizations,
+                    },
+                    CommandChange::OptionChanged {
+                        path: "set".to_owned()
+                    },
+                ],
+            }]
+            .as_ref(),
+            diffs.as_slice()
+        );
+    }
+
+    #[test]
+    fn test_option_localizations_unchanged() {
+        let mut declared = command("ping", vec![boolean("loud", false)]);
+
+        if let CommandOption::Boolean(data) = &mut declared.options[0] {
+            data.name_localizations = Some(BTreeMap::new());
+        }
+
+        let remote = existing(2, command("ping", vec![boolean("loud", false)]));
+
+        assert!(CommandDiff::between(&[declared], &[remote]).is_empty());
+    }
+
+    #[test]
+    fn test_removed_without_id() {
+        let remote = command("old", Vec::new());
+
+        assert!(CommandDiff::between(&[], &[remote]).is_empty());
+    }
+
+    #[test]
+    fn test_member_permissions_changed() {
+        let mut declared = command("ban", Vec::new());
+        declared.default_member_permissions = Some(Permissions::BAN_MEMBERS);
+        declared.dm_permission = Some(false);
+
+        let mut remote = existing(2, command("ban", Vec::new()));
+        remote.dm_permission = Some(true);
+
+        let diffs = CommandDiff::between(&[declared.clone()], &[remote]);
+
+        assert_eq!(
+            [CommandDiff::Changed {
+                command: declared.clone(),
+                id: Id::new(2),
+                changes: vec![
+                    CommandChange::DefaultMemberPermissions {
+                        old: None,
+                        new: Some(Permissions::BAN_MEMBERS),
+                    },
+                    CommandChange::DmPermission {
+                        old: true,
+                        new: false,
+                    },
+                ],
+            }]
+            .as_ref(),
+            diffs.as_slice()
+        );
+
+        // An absent DM permission is the same as it being allowed.
+        let mut remote = existing(3, command("ban", Vec::new()));
+        remote.dm_permission = Some(true);
+
+        assert!(CommandDiff::between(&[command("ban", Vec::new())], &[remote]).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sync() -> Result<(), Box<dyn Error>> {
+        let declared = [
+            command("ping", Vec::new()),
+            command("settings", vec![boolean("global", true)]),
+        ];
+        let existing = vec![
+            existing(2, command("settings", vec![boolean("global", false)])),
+            existing(3, command("old", Vec::new())),
+        ];
+        let (client, requests) = client(&existing);
+        let interaction_client = client.interaction(Id::new(1));
+
+        let report = interaction_client
+            .sync_guild_commands(Id::new(4), &declared)
+            .dry_run(true)
+            .exec()
+            .await?;
+        assert_eq!(3, report.diffs().count());
+        assert!(!report.is_complete());
+        assert!(report
+            .outcomes()
+            .iter()
+            .all(|(_, outcome)| matches!(outcome, SyncOutcome::Skipped)));
+        assert!(applied(&requests).is_empty());
+
+        let report = interaction_client
+            .sync_guild_commands(Id::new(4), &declared)
+            .exec()
+            .await?;
+        assert!(report.is_complete());
+        assert_eq!(
+            [
+                (
+                    Method::POST,
+                    "/api/v9/applications/1/guilds/4/commands".to_owned()
+                ),
+                (
+                    Method::PATCH,
+                    "/api/v9/applications/1/guilds/4/commands/2".to_owned()
+                ),
+                (
+                    Method::DELETE,
+                    "/api/v9/applications/1/guilds/4/commands/3".to_owned()
+                ),
+            ]
+            .as_ref(),
+            applied(&requests).as_slice()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sync_guild_unchanged() -> Result<(), Box<dyn Error>> {
+        // Guild commands are returned without DM permissions.
+        let mut declared = command("ban", Vec::new());
+        declared.dm_permission = Some(false);
+        let (client, requests) = client(&[existing(2, command("ban", Vec::new()))]);
+        let interaction_client = client.interaction(Id::new(1));
+
+        for _ in 0..2 {
+            let report = interaction_client
+                .sync_guild_commands(Id::new(4), &[declared.clone()])
+                .exec()
+                .await?;
+            assert!(report.is_unchanged());
+        }
+
+        assert_eq!(2, requests.lock().unwrap().len());
+        assert!(applied(&requests).is_empty());
+
+        // Global commands do compare DM permissions.
+        assert_eq!(
+            1,
+            CommandDiff::between(&[declared], &[existing(2, command("ban", Vec::new()))]).len()
+        );
+
+        Ok(())
+    }
+}
@@ -6,8 +6,10 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::collections::BTreeMap;
 use twilight_model::{
     application::command::{Command, CommandOption},
+    guild::Permissions,
     id::{
         marker::{ApplicationMarker, CommandMarker},
         Id,
@@ -16,11 +18,19 @@ use twilight_model::{
 
 #[derive(Serialize)]
 struct UpdateGlobalCommandFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_member_permissions: Option<Permissions>,
     #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    description_localizations: Option<&'a BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dm_permission: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    name_localizations: Option<&'a BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<&'a [CommandOption]>,
 }
 
@@ -48,8 +58,12 @@ impl<'a> UpdateGlobalCommand<'a> {
             application_id,
             command_id,
             fields: UpdateGlobalCommandFields {
+                default_member_permissions: None,
                 description: None,
+                description_localizations: None,
+                dm_permission: None,
                 name: None,
+                name_localizations: None,
                 options: None,
             },
             http,
@@ -70,6 +84,37 @@ impl<'a> UpdateGlobalCommand<'a> {
         self
     }
 
+    /// Edit the default permissions required for a member to run the command.
+    pub const fn default_member_permissions(mut self, default: Permissions) -> Self {
+        self.fields.default_member_permissions = Some(default);
+
+        self
+    }
+
+    /// Edit the localization dictionary for the description of the command.
+    pub const fn description_localizations(
+        mut self,
+        localizations: &'a BTreeMap<String, String>,
+    ) -> Self {
+        self.fields.description_localizations = Some(localizations);
+
+        self
+    }
+
+    /// Edit whether the command is available in DMs.
+    pub const fn dm_permission(mut self, dm_permission: bool) -> Self {
+        self.fields.dm_permission = Some(dm_permission);
+
+        self
+    }
+
+    /// Edit the localization dictionary for the name of the command.
+    pub const fn name_localizations(mut self, localizations: &'a BTreeMap<String, String>) -> Self {
+        self.fields.name_localizations = Some(localizations);
+
+        self
+    }
+
     /// Edit the command options of the command.
     pub const fn command_options(mut self, options: &'a [CommandOption]) -> Self {
         self.fields.options = Some(options);
@@ -6,8 +6,10 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::collections::BTreeMap;
 use twilight_model::{
     application::command::{Command, CommandOption},
+    guild::Permissions,
     id::{
         marker::{ApplicationMarker, CommandMarker, GuildMarker},
         Id,
@@ -16,11 +18,17 @@ use twilight_model::{
 
 #[derive(Serialize)]
 struct UpdateGuildCommandFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_member_permissions: Option<Permissions>,
     #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    description_localizations: Option<&'a BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    name_localizations: Option<&'a BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<&'a [CommandOption]>,
 }
 
@@ -50,8 +58,11 @@ impl<'a> UpdateGuildCommand<'a> {
             application_id,
             command_id,
             fields: UpdateGuildCommandFields {
+                default_member_permissions: None,
                 description: None,
+                description_localizations: None,
                 name: None,
+                name_localizations: None,
                 options: None,
             },
             guild_id,
@@ -73,6 +84,30 @@ impl<'a> UpdateGuildCommand<'a> {
         self
     }
 
+    /// Edit the default permissions required for a member to run the command.
+    pub const fn default_member_permissions(mut self, default: Permissions) -> Self {
+        self.fields.default_member_permissions = Some(default);
+
+        self
+    }
+
+    /// Edit the localization dictionary for the description of the command.
+    pub const fn description_localizations(
+        mut self,
+        localizations: &'a BTreeMap<String, String>,
+    ) -> Self {
+        self.fields.description_localizations = Some(localizations);
+
+        self
+    }
+
+    /// Edit the localization dictionary for the name of the command.
+    pub const fn name_localizations(mut self, localizations: &'a BTreeMap<String, String>) -> Self {
+        self.fields.name_localizations = Some(localizations);
+
+        self
+    }
+
     /// Edit the command options of the command.
     pub const fn command_options(mut self, options: &'a [CommandOption]) -> Self {
         self.fields.options = Some(options);
@@ -1,9 +1,27 @@
+use futures_util::stream::{Stream, StreamExt};
+use hyper::body::Bytes;
+use std::{
+    error::Error,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+use tokio::io::{AsyncRead, ReadBuf};
+
+/// Size of the buffer used to read chunks from an [`AsyncRead`]er.
+const READ_BUFFER_SIZE: usize = 8 * 1024;
+
+/// Boxed stream of the chunks of a streamed attachment.
+pub(crate) type BoxedStream =
+    Pin<Box<dyn Stream<Item = Result<Bytes, Box<dyn Error + Send + Sync>>> + Send + Sync>>;
+
 /// Attachment for when creating and updating messages.
 #[derive(Clone, Debug)]
 pub struct AttachmentFile<'a> {
     pub(crate) filename: &'a str,
     pub(crate) description: Option<&'a str>,
-    pub(crate) file: &'a [u8],
+    pub(crate) file: AttachmentData<'a>,
 }
 
 impl<'a> AttachmentFile<'a> {
@@ -12,7 +30,19 @@ impl<'a> AttachmentFile<'a> {
         AttachmentFile {
             filename,
             description: None,
-            file,
+            file: AttachmentData::Bytes(file),
+        }
+    }
+
+    /// Create a attachment from a filename and a stream of its contents.
+    ///
+    /// The contents are not loaded into memory, but are instead sent to
+    /// Discord chunk by chunk while the request is being sent.
+    pub const fn from_stream(filename: &'a str, stream: AttachmentStream) -> Self {
+        AttachmentFile {
+            filename,
+            description: None,
+            file: AttachmentData::Stream(stream),
         }
     }
 
@@ -28,3 +58,191 @@ impl<'a> AttachmentFile<'a> {
         pairs.iter().map(|(n, f)| Self::from_bytes(n, f)).collect()
     }
 }
+
+/// Contents of an [`AttachmentFile`].
+#[derive(Clone, Debug)]
+pub(crate) enum AttachmentData<'a> {
+    /// Contents are already in memory.
+    Bytes(&'a [u8]),
+    /// Contents are streamed while sending the request.
+    Stream(AttachmentStream),
+}
+
+/// Contents of a file streamed from an async reader or stream.
+///
+/// The length of the contents must be known in advance, since it's required
+/// to calculate the length of the request body. Sending a request fails if
+/// the stream ends up producing more or fewer bytes than specified.
+///
+/// A stream can only be sent once. Clones refer to the same stream, so once a
+/// request containing the stream is built then other requests using it will
+/// fail with [`ErrorType::AttachmentStreamConsumed`].
+///
+/// # Examples
+///
+/// Upload a file without loading it into memory:
+///
+/// ```no_run
+/// use twilight_http::{
+///     request::{AttachmentFile, AttachmentStream},
+///     Client,
+/// };
+/// use twilight_model::id::Id;
+/// use tokio::fs::File;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let file = File::open("video.mp4").await?;
+/// let length = file.metadata().await?.len();
+/// let stream = AttachmentStream::from_reader(file, length);
+///
+/// client
+///     .create_message(Id::new(1))
+///     .attach(&[AttachmentFile::from_stream("video.mp4", stream)])
+///     .exec()
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`ErrorType::AttachmentStreamConsumed`]: crate::error::ErrorType::AttachmentStreamConsumed
+#[derive(Clone)]
+pub struct AttachmentStream {
+    length: u64,
+    stream: Arc<Mutex<Option<BoxedStream>>>,
+}
+
+impl AttachmentStream {
+    /// Create a streamed file from an async reader producing exactly `length`
+    /// bytes.
+    pub fn from_reader(reader: impl AsyncRead + Send + Sync + 'static, length: u64) -> Self {
+        Self::from_boxed(
+            Box::pin(ReaderStream {
+                buffer: vec![0; READ_BUFFER_SIZE].into_boxed_slice(),
+                reader: Some(Box::pin(reader)),
+            }),
+            length,
+        )
+    }
+
+    /// Create a streamed file from a stream of chunks producing exactly
+    /// `length` bytes.
+    pub fn from_stream<S, B, E>(stream: S, length: u64) -> Self
+    where
+        S: Stream<Item = Result<B, E>> + Send + Sync + 'static,
+        B: Into<Bytes>,
+        E: Into<Box<dyn Error + Send + Sync>>,
+    {
+        let stream = stream.map(|chunk| chunk.map(Into::into).map_err(Into::into));
+
+        Self::from_boxed(Box::pin(stream), length)
+    }
+
+    /// Number of bytes the stream produces.
+    pub const fn length(&self) -> u64 {
+        self.length
+    }
+
+    fn from_boxed(stream: BoxedStream, length: u64) -> Self {
+        Self {
+            length,
+            stream: Arc::new(Mutex::new(Some(stream))),
+        }
+    }
+
+    /// Take the stream out, if it hasn't already been taken.
+    pub(crate) fn take(&self) -> Option<BoxedStream> {
+        self.stream
+            .lock()
+            .expect("attachment stream poisoned")
+            .take()
+    }
+}
+
+impl Debug for AttachmentStream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("AttachmentStream")
+            .field("length", &self.length)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Stream reading chunks from an [`AsyncRead`]er until it's exhausted.
+struct ReaderStream<R> {
+    buffer: Box<[u8]>,
+    /// Reader, or `None` if it has been exhausted.
+    reader: Option<Pin<Box<R>>>,
+}
+
+impl<R: AsyncRead> Stream for ReaderStream<R> {
+    type Item = Result<Bytes, Box<dyn Error + Send + Sync>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        let reader = match this.reader.as_mut() {
+            Some(reader) => reader,
+            None => return Poll::Ready(None),
+        };
+
+        let mut buffer = ReadBuf::new(&mut this.buffer);
+
+        match reader.as_mut().poll_read(cx, &mut buffer) {
+            Poll::Ready(Ok(())) if buffer.filled().is_empty() => {
+                this.reader = None;
+
+                Poll::Ready(None)
+            }
+            Poll::Ready(Ok(())) => Poll::Ready(Some(Ok(Bytes::copy_from_slice(buffer.filled())))),
+            Poll::Ready(Err(source)) => {
+                this.reader = None;
+
+                Poll::Ready(Some(Err(Box::new(source))))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AttachmentFile, AttachmentStream};
+    use futures_util::stream::{self, StreamExt};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, io::Error as IoError};
+
+    assert_impl_all!(AttachmentFile<'_>: Clone, Debug, Send, Sync);
+    assert_impl_all!(AttachmentStream: Clone, Debug, Send, Sync);
+
+    static CONTENTS: [u8; 20_000] = [7; 20_000];
+
+    #[tokio::test]
+    async fn test_from_reader() {
+        let stream = AttachmentStream::from_reader(&CONTENTS[..], 20_000);
+        assert_eq!(20_000, stream.length());
+
+        let mut inner = stream.take().unwrap();
+        let mut read = Vec::new();
+
+        while let Some(chunk) = inner.next().await {
+            read.extend_from_slice(&chunk.unwrap());
+        }
+
+        assert_eq!(CONTENTS[..], read[..]);
+    }
+
+    #[tokio::test]
+    async fn test_take_once() {
+        let chunks = stream::iter([Ok::<_, IoError>(b"ab".as_ref()), Ok(b"c".as_ref())]);
+        let stream = AttachmentStream::from_stream(chunks, 3);
+        let clone = stream.clone();
+
+        let mut inner = clone.take().unwrap();
+        assert!(stream.take().is_none());
+
+        assert_eq!(b"ab".as_ref(), inner.next().await.unwrap().unwrap());
+        assert_eq!(b"c".as_ref(), inner.next().await.unwrap().unwrap());
+        assert!(inner.next().await.is_none());
+    }
+}
@@ -13,7 +13,7 @@ mod private {
     use crate::request::{
         channel::{
             invite::{CreateInvite, DeleteInvite},
-            message::{DeleteMessage, DeleteMessages},
+            message::{DeleteMessage, DeleteMessages, PurgeMessages},
             thread::UpdateThread,
             webhook::{
                 CreateWebhook, DeleteWebhook, DeleteWebhookMessage, UpdateWebhook,
@@ -45,6 +45,7 @@ mod private {
     impl<'a> Sealed for DeleteInvite<'a> {}
     impl<'a> Sealed for DeleteMessage<'a> {}
     impl<'a> Sealed for DeleteMessages<'a> {}
+    impl Sealed for PurgeMessages<'_> {}
     impl<'a> Sealed for UpdateChannel<'a> {}
     impl<'a> Sealed for CreateWebhook<'a> {}
     impl Sealed for DeleteWebhookMessage<'_> {}
@@ -160,7 +161,7 @@ mod test {
     use crate::request::{
         channel::{
             invite::{CreateInvite, DeleteInvite},
-            message::{DeleteMessage, DeleteMessages},
+            message::{DeleteMessage, DeleteMessages, PurgeMessages},
             webhook::{CreateWebhook, DeleteWebhook, UpdateWebhook},
             CreatePin, DeleteChannel, DeleteChannelPermissionConfigured, DeletePin, UpdateChannel,
             UpdateChannelPermissionConfigured,
@@ -184,6 +185,7 @@ mod test {
     assert_impl_all!(DeleteInvite<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteMessage<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteMessages<'_>: AuditLogReason<'static>);
+    assert_impl_all!(PurgeMessages<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateChannel<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateWebhook<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteWebhook<'_>: AuditLogReason<'static>);
@@ -215,6 +215,12 @@ impl Request {
         self.headers.as_ref()
     }
 
+    /// Mutable headers to set in the request, inserting an empty map if there
+    /// are none.
+    pub fn headers_mut(&mut self) -> &mut HeaderMap<HeaderValue> {
+        self.headers.get_or_insert_with(HeaderMap::new)
+    }
+
     /// Method when sending the request.
     pub const fn method(&self) -> Method {
         self.method
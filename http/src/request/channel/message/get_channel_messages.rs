@@ -1,8 +1,8 @@
-use super::GetChannelMessagesConfigured;
+use super::{GetChannelMessagesConfigured, PurgeMessages};
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{Request, TryIntoRequest},
+    request::{paginate::Paginate, Request, TryIntoRequest},
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
@@ -135,6 +135,42 @@ impl<'a> GetChannelMessages<'a> {
             Err(source) => ResponseFuture::error(source),
         }
     }
+
+    /// Delete the most recent messages matching a filter.
+    ///
+    /// Messages are requested until the configured limit of messages matching
+    /// the filter are found, or 50 if unset, until the [maximum number of
+    /// messages] have been scanned, or until there are no more messages.
+    /// Refer to [`PurgeMessages`] for more information.
+    ///
+    /// [maximum number of messages]: PurgeMessages::max_scanned
+    pub fn purge(self, filter: impl FnMut(&Message) -> bool + Send + 'a) -> PurgeMessages<'a> {
+        GetChannelMessagesConfigured::new(
+            self.http,
+            self.channel_id,
+            None,
+            None,
+            None,
+            self.fields.limit,
+        )
+        .purge(filter)
+    }
+
+    /// Stream the messages, automatically requesting pages as needed.
+    ///
+    /// Messages are paginated from the most recent message in descending
+    /// order. Pages are of the configured limit, or 100 messages if unset.
+    pub fn stream(self) -> Paginate<'a, Message> {
+        GetChannelMessagesConfigured::new(
+            self.http,
+            self.channel_id,
+            None,
+            None,
+            None,
+            self.fields.limit,
+        )
+        .stream()
+    }
 }
 
 impl TryIntoRequest for GetChannelMessages<'_> {
@@ -1,7 +1,11 @@
+use super::PurgeMessages;
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{Request, TryIntoRequest},
+    request::{
+        paginate::{self, Direction, Paginate},
+        Request, TryIntoRequest,
+    },
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
@@ -87,6 +91,76 @@ impl<'a> GetChannelMessagesConfigured<'a> {
             Err(source) => ResponseFuture::error(source),
         }
     }
+
+    /// Delete the messages matching a filter.
+    ///
+    /// Messages are requested in the same order as [`stream`] until the
+    /// configured limit of messages matching the filter are found, or 50 if
+    /// unset, until the [maximum number of messages] have been scanned, or
+    /// until there are no more messages. Refer to [`PurgeMessages`] for more
+    /// information.
+    ///
+    /// [maximum number of messages]: PurgeMessages::max_scanned
+    ///
+    /// [`stream`]: Self::stream
+    pub fn purge(mut self, filter: impl FnMut(&Message) -> bool + Send + 'a) -> PurgeMessages<'a> {
+        let channel_id = self.channel_id;
+        let count = self
+            .fields
+            .limit
+            .map_or(50, |limit| usize::try_from(limit).unwrap_or(usize::MAX));
+        let http = self.http;
+
+        // Request full pages, as most messages may not match the filter.
+        self.fields.limit = Some(100);
+
+        PurgeMessages::filtered(http, channel_id, self.stream(), count, filter)
+    }
+
+    /// Stream the messages, automatically requesting pages as needed.
+    ///
+    /// Messages are paginated from the configured message: newer messages in
+    /// ascending order if configured with `after`, and older messages in
+    /// descending order if configured with `before`. Messages around a message
+    /// can't be paginated, so if configured with `around` only the single page
+    /// around the message is yielded.
+    ///
+    /// Pages are of the configured limit, or 100 messages if unset.
+    pub fn stream(self) -> Paginate<'a, Message> {
+        let channel_id = self.channel_id.get();
+        let http = self.http;
+        let limit = self.fields.limit.unwrap_or(100);
+
+        let (direction, cursor) = match (self.after, self.around, self.before) {
+            (Some(after), _, _) => (Direction::After, Some(after)),
+            (_, Some(around), _) => (Direction::Single, Some(around)),
+            (_, _, before) => (Direction::Before, before),
+        };
+
+        Paginate::new(
+            direction,
+            cursor.map(Id::get),
+            limit,
+            |message| message.id.get(),
+            move |cursor| {
+                let (after, around, before) = match direction {
+                    Direction::After => (cursor, None, None),
+                    Direction::Before => (None, None, cursor),
+                    Direction::Single => (None, cursor, None),
+                };
+
+                let request = Request::from_route(&Route::GetMessages {
+                    after,
+                    around,
+                    before,
+                    channel_id,
+                    limit: Some(limit),
+                });
+
+                Box::pin(paginate::list(http.request(request)))
+            },
+        )
+    }
 }
 
 impl TryIntoRequest for GetChannelMessagesConfigured<'_> {
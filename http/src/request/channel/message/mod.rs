@@ -7,11 +7,18 @@ mod delete_messages;
 mod get_channel_messages;
 mod get_channel_messages_configured;
 mod get_message;
+mod purge_messages;
 
 pub use self::{
-    create_message::CreateMessage, crosspost_message::CrosspostMessage,
-    delete_message::DeleteMessage, delete_messages::DeleteMessages,
+    create_message::CreateMessage,
+    crosspost_message::CrosspostMessage,
+    delete_message::DeleteMessage,
+    delete_messages::DeleteMessages,
     get_channel_messages::GetChannelMessages,
-    get_channel_messages_configured::GetChannelMessagesConfigured, get_message::GetMessage,
+    get_channel_messages_configured::GetChannelMessagesConfigured,
+    get_message::GetMessage,
+    purge_messages::{
+        PurgeError, PurgeErrorType, PurgeFuture, PurgeMessages, PurgeOutcome, PurgeReport,
+    },
     update_message::UpdateMessage,
 };
@@ -0,0 +1,685 @@
+use crate::{
+    api_error::{ApiError, ErrorCode},
+    client::Client,
+    error::{Error, ErrorType},
+    request::{
+        paginate::{Paginate, PaginateError, PaginateErrorType, DISCORD_EPOCH},
+        AuditLogReason, AuditLogReasonError,
+    },
+};
+use futures_util::StreamExt;
+use std::{
+    collections::HashSet,
+    convert::TryFrom,
+    error::Error as StdError,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    future::Future,
+    mem,
+    pin::Pin,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use twilight_model::{
+    channel::Message,
+    id::{
+        marker::{ChannelMarker, MessageMarker},
+        Id,
+    },
+};
+
+/// Maximum number of messages that can be deleted in one bulk delete.
+const BULK_DELETE_MAX: usize = 100;
+
+/// Maximum age in milliseconds of messages that can be bulk deleted, with a
+/// minute of leeway for clock skew and time spent deleting other messages.
+const BULK_DELETE_MAX_AGE: u64 = 14 * 24 * 60 * 60 * 1000 - 60 * 1000;
+
+/// Default maximum number of messages scanned by a filtered purge.
+const MAX_SCANNED_DEFAULT: usize = 1000;
+
+/// Future resolving to the report of a purge.
+pub type PurgeFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<PurgeReport, PurgeError>> + Send + 'a>>;
+
+/// Filter deciding whether to delete a message.
+type Filter<'a> = Box<dyn FnMut(&Message) -> bool + Send + 'a>;
+
+/// Retrieving or deleting the messages to purge failed.
+#[derive(Debug)]
+pub struct PurgeError {
+    kind: PurgeErrorType,
+    source: Option<Box<dyn StdError + Send + Sync>>,
+}
+
+impl PurgeError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &PurgeErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn StdError + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (PurgeErrorType, Option<Box<dyn StdError + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    fn deleting(report: PurgeReport, source: Error) -> Self {
+        Self {
+            kind: PurgeErrorType::Deleting { report },
+            source: Some(Box::new(source)),
+        }
+    }
+
+    fn paginating(source: PaginateError) -> Self {
+        let (kind, source) = source.into_parts();
+
+        let kind = match kind {
+            PaginateErrorType::Deserializing => PurgeErrorType::Deserializing,
+            PaginateErrorType::Requesting => PurgeErrorType::Requesting,
+        };
+
+        Self { kind, source }
+    }
+}
+
+impl Display for PurgeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            PurgeErrorType::Deleting { .. } => f.write_str("failed to delete messages"),
+            PurgeErrorType::Deserializing => f.write_str("failed to deserialize messages"),
+            PurgeErrorType::Requesting => f.write_str("failed to request messages"),
+        }
+    }
+}
+
+impl StdError for PurgeError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn StdError + 'static))
+    }
+}
+
+/// Type of [`PurgeError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PurgeErrorType {
+    /// Deleting messages failed for a reason that isn't specific to a
+    /// message, such as missing permissions or being ratelimited, so the
+    /// purge was stopped.
+    ///
+    /// The source is an [`Error`] and may be inspected for the response.
+    ///
+    /// [`Error`]: crate::Error
+    Deleting {
+        /// Outcomes of the messages processed before the purge was stopped.
+        report: PurgeReport,
+    },
+    /// Response body of the messages could not be deserialized.
+    Deserializing,
+    /// Request for the messages failed.
+    ///
+    /// The source is an [`Error`] and may be inspected for the response.
+    ///
+    /// [`Error`]: crate::Error
+    Requesting,
+}
+
+/// Outcome of deleting a message in a purge.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PurgeOutcome {
+    /// Message was deleted as part of a bulk delete.
+    BulkDeleted,
+    /// Message was deleted on its own.
+    Deleted,
+    /// Deleting the message failed for a reason specific to the message, such
+    /// as it not existing.
+    Failed(Error),
+}
+
+impl PurgeOutcome {
+    /// Whether the message was deleted.
+    pub const fn is_deleted(&self) -> bool {
+        matches!(self, Self::BulkDeleted | Self::Deleted)
+    }
+}
+
+/// Report of the outcome of deleting each message in a purge.
+///
+/// Outcomes are in the order the messages were provided, or returned by the
+/// API for filtered purges.
+#[derive(Debug, Default)]
+pub struct PurgeReport {
+    outcomes: Vec<(Id<MessageMarker>, PurgeOutcome)>,
+    scan_limited: bool,
+    scanned: usize,
+}
+
+impl PurgeReport {
+    /// IDs of the messages that were deleted.
+    pub fn deleted(&self) -> impl Iterator<Item = Id<MessageMarker>> + '_ {
+        self.outcomes
+            .iter()
+            .filter(|(_, outcome)| outcome.is_deleted())
+            .map(|(id, _)| *id)
+    }
+
+    /// IDs of the messages that failed to be deleted and their errors.
+    pub fn failed(&self) -> impl Iterator<Item = (Id<MessageMarker>, &Error)> + '_ {
+        self.outcomes
+            .iter()
+            .filter_map(|(id, outcome)| match outcome {
+                PurgeOutcome::Failed(error) => Some((*id, error)),
+                _ => None,
+            })
+    }
+
+    /// Whether a filtered purge stopped scanning messages because it reached
+    /// its [maximum number of scanned messages] before finding enough
+    /// messages matching the filter.
+    ///
+    /// [maximum number of scanned messages]: PurgeMessages::max_scanned
+    pub const fn is_scan_limited(&self) -> bool {
+        self.scan_limited
+    }
+
+    /// Number of messages scanned by a filtered purge, or 0 for a purge of
+    /// messages by their IDs.
+    pub const fn scanned(&self) -> usize {
+        self.scanned
+    }
+
+    /// Whether every message was deleted.
+    pub fn is_complete(&self) -> bool {
+        self.outcomes
+            .iter()
+            .all(|(_, outcome)| outcome.is_deleted())
+    }
+
+    /// Outcomes of each message.
+    pub fn outcomes(&self) -> &[(Id<MessageMarker>, PurgeOutcome)] {
+        &self.outcomes
+    }
+
+    /// Consume the report, returning the outcomes of each message.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_outcomes(self) -> Vec<(Id<MessageMarker>, PurgeOutcome)> {
+        self.outcomes
+    }
+}
+
+/// Messages to purge.
+enum Source<'a> {
+    /// Purge messages by their IDs.
+    Ids(&'a [Id<MessageMarker>]),
+    /// Purge up to a number of the messages of a stream that match a filter.
+    Filtered {
+        count: usize,
+        filter: Filter<'a>,
+        max_scanned: usize,
+        messages: Paginate<'a, Message>,
+    },
+}
+
+/// Delete any number of messages in a channel, regardless of their age.
+///
+/// Messages recent enough to be bulk deleted are deleted in chunks of up to
+/// 100 via [`DeleteMessages`], while older messages and lone messages are
+/// deleted one at a time via [`DeleteMessage`]. If a bulk delete fails
+/// because of one of its messages then its messages are deleted one at a time
+/// instead, so that each message's outcome is known. Requests are sent one
+/// after another through the client, and so are subject to its ratelimiter.
+///
+/// Deleting messages doesn't stop when a message fails to be deleted, such as
+/// when it doesn't exist; the outcome of each message is returned in a
+/// [`PurgeReport`]. Failures that aren't specific to a message, such as
+/// missing permissions or being ratelimited, stop the purge and are returned
+/// as an error of type [`PurgeErrorType::Deleting`].
+///
+/// When purging messages matching a filter, messages are requested page by
+/// page until the limit of messages matching the filter is found, the
+/// [maximum number of messages] have been scanned, or there are no more
+/// messages. The report says whether scanning was stopped by the maximum.
+///
+/// # Examples
+///
+/// Delete the last 100 messages of a user in a channel:
+///
+/// ```no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+/// let user_id = Id::new(2);
+///
+/// let report = client
+///     .channel_messages(Id::new(1))
+///     .limit(100)?
+///     .purge(|message| message.author.id == user_id)
+///     .exec()
+///     .await?;
+///
+/// println!("deleted {} messages", report.deleted().count());
+///
+/// for (message_id, error) in report.failed() {
+///     println!("failed to delete {}: {}", message_id, error);
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`DeleteMessage`]: super::DeleteMessage
+/// [`DeleteMessages`]: super::DeleteMessages
+/// [maximum number of messages]: Self::max_scanned
+#[must_use = "requests must be configured and executed"]
+pub struct PurgeMessages<'a> {
+    channel_id: Id<ChannelMarker>,
+    http: &'a Client,
+    reason: Option<&'a str>,
+    source: Source<'a>,
+}
+
+impl<'a> PurgeMessages<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        channel_id: Id<ChannelMarker>,
+        message_ids: &'a [Id<MessageMarker>],
+    ) -> Self {
+        Self {
+            channel_id,
+            http,
+            reason: None,
+            source: Source::Ids(message_ids),
+        }
+    }
+
+    pub(crate) fn filtered(
+        http: &'a Client,
+        channel_id: Id<ChannelMarker>,
+        messages: Paginate<'a, Message>,
+        count: usize,
+        filter: impl FnMut(&Message) -> bool + Send + 'a,
+    ) -> Self {
+        Self {
+            channel_id,
+            http,
+            reason: None,
+            source: Source::Filtered {
+                count,
+                filter: Box::new(filter),
+                max_scanned: MAX_SCANNED_DEFAULT,
+                messages,
+            },
+        }
+    }
+
+    /// Set the maximum number of messages to scan when purging messages
+    /// matching a filter.
+    ///
+    /// Defaults to 1000. Has no effect when purging messages by their IDs.
+    pub fn max_scanned(mut self, max: usize) -> Self {
+        if let Source::Filtered { max_scanned, .. } = &mut self.source {
+            *max_scanned = max;
+        }
+
+        self
+    }
+
+    /// Execute the purge, returning a future resolving to a report of the
+    /// outcome of each message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Deleting`] if deleting messages failed for
+    /// a reason that isn't specific to a message.
+    ///
+    /// Returns an error of type [`Requesting`] if requesting the messages of
+    /// a filtered purge failed.
+    ///
+    /// Returns an error of type [`Deserializing`] if the messages of a
+    /// filtered purge could not be deserialized.
+    ///
+    /// [`Deleting`]: PurgeErrorType::Deleting
+    /// [`Deserializing`]: PurgeErrorType::Deserializing
+    /// [`Requesting`]: PurgeErrorType::Requesting
+    pub fn exec(self) -> PurgeFuture<'a> {
+        Box::pin(async move {
+            let mut report = PurgeReport::default();
+
+            let message_ids = match self.source {
+                Source::Ids(message_ids) => message_ids.to_vec(),
+                Source::Filtered {
+                    count,
+                    mut filter,
+                    max_scanned,
+                    mut messages,
+                } => {
+                    let mut message_ids = Vec::with_capacity(count.min(BULK_DELETE_MAX));
+
+                    while message_ids.len() < count {
+                        if report.scanned >= max_scanned {
+                            report.scan_limited = true;
+
+                            break;
+                        }
+
+                        let message = match messages.next().await {
+                            Some(Ok(message)) => message,
+                            Some(Err(source)) => return Err(PurgeError::paginating(source)),
+                            None => break,
+                        };
+
+                        report.scanned += 1;
+
+                        if filter(&message) {
+                            message_ids.push(message.id);
+                        }
+                    }
+
+                    message_ids
+                }
+            };
+
+            let purge = Purge {
+                channel_id: self.channel_id,
+                http: self.http,
+                reason: self.reason,
+            };
+
+            purge.run(&mut report, message_ids).await?;
+
+            Ok(report)
+        })
+    }
+}
+
+impl<'a> AuditLogReason<'a> for PurgeMessages<'a> {
+    fn reason(mut self, reason: &'a str) -> Result<Self, AuditLogReasonError> {
+        self.reason.replace(AuditLogReasonError::validate(reason)?);
+
+        Ok(self)
+    }
+}
+
+/// State of a purge being run.
+struct Purge<'a> {
+    channel_id: Id<ChannelMarker>,
+    http: &'a Client,
+    reason: Option<&'a str>,
+}
+
+impl Purge<'_> {
+    async fn run(
+        &self,
+        report: &mut PurgeReport,
+        mut message_ids: Vec<Id<MessageMarker>>,
+    ) -> Result<(), PurgeError> {
+        // Deduplicate while keeping the order of the messages.
+        let mut seen = HashSet::with_capacity(message_ids.len());
+        message_ids.retain(|id| seen.insert(*id));
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| {
+                u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+            });
+        let (recent, old): (Vec<_>, Vec<_>) = message_ids
+            .into_iter()
+            .partition(|id| bulk_deletable(*id, now));
+
+        report.outcomes.reserve(recent.len() + old.len());
+
+        for chunk in recent.chunks(BULK_DELETE_MAX) {
+            if chunk.len() == 1 {
+                self.delete(report, chunk[0]).await?;
+
+                continue;
+            }
+
+            let mut request = self.http.delete_messages(self.channel_id, chunk);
+
+            if let Some(reason) = self.reason {
+                request = request.reason(reason).expect("reason already validated");
+            }
+
+            match request.exec().await {
+                Ok(_) => {
+                    report
+                        .outcomes
+                        .extend(chunk.iter().map(|id| (*id, PurgeOutcome::BulkDeleted)));
+
+                    continue;
+                }
+                Err(source) if !is_message_error(&source) => {
+                    return Err(PurgeError::deleting(mem::take(report), source))
+                }
+                Err(_) => {}
+            }
+
+            // Delete the messages one at a time to find out which ones failed.
+            for id in chunk {
+                self.delete(report, *id).await?;
+            }
+        }
+
+        for id in old {
+            self.delete(report, id).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Delete a message on its own, adding its outcome to the report.
+    async fn delete(
+        &self,
+        report: &mut PurgeReport,
+        message_id: Id<MessageMarker>,
+    ) -> Result<(), PurgeError> {
+        let mut request = self.http.delete_message(self.channel_id, message_id);
+
+        if let Some(reason) = self.reason {
+            request = request.reason(reason).expect("reason already validated");
+        }
+
+        let outcome = match request.exec().await {
+            Ok(_) => PurgeOutcome::Deleted,
+            Err(source) if is_message_error(&source) => PurgeOutcome::Failed(source),
+            Err(source) => return Err(PurgeError::deleting(mem::take(report), source)),
+        };
+
+        report.outcomes.push((message_id, outcome));
+
+        Ok(())
+    }
+}
+
+/// Whether an error deleting messages is caused by one of the messages, such
+/// as it not existing, rather than affecting every message, such as missing
+/// permissions or being ratelimited.
+fn is_message_error(error: &Error) -> bool {
+    let code = match error.kind() {
+        ErrorType::Response {
+            error: ApiError::General(general),
+            ..
+        } => general.error_code(),
+        _ => return false,
+    };
+
+    matches!(
+        code,
+        ErrorCode::BulkDeleteMessageTooOld | ErrorCode::SystemMessage | ErrorCode::UnknownMessage
+    )
+}
+
+/// Whether a message is recent enough to be bulk deleted, given the current
+/// time in milliseconds since the Unix epoch.
+const fn bulk_deletable(message_id: Id<MessageMarker>, now: u64) -> bool {
+    let created = (message_id.get() >> 22) + DISCORD_EPOCH as u64;
+
+    now.saturating_sub(created) < BULK_DELETE_MAX_AGE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        bulk_deletable, PurgeError, PurgeErrorType, PurgeMessages, PurgeOutcome, PurgeReport,
+        BULK_DELETE_MAX_AGE, DISCORD_EPOCH,
+    };
+    use crate::{
+        client::Client,
+        test::{RecordedRequest, Recorder},
+    };
+    use hyper::{Method, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(PurgeErrorType: Debug, Send, Sync);
+    assert_impl_all!(PurgeError: Error, Send, Sync);
+    assert_impl_all!(PurgeMessages<'_>: Send);
+    assert_impl_all!(PurgeOutcome: Debug, Send, Sync);
+    assert_impl_all!(PurgeReport: Debug, Default, Send, Sync);
+
+    /// Create the JSON of a message by a user.
+    fn message(id: u64, author_id: u64) -> String {
+        format!(
+            r#"{{"attachments":[],"author":{{"avatar":null,"discriminator":"0001","id":"{}","username":"user"}},"channel_id":"1","content":"","edited_timestamp":null,"embeds":[],"id":"{}","mention_everyone":false,"mention_roles":[],"mentions":[],"pinned":false,"timestamp":"2021-01-01T00:00:00.000000+00:00","tts":false,"type":0}}"#,
+            author_id, id,
+        )
+    }
+
+    /// Respond to a request for the messages of a channel with messages
+    /// alternating between two authors, and to deletions with no content.
+    fn respond(request: &RecordedRequest) -> (StatusCode, String) {
+        if request.method == Method::DELETE {
+            return (StatusCode::NO_CONTENT, String::new());
+        }
+
+        let messages = (1..=5)
+            .rev()
+            .map(|id| message(id, id % 2 + 1))
+            .collect::<Vec<_>>();
+
+        (StatusCode::OK, format!("[{}]", messages.join(",")))
+    }
+
+    #[test]
+    fn test_bulk_deletable() {
+        let created = 1_600_000_000_000;
+        let id = Id::new((created - DISCORD_EPOCH as u64) << 22);
+
+        assert!(bulk_deletable(id, created));
+        assert!(bulk_deletable(id, created + BULK_DELETE_MAX_AGE - 1));
+        assert!(!bulk_deletable(id, created + BULK_DELETE_MAX_AGE));
+        // Messages from the future, relative to a skewed clock.
+        assert!(bulk_deletable(id, created - 1000));
+    }
+
+    #[tokio::test]
+    async fn test_filtered() -> Result<(), Box<dyn Error>> {
+        let recorder = Recorder::new(respond);
+        let requests = recorder.requests();
+        let client = Client::builder()
+            .ratelimiter(None)
+            .transport(Box::new(recorder))
+            .build();
+
+        let report = client
+            .channel_messages(Id::new(1))
+            .limit(2)?
+            .purge(|message| message.author.id == Id::new(2))
+            .exec()
+            .await?;
+
+        // Scanning stops once enough messages match the filter.
+        assert_eq!(3, report.scanned());
+        assert!(!report.is_scan_limited());
+        assert!(report.is_complete());
+        assert_eq!(
+            [Id::new(5), Id::new(3)].as_ref(),
+            report.deleted().collect::<Vec<_>>()
+        );
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(3, requests.len());
+        assert_eq!(Method::GET, requests[0].method);
+        assert_eq!("/api/v9/channels/1/messages/5", requests[1].path());
+        assert_eq!("/api/v9/channels/1/messages/3", requests[2].path());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_filtered_max_scanned() -> Result<(), Box<dyn Error>> {
+        let recorder = Recorder::new(respond);
+        let requests = recorder.requests();
+        let client = Client::builder()
+            .ratelimiter(None)
+            .transport(Box::new(recorder))
+            .build();
+
+        let report = client
+            .channel_messages(Id::new(1))
+            .purge(|message| message.author.id == Id::new(2))
+            .max_scanned(2)
+            .exec()
+            .await?;
+
+        assert_eq!(2, report.scanned());
+        assert!(report.is_scan_limited());
+        assert_eq!([Id::new(5)].as_ref(), report.deleted().collect::<Vec<_>>());
+        assert_eq!(2, requests.lock().unwrap().len());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stop_on_non_message_error() {
+        let recorder = Recorder::new(|request| match request.path() {
+            "/api/v9/channels/1/messages/4" => (
+                StatusCode::NOT_FOUND,
+                r#"{"code":10008,"message":"Unknown Message"}"#.to_owned(),
+            ),
+            "/api/v9/channels/1/messages/2" => (
+                StatusCode::FORBIDDEN,
+                r#"{"code":50013,"message":"Missing Permissions"}"#.to_owned(),
+            ),
+            _ => (StatusCode::NO_CONTENT, String::new()),
+        });
+        let requests = recorder.requests();
+        let client = Client::builder()
+            .ratelimiter(None)
+            .transport(Box::new(recorder))
+            .build();
+        let ids = [Id::new(4), Id::new(3), Id::new(2), Id::new(1)];
+
+        let error = client
+            .purge_messages(Id::new(1), &ids)
+            .exec()
+            .await
+            .unwrap_err();
+
+        let report = match error.into_parts() {
+            (PurgeErrorType::Deleting { report }, Some(_)) => report,
+            other => panic!("unexpected error: {:?}", other),
+        };
+
+        // The unknown message doesn't stop the purge, but missing permissions
+        // do, before the last message is deleted.
+        let outcomes = report.outcomes();
+        assert_eq!(2, outcomes.len());
+        assert_eq!(Id::new(4), outcomes[0].0);
+        assert!(matches!(outcomes[0].1, PurgeOutcome::Failed(_)));
+        assert_eq!(Id::new(3), outcomes[1].0);
+        assert!(matches!(outcomes[1].1, PurgeOutcome::Deleted));
+        assert_eq!(3, requests.lock().unwrap().len());
+    }
+}
@@ -2,7 +2,10 @@ use super::RequestReactionType;
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{Request, TryIntoRequest},
+    request::{
+        paginate::{self, Direction, Paginate},
+        Request, TryIntoRequest,
+    },
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
@@ -93,6 +96,37 @@ impl<'a> GetReactions<'a> {
             Err(source) => ResponseFuture::error(source),
         }
     }
+
+    /// Stream the users, automatically requesting pages as needed.
+    ///
+    /// Users are paginated in ascending order of their IDs, from the
+    /// configured user ID if set. Pages are of the configured limit, or 100
+    /// users if unset.
+    pub fn stream(self) -> Paginate<'a, User> {
+        let channel_id = self.channel_id.get();
+        let emoji = self.emoji;
+        let http = self.http;
+        let limit = self.fields.limit.unwrap_or(100);
+        let message_id = self.message_id.get();
+
+        Paginate::new(
+            Direction::After,
+            self.fields.after.map(Id::get),
+            limit,
+            |user| user.id.get(),
+            move |after| {
+                let request = Request::from_route(&Route::GetReactionUsers {
+                    after,
+                    channel_id,
+                    emoji,
+                    limit: Some(limit),
+                    message_id,
+                });
+
+                Box::pin(paginate::list(http.request(request)))
+            },
+        )
+    }
 }
 
 impl TryIntoRequest for GetReactions<'_> {
@@ -308,11 +308,7 @@ impl<'a> ExecuteWebhook<'a> {
 
             if !self.attachments.is_empty() {
                 for (index, attachment) in self.attachments.iter().enumerate() {
-                    form.attach(
-                        index as u64,
-                        attachment.filename.as_bytes(),
-                        attachment.file,
-                    );
+                    form.attach_file(index as u64, attachment)?;
                     self.fields.attachments.push(PartialAttachment {
                         id: index as u64,
                         filename: attachment.filename,
@@ -1,12 +1,15 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{Request, TryIntoRequest},
+    request::{
+        paginate::{self, Direction, Paginate},
+        Request, TryIntoRequest,
+    },
     response::ResponseFuture,
     routing::Route,
 };
 use twilight_model::{
-    guild::audit_log::{AuditLog, AuditLogEventType},
+    guild::audit_log::{AuditLog, AuditLogEntry, AuditLogEventType},
     id::{
         marker::{GuildMarker, UserMarker},
         Id,
@@ -129,6 +132,41 @@ impl<'a> GetAuditLog<'a> {
             Err(source) => ResponseFuture::error(source),
         }
     }
+
+    /// Stream the audit log entries, automatically requesting pages as needed.
+    ///
+    /// Entries are paginated in descending order of their IDs, from the
+    /// configured entry if set. Pages are of the configured limit, or 100
+    /// entries if unset.
+    ///
+    /// Only the entries of the audit log are yielded; the users, webhooks, and
+    /// other resources they reference are not.
+    pub fn stream(self) -> Paginate<'a, AuditLogEntry> {
+        let action_type = self.fields.action_type.map(|x| x as u64);
+        let guild_id = self.guild_id.get();
+        let http = self.http;
+        let limit = self.fields.limit.unwrap_or(100);
+        let user_id = self.fields.user_id.map(Id::get);
+
+        Paginate::new(
+            Direction::Before,
+            self.fields.before,
+            limit,
+            |entry| entry.id.get(),
+            move |before| {
+                let request = Request::from_route(&Route::GetAuditLogs {
+                    action_type,
+                    before,
+                    guild_id,
+                    limit: Some(limit),
+                    user_id,
+                });
+                let future = paginate::model::<AuditLog>(http.request(request));
+
+                Box::pin(async move { future.await.map(|audit_log| audit_log.entries) })
+            },
+        )
+    }
 }
 
 impl TryIntoRequest for GetAuditLog<'_> {
@@ -1,13 +1,19 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{Request, TryIntoRequest},
+    request::{
+        paginate::{self, Direction, Paginate},
+        Request, TryIntoRequest,
+    },
     response::{marker::MemberListBody, ResponseFuture},
     routing::Route,
 };
-use twilight_model::id::{
-    marker::{GuildMarker, UserMarker},
-    Id,
+use twilight_model::{
+    guild::member::Member,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
 };
 use twilight_validate::request::{
     get_guild_members_limit as validate_get_guild_members_limit, ValidationError,
@@ -112,6 +118,38 @@ impl<'a> GetGuildMembers<'a> {
             Err(source) => ResponseFuture::error(source),
         }
     }
+
+    /// Stream the members, automatically requesting pages as needed.
+    ///
+    /// Members are paginated in ascending order of their user IDs, from the
+    /// configured user ID if set. Pages are of the configured limit, or 1000
+    /// members if unset.
+    pub fn stream(self) -> Paginate<'a, Member> {
+        let guild_id = self.guild_id;
+        let http = self.http;
+        let limit = self.fields.limit.unwrap_or(1000);
+        let presences = self.fields.presences;
+
+        Paginate::new(
+            Direction::After,
+            self.fields.after.map(Id::get),
+            limit,
+            |member| member.user.id.get(),
+            move |after| {
+                let request = Request::from_route(&Route::GetGuildMembers {
+                    after,
+                    guild_id: guild_id.get(),
+                    limit: Some(limit),
+                    presences,
+                });
+
+                let mut future = http.request(request);
+                future.set_guild_id(guild_id);
+
+                Box::pin(paginate::members(future))
+            },
+        )
+    }
 }
 
 impl TryIntoRequest for GetGuildMembers<'_> {
@@ -1,7 +1,10 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{multipart::Form, AuditLogReason, AuditLogReasonError, Request, TryIntoRequest},
+    request::{
+        attachment::AttachmentData, multipart::Form, AttachmentStream, AuditLogReason,
+        AuditLogReasonError, Request, TryIntoRequest,
+    },
     response::ResponseFuture,
     routing::Route,
 };
@@ -16,7 +19,7 @@ use twilight_validate::sticker::{
 
 struct CreateGuildStickerFields<'a> {
     description: &'a str,
-    file: &'a [u8],
+    file: AttachmentData<'a>,
     name: &'a str,
     tags: &'a str,
 }
@@ -65,6 +68,42 @@ impl<'a> CreateGuildSticker<'a> {
         description: &'a str,
         tags: &'a str,
         file: &'a [u8],
+    ) -> Result<Self, StickerValidationError> {
+        Self::with_file(
+            http,
+            guild_id,
+            name,
+            description,
+            tags,
+            AttachmentData::Bytes(file),
+        )
+    }
+
+    pub(crate) fn new_stream(
+        http: &'a Client,
+        guild_id: Id<GuildMarker>,
+        name: &'a str,
+        description: &'a str,
+        tags: &'a str,
+        file: AttachmentStream,
+    ) -> Result<Self, StickerValidationError> {
+        Self::with_file(
+            http,
+            guild_id,
+            name,
+            description,
+            tags,
+            AttachmentData::Stream(file),
+        )
+    }
+
+    fn with_file(
+        http: &'a Client,
+        guild_id: Id<GuildMarker>,
+        name: &'a str,
+        description: &'a str,
+        tags: &'a str,
+        file: AttachmentData<'a>,
     ) -> Result<Self, StickerValidationError> {
         validate_description(description)?;
 
@@ -120,7 +159,14 @@ impl TryIntoRequest for CreateGuildSticker<'_> {
 
         form.part("tags".as_bytes(), self.fields.tags.as_bytes());
 
-        form.part("file".as_bytes(), self.fields.file);
+        match &self.fields.file {
+            AttachmentData::Bytes(bytes) => {
+                form.part("file".as_bytes(), bytes);
+            }
+            AttachmentData::Stream(stream) => {
+                form.part_stream("file".as_bytes(), stream)?;
+            }
+        }
 
         request = request.form(form);
 
@@ -1,6 +1,8 @@
 pub mod application;
 pub mod channel;
 pub mod guild;
+pub mod oauth;
+pub mod paginate;
 pub mod scheduled_event;
 pub mod sticker;
 pub mod template;
@@ -17,7 +19,7 @@ mod multipart;
 mod try_into_request;
 
 pub use self::{
-    attachment::AttachmentFile,
+    attachment::{AttachmentFile, AttachmentStream},
     audit_reason::{AuditLogReason, AuditLogReasonError},
     base::{Request, RequestBuilder},
     get_gateway::GetGateway,
@@ -1,9 +1,21 @@
+use super::attachment::{AttachmentData, AttachmentFile, AttachmentStream, BoxedStream};
+use crate::error::{Error, ErrorType};
+use futures_util::stream::Stream;
+use hyper::body::{Body, Bytes};
 use rand::{distributions::Alphanumeric, Rng};
+use std::{
+    collections::VecDeque,
+    error::Error as StdError,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    pin::Pin,
+    task::{Context, Poll},
+};
 
-#[derive(Debug)]
 pub struct Form {
     boundary: [u8; 15],
     buffer: Vec<u8>,
+    /// Streamed files and the position in the buffer they're inserted at.
+    streams: Vec<FormStream>,
 }
 
 impl Form {
@@ -21,12 +33,90 @@ impl Form {
         self
     }
 
-    pub fn build(mut self) -> Vec<u8> {
-        self.buffer.extend(b"\r\n");
-        self.boundary();
-        self.buffer.extend(b"--");
+    /// Write the data needed to attach a multipart file whose contents are
+    /// streamed while sending the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::AttachmentStreamConsumed`] if the
+    /// stream has already been used by another request.
+    pub fn attach_stream(
+        &mut self,
+        id: u64,
+        filename: &[u8],
+        stream: &AttachmentStream,
+    ) -> Result<&mut Self, Error> {
+        self.start();
+        self.name_id(id);
+        self.filename(filename);
+        self.stream(stream)?;
+
+        Ok(self)
+    }
+
+    /// Attach an attachment file, whether it is in memory or streamed.
+    pub(crate) fn attach_file(
+        &mut self,
+        id: u64,
+        attachment: &AttachmentFile<'_>,
+    ) -> Result<&mut Self, Error> {
+        let filename = attachment.filename.as_bytes();
+
+        match &attachment.file {
+            AttachmentData::Bytes(bytes) => Ok(self.attach(id, filename, bytes)),
+            AttachmentData::Stream(stream) => self.attach_stream(id, filename, stream),
+        }
+    }
+
+    /// Build the body of the form.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`BuildingRequest`] if the form contains
+    /// streamed parts, which can't be read into memory. Use [`into_body`]
+    /// instead.
+    ///
+    /// [`BuildingRequest`]: ErrorType::BuildingRequest
+    /// [`into_body`]: Self::into_body
+    pub fn build(mut self) -> Result<Vec<u8>, Error> {
+        if !self.streams.is_empty() {
+            return Err(Error {
+                kind: ErrorType::BuildingRequest,
+                source: None,
+            });
+        }
+
+        self.finish();
+
+        Ok(self.buffer)
+    }
 
-        self.buffer
+    /// Build a streaming body of the form, returning the body and its length.
+    ///
+    /// Parts of the form that are in memory are sent as-is, while streamed
+    /// parts are sent chunk by chunk as they are read.
+    pub fn into_body(mut self) -> (Body, u64) {
+        self.finish();
+
+        let length = self
+            .streams
+            .iter()
+            .fold(self.buffer.len() as u64, |length, stream| {
+                length + stream.length
+            });
+
+        if self.streams.is_empty() {
+            return (Body::from(self.buffer), length);
+        }
+
+        let body = FormBody {
+            buffer: Bytes::from(self.buffer),
+            current: None,
+            position: 0,
+            streams: self.streams.into(),
+        };
+
+        (Body::wrap_stream(body), length)
     }
 
     pub fn content_type(&self) -> Vec<u8> {
@@ -57,6 +147,24 @@ impl Form {
         self
     }
 
+    /// Write a part whose contents are streamed while sending the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ErrorType::AttachmentStreamConsumed`] if the
+    /// stream has already been used by another request.
+    pub fn part_stream(
+        &mut self,
+        name: &[u8],
+        stream: &AttachmentStream,
+    ) -> Result<&mut Self, Error> {
+        self.start();
+        self.name(name);
+        self.stream(stream)?;
+
+        Ok(self)
+    }
+
     pub fn payload_json(&mut self, json: &[u8]) -> &mut Self {
         self.start();
         self.name(b"payload_json");
@@ -99,6 +207,38 @@ impl Form {
         self.buffer.extend(b"\r\n\r\n");
         self.buffer.extend(data);
     }
+
+    fn stream(&mut self, stream: &AttachmentStream) -> Result<(), Error> {
+        let inner = stream.take().ok_or(Error {
+            kind: ErrorType::AttachmentStreamConsumed,
+            source: None,
+        })?;
+
+        self.buffer.extend(b"\r\n\r\n");
+        self.streams.push(FormStream {
+            length: stream.length(),
+            offset: self.buffer.len(),
+            stream: inner,
+        });
+
+        Ok(())
+    }
+
+    fn finish(&mut self) {
+        self.buffer.extend(b"\r\n");
+        self.boundary();
+        self.buffer.extend(b"--");
+    }
+}
+
+impl Debug for Form {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Form")
+            .field("boundary", &self.boundary)
+            .field("buffer", &self.buffer)
+            .field("streams", &self.streams.len())
+            .finish()
+    }
 }
 
 impl Default for Form {
@@ -113,10 +253,93 @@ impl Default for Form {
         Self {
             boundary,
             buffer: Vec::new(),
+            streams: Vec::new(),
         }
     }
 }
 
+/// Streamed part of a form.
+struct FormStream {
+    /// Number of bytes the stream must produce.
+    length: u64,
+    /// Position in the form's buffer the stream is inserted at.
+    offset: usize,
+    stream: BoxedStream,
+}
+
+/// Stream of a form's body, interleaving its buffer with its streamed parts.
+struct FormBody {
+    buffer: Bytes,
+    /// Streamed part currently being sent, and the number of bytes it has
+    /// left to produce.
+    current: Option<(u64, BoxedStream)>,
+    /// Position in the buffer up to which it has been sent.
+    position: usize,
+    streams: VecDeque<FormStream>,
+}
+
+impl Stream for FormBody {
+    type Item = Result<Bytes, Box<dyn StdError + Send + Sync>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some((remaining, stream)) = self.current.as_mut() {
+                match stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(Ok(chunk))) => {
+                        if chunk.len() as u64 > *remaining {
+                            self.current = None;
+
+                            return Poll::Ready(Some(Err(Box::new(LengthMismatchError))));
+                        }
+
+                        *remaining -= chunk.len() as u64;
+
+                        return Poll::Ready(Some(Ok(chunk)));
+                    }
+                    Poll::Ready(Some(Err(source))) => return Poll::Ready(Some(Err(source))),
+                    Poll::Ready(None) if *remaining > 0 => {
+                        self.current = None;
+
+                        return Poll::Ready(Some(Err(Box::new(LengthMismatchError))));
+                    }
+                    Poll::Ready(None) => self.current = None,
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+
+            let end = self
+                .streams
+                .front()
+                .map_or(self.buffer.len(), |stream| stream.offset);
+
+            if self.position < end {
+                let chunk = self.buffer.slice(self.position..end);
+                self.position = end;
+
+                return Poll::Ready(Some(Ok(chunk)));
+            }
+
+            match self.streams.pop_front() {
+                Some(next) => self.current = Some((next.length, next.stream)),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// Streamed part produced a different number of bytes than it was created
+/// with.
+#[derive(Debug)]
+struct LengthMismatchError;
+
+impl Display for LengthMismatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("attachment stream length differs from the specified length")
+    }
+}
+
+impl StdError for LengthMismatchError {}
+
 /// Value of '0' in ascii
 const ASCII_NUMBER: u8 = 0x30;
 
@@ -161,7 +384,91 @@ fn push_digits(mut id: u64, buf: &mut Vec<u8>) {
 
 #[cfg(test)]
 mod tests {
-    use super::push_digits;
+    use super::{push_digits, Form};
+    use crate::{
+        error::ErrorType,
+        request::{AttachmentFile, AttachmentStream},
+    };
+    use futures_util::stream;
+    use std::{convert::Infallible, io::Error as IoError};
+
+    fn chunks(chunks: &'static [&'static [u8]], length: u64) -> AttachmentStream {
+        AttachmentStream::from_stream(
+            stream::iter(chunks.iter().copied().map(Ok::<_, Infallible>)),
+            length,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_into_body_streamed() -> Result<(), Box<dyn std::error::Error>> {
+        let mut buffered = Form::new();
+        let mut streamed = Form {
+            boundary: buffered.boundary,
+            ..Form::new()
+        };
+
+        buffered
+            .attach(0, b"one.txt", b"first file")
+            .part(b"name", b"value")
+            .attach(1, b"two.txt", b"second file")
+            .payload_json(b"{}");
+        streamed
+            .attach_file(
+                0,
+                &AttachmentFile::from_stream("one.txt", chunks(&[b"first", b" file"], 10)),
+            )?
+            .part(b"name", b"value")
+            .attach_file(1, &AttachmentFile::from_bytes("two.txt", b"second file"))?
+            .payload_json(b"{}");
+
+        let expected = buffered.build()?;
+        let (body, length) = streamed.into_body();
+        assert_eq!(expected.len() as u64, length);
+        assert_eq!(expected, hyper::body::to_bytes(body).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_into_body_length_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+        let mut form = Form::new();
+        form.part_stream(b"file", &chunks(&[b"too", b"long"], 5))?;
+        let (body, _) = form.into_body();
+        assert!(hyper::body::to_bytes(body).await.is_err());
+
+        let mut form = Form::new();
+        form.part_stream(b"file", &chunks(&[b"short"], 10))?;
+        let (body, _) = form.into_body();
+        assert!(hyper::body::to_bytes(body).await.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_consumed() {
+        let stream = AttachmentStream::from_stream(stream::empty::<Result<&[u8], IoError>>(), 0);
+        let attachment = AttachmentFile::from_stream("empty.txt", stream);
+
+        assert!(Form::new().attach_file(0, &attachment).is_ok());
+        assert!(matches!(
+            Form::new().attach_file(0, &attachment).unwrap_err().kind(),
+            ErrorType::AttachmentStreamConsumed
+        ));
+    }
+
+    #[test]
+    fn test_build_streamed() {
+        let stream = AttachmentStream::from_stream(stream::empty::<Result<&[u8], IoError>>(), 0);
+        let attachment = AttachmentFile::from_stream("empty.txt", stream);
+
+        let mut form = Form::new();
+        form.attach_file(0, &attachment).unwrap();
+
+        assert!(matches!(
+            form.build().unwrap_err().kind(),
+            ErrorType::BuildingRequest
+        ));
+    }
 
     #[test]
     fn test_push_digits() {
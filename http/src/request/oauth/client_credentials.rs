@@ -0,0 +1,97 @@
+use super::form_request;
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use twilight_model::{
+    id::{marker::ApplicationMarker, Id},
+    oauth::AccessToken,
+};
+
+/// Grant an access token for the application's owner via the client
+/// credentials flow.
+///
+/// Tokens granted via the client credentials flow have no refresh token; a
+/// new token is requested instead once it expires.
+#[must_use = "requests must be configured and executed"]
+pub struct ClientCredentials<'a> {
+    application_id: Id<ApplicationMarker>,
+    client_secret: &'a str,
+    http: &'a Client,
+    scopes: &'a [&'a str],
+}
+
+impl<'a> ClientCredentials<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        client_secret: &'a str,
+        scopes: &'a [&'a str],
+    ) -> Self {
+        Self {
+            application_id,
+            client_secret,
+            http,
+            scopes,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<AccessToken> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for ClientCredentials<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let client_id = self.application_id.to_string();
+        let scope = self.scopes.join(" ");
+
+        Ok(form_request(
+            &Route::CreateOauthToken,
+            &[
+                ("client_id", &client_id),
+                ("client_secret", self.client_secret),
+                ("grant_type", "client_credentials"),
+                ("scope", &scope),
+            ],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClientCredentials;
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let request = ClientCredentials::new(
+            &client,
+            Id::new(1),
+            "secret",
+            &["identify", "applications.commands.update"],
+        )
+        .try_into_request()?;
+
+        assert_eq!(
+            b"client_id=1&client_secret=secret&grant_type=client_credentials&scope=identify%20applications.commands.update".as_ref(),
+            request.body().unwrap()
+        );
+
+        Ok(())
+    }
+}
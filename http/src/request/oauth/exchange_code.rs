@@ -0,0 +1,107 @@
+use super::form_request;
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use twilight_model::{
+    id::{marker::ApplicationMarker, Id},
+    oauth::AccessToken,
+};
+
+/// Exchange an authorization code for an access token.
+///
+/// The code is granted to the redirect URI once a user has authorized the
+/// application. The redirect URI must match the one used in the
+/// authorization URL.
+#[must_use = "requests must be configured and executed"]
+pub struct ExchangeCode<'a> {
+    application_id: Id<ApplicationMarker>,
+    client_secret: &'a str,
+    code: &'a str,
+    http: &'a Client,
+    redirect_uri: &'a str,
+}
+
+impl<'a> ExchangeCode<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        client_secret: &'a str,
+        code: &'a str,
+        redirect_uri: &'a str,
+    ) -> Self {
+        Self {
+            application_id,
+            client_secret,
+            code,
+            http,
+            redirect_uri,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<AccessToken> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for ExchangeCode<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let client_id = self.application_id.to_string();
+
+        Ok(form_request(
+            &Route::CreateOauthToken,
+            &[
+                ("client_id", &client_id),
+                ("client_secret", self.client_secret),
+                ("code", self.code),
+                ("grant_type", "authorization_code"),
+                ("redirect_uri", self.redirect_uri),
+            ],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExchangeCode;
+    use crate::{
+        client::Client,
+        request::{Method, TryIntoRequest},
+    };
+    use hyper::header::CONTENT_TYPE;
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+        let request =
+            ExchangeCode::new(&client, Id::new(1), "secret", "code", "https://example.com")
+                .try_into_request()?;
+
+        assert_eq!(Method::Post, request.method());
+        assert_eq!("oauth2/token", request.path());
+        assert!(!request.use_authorization_token());
+        assert_eq!(
+            "application/x-www-form-urlencoded",
+            request.headers().unwrap()[CONTENT_TYPE]
+        );
+        assert_eq!(
+            b"client_id=1&client_secret=secret&code=code&grant_type=authorization_code&redirect_uri=https%3A%2F%2Fexample.com".as_ref(),
+            request.body().unwrap()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use twilight_model::oauth::CurrentAuthorizationInformation;
+
+/// Get information about the authorization of the client's bearer token.
+#[must_use = "requests must be configured and executed"]
+pub struct GetCurrentAuthorizationInformation<'a> {
+    http: &'a Client,
+}
+
+impl<'a> GetCurrentAuthorizationInformation<'a> {
+    pub(crate) const fn new(http: &'a Client) -> Self {
+        Self { http }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<CurrentAuthorizationInformation> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for GetCurrentAuthorizationInformation<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(Request::from_route(
+            &Route::GetCurrentAuthorizationInformation,
+        ))
+    }
+}
@@ -0,0 +1,80 @@
+//! Requests of the `OAuth2` flows, for obtaining, refreshing, and revoking
+//! access tokens.
+//!
+//! Token requests are authorized by the application's ID and secret instead
+//! of the client's token; create them via [`Client::oauth`].
+//!
+//! [`Client::oauth`]: crate::Client::oauth
+
+mod client_credentials;
+mod exchange_code;
+mod get_current_authorization_information;
+mod refresh_token;
+mod revoke_token;
+
+pub use self::{
+    client_credentials::ClientCredentials, exchange_code::ExchangeCode,
+    get_current_authorization_information::GetCurrentAuthorizationInformation,
+    refresh_token::RefreshToken, revoke_token::RevokeToken,
+};
+
+use super::Request;
+use crate::routing::Route;
+use hyper::header::{HeaderValue, CONTENT_TYPE};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::iter;
+
+/// Characters encoded in form values, which are all but those left as-is by
+/// the `application/x-www-form-urlencoded` serializer.
+const FORM: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'*')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_');
+
+/// Encode pairs as an `application/x-www-form-urlencoded` body.
+pub(crate) fn form_body(pairs: &[(&str, &str)]) -> Vec<u8> {
+    let mut body = String::new();
+
+    for (key, value) in pairs {
+        if !body.is_empty() {
+            body.push('&');
+        }
+
+        body.push_str(key);
+        body.push('=');
+        body.extend(utf8_percent_encode(value, FORM));
+    }
+
+    body.into_bytes()
+}
+
+/// Create a request to a route with a form body, which isn't authorized with
+/// the client's token.
+fn form_request(route: &Route<'_>, pairs: &[(&str, &str)]) -> Request {
+    let content_type = HeaderValue::from_static("application/x-www-form-urlencoded");
+
+    Request::builder(route)
+        .body(form_body(pairs))
+        .headers(iter::once((CONTENT_TYPE, content_type)))
+        .use_authorization_token(false)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::form_body;
+
+    #[test]
+    fn test_form_body() {
+        assert_eq!(
+            b"grant_type=authorization_code&redirect_uri=https%3A%2F%2Fexample.com%2F%3Fa%3Db"
+                .as_ref(),
+            form_body(&[
+                ("grant_type", "authorization_code"),
+                ("redirect_uri", "https://example.com/?a=b"),
+            ])
+        );
+        assert!(form_body(&[]).is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+use super::form_request;
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::ResponseFuture,
+    routing::Route,
+};
+use twilight_model::{
+    id::{marker::ApplicationMarker, Id},
+    oauth::AccessToken,
+};
+
+/// Refresh an access token, granting a new access token and refresh token.
+///
+/// The previous refresh token is invalidated once used.
+#[must_use = "requests must be configured and executed"]
+pub struct RefreshToken<'a> {
+    application_id: Id<ApplicationMarker>,
+    client_secret: &'a str,
+    http: &'a Client,
+    token: &'a str,
+}
+
+impl<'a> RefreshToken<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        client_secret: &'a str,
+        refresh_token: &'a str,
+    ) -> Self {
+        Self {
+            application_id,
+            client_secret,
+            http,
+            token: refresh_token,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<AccessToken> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for RefreshToken<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let client_id = self.application_id.to_string();
+
+        Ok(form_request(
+            &Route::CreateOauthToken,
+            &[
+                ("client_id", &client_id),
+                ("client_secret", self.client_secret),
+                ("grant_type", "refresh_token"),
+                ("refresh_token", self.token),
+            ],
+        ))
+    }
+}
@@ -0,0 +1,63 @@
+use super::form_request;
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{marker::EmptyBody, ResponseFuture},
+    routing::Route,
+};
+use twilight_model::id::{marker::ApplicationMarker, Id};
+
+/// Revoke an access token or refresh token.
+///
+/// Revoking either token of a grant revokes the other.
+#[must_use = "requests must be configured and executed"]
+pub struct RevokeToken<'a> {
+    application_id: Id<ApplicationMarker>,
+    client_secret: &'a str,
+    http: &'a Client,
+    token: &'a str,
+}
+
+impl<'a> RevokeToken<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        client_secret: &'a str,
+        token: &'a str,
+    ) -> Self {
+        Self {
+            application_id,
+            client_secret,
+            http,
+            token,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<EmptyBody> {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for RevokeToken<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let client_id = self.application_id.to_string();
+
+        Ok(form_request(
+            &Route::RevokeOauthToken,
+            &[
+                ("client_id", &client_id),
+                ("client_secret", self.client_secret),
+                ("token", self.token),
+            ],
+        ))
+    }
+}
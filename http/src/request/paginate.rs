@@ -0,0 +1,558 @@
+//! Streams automatically paginating through list endpoints.
+//!
+//! Endpoints returning lists of resources, such as [`GetChannelMessages`] and
+//! [`GetGuildMembers`], return a page of a limited size per request. The next
+//! page is requested by passing the ID of the last resource of the page as a
+//! cursor. The request builders of these endpoints have a `stream` method
+//! returning a [`Paginate`] stream that follows the cursor and yields the
+//! resources of each page in turn.
+//!
+//! Pages are only requested when the stream is polled and the previous page
+//! has been exhausted. Each page is requested through the client, and so is
+//! subject to its ratelimiter.
+//!
+//! [`GetChannelMessages`]: super::channel::message::GetChannelMessages
+//! [`GetGuildMembers`]: super::guild::member::GetGuildMembers
+
+use crate::{
+    response::{
+        marker::{ListBody, MemberListBody},
+        DeserializeBodyError, ResponseFuture,
+    },
+    Error,
+};
+use futures_util::stream::{FusedStream, Stream};
+use serde::de::DeserializeOwned;
+use std::{
+    collections::VecDeque,
+    error::Error as StdError,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use twilight_model::{datetime::Timestamp, guild::member::Member};
+
+/// Milliseconds since the Unix epoch of the first second of 2015, the epoch
+/// of snowflakes.
+pub(crate) const DISCORD_EPOCH: i64 = 1_420_070_400_000;
+
+/// Future resolving to a page of resources.
+pub(crate) type PageFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<Vec<T>, PaginateError>> + Send + 'a>>;
+
+/// Function requesting the page following a cursor, if any.
+type FetchPage<'a, T> = Box<dyn Fn(Option<u64>) -> PageFuture<'a, T> + Send + 'a>;
+
+/// Requesting a page of resources failed.
+#[derive(Debug)]
+pub struct PaginateError {
+    kind: PaginateErrorType,
+    source: Option<Box<dyn StdError + Send + Sync>>,
+}
+
+impl PaginateError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &PaginateErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn StdError + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (PaginateErrorType, Option<Box<dyn StdError + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    fn deserializing(source: DeserializeBodyError) -> Self {
+        Self {
+            kind: PaginateErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    fn requesting(source: Error) -> Self {
+        Self {
+            kind: PaginateErrorType::Requesting,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for PaginateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            PaginateErrorType::Deserializing => f.write_str("failed to deserialize page"),
+            PaginateErrorType::Requesting => f.write_str("failed to request page"),
+        }
+    }
+}
+
+impl StdError for PaginateError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn StdError + 'static))
+    }
+}
+
+/// Type of [`PaginateError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PaginateErrorType {
+    /// Response body of a page could not be deserialized.
+    Deserializing,
+    /// Request for a page failed.
+    ///
+    /// The source is an [`Error`] and may be inspected for the response.
+    ///
+    /// [`Error`]: crate::Error
+    Requesting,
+}
+
+/// Bound at which a [`Paginate`] stream ends.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Bound {
+    /// End the stream once it has yielded a number of resources.
+    Count(usize),
+    /// End the stream before yielding the resource with an ID, or any
+    /// resource past it in the direction of pagination.
+    Snowflake(u64),
+    /// End the stream before yielding any resource with an ID created at or
+    /// past a timestamp in the direction of pagination.
+    ///
+    /// Note that this compares against the creation time of the IDs the
+    /// endpoint paginates by. For example, this is the creation time of the
+    /// users in a list of guild members, not when they joined the guild.
+    Timestamp(Timestamp),
+}
+
+/// Direction the cursor of a [`Paginate`] stream moves in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Direction {
+    /// Resources are paginated in ascending order of their IDs.
+    After,
+    /// Resources are paginated in descending order of their IDs.
+    Before,
+    /// Only a single page is requested, in descending order of IDs.
+    Single,
+}
+
+/// Stream of resources, requesting pages as they are needed.
+///
+/// Resources are yielded in the direction of pagination: in ascending order of
+/// their IDs when paginating after a cursor, and in descending order when
+/// paginating before a cursor. The stream ends once a page shorter than the
+/// page size is received or a configured [`Bound`] is reached.
+///
+/// If a request for a page fails then the error is yielded and the stream
+/// ends.
+///
+/// # Examples
+///
+/// Get the 250 most recent messages in a channel:
+///
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use twilight_http::{request::paginate::Bound, Client};
+/// use twilight_model::id::Id;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let mut messages = client
+///     .channel_messages(Id::new(1))
+///     .stream()
+///     .bound(Bound::Count(250));
+///
+/// while let Some(message) = messages.next().await {
+///     println!("{}", message?.content);
+/// }
+/// # Ok(()) }
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct Paginate<'a, T> {
+    buffer: VecDeque<T>,
+    cursor: Option<u64>,
+    direction: Direction,
+    fetch: FetchPage<'a, T>,
+    finished: bool,
+    future: Option<PageFuture<'a, T>>,
+    id: fn(&T) -> u64,
+    page_size: usize,
+    remaining: Option<usize>,
+    until: Option<u64>,
+}
+
+impl<'a, T> Paginate<'a, T> {
+    /// Create a new stream starting at a cursor, with pages requested by
+    /// `fetch` and resources identified by `id`.
+    pub(crate) fn new(
+        direction: Direction,
+        cursor: Option<u64>,
+        page_size: u64,
+        id: fn(&T) -> u64,
+        fetch: impl Fn(Option<u64>) -> PageFuture<'a, T> + Send + 'a,
+    ) -> Self {
+        Self {
+            buffer: VecDeque::new(),
+            cursor,
+            direction,
+            fetch: Box::new(fetch),
+            finished: false,
+            future: None,
+            id,
+            page_size: usize::try_from(page_size).unwrap_or(usize::MAX),
+            remaining: None,
+            until: None,
+        }
+    }
+
+    /// Set a bound at which to end the stream.
+    ///
+    /// A count and a snowflake or timestamp bound may both be set, in which
+    /// case the stream ends at whichever is reached first. Setting a snowflake
+    /// or timestamp bound replaces the previous one.
+    pub fn bound(mut self, bound: Bound) -> Self {
+        match bound {
+            Bound::Count(count) => self.remaining = Some(count),
+            Bound::Snowflake(id) => self.until = Some(id),
+            Bound::Timestamp(timestamp) => {
+                let millis = timestamp.as_micros() / 1000 - DISCORD_EPOCH;
+                let id = u64::try_from(millis).unwrap_or(0) << 22;
+
+                self.until = Some(id);
+            }
+        }
+
+        self
+    }
+
+    /// Whether a resource's ID is at or past the snowflake bound.
+    const fn is_past_bound(&self, id: u64) -> bool {
+        match (self.until, self.direction) {
+            (Some(until), Direction::After) => id >= until,
+            (Some(until), Direction::Before | Direction::Single) => id <= until,
+            (None, _) => false,
+        }
+    }
+
+    fn finish(&mut self) {
+        self.buffer.clear();
+        self.finished = true;
+        self.future = None;
+    }
+}
+
+impl<T> Debug for Paginate<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Paginate")
+            .field("buffered", &self.buffer.len())
+            .field("cursor", &self.cursor)
+            .field("direction", &self.direction)
+            .field("finished", &self.finished)
+            .field("page_size", &self.page_size)
+            .field("remaining", &self.remaining)
+            .field("until", &self.until)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> FusedStream for Paginate<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.finished && self.buffer.is_empty()
+    }
+}
+
+impl<T> Stream for Paginate<'_, T> {
+    type Item = Result<T, PaginateError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.remaining == Some(0) {
+                this.finish();
+
+                return Poll::Ready(None);
+            }
+
+            if let Some(item) = this.buffer.pop_front() {
+                if this.is_past_bound((this.id)(&item)) {
+                    this.finish();
+
+                    return Poll::Ready(None);
+                }
+
+                if let Some(remaining) = this.remaining.as_mut() {
+                    *remaining -= 1;
+                }
+
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if this.finished {
+                return Poll::Ready(None);
+            }
+
+            let cursor = this.cursor;
+            let fetch = &this.fetch;
+            let future = this.future.get_or_insert_with(|| fetch(cursor));
+
+            let mut page = match future.as_mut().poll(cx) {
+                Poll::Ready(Ok(page)) => page,
+                Poll::Ready(Err(source)) => {
+                    this.finish();
+
+                    return Poll::Ready(Some(Err(source)));
+                }
+                Poll::Pending => return Poll::Pending,
+            };
+
+            this.future = None;
+
+            if page.len() < this.page_size || this.direction == Direction::Single {
+                this.finished = true;
+            }
+
+            let id = this.id;
+
+            match this.direction {
+                Direction::After => page.sort_unstable_by_key(id),
+                Direction::Before | Direction::Single => {
+                    page.sort_unstable_by_key(|item| std::cmp::Reverse(id(item)));
+                }
+            }
+
+            match page.last() {
+                Some(last) => this.cursor = Some(id(last)),
+                None => this.finished = true,
+            }
+
+            this.buffer.extend(page);
+        }
+    }
+}
+
+// Resources are never pinned, only moved out of the buffer.
+impl<T> Unpin for Paginate<'_, T> {}
+
+/// Resolve a response to a list of resources.
+pub(crate) async fn list<T: DeserializeOwned + Unpin>(
+    future: ResponseFuture<ListBody<T>>,
+) -> Result<Vec<T>, PaginateError> {
+    future
+        .await
+        .map_err(PaginateError::requesting)?
+        .models()
+        .await
+        .map_err(PaginateError::deserializing)
+}
+
+/// Resolve a response to a list of members.
+pub(crate) async fn members(
+    future: ResponseFuture<MemberListBody>,
+) -> Result<Vec<Member>, PaginateError> {
+    future
+        .await
+        .map_err(PaginateError::requesting)?
+        .models()
+        .await
+        .map_err(PaginateError::deserializing)
+}
+
+/// Resolve a response to a model.
+pub(crate) async fn model<T: DeserializeOwned + Unpin>(
+    future: ResponseFuture<T>,
+) -> Result<T, PaginateError> {
+    future
+        .await
+        .map_err(PaginateError::requesting)?
+        .model()
+        .await
+        .map_err(PaginateError::deserializing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bound, Direction, PageFuture, Paginate, PaginateError, PaginateErrorType};
+    use futures_util::{future, StreamExt};
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    };
+    use twilight_model::datetime::Timestamp;
+
+    assert_impl_all!(Bound: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(PaginateErrorType: Debug, Send, Sync);
+    assert_impl_all!(PaginateError: Error, Send, Sync);
+    assert_impl_all!(Paginate<'static, u64>: Debug, Send, Unpin);
+
+    /// Cursors that pages have been requested with.
+    type Requested = Arc<Mutex<Vec<Option<u64>>>>;
+
+    /// Paginate over the IDs 1 through `total`, recording the cursors
+    /// requested.
+    fn ids(
+        direction: Direction,
+        cursor: Option<u64>,
+        total: u64,
+        page_size: u64,
+    ) -> (Paginate<'static, u64>, Requested) {
+        let take = usize::try_from(page_size).unwrap();
+        let requested = Arc::new(Mutex::new(Vec::new()));
+        let cursors = Arc::clone(&requested);
+
+        let paginate = Paginate::new(
+            direction,
+            cursor,
+            page_size,
+            |id| *id,
+            move |cursor| {
+                cursors.lock().expect("cursors poisoned").push(cursor);
+
+                let page: Vec<u64> = match direction {
+                    Direction::After => (cursor.unwrap_or(0) + 1..=total).take(take).collect(),
+                    Direction::Before | Direction::Single => {
+                        (1..cursor.unwrap_or(total + 1)).rev().take(take).collect()
+                    }
+                };
+
+                Box::pin(future::ready(Ok(page))) as PageFuture<'static, u64>
+            },
+        );
+
+        (paginate, requested)
+    }
+
+    #[tokio::test]
+    async fn test_after() {
+        let (paginate, requested) = ids(Direction::After, None, 7, 3);
+
+        assert_eq!(
+            (1..=7).collect::<Vec<_>>(),
+            paginate.map(Result::unwrap).collect::<Vec<_>>().await
+        );
+        assert_eq!(
+            [None, Some(3), Some(6)].as_ref(),
+            requested.lock().unwrap().as_slice()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_before() {
+        let (paginate, requested) = ids(Direction::Before, Some(7), 10, 3);
+
+        assert_eq!(
+            (1..7).rev().collect::<Vec<_>>(),
+            paginate.map(Result::unwrap).collect::<Vec<_>>().await
+        );
+        assert_eq!(
+            [Some(7), Some(4), Some(1)].as_ref(),
+            requested.lock().unwrap().as_slice()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_single() {
+        let (paginate, requested) = ids(Direction::Single, None, 10, 3);
+
+        assert_eq!(
+            vec![10, 9, 8],
+            paginate.map(Result::unwrap).collect::<Vec<_>>().await
+        );
+        assert_eq!(1, requested.lock().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn test_bound_count() {
+        let (paginate, requested) = ids(Direction::After, None, 100, 3);
+
+        assert_eq!(
+            vec![1, 2, 3, 4],
+            paginate
+                .bound(Bound::Count(4))
+                .map(Result::unwrap)
+                .collect::<Vec<_>>()
+                .await
+        );
+        assert_eq!(2, requested.lock().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn test_bound_snowflake() {
+        let (paginate, _) = ids(Direction::Before, None, 100, 10);
+
+        assert_eq!(
+            (96..=100).rev().collect::<Vec<_>>(),
+            paginate
+                .bound(Bound::Snowflake(95))
+                .map(Result::unwrap)
+                .collect::<Vec<_>>()
+                .await
+        );
+
+        let (paginate, _) = ids(Direction::After, None, 100, 10);
+
+        assert_eq!(
+            (1..5).collect::<Vec<_>>(),
+            paginate
+                .bound(Bound::Snowflake(5))
+                .bound(Bound::Count(10))
+                .map(Result::unwrap)
+                .collect::<Vec<_>>()
+                .await
+        );
+    }
+
+    #[test]
+    fn test_bound_timestamp() {
+        // 2015-01-01T00:00:01.000Z, a second after the epoch of snowflakes.
+        let timestamp = Timestamp::from_secs(1_420_070_401).unwrap();
+        let (paginate, _) = ids(Direction::After, None, 0, 1);
+
+        assert_eq!(
+            Some(1000 << 22),
+            paginate.bound(Bound::Timestamp(timestamp)).until
+        );
+
+        let timestamp = Timestamp::from_secs(0).unwrap();
+        let (paginate, _) = ids(Direction::After, None, 0, 1);
+
+        assert_eq!(Some(0), paginate.bound(Bound::Timestamp(timestamp)).until);
+    }
+
+    #[tokio::test]
+    async fn test_error_ends_stream() {
+        let paginate = Paginate::new(
+            Direction::After,
+            None,
+            10,
+            |id: &u64| *id,
+            |_| {
+                Box::pin(future::ready(Err(PaginateError {
+                    kind: PaginateErrorType::Requesting,
+                    source: None,
+                }))) as PageFuture<'static, u64>
+            },
+        );
+
+        let results = paginate.collect::<Vec<_>>().await;
+
+        assert_eq!(1, results.len());
+        assert!(matches!(
+            results[0].as_ref().unwrap_err().kind(),
+            PaginateErrorType::Requesting
+        ));
+    }
+}
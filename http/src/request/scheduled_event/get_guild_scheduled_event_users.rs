@@ -1,7 +1,10 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, TryIntoRequest},
+    request::{
+        paginate::{self, Direction, Paginate},
+        Request, TryIntoRequest,
+    },
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
@@ -116,6 +119,53 @@ impl<'a> GetGuildScheduledEventUsers<'a> {
             Err(source) => ResponseFuture::error(source),
         }
     }
+
+    /// Stream the users, automatically requesting pages as needed.
+    ///
+    /// Users are paginated in descending order of their IDs if configured
+    /// with [`before`], and otherwise in ascending order from the user
+    /// configured with [`after`], if any. Pages are of the configured limit,
+    /// or 100 users if unset.
+    ///
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    pub fn stream(self) -> Paginate<'a, GuildScheduledEventUser> {
+        let guild_id = self.guild_id.get();
+        let http = self.http;
+        let limit = self.limit.unwrap_or(100);
+        let scheduled_event_id = self.scheduled_event_id.get();
+        let with_member = self.with_member.unwrap_or_default();
+
+        let (direction, cursor) = match (self.after, self.before) {
+            (_, Some(before)) => (Direction::Before, Some(before)),
+            (after, None) => (Direction::After, after),
+        };
+
+        Paginate::new(
+            direction,
+            cursor.map(Id::get),
+            limit,
+            |user| user.user.id.get(),
+            move |cursor| {
+                let (after, before) = if direction == Direction::After {
+                    (cursor, None)
+                } else {
+                    (None, cursor)
+                };
+
+                let request = Request::from_route(&Route::GetGuildScheduledEventUsers {
+                    after,
+                    before,
+                    guild_id,
+                    limit: Some(limit),
+                    scheduled_event_id,
+                    with_member,
+                });
+
+                Box::pin(paginate::list(http.request(request)))
+            },
+        )
+    }
 }
 
 impl TryIntoRequest for GetGuildScheduledEventUsers<'_> {
@@ -68,6 +68,10 @@ mod private {
             GetGuildWelcomeScreen, GetGuildWidget, UpdateCurrentMember, UpdateGuild,
             UpdateGuildChannelPositions, UpdateGuildWelcomeScreen, UpdateGuildWidget,
         },
+        oauth::{
+            ClientCredentials, ExchangeCode, GetCurrentAuthorizationInformation, RefreshToken,
+            RevokeToken,
+        },
         scheduled_event::{
             CreateGuildExternalScheduledEvent, CreateGuildScheduledEvent,
             CreateGuildStageInstanceScheduledEvent, CreateGuildVoiceScheduledEvent,
@@ -92,6 +96,7 @@ mod private {
     impl Sealed for AddGuildMember<'_> {}
     impl Sealed for AddRoleToMember<'_> {}
     impl Sealed for AddThreadMember<'_> {}
+    impl Sealed for ClientCredentials<'_> {}
     impl Sealed for CreateBan<'_> {}
     impl Sealed for CreateEmoji<'_> {}
     impl Sealed for CreateFollowupMessage<'_> {}
@@ -150,6 +155,7 @@ mod private {
     impl Sealed for DeleteTemplate<'_> {}
     impl Sealed for DeleteWebhook<'_> {}
     impl Sealed for DeleteWebhookMessage<'_> {}
+    impl Sealed for ExchangeCode<'_> {}
     impl Sealed for ExecuteWebhook<'_> {}
     impl Sealed for ExecuteWebhookAndWait<'_> {}
     impl Sealed for FollowNewsChannel<'_> {}
@@ -163,6 +169,7 @@ mod private {
     impl Sealed for GetChannelMessagesConfigured<'_> {}
     impl Sealed for GetChannelWebhooks<'_> {}
     impl Sealed for GetCommandPermissions<'_> {}
+    impl Sealed for GetCurrentAuthorizationInformation<'_> {}
     impl Sealed for GetCurrentUser<'_> {}
     impl Sealed for GetCurrentUserConnections<'_> {}
     impl Sealed for GetCurrentUserGuildMember<'_> {}
@@ -220,9 +227,11 @@ mod private {
     impl Sealed for JoinThread<'_> {}
     impl Sealed for LeaveGuild<'_> {}
     impl Sealed for LeaveThread<'_> {}
+    impl Sealed for RefreshToken<'_> {}
     impl Sealed for RemoveMember<'_> {}
     impl Sealed for RemoveRoleFromMember<'_> {}
     impl Sealed for RemoveThreadMember<'_> {}
+    impl Sealed for RevokeToken<'_> {}
     impl Sealed for SearchGuildMembers<'_> {}
     impl Sealed for SetCommandPermissions<'_> {}
     impl Sealed for SetGlobalCommands<'_> {}
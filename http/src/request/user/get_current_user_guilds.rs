@@ -1,7 +1,10 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{Request, TryIntoRequest},
+    request::{
+        paginate::{self, Direction, Paginate},
+        Request, TryIntoRequest,
+    },
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
@@ -109,6 +112,47 @@ impl<'a> GetCurrentUserGuilds<'a> {
             Err(source) => ResponseFuture::error(source),
         }
     }
+
+    /// Stream the guilds, automatically requesting pages as needed.
+    ///
+    /// Guilds are paginated in descending order of their IDs if configured
+    /// with [`before`], and otherwise in ascending order from the guild
+    /// configured with [`after`], if any. Pages are of the configured limit,
+    /// or 200 guilds if unset.
+    ///
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    pub fn stream(self) -> Paginate<'a, CurrentUserGuild> {
+        let http = self.http;
+        let limit = self.fields.limit.unwrap_or(200);
+
+        let (direction, cursor) = match (self.fields.after, self.fields.before) {
+            (_, Some(before)) => (Direction::Before, Some(before)),
+            (after, None) => (Direction::After, after),
+        };
+
+        Paginate::new(
+            direction,
+            cursor.map(Id::get),
+            limit,
+            |guild| guild.id.get(),
+            move |cursor| {
+                let (after, before) = if direction == Direction::After {
+                    (cursor, None)
+                } else {
+                    (None, cursor)
+                };
+
+                let request = Request::from_route(&Route::GetGuilds {
+                    after,
+                    before,
+                    limit: Some(limit),
+                });
+
+                Box::pin(paginate::list(http.request(request)))
+            },
+        )
+    }
 }
 
 impl TryIntoRequest for GetCurrentUserGuilds<'_> {
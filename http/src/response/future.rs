@@ -1,9 +1,10 @@
 use super::{Response, StatusCode};
 use crate::{
     api_error::ApiError,
+    client::TransportFuture,
     error::{Error, ErrorType},
 };
-use hyper::{client::ResponseFuture as HyperResponseFuture, StatusCode as HyperStatusCode};
+use hyper::StatusCode as HyperStatusCode;
 use std::{
     future::Future,
     marker::PhantomData,
@@ -14,10 +15,12 @@ use std::{
         Arc,
     },
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::time::{self, Timeout};
-use twilight_http_ratelimiting::{ticket::TicketSender, RatelimitHeaders, WaitForTicketFuture};
+use twilight_http_ratelimiting::{
+    ticket::TicketSender, Path, RatelimitHeaders, Ratelimiter, WaitForTicketFuture,
+};
 use twilight_model::id::{marker::GuildMarker, Id};
 
 type Output<T> = Result<Response<T>, Error>;
@@ -78,7 +81,7 @@ impl Failed {
 }
 
 struct InFlight {
-    future: Pin<Box<Timeout<HyperResponseFuture>>>,
+    future: Pin<Box<Timeout<TransportFuture>>>,
     guild_id: Option<Id<GuildMarker>>,
     invalid_token: Option<Arc<AtomicBool>>,
     tx: Option<TicketSender>,
@@ -91,7 +94,7 @@ impl InFlight {
             Poll::Ready(Ok(Err(source))) => {
                 return InnerPoll::Ready(Err(Error {
                     kind: ErrorType::RequestError,
-                    source: Some(Box::new(source)),
+                    source: Some(source),
                 }))
             }
             Poll::Ready(Err(source)) => {
@@ -191,15 +194,31 @@ impl InFlight {
 struct RatelimitQueue {
     guild_id: Option<Id<GuildMarker>>,
     invalid_token: Option<Arc<AtomicBool>>,
+    path: Path,
     pre_flight_check: Option<Box<dyn FnOnce() -> bool + Send + 'static>>,
+    ratelimiter: Arc<dyn Ratelimiter>,
     request_timeout: Duration,
-    response_future: HyperResponseFuture,
-    wait_for_sender: WaitForTicketFuture,
+    response_future: TransportFuture,
+    ticket_deadline: Option<Instant>,
+    /// Future waiting for a ticket, created when first polled so that a
+    /// deadline may be set beforehand.
+    wait_for_sender: Option<WaitForTicketFuture>,
 }
 
 impl RatelimitQueue {
     fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
-        let tx = match Pin::new(&mut self.wait_for_sender).poll(cx) {
+        let ratelimiter = &self.ratelimiter;
+        let path = &self.path;
+        let ticket_deadline = self.ticket_deadline;
+        let wait_for_sender = self.wait_for_sender.get_or_insert_with(|| {
+            if let Some(deadline) = ticket_deadline {
+                ratelimiter.wait_for_ticket_before(path.clone(), deadline)
+            } else {
+                ratelimiter.wait_for_ticket(path.clone())
+            }
+        });
+
+        let tx = match Pin::new(wait_for_sender).poll(cx) {
             Poll::Ready(Ok(tx)) => tx,
             Poll::Ready(Err(source)) => {
                 return InnerPoll::Ready(Err(Error {
@@ -207,16 +226,7 @@ impl RatelimitQueue {
                     source: Some(source),
                 }))
             }
-            Poll::Pending => {
-                return InnerPoll::Pending(ResponseFutureStage::RatelimitQueue(Self {
-                    guild_id: self.guild_id,
-                    invalid_token: self.invalid_token,
-                    pre_flight_check: self.pre_flight_check,
-                    request_timeout: self.request_timeout,
-                    response_future: self.response_future,
-                    wait_for_sender: self.wait_for_sender,
-                }))
-            }
+            Poll::Pending => return InnerPoll::Pending(ResponseFutureStage::RatelimitQueue(self)),
         };
 
         if let Some(pre_flight_check) = self.pre_flight_check {
@@ -297,7 +307,7 @@ pub struct ResponseFuture<T> {
 impl<T> ResponseFuture<T> {
     pub(crate) fn new(
         invalid_token: Option<Arc<AtomicBool>>,
-        future: Timeout<HyperResponseFuture>,
+        future: Timeout<TransportFuture>,
         ratelimit_tx: Option<TicketSender>,
     ) -> Self {
         Self {
@@ -374,6 +384,65 @@ impl<T> ResponseFuture<T> {
         }
     }
 
+    /// Set a deadline for waiting for the ratelimiter to allow the request
+    /// to be sent.
+    ///
+    /// If the request's bucket is exhausted and refreshes after the deadline
+    /// then the request fails immediately, instead of waiting for the
+    /// deadline. This is useful for requests with a time budget, such as
+    /// responding to an interaction within 3 seconds.
+    ///
+    /// The request fails with an error of type [`RatelimiterTicket`] whose
+    /// source is a [`TicketDeadlineError`] if a ticket isn't granted before
+    /// the deadline.
+    ///
+    /// This function will be a no-op if the request has failed, has already
+    /// been polled, or if there is no ratelimiter configured.
+    ///
+    /// Returns whether the deadline was set.
+    ///
+    /// # Examples
+    ///
+    /// Give up on responding to a component interaction if the response can't
+    /// be sent within 3 seconds of receiving it:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::{env, time::{Duration, Instant}};
+    /// use twilight_http::Client;
+    /// use twilight_model::{
+    ///     application::callback::InteractionResponse,
+    ///     id::Id,
+    /// };
+    ///
+    /// let received_at = Instant::now();
+    /// let client = Client::new(env::var("DISCORD_TOKEN")?);
+    /// let application_id = Id::new(1);
+    ///
+    /// let response = InteractionResponse::DeferredUpdateMessage;
+    /// let mut req = client
+    ///     .interaction(application_id)
+    ///     .interaction_callback(Id::new(2), "token", &response)
+    ///     .exec();
+    /// req.set_ticket_deadline(received_at + Duration::from_secs(3));
+    ///
+    /// req.await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`RatelimiterTicket`]: crate::error::ErrorType::RatelimiterTicket
+    /// [`TicketDeadlineError`]: twilight_http_ratelimiting::ticket::TicketDeadlineError
+    pub fn set_ticket_deadline(&mut self, deadline: Instant) -> bool {
+        match &mut self.stage {
+            ResponseFutureStage::RatelimitQueue(queue) if queue.wait_for_sender.is_none() => {
+                queue.ticket_deadline = Some(deadline);
+
+                true
+            }
+            _ => false,
+        }
+    }
+
     pub(crate) const fn error(source: Error) -> Self {
         Self {
             phantom: PhantomData,
@@ -384,19 +453,23 @@ impl<T> ResponseFuture<T> {
     pub(crate) fn ratelimit(
         guild_id: Option<Id<GuildMarker>>,
         invalid_token: Option<Arc<AtomicBool>>,
-        wait_for_sender: WaitForTicketFuture,
+        ratelimiter: Arc<dyn Ratelimiter>,
+        path: Path,
         request_timeout: Duration,
-        response_future: HyperResponseFuture,
+        response_future: TransportFuture,
     ) -> Self {
         Self {
             phantom: PhantomData,
             stage: ResponseFutureStage::RatelimitQueue(RatelimitQueue {
                 guild_id,
                 invalid_token,
+                path,
                 pre_flight_check: None,
+                ratelimiter,
                 request_timeout,
                 response_future,
-                wait_for_sender,
+                ticket_deadline: None,
+                wait_for_sender: None,
             }),
         }
     }
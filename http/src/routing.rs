@@ -107,6 +107,8 @@ pub enum Route<'a> {
         /// The ID of the channel.
         channel_id: u64,
     },
+    /// Route information to create an `OAuth2` access token.
+    CreateOauthToken,
     /// Route information to create a private channel.
     CreatePrivateChannel,
     /// Route information to create a thread in a channel.
@@ -398,6 +400,9 @@ pub enum Route<'a> {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get information about the current `OAuth2`
+    /// authorization.
+    GetCurrentAuthorizationInformation,
     /// Route information to get info about application the current bot user belongs to
     GetCurrentUserApplicationInfo,
     /// Route information to get the current user.
@@ -445,6 +450,9 @@ pub enum Route<'a> {
     GetGlobalCommands {
         /// The ID of the owner application.
         application_id: u64,
+        /// Whether to include the full localization dictionaries of the
+        /// commands.
+        with_localizations: bool,
     },
     /// Route information to get a guild.
     GetGuild {
@@ -476,6 +484,9 @@ pub enum Route<'a> {
         application_id: u64,
         /// The ID of the guild.
         guild_id: u64,
+        /// Whether to include the full localization dictionaries of the
+        /// commands.
+        with_localizations: bool,
     },
     /// Route information to get a guild's widget.
     GetGuildWidget {
@@ -812,6 +823,8 @@ pub enum Route<'a> {
         /// ID of the member.
         user_id: u64,
     },
+    /// Route information to revoke an `OAuth2` access or refresh token.
+    RevokeOauthToken,
     /// Route information to search for members in a guild.
     SearchGuildMembers {
         /// ID of the guild to search in.
@@ -1093,6 +1106,7 @@ impl<'a> Route<'a> {
             | Self::GetChannelWebhooks { .. }
             | Self::GetChannels { .. }
             | Self::GetCommandPermissions { .. }
+            | Self::GetCurrentAuthorizationInformation
             | Self::GetCurrentUserApplicationInfo
             | Self::GetCurrentUser
             | Self::GetCurrentUserGuildMember { .. }
@@ -1185,6 +1199,7 @@ impl<'a> Route<'a> {
             | Self::CreateGuildSticker { .. }
             | Self::CreateInvite { .. }
             | Self::CreateMessage { .. }
+            | Self::CreateOauthToken
             | Self::CreatePrivateChannel
             | Self::CreateThread { .. }
             | Self::CreateThreadFromMessage { .. }
@@ -1198,6 +1213,7 @@ impl<'a> Route<'a> {
             | Self::ExecuteWebhook { .. }
             | Self::FollowNewsChannel { .. }
             | Self::InteractionCallback { .. }
+            | Self::RevokeOauthToken
             | Self::SyncGuildIntegration { .. } => Method::Post,
             Self::AddGuildMember { .. }
             | Self::AddMemberRole { .. }
@@ -1271,7 +1287,7 @@ impl<'a> Route<'a> {
                 Path::GuildsIdEmojis(guild_id)
             }
             Self::CreateGlobalCommand { application_id }
-            | Self::GetGlobalCommands { application_id }
+            | Self::GetGlobalCommands { application_id, .. }
             | Self::SetGlobalCommands { application_id } => {
                 Path::ApplicationCommand(application_id)
             }
@@ -1305,6 +1321,7 @@ impl<'a> Route<'a> {
             Self::CreateMessage { channel_id } | Self::GetMessages { channel_id, .. } => {
                 Path::ChannelsIdMessages(channel_id)
             }
+            Self::CreateOauthToken => Path::OauthToken,
             Self::CreatePrivateChannel | Self::GetUserPrivateChannels => Path::UsersIdChannels,
             Self::CreateReaction { channel_id, .. }
             | Self::DeleteReactionCurrentUser { channel_id, .. }
@@ -1448,6 +1465,7 @@ impl<'a> Route<'a> {
             | Self::UpdateCommandPermissions { application_id, .. } => {
                 Path::ApplicationGuildCommandId(application_id)
             }
+            Self::GetCurrentAuthorizationInformation => Path::OauthMe,
             Self::GetCurrentUserApplicationInfo => Path::OauthApplicationsMe,
             Self::GetCurrentUser | Self::GetUser { .. } | Self::UpdateCurrentUser => Path::UsersId,
             Self::GetCurrentUserGuildMember { .. } => Path::UsersIdGuildsIdMember,
@@ -1499,6 +1517,7 @@ impl<'a> Route<'a> {
                 Path::InteractionCallback(interaction_id)
             }
             Self::LeaveGuild { .. } => Path::UsersIdGuildsId,
+            Self::RevokeOauthToken => Path::OauthTokenRevoke,
             Self::SearchGuildMembers { guild_id, .. } => Path::GuildsIdMembersSearch(guild_id),
             Self::SyncGuildIntegration { guild_id, .. } => {
                 Path::GuildsIdIntegrationsIdSync(guild_id)
@@ -1649,32 +1668,58 @@ impl Display for Route<'_> {
                 f.write_str("/emojis")
             }
             Route::CreateGlobalCommand { application_id }
-            | Route::GetGlobalCommands { application_id }
             | Route::SetGlobalCommands { application_id } => {
                 f.write_str("applications/")?;
                 Display::fmt(application_id, f)?;
 
                 f.write_str("/commands")
             }
+            Route::GetGlobalCommands {
+                application_id,
+                with_localizations,
+            } => {
+                f.write_str("applications/")?;
+                Display::fmt(application_id, f)?;
+                f.write_str("/commands")?;
+
+                if *with_localizations {
+                    f.write_str("?with_localizations=true")?;
+                }
+
+                Ok(())
+            }
             Route::CreateGuild => f.write_str("guilds"),
             Route::CreateGuildCommand {
                 application_id,
                 guild_id,
             }
-            | Route::GetGuildCommands {
+            | Route::SetGuildCommands {
                 application_id,
                 guild_id,
+            } => {
+                f.write_str("applications/")?;
+                Display::fmt(application_id, f)?;
+                f.write_str("/guilds/")?;
+                Display::fmt(guild_id, f)?;
+
+                f.write_str("/commands")
             }
-            | Route::SetGuildCommands {
+            Route::GetGuildCommands {
                 application_id,
                 guild_id,
+                with_localizations,
             } => {
                 f.write_str("applications/")?;
                 Display::fmt(application_id, f)?;
                 f.write_str("/guilds/")?;
                 Display::fmt(guild_id, f)?;
+                f.write_str("/commands")?;
 
-                f.write_str("/commands")
+                if *with_localizations {
+                    f.write_str("?with_localizations=true")?;
+                }
+
+                Ok(())
             }
             Route::CreateGuildFromTemplate { template_code }
             | Route::GetTemplate { template_code } => {
@@ -1750,6 +1795,7 @@ impl Display for Route<'_> {
 
                 f.write_str("/messages")
             }
+            Route::CreateOauthToken => f.write_str("oauth2/token"),
             Route::CreatePrivateChannel | Route::GetUserPrivateChannels => {
                 f.write_str("users/@me/channels")
             }
@@ -2191,6 +2237,7 @@ impl Display for Route<'_> {
 
                 f.write_str("/permissions")
             }
+            Route::GetCurrentAuthorizationInformation => f.write_str("oauth2/@me"),
             Route::GetCurrentUserApplicationInfo => f.write_str("oauth2/applications/@me"),
             Route::GetCurrentUser | Route::UpdateCurrentUser => f.write_str("users/@me"),
             Route::GetCurrentUserGuildMember { guild_id } => {
@@ -2653,6 +2700,7 @@ impl Display for Route<'_> {
 
                 Display::fmt(message_id, f)
             }
+            Route::RevokeOauthToken => f.write_str("oauth2/token/revoke"),
             Route::SearchGuildMembers {
                 guild_id,
                 limit,
@@ -3029,6 +3077,7 @@ mod tests {
     fn test_get_global_commands() {
         let route = Route::GetGlobalCommands {
             application_id: APPLICATION_ID,
+            with_localizations: false,
         };
         assert_eq!(
             route.to_string(),
@@ -3037,6 +3086,18 @@ mod tests {
                 application_id = APPLICATION_ID
             )
         );
+
+        let route = Route::GetGlobalCommands {
+            application_id: APPLICATION_ID,
+            with_localizations: true,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!(
+                "applications/{application_id}/commands?with_localizations=true",
+                application_id = APPLICATION_ID
+            )
+        );
     }
 
     #[test]
@@ -3080,6 +3141,7 @@ mod tests {
         let route = Route::GetGuildCommands {
             application_id: APPLICATION_ID,
             guild_id: GUILD_ID,
+            with_localizations: false,
         };
         assert_eq!(
             route.to_string(),
@@ -3089,6 +3151,20 @@ mod tests {
                 guild_id = GUILD_ID
             )
         );
+
+        let route = Route::GetGuildCommands {
+            application_id: APPLICATION_ID,
+            guild_id: GUILD_ID,
+            with_localizations: true,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!(
+                "applications/{application_id}/guilds/{guild_id}/commands?with_localizations=true",
+                application_id = APPLICATION_ID,
+                guild_id = GUILD_ID
+            )
+        );
     }
 
     #[test]
@@ -3204,6 +3280,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_create_oauth_token() {
+        let route = Route::CreateOauthToken;
+        assert_eq!(route.to_string(), "oauth2/token");
+    }
+
+    #[test]
+    fn test_revoke_oauth_token() {
+        let route = Route::RevokeOauthToken;
+        assert_eq!(route.to_string(), "oauth2/token/revoke");
+    }
+
     #[test]
     fn test_create_private_channel() {
         let route = Route::CreatePrivateChannel;
@@ -4038,6 +4126,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_current_authorization_information() {
+        let route = Route::GetCurrentAuthorizationInformation;
+        assert_eq!(route.to_string(), "oauth2/@me");
+    }
+
     #[test]
     fn test_get_current_user_application_info() {
         let route = Route::GetCurrentUserApplicationInfo;
@@ -0,0 +1,104 @@
+//! Scaffolding for unit tests.
+
+use crate::client::{Transport, TransportFuture};
+use hyper::{body, header::HeaderMap, Body, Method, Request, Response, StatusCode, Uri};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::{Arc, Mutex},
+};
+
+/// Request sent to a [`Recorder`].
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    pub body: Vec<u8>,
+    pub headers: HeaderMap,
+    pub method: Method,
+    pub uri: Uri,
+}
+
+impl RecordedRequest {
+    /// Body of the request as a string.
+    pub fn body(&self) -> &str {
+        std::str::from_utf8(&self.body).expect("body is UTF-8")
+    }
+
+    /// Value of a header of the request, if it's present.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(name)
+            .map(|value| value.to_str().expect("header is ASCII"))
+    }
+
+    /// Path of the request's URL.
+    pub fn path(&self) -> &str {
+        self.uri.path()
+    }
+}
+
+/// Requests recorded by a [`Recorder`].
+pub type Requests = Arc<Mutex<Vec<RecordedRequest>>>;
+
+/// Function creating the status and body of the response to a request.
+type Respond = dyn Fn(&RecordedRequest) -> (StatusCode, String) + Send + Sync;
+
+/// Transport recording the requests sent to it and responding to each with
+/// the status and body created by a function.
+pub struct Recorder {
+    requests: Requests,
+    respond: Arc<Respond>,
+}
+
+impl Recorder {
+    /// Create a new recorder responding to requests via a function.
+    pub fn new(
+        respond: impl Fn(&RecordedRequest) -> (StatusCode, String) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            requests: Requests::default(),
+            respond: Arc::new(respond),
+        }
+    }
+
+    /// Create a new recorder responding to every request with a body.
+    pub fn ok(body: &'static str) -> Self {
+        Self::new(move |_| (StatusCode::OK, body.to_owned()))
+    }
+
+    /// Requests recorded so far.
+    pub fn requests(&self) -> Requests {
+        Arc::clone(&self.requests)
+    }
+}
+
+impl Debug for Recorder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Recorder")
+            .field("requests", &self.requests)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Transport for Recorder {
+    fn send(&self, request: Request<Body>) -> TransportFuture {
+        let requests = Arc::clone(&self.requests);
+        let respond = Arc::clone(&self.respond);
+
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let request = RecordedRequest {
+                body: body::to_bytes(body).await?.to_vec(),
+                headers: parts.headers,
+                method: parts.method,
+                uri: parts.uri,
+            };
+
+            let (status, body) = respond(&request);
+            requests.lock().expect("requests poisoned").push(request);
+
+            let mut response = Response::new(Body::from(body));
+            *response.status_mut() = status;
+
+            Ok(response)
+        })
+    }
+}
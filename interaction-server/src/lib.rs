@@ -0,0 +1,146 @@
+//! # twilight-interaction-server
+//!
+//! [![codecov badge][]][codecov link] [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-interaction-server` is a server for receiving Discord
+//! interactions over HTTP, as an alternative to receiving them over the
+//! gateway, for the [`twilight-rs`] ecosystem.
+//!
+//! The [`InteractionService`] is a [`hyper`] service, and so a [`tower`]
+//! service, that verifies the signatures of requests sent by Discord, answers
+//! pings, and dispatches interactions to a [`Handler`]. Handlers [reply] with
+//! an interaction response, or defer their response and update it once a
+//! follow-up has completed.
+//!
+//! Signatures can also be verified on their own with a [`Verifier`] for use
+//! with other web frameworks.
+//!
+//! ## Features
+//!
+//! ### TLS
+//!
+//! `twilight-interaction-server` has features to enable [`twilight-http`]'s
+//! TLS features, which are used to send follow-ups to deferred responses.
+//! These features are mutually exclusive. `rustls-native-roots` is enabled by
+//! default.
+//!
+//! #### `native`
+//!
+//! The `native` feature enables [`twilight-http`]'s `native` feature.
+//!
+//! #### `rustls-native-roots`
+//!
+//! The `rustls-native-roots` feature enables [`twilight-http`]'s
+//! `rustls-native-roots` feature.
+//!
+//! This is enabled by default.
+//!
+//! #### `rustls-webpki-roots`
+//!
+//! The `rustls-webpki-roots` feature enables [`twilight-http`]'s
+//! `rustls-webpki-roots` feature.
+//!
+//! ### Tracing
+//!
+//! The `tracing` feature enables logging via the [`tracing`] crate.
+//!
+//! This is enabled by default.
+//!
+//! ## Examples
+//!
+//! Serve a handler responding to the `ping` command and deferring the
+//! response to all other commands:
+//!
+//! ```no_run
+//! use hyper::{service::make_service_fn, Server};
+//! use std::{convert::Infallible, env, sync::Arc};
+//! use twilight_http::Client;
+//! use twilight_interaction_server::{HandlerError, InteractionService, Reply};
+//! use twilight_model::application::{
+//!     callback::{CallbackData, InteractionResponse},
+//!     interaction::Interaction,
+//! };
+//!
+//! fn message(content: &str) -> CallbackData {
+//!     CallbackData {
+//!         allowed_mentions: None,
+//!         components: None,
+//!         content: Some(content.to_owned()),
+//!         embeds: None,
+//!         flags: None,
+//!         tts: None,
+//!     }
+//! }
+//!
+//! async fn handler(interaction: Interaction) -> Result<Reply, HandlerError> {
+//!     match interaction {
+//!         Interaction::ApplicationCommand(command) if command.data.name == "ping" => Ok(
+//!             InteractionResponse::ChannelMessageWithSource(message("Pong!")).into(),
+//!         ),
+//!         _ => Ok(Reply::defer(async {
+//!             // Do something taking longer than 3 seconds.
+//!
+//!             Ok(message("Done!"))
+//!         })),
+//!     }
+//! }
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let http = Arc::new(Client::new(env::var("DISCORD_TOKEN")?));
+//! let service = InteractionService::new(http, &env::var("PUBLIC_KEY")?, handler)?;
+//!
+//! let address = ([127, 0, 0, 1], 3030).into();
+//! Server::bind(&address)
+//!     .serve(make_service_fn(move |_| {
+//!         let service = service.clone();
+//!
+//!         async move { Ok::<_, Infallible>(service) }
+//!     }))
+//!     .await?;
+//! # Ok(()) }
+//! ```
+//!
+//! [`tower`]: https://docs.rs/tower
+//! [`tracing`]: https://crates.io/crates/tracing
+//! [`twilight-http`]: https://crates.io/crates/twilight-http
+//! [`twilight-rs`]: https://github.com/twilight-rs/twilight
+//! [codecov badge]: https://img.shields.io/codecov/c/gh/twilight-rs/twilight?logo=codecov&style=for-the-badge&token=E9ERLJL0L2
+//! [codecov link]: https://app.codecov.io/gh/twilight-rs/twilight/
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/main/LICENSE.md
+//! [reply]: Reply
+//! [rust badge]: https://img.shields.io/badge/rust-1.57+-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    rustdoc::broken_intra_doc_links,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(
+    clippy::module_name_repetitions,
+    clippy::must_use_candidate,
+    clippy::used_underscore_binding
+)]
+
+pub mod verify;
+
+mod reply;
+mod service;
+
+pub use self::{
+    reply::{Handler, HandlerError, HandlerFuture, Reply},
+    service::InteractionService,
+    verify::{PublicKeyError, PublicKeyErrorType, Verifier, VerifyError, VerifyErrorType},
+};
@@ -0,0 +1,155 @@
+//! Replies of handlers to interactions.
+
+use std::{
+    error::Error,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+};
+use twilight_model::application::{
+    callback::{CallbackData, InteractionResponse},
+    interaction::Interaction,
+};
+
+/// Error returned by handlers and follow-ups.
+pub type HandlerError = Box<dyn Error + Send + Sync>;
+
+/// Future resolving to the data of a deferred response's follow-up.
+pub(crate) type FollowUp = Pin<Box<dyn Future<Output = Result<CallbackData, HandlerError>> + Send>>;
+
+/// Future resolving to a handler's reply.
+pub type HandlerFuture = Pin<Box<dyn Future<Output = Result<Reply, HandlerError>> + Send>>;
+
+/// Handler of interactions received by an [`InteractionService`].
+///
+/// This is implemented for functions taking an [`Interaction`] and returning
+/// a future resolving to a [`Reply`].
+///
+/// [`InteractionService`]: crate::InteractionService
+pub trait Handler: Send + Sync + 'static {
+    /// Handle an interaction, replying to it.
+    ///
+    /// If an error is returned then the request is responded to with an
+    /// internal server error.
+    fn handle(&self, interaction: Interaction) -> HandlerFuture;
+}
+
+impl<F, Fut> Handler for F
+where
+    F: Fn(Interaction) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Reply, HandlerError>> + Send + 'static,
+{
+    fn handle(&self, interaction: Interaction) -> HandlerFuture {
+        Box::pin(self(interaction))
+    }
+}
+
+/// Kind of a [`Reply`].
+pub(crate) enum ReplyKind {
+    /// Acknowledge the interaction, then update the response with the result
+    /// of a follow-up.
+    Defer {
+        ephemeral: bool,
+        follow_up: FollowUp,
+    },
+    /// Respond to the interaction.
+    Respond(InteractionResponse),
+}
+
+/// Reply of a [`Handler`] to an interaction.
+///
+/// Interactions must be responded to within 3 seconds. Handlers that can't
+/// respond in time can [`defer`] their response, acknowledging the interaction
+/// immediately and editing the response once a follow-up has completed.
+///
+/// [`defer`]: Self::defer
+pub struct Reply(pub(crate) ReplyKind);
+
+impl Reply {
+    /// Respond to the interaction with a response.
+    pub const fn respond(response: InteractionResponse) -> Self {
+        Self(ReplyKind::Respond(response))
+    }
+
+    /// Defer the response to the interaction, updating it with the data
+    /// resolved by a follow-up.
+    ///
+    /// For message components, the deferral doesn't show a loading state and
+    /// the follow-up updates the message the component is attached to.
+    ///
+    /// Only the fields of the follow-up's data that are set are updated. If
+    /// the follow-up fails, an error message is sent instead.
+    ///
+    /// Whether a command's response is ephemeral is decided by the deferral,
+    /// and edits can't be text-to-speech. Ephemeral or text-to-speech data for
+    /// message components is sent as a new message rather than updating the
+    /// component's message.
+    ///
+    /// Autocomplete interactions can't be deferred; the service responds to
+    /// them with an error if they are.
+    pub fn defer(
+        follow_up: impl Future<Output = Result<CallbackData, HandlerError>> + Send + 'static,
+    ) -> Self {
+        Self(ReplyKind::Defer {
+            ephemeral: false,
+            follow_up: Box::pin(follow_up),
+        })
+    }
+
+    /// Defer the response to the interaction as an ephemeral message, updating
+    /// it with the data resolved by a follow-up.
+    ///
+    /// Only application commands can be deferred as ephemeral messages. For
+    /// message components, this is the same as [`defer`].
+    ///
+    /// [`defer`]: Self::defer
+    pub fn defer_ephemeral(
+        follow_up: impl Future<Output = Result<CallbackData, HandlerError>> + Send + 'static,
+    ) -> Self {
+        Self(ReplyKind::Defer {
+            ephemeral: true,
+            follow_up: Box::pin(follow_up),
+        })
+    }
+}
+
+impl Debug for Reply {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.0 {
+            ReplyKind::Defer { ephemeral, .. } => f
+                .debug_struct("Reply")
+                .field("ephemeral", ephemeral)
+                .finish_non_exhaustive(),
+            ReplyKind::Respond(response) => {
+                f.debug_struct("Reply").field("response", response).finish()
+            }
+        }
+    }
+}
+
+impl From<InteractionResponse> for Reply {
+    fn from(response: InteractionResponse) -> Self {
+        Self::respond(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Handler, Reply};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::application::{callback::InteractionResponse, interaction::Interaction};
+
+    assert_impl_all!(Reply: Debug, From<InteractionResponse>, Send);
+
+    async fn handler(_: Interaction) -> Result<Reply, super::HandlerError> {
+        Ok(InteractionResponse::DeferredUpdateMessage.into())
+    }
+
+    #[test]
+    fn test_fn_handler() {
+        fn assert_handler(_: &impl Handler) {}
+
+        assert_handler(&handler);
+    }
+}
@@ -0,0 +1,602 @@
+//! Service handling requests sent to an interactions endpoint.
+
+use crate::{
+    reply::{FollowUp, Handler, ReplyKind},
+    verify::{PublicKeyError, Verifier, SIGNATURE_HEADER, TIMESTAMP_HEADER},
+};
+use hyper::{
+    body::HttpBody,
+    header::{HeaderValue, CONTENT_LENGTH, CONTENT_TYPE},
+    service::Service,
+    Body, Method, Request, Response, StatusCode,
+};
+use std::{
+    convert::Infallible,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tokio::sync::oneshot;
+use twilight_http::Client;
+use twilight_model::{
+    application::{
+        callback::{CallbackData, InteractionResponse},
+        interaction::Interaction,
+    },
+    channel::message::MessageFlags,
+    id::{marker::ApplicationMarker, Id},
+};
+
+/// Maximum length of a request body, in bytes.
+///
+/// Bodies are read before their signature is verified, so this bounds how much
+/// an unauthenticated peer can make the service buffer.
+const MAX_BODY_LENGTH: usize = 1024 * 1024;
+
+/// Content of the response sent when a deferred reply's follow-up fails.
+const FOLLOW_UP_ERROR_CONTENT: &str = "An error occurred while responding to this interaction.";
+
+struct InteractionServiceRef<H> {
+    handler: H,
+    http: Arc<Client>,
+    verifier: Verifier,
+}
+
+/// [`hyper`] service handling requests sent by Discord to an interactions
+/// endpoint.
+///
+/// Requests must use the `POST` method and are verified by their signature
+/// before their [`Interaction`] is deserialized. `Ping` interactions are
+/// answered with a `Pong` and all other interactions are passed to the
+/// [`Handler`], whose [`Reply`] is sent as the response.
+///
+/// The service responds to requests with:
+///
+/// - `405 Method Not Allowed` if the method isn't `POST`;
+/// - `400 Bad Request` if the signature headers are missing or the body isn't
+///   an interaction;
+/// - `401 Unauthorized` if the signature doesn't match;
+/// - `413 Payload Too Large` if the body is longer than 1 MiB;
+/// - `500 Internal Server Error` if the handler returned an error.
+///
+/// The service doesn't route requests by their path, so it should be served
+/// under the path configured as the application's interactions endpoint.
+///
+/// Refer to the [crate-level] documentation for an example.
+///
+/// [crate-level]: crate
+/// [`Reply`]: crate::Reply
+pub struct InteractionService<H>(Arc<InteractionServiceRef<H>>);
+
+impl<H: Handler> InteractionService<H> {
+    /// Create a new service from an application's hex encoded public key and
+    /// a handler.
+    ///
+    /// The HTTP client is used to update the responses of [deferred] replies
+    /// once their follow-up has completed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PublicKeyErrorType::Decoding`] error type if the public key
+    /// is not valid hex of the right length.
+    ///
+    /// Returns a [`PublicKeyErrorType::Invalid`] error type if the public key
+    /// is not a valid Ed25519 public key.
+    ///
+    /// [`PublicKeyErrorType::Decoding`]: crate::PublicKeyErrorType::Decoding
+    /// [`PublicKeyErrorType::Invalid`]: crate::PublicKeyErrorType::Invalid
+    /// [deferred]: crate::Reply::defer
+    pub fn new(http: Arc<Client>, public_key: &str, handler: H) -> Result<Self, PublicKeyError> {
+        Ok(Self(Arc::new(InteractionServiceRef {
+            handler,
+            http,
+            verifier: Verifier::new(public_key)?,
+        })))
+    }
+
+    /// Handle a request sent to the interactions endpoint.
+    async fn request(self, request: Request<Body>) -> Response<Body> {
+        if request.method() != Method::POST {
+            return status(StatusCode::METHOD_NOT_ALLOWED);
+        }
+
+        let headers = request.headers();
+        let signature = headers
+            .get(SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok());
+        let timestamp = headers
+            .get(TIMESTAMP_HEADER)
+            .and_then(|value| value.to_str().ok());
+
+        let (signature, timestamp) = match (signature, timestamp) {
+            (Some(signature), Some(timestamp)) => (signature.to_owned(), timestamp.to_owned()),
+            _ => return status(StatusCode::BAD_REQUEST),
+        };
+
+        let content_length = headers
+            .get(CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<usize>().ok());
+
+        if content_length.map_or(false, |length| length > MAX_BODY_LENGTH) {
+            return status(StatusCode::PAYLOAD_TOO_LARGE);
+        }
+
+        let body = match read_body(request.into_body()).await {
+            Ok(body) => body,
+            Err(status_code) => return status(status_code),
+        };
+
+        if self
+            .0
+            .verifier
+            .verify(&signature, &timestamp, &body)
+            .is_err()
+        {
+            return status(StatusCode::UNAUTHORIZED);
+        }
+
+        let interaction = match serde_json::from_slice::<Interaction>(&body) {
+            Ok(interaction) => interaction,
+            Err(_source) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("deserializing interaction failed: {}", _source);
+
+                return status(StatusCode::BAD_REQUEST);
+            }
+        };
+
+        if matches!(interaction, Interaction::Ping(_)) {
+            return json(&InteractionResponse::Pong);
+        }
+
+        // Only deferred replies need the interaction once the handler has it.
+        let deferrable = application_id(&interaction).map(|id| (id, interaction.clone()));
+
+        let reply = match self.0.handler.handle(interaction).await {
+            Ok(reply) => reply,
+            Err(_source) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("interaction handler failed: {}", _source);
+
+                return status(StatusCode::INTERNAL_SERVER_ERROR);
+            }
+        };
+
+        match reply.0 {
+            ReplyKind::Defer {
+                ephemeral,
+                follow_up,
+            } => {
+                let (application_id, interaction) = match deferrable {
+                    Some(deferrable) => deferrable,
+                    None => return status(StatusCode::INTERNAL_SERVER_ERROR),
+                };
+
+                let (deferral_tx, deferral_rx) = oneshot::channel();
+
+                tokio::spawn(follow_up_task(
+                    Arc::clone(&self.0.http),
+                    application_id,
+                    interaction,
+                    ephemeral,
+                    follow_up,
+                    deferral_tx,
+                ));
+
+                match deferral_rx.await {
+                    Ok(response) => json(&response),
+                    Err(_) => status(StatusCode::INTERNAL_SERVER_ERROR),
+                }
+            }
+            ReplyKind::Respond(response) => json(&response),
+        }
+    }
+}
+
+impl<H> Clone for InteractionService<H> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<H> Debug for InteractionService<H> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InteractionService")
+            .field("http", &self.0.http)
+            .field("verifier", &self.0.verifier)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<H: Handler> Service<Request<Body>> for InteractionService<H> {
+    type Response = Response<Body>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let service = self.clone();
+
+        Box::pin(async move { Ok(service.request(request).await) })
+    }
+}
+
+/// ID of the application of an interaction that can be deferred.
+///
+/// Autocomplete interactions can't be deferred.
+const fn application_id(interaction: &Interaction) -> Option<Id<ApplicationMarker>> {
+    match interaction {
+        Interaction::ApplicationCommand(command) => Some(command.application_id),
+        Interaction::MessageComponent(component) => Some(component.application_id),
+        _ => None,
+    }
+}
+
+/// Defer an interaction, sending the deferral to be returned as the response
+/// to the request, then wait for a follow-up to complete and respond with its
+/// data.
+///
+/// Responses are routed by an [`InteractionResponder`], so only the fields of
+/// the data that are set are updated. If the follow-up or the response fails
+/// then an error message is sent instead, so that the interaction isn't left
+/// loading.
+///
+/// [`InteractionResponder`]: twilight_http::client::InteractionResponder
+async fn follow_up_task(
+    http: Arc<Client>,
+    application_id: Id<ApplicationMarker>,
+    interaction: Interaction,
+    ephemeral: bool,
+    follow_up: FollowUp,
+    deferral_tx: oneshot::Sender<InteractionResponse>,
+) {
+    let client = http.interaction(application_id);
+    let mut responder = client.responder(&interaction).ephemeral(ephemeral);
+
+    let deferral = match responder.deferral() {
+        Some(deferral) => deferral,
+        None => return,
+    };
+
+    // The request was dropped, so the interaction wasn't deferred.
+    if deferral_tx.send(deferral).is_err() {
+        return;
+    }
+
+    let result = match follow_up.await {
+        Ok(data) => responder.respond(data).await.map_err(From::from),
+        Err(source) => Err(source),
+    };
+
+    if let Err(_source) = result {
+        #[cfg(feature = "tracing")]
+        tracing::warn!("interaction follow-up failed: {}", _source);
+
+        if let Err(_source) = responder.respond(follow_up_error()).await {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("responding with follow-up error failed: {}", _source);
+        }
+    }
+}
+
+/// Data of the response sent when a follow-up fails.
+fn follow_up_error() -> CallbackData {
+    CallbackData {
+        allowed_mentions: None,
+        components: None,
+        content: Some(FOLLOW_UP_ERROR_CONTENT.to_owned()),
+        embeds: None,
+        flags: Some(MessageFlags::EPHEMERAL),
+        tts: None,
+    }
+}
+
+/// Read a request body, failing if it's longer than [`MAX_BODY_LENGTH`].
+///
+/// The length is checked as the body is read, since it may not have a
+/// `Content-Length` header.
+async fn read_body(mut body: Body) -> Result<Vec<u8>, StatusCode> {
+    let mut bytes = Vec::new();
+
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.map_err(|_source| {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("reading interaction request body failed: {}", _source);
+
+            StatusCode::BAD_REQUEST
+        })?;
+
+        if bytes.len() + chunk.len() > MAX_BODY_LENGTH {
+            return Err(StatusCode::PAYLOAD_TOO_LARGE);
+        }
+
+        bytes.extend_from_slice(&chunk);
+    }
+
+    Ok(bytes)
+}
+
+/// Create an empty response with a status code.
+fn status(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = status;
+
+    response
+}
+
+/// Create a response with an interaction response as its JSON body.
+fn json(response: &InteractionResponse) -> Response<Body> {
+    let body = match serde_json::to_vec(response) {
+        Ok(body) => body,
+        Err(_source) => {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("serializing interaction response failed: {}", _source);
+
+            return status(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let mut response = Response::new(Body::from(body));
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{application_id, InteractionService, FOLLOW_UP_ERROR_CONTENT, MAX_BODY_LENGTH};
+    use crate::{HandlerError, Reply};
+    use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+    use hyper::{body, service::Service, Body, Method, Request, Response, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, future, sync::Arc};
+    use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+    use twilight_http::{
+        client::{Transport, TransportFuture},
+        Client,
+    };
+    use twilight_model::application::{
+        callback::{CallbackData, InteractionResponse},
+        interaction::Interaction,
+    };
+
+    type Handler = fn(Interaction) -> future::Ready<Result<Reply, HandlerError>>;
+
+    assert_impl_all!(InteractionService<Handler>: Clone, Debug, Send, Sync);
+
+    const COMMAND: &str = r#"{
+        "application_id": "1",
+        "channel_id": "2",
+        "data": { "id": "3", "name": "ping", "type": 1 },
+        "guild_id": "4",
+        "id": "5",
+        "locale": "en-US",
+        "member": {
+            "deaf": false,
+            "joined_at": "2020-01-01T00:00:00.000000+00:00",
+            "mute": false,
+            "permissions": "0",
+            "roles": [],
+            "user": { "avatar": null, "discriminator": "0001", "id": "6", "username": "user" }
+        },
+        "token": "token",
+        "type": 2,
+        "version": 1
+    }"#;
+
+    /// Transport sending the method, path, and body of requests to a channel.
+    #[derive(Debug)]
+    struct Recorder(UnboundedSender<(Method, String, String)>);
+
+    impl Transport for Recorder {
+        fn send(&self, request: Request<Body>) -> TransportFuture {
+            let requests = self.0.clone();
+
+            Box::pin(async move {
+                let (parts, body) = request.into_parts();
+                let body = String::from_utf8(body::to_bytes(body).await?.to_vec())?;
+                let _ = requests.send((parts.method, parts.uri.path().to_owned(), body));
+
+                Ok(Response::new(Body::from("{}")))
+            })
+        }
+    }
+
+    fn keypair() -> Keypair {
+        let secret = SecretKey::from_bytes(&[7; 32]).unwrap();
+        let public = PublicKey::from(&secret);
+
+        Keypair { public, secret }
+    }
+
+    fn content(content: &str) -> CallbackData {
+        CallbackData {
+            allowed_mentions: None,
+            components: None,
+            content: Some(content.to_owned()),
+            embeds: None,
+            flags: None,
+            tts: None,
+        }
+    }
+
+    /// Create a service whose HTTP client sends its requests to a channel.
+    fn service() -> (
+        InteractionService<impl crate::Handler>,
+        UnboundedReceiver<(Method, String, String)>,
+    ) {
+        async fn handler(interaction: Interaction) -> Result<Reply, HandlerError> {
+            let name = match &interaction {
+                Interaction::ApplicationCommand(command) => command.data.name.as_str(),
+                _ => return Err("unknown interaction".into()),
+            };
+
+            match name {
+                "defer" => Ok(Reply::defer(async { Ok(content("done")) })),
+                "fail" => Ok(Reply::defer(async { Err("follow-up failed".into()) })),
+                "ping" => Ok(Reply::respond(
+                    InteractionResponse::ChannelMessageWithSource(content("pong")),
+                )),
+                _ => Err("unknown command".into()),
+            }
+        }
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let http = Client::builder()
+            .ratelimiter(None)
+            .transport(Box::new(Recorder(tx)))
+            .build();
+        let public_key = hex::encode(keypair().public.as_bytes());
+        let service = InteractionService::new(Arc::new(http), &public_key, handler).unwrap();
+
+        (service, rx)
+    }
+
+    fn request(body: &str, sign: bool) -> Request<Body> {
+        let timestamp = "1640995200";
+        let message = if sign {
+            format!("{}{}", timestamp, body)
+        } else {
+            "other".to_owned()
+        };
+        let signature = hex::encode(keypair().sign(message.as_bytes()).to_bytes());
+
+        Request::builder()
+            .method(Method::POST)
+            .header("x-signature-ed25519", signature)
+            .header("x-signature-timestamp", timestamp)
+            .body(Body::from(body.to_owned()))
+            .unwrap()
+    }
+
+    async fn body(response: hyper::Response<Body>) -> String {
+        let bytes = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_ping() {
+        let response = service()
+            .0
+            .call(request(
+                r#"{"application_id":"1","id":"2","token":"a","type":1}"#,
+                true,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(r#"{"type":1}"#, body(response).await);
+    }
+
+    #[tokio::test]
+    async fn test_handler() {
+        let response = service().0.call(request(COMMAND, true)).await.unwrap();
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(
+            r#"{"type":4,"data":{"content":"pong"}}"#,
+            body(response).await
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejected() {
+        let (mut service, _) = service();
+
+        let response = service.call(request(COMMAND, false)).await.unwrap();
+        assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+
+        let response = service
+            .call(Request::new(Body::from(COMMAND)))
+            .await
+            .unwrap();
+        assert_eq!(StatusCode::METHOD_NOT_ALLOWED, response.status());
+
+        let mut unsigned = request(COMMAND, true);
+        unsigned.headers_mut().remove("x-signature-ed25519");
+        let response = service.call(unsigned).await.unwrap();
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    }
+
+    #[tokio::test]
+    async fn test_handler_error() {
+        let body = COMMAND.replace("\"ping\"", "\"other\"");
+        let response = service().0.call(request(&body, true)).await.unwrap();
+
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, response.status());
+    }
+
+    #[tokio::test]
+    async fn test_body_too_large() {
+        let (mut service, _) = service();
+        let body = " ".repeat(MAX_BODY_LENGTH + 1);
+
+        let response = service.call(request(&body, true)).await.unwrap();
+        assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, response.status());
+
+        let mut declared = request(COMMAND, true);
+        declared.headers_mut().insert(
+            "content-length",
+            (MAX_BODY_LENGTH + 1).to_string().parse().unwrap(),
+        );
+        let response = service.call(declared).await.unwrap();
+        assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, response.status());
+    }
+
+    #[test]
+    fn test_autocomplete_not_deferred() {
+        let command = serde_json::from_str::<Interaction>(COMMAND).unwrap();
+        assert!(application_id(&command).is_some());
+
+        let autocomplete = COMMAND.replace("\"type\": 2", "\"type\": 4");
+        let autocomplete = serde_json::from_str::<Interaction>(&autocomplete).unwrap();
+        assert!(matches!(
+            autocomplete,
+            Interaction::ApplicationCommandAutocomplete(_)
+        ));
+        assert!(application_id(&autocomplete).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_defer() {
+        let (mut service, mut requests) = service();
+        let body = COMMAND.replace("\"ping\"", "\"defer\"");
+
+        let response = service.call(request(&body, true)).await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(r#"{"type":5,"data":{}}"#, self::body(response).await);
+
+        // Only the fields of the follow-up's data that are set are updated.
+        let (method, path, body) = requests.recv().await.unwrap();
+        assert_eq!(Method::PATCH, method);
+        assert_eq!("/api/v9/webhooks/1/token/messages/@original", path);
+        assert_eq!(r#"{"content":"done"}"#, body);
+    }
+
+    #[tokio::test]
+    async fn test_defer_follow_up_error() {
+        let (mut service, mut requests) = service();
+        let body = COMMAND.replace("\"ping\"", "\"fail\"");
+
+        let response = service.call(request(&body, true)).await.unwrap();
+        assert_eq!(r#"{"type":5,"data":{}}"#, self::body(response).await);
+
+        let (method, path, body) = requests.recv().await.unwrap();
+        assert_eq!(Method::PATCH, method);
+        assert_eq!("/api/v9/webhooks/1/token/messages/@original", path);
+        assert_eq!(
+            format!(r#"{{"content":"{}"}}"#, FOLLOW_UP_ERROR_CONTENT),
+            body
+        );
+    }
+}
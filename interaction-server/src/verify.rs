@@ -0,0 +1,264 @@
+//! Verification of the signatures of requests sent by Discord.
+//!
+//! Discord signs each request it sends to an interactions endpoint with the
+//! application's Ed25519 key. The signature is sent in the
+//! `x-signature-ed25519` header as hex, and is of the request's timestamp,
+//! sent in the `x-signature-timestamp` header, followed by the request's body.
+
+use ed25519_dalek::{PublicKey, Signature, Verifier as _, PUBLIC_KEY_LENGTH, SIGNATURE_LENGTH};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Name of the header containing the signature of a request.
+pub const SIGNATURE_HEADER: &str = "x-signature-ed25519";
+
+/// Name of the header containing the timestamp of a request.
+pub const TIMESTAMP_HEADER: &str = "x-signature-timestamp";
+
+/// Parsing a public key failed.
+#[derive(Debug)]
+pub struct PublicKeyError {
+    kind: PublicKeyErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl PublicKeyError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &PublicKeyErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (PublicKeyErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for PublicKeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            PublicKeyErrorType::Decoding => f.write_str("public key is not valid hex"),
+            PublicKeyErrorType::Invalid => f.write_str("public key is not a valid ed25519 key"),
+        }
+    }
+}
+
+impl Error for PublicKeyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`PublicKeyError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PublicKeyErrorType {
+    /// Public key is not hex encoded or is of the wrong length.
+    Decoding,
+    /// Public key is not a valid Ed25519 public key.
+    Invalid,
+}
+
+/// Verifying the signature of a request failed.
+#[derive(Debug)]
+pub struct VerifyError {
+    kind: VerifyErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl VerifyError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &VerifyErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (VerifyErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            VerifyErrorType::SignatureDecoding => f.write_str("signature is not valid hex"),
+            VerifyErrorType::SignatureMismatch => {
+                f.write_str("signature doesn't match the request")
+            }
+        }
+    }
+}
+
+impl Error for VerifyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`VerifyError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum VerifyErrorType {
+    /// Signature is not hex encoded or is of the wrong length.
+    SignatureDecoding,
+    /// Signature is not of the request's timestamp and body.
+    SignatureMismatch,
+}
+
+/// Verifier of the signatures of requests, using an application's public
+/// key.
+///
+/// # Examples
+///
+/// Verify a request by its signature and timestamp headers and body:
+///
+/// ```no_run
+/// use twilight_interaction_server::Verifier;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let verifier = Verifier::new("application public key")?;
+///
+/// let signature = "signature header";
+/// let timestamp = "timestamp header";
+/// let body = br#"{"type":1}"#;
+///
+/// verifier.verify(signature, timestamp, body)?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Verifier {
+    public_key: PublicKey,
+}
+
+impl Verifier {
+    /// Create a new verifier from an application's hex encoded public key, as
+    /// shown in the Developer Portal.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PublicKeyErrorType::Decoding`] error type if the public key
+    /// is not valid hex of the right length.
+    ///
+    /// Returns a [`PublicKeyErrorType::Invalid`] error type if the public key
+    /// is not a valid Ed25519 public key.
+    pub fn new(public_key: &str) -> Result<Self, PublicKeyError> {
+        let mut bytes = [0; PUBLIC_KEY_LENGTH];
+        hex::decode_to_slice(public_key, &mut bytes).map_err(|source| PublicKeyError {
+            kind: PublicKeyErrorType::Decoding,
+            source: Some(Box::new(source)),
+        })?;
+
+        let public_key = PublicKey::from_bytes(&bytes).map_err(|source| PublicKeyError {
+            kind: PublicKeyErrorType::Invalid,
+            source: Some(Box::new(source)),
+        })?;
+
+        Ok(Self { public_key })
+    }
+
+    /// Verify that a hex encoded signature is of a request's timestamp and
+    /// body.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VerifyErrorType::SignatureDecoding`] error type if the
+    /// signature is not valid hex of the right length.
+    ///
+    /// Returns a [`VerifyErrorType::SignatureMismatch`] error type if the
+    /// signature is not of the timestamp and body.
+    pub fn verify(&self, signature: &str, timestamp: &str, body: &[u8]) -> Result<(), VerifyError> {
+        let mut bytes = [0; SIGNATURE_LENGTH];
+        hex::decode_to_slice(signature, &mut bytes).map_err(|source| VerifyError {
+            kind: VerifyErrorType::SignatureDecoding,
+            source: Some(Box::new(source)),
+        })?;
+
+        let signature = Signature::from_bytes(&bytes).map_err(|source| VerifyError {
+            kind: VerifyErrorType::SignatureDecoding,
+            source: Some(Box::new(source)),
+        })?;
+
+        let message = [timestamp.as_bytes(), body].concat();
+
+        self.public_key
+            .verify(&message, &signature)
+            .map_err(|source| VerifyError {
+                kind: VerifyErrorType::SignatureMismatch,
+                source: Some(Box::new(source)),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PublicKeyError, PublicKeyErrorType, Verifier, VerifyError, VerifyErrorType};
+    use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(PublicKeyErrorType: Debug, Send, Sync);
+    assert_impl_all!(PublicKeyError: Error, Send, Sync);
+    assert_impl_all!(VerifyErrorType: Debug, Send, Sync);
+    assert_impl_all!(VerifyError: Error, Send, Sync);
+    assert_impl_all!(Verifier: Clone, Debug, Send, Sync);
+
+    fn keypair() -> Keypair {
+        let secret = SecretKey::from_bytes(&[7; 32]).unwrap();
+        let public = PublicKey::from(&secret);
+
+        Keypair { public, secret }
+    }
+
+    #[test]
+    fn test_verify() {
+        let keypair = keypair();
+        let verifier = Verifier::new(&hex::encode(keypair.public.as_bytes())).unwrap();
+        let signature = hex::encode(keypair.sign(b"1234{\"type\":1}").to_bytes());
+
+        assert!(verifier.verify(&signature, "1234", b"{\"type\":1}").is_ok());
+        assert!(matches!(
+            verifier
+                .verify(&signature, "1235", b"{\"type\":1}")
+                .unwrap_err()
+                .kind(),
+            VerifyErrorType::SignatureMismatch
+        ));
+        assert!(matches!(
+            verifier
+                .verify("zz", "1234", b"{\"type\":1}")
+                .unwrap_err()
+                .kind(),
+            VerifyErrorType::SignatureDecoding
+        ));
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        assert!(matches!(
+            Verifier::new("abc").unwrap_err().kind(),
+            PublicKeyErrorType::Decoding
+        ));
+    }
+}
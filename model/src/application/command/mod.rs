@@ -14,11 +14,15 @@ pub use self::{
     },
 };
 
-use crate::id::{
-    marker::{ApplicationMarker, CommandMarker, CommandVersionMarker, GuildMarker},
-    Id,
+use crate::{
+    guild::Permissions,
+    id::{
+        marker::{ApplicationMarker, CommandMarker, CommandVersionMarker, GuildMarker},
+        Id,
+    },
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Data sent to Discord to create a command.
 ///
@@ -35,6 +39,12 @@ use serde::{Deserialize, Serialize};
 pub struct Command {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_id: Option<Id<ApplicationMarker>>,
+    /// Default permissions required for a member to run the command.
+    ///
+    /// Setting this [`Permissions::empty()`] will prohibit anyone from running
+    /// the command, except for guild administrators.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_member_permissions: Option<Permissions>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_permission: Option<bool>,
     /// Description of the command.
@@ -44,6 +54,19 @@ pub struct Command {
     /// [`User`]: CommandType::User
     /// [`Message`]: CommandType::Message
     pub description: String,
+    /// Localization dictionary for the `description` field.
+    ///
+    /// See [Discord Docs/Localization].
+    ///
+    /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<BTreeMap<String, String>>,
+    /// Whether the command is available in DMs.
+    ///
+    /// This is only relevant for globally-scoped commands. By default, commands
+    /// are visible in DMs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dm_permission: Option<bool>,
     /// Guild ID of the command, if not global.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
@@ -52,8 +75,124 @@ pub struct Command {
     #[serde(rename = "type")]
     pub kind: CommandType,
     pub name: String,
+    /// Localization dictionary for the `name` field.
+    ///
+    /// Keys should be valid locales. See [Discord Docs/Localization].
+    ///
+    /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<BTreeMap<String, String>>,
     #[serde(default)]
     pub options: Vec<CommandOption>,
     /// Autoincrementing version identifier.
     pub version: Id<CommandVersionMarker>,
 }
+
+impl Command {
+    /// Description of the command in a locale, such as the [`locale`] of an
+    /// interaction.
+    ///
+    /// Falls back to the [`description`] if there is no localization for the
+    /// locale.
+    ///
+    /// [`description`]: Self::description
+    /// [`locale`]: crate::application::interaction::Interaction::locale
+    pub fn localized_description(&self, locale: &str) -> &str {
+        localize(
+            &self.description,
+            self.description_localizations.as_ref(),
+            locale,
+        )
+    }
+
+    /// Name of the command in a locale, such as the [`locale`] of an
+    /// interaction.
+    ///
+    /// Falls back to the [`name`] if there is no localization for the locale.
+    ///
+    /// [`locale`]: crate::application::interaction::Interaction::locale
+    /// [`name`]: Self::name
+    pub fn localized_name(&self, locale: &str) -> &str {
+        localize(&self.name, self.name_localizations.as_ref(), locale)
+    }
+}
+
+/// Pick the localization of a value matching a locale.
+///
+/// Locales are matched case insensitively, since Discord documents them as
+/// `en-US` but some payloads use `en-us`. The value itself is returned if
+/// there is no matching localization.
+fn localize<'a>(
+    value: &'a str,
+    localizations: Option<&'a BTreeMap<String, String>>,
+    locale: &str,
+) -> &'a str {
+    let localizations = match localizations {
+        Some(localizations) => localizations,
+        None => return value,
+    };
+
+    localizations
+        .get(locale)
+        .or_else(|| {
+            localizations
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(locale))
+                .map(|(_, localization)| localization)
+        })
+        .map_or(value, String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Command, CommandType};
+    use crate::id::Id;
+    use std::collections::BTreeMap;
+
+    fn command() -> Command {
+        Command {
+            application_id: None,
+            default_member_permissions: None,
+            default_permission: None,
+            description: "Ping the bot".to_owned(),
+            description_localizations: Some(BTreeMap::from([(
+                "de".to_owned(),
+                "Pinge den Bot".to_owned(),
+            )])),
+            dm_permission: None,
+            guild_id: None,
+            id: None,
+            kind: CommandType::ChatInput,
+            name: "ping".to_owned(),
+            name_localizations: Some(BTreeMap::from([
+                ("de".to_owned(), "pingen".to_owned()),
+                ("es-ES".to_owned(), "hacer-ping".to_owned()),
+            ])),
+            options: Vec::new(),
+            version: Id::new(1),
+        }
+    }
+
+    #[test]
+    fn test_localized() {
+        let command = command();
+
+        assert_eq!("pingen", command.localized_name("de"));
+        assert_eq!("Pinge den Bot", command.localized_description("de"));
+        assert_eq!("hacer-ping", command.localized_name("es-es"));
+        assert_eq!("ping", command.localized_name("en-US"));
+        assert_eq!("Ping the bot", command.localized_description("es-ES"));
+    }
+
+    #[test]
+    fn test_localized_missing() {
+        let command = Command {
+            description_localizations: None,
+            name_localizations: None,
+            ..command()
+        };
+
+        assert_eq!("ping", command.localized_name("de"));
+        assert_eq!("Ping the bot", command.localized_description("de"));
+    }
+}
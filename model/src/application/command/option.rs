@@ -7,6 +7,7 @@ use serde::{
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::{
     cmp::Eq,
+    collections::BTreeMap,
     fmt::{Formatter, Result as FmtResult},
     hash::{Hash, Hasher},
 };
@@ -66,6 +67,74 @@ impl CommandOption {
         }
     }
 
+    /// Name of the option.
+    pub fn name(&self) -> &str {
+        match self {
+            CommandOption::SubCommand(data) | CommandOption::SubCommandGroup(data) => &data.name,
+            CommandOption::String(data) => &data.name,
+            CommandOption::Integer(data) | CommandOption::Number(data) => &data.name,
+            CommandOption::Channel(data) => &data.name,
+            CommandOption::Boolean(data)
+            | CommandOption::User(data)
+            | CommandOption::Role(data)
+            | CommandOption::Mentionable(data) => &data.name,
+        }
+    }
+
+    /// Description of the option in a locale, such as the [`locale`] of an
+    /// interaction.
+    ///
+    /// Falls back to the description if there is no localization for the
+    /// locale.
+    ///
+    /// [`locale`]: crate::application::interaction::Interaction::locale
+    pub fn localized_description(&self, locale: &str) -> &str {
+        let (description, localizations) = match self {
+            CommandOption::SubCommand(data) | CommandOption::SubCommandGroup(data) => {
+                (&data.description, &data.description_localizations)
+            }
+            CommandOption::String(data) => (&data.description, &data.description_localizations),
+            CommandOption::Integer(data) | CommandOption::Number(data) => {
+                (&data.description, &data.description_localizations)
+            }
+            CommandOption::Channel(data) => (&data.description, &data.description_localizations),
+            CommandOption::Boolean(data)
+            | CommandOption::User(data)
+            | CommandOption::Role(data)
+            | CommandOption::Mentionable(data) => {
+                (&data.description, &data.description_localizations)
+            }
+        };
+
+        super::localize(description, localizations.as_ref(), locale)
+    }
+
+    /// Name of the option in a locale, such as the [`locale`] of an
+    /// interaction.
+    ///
+    /// Falls back to the [`name`] if there is no localization for the locale.
+    ///
+    /// [`locale`]: crate::application::interaction::Interaction::locale
+    /// [`name`]: Self::name
+    pub fn localized_name(&self, locale: &str) -> &str {
+        let (name, localizations) = match self {
+            CommandOption::SubCommand(data) | CommandOption::SubCommandGroup(data) => {
+                (&data.name, &data.name_localizations)
+            }
+            CommandOption::String(data) => (&data.name, &data.name_localizations),
+            CommandOption::Integer(data) | CommandOption::Number(data) => {
+                (&data.name, &data.name_localizations)
+            }
+            CommandOption::Channel(data) => (&data.name, &data.name_localizations),
+            CommandOption::Boolean(data)
+            | CommandOption::User(data)
+            | CommandOption::Role(data)
+            | CommandOption::Mentionable(data) => (&data.name, &data.name_localizations),
+        };
+
+        super::localize(name, localizations.as_ref(), locale)
+    }
+
     pub const fn is_required(&self) -> bool {
         match self {
             CommandOption::SubCommand(_) | CommandOption::SubCommandGroup(_) => false,
@@ -96,11 +165,15 @@ struct CommandOptionEnvelope<'ser> {
     choices: Option<&'ser [CommandOptionChoice]>,
     description: &'ser str,
     #[serde(skip_serializing_if = "Option::is_none")]
+    description_localizations: Option<&'ser BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     max_value: Option<CommandOptionValue>,
     #[serde(skip_serializing_if = "Option::is_none")]
     min_value: Option<CommandOptionValue>,
     name: &'ser str,
     #[serde(skip_serializing_if = "Option::is_none")]
+    name_localizations: Option<&'ser BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<&'ser [CommandOption]>,
     #[serde(skip_serializing_if = "is_false")]
     required: bool,
@@ -116,9 +189,11 @@ impl Serialize for CommandOption {
                 channel_types: None,
                 choices: None,
                 description: data.description.as_ref(),
+                description_localizations: data.description_localizations.as_ref(),
                 max_value: None,
                 min_value: None,
                 name: data.name.as_ref(),
+                name_localizations: data.name_localizations.as_ref(),
                 options: Some(data.options.as_ref()),
                 required: false,
                 kind: self.kind(),
@@ -128,9 +203,11 @@ impl Serialize for CommandOption {
                 channel_types: None,
                 choices: Some(data.choices.as_ref()),
                 description: data.description.as_ref(),
+                description_localizations: data.description_localizations.as_ref(),
                 max_value: None,
                 min_value: None,
                 name: data.name.as_ref(),
+                name_localizations: data.name_localizations.as_ref(),
                 options: None,
                 required: data.required,
                 kind: self.kind(),
@@ -140,9 +217,11 @@ impl Serialize for CommandOption {
                 channel_types: None,
                 choices: Some(data.choices.as_ref()),
                 description: data.description.as_ref(),
+                description_localizations: data.description_localizations.as_ref(),
                 max_value: data.max_value,
                 min_value: data.min_value,
                 name: data.name.as_ref(),
+                name_localizations: data.name_localizations.as_ref(),
                 options: None,
                 required: data.required,
                 kind: self.kind(),
@@ -152,9 +231,11 @@ impl Serialize for CommandOption {
                 channel_types: Some(data.channel_types.as_ref()),
                 choices: None,
                 description: data.description.as_ref(),
+                description_localizations: data.description_localizations.as_ref(),
                 max_value: None,
                 min_value: None,
                 name: data.name.as_ref(),
+                name_localizations: data.name_localizations.as_ref(),
                 options: None,
                 required: data.required,
                 kind: self.kind(),
@@ -165,9 +246,11 @@ impl Serialize for CommandOption {
                     channel_types: None,
                     choices: None,
                     description: data.description.as_ref(),
+                    description_localizations: data.description_localizations.as_ref(),
                     max_value: None,
                     min_value: None,
                     name: data.name.as_ref(),
+                    name_localizations: data.name_localizations.as_ref(),
                     options: None,
                     required: data.required,
                     kind: self.kind(),
@@ -186,9 +269,11 @@ enum OptionField {
     ChannelTypes,
     Choices,
     Description,
+    DescriptionLocalizations,
     MaxValue,
     MinValue,
     Name,
+    NameLocalizations,
     Options,
     Required,
     Type,
@@ -209,10 +294,12 @@ impl<'de> Visitor<'de> for OptionVisitor {
         let mut channel_types: Option<Option<Vec<ChannelType>>> = None;
         let mut choices: Option<Option<Vec<CommandOptionChoice>>> = None;
         let mut description: Option<String> = None;
+        let mut description_localizations: Option<Option<BTreeMap<String, String>>> = None;
         let mut kind: Option<CommandOptionType> = None;
         let mut max_value: Option<Option<CommandOptionValue>> = None;
         let mut min_value: Option<Option<CommandOptionValue>> = None;
         let mut name: Option<String> = None;
+        let mut name_localizations: Option<Option<BTreeMap<String, String>>> = None;
         let mut options: Option<Option<Vec<CommandOption>>> = None;
         let mut required: Option<bool> = None;
 
@@ -280,6 +367,13 @@ impl<'de> Visitor<'de> for OptionVisitor {
 
                     description = Some(map.next_value()?);
                 }
+                OptionField::DescriptionLocalizations => {
+                    if description_localizations.is_some() {
+                        return Err(DeError::duplicate_field("description_localizations"));
+                    }
+
+                    description_localizations = Some(map.next_value()?);
+                }
                 OptionField::MaxValue => {
                     if max_value.is_some() {
                         return Err(DeError::duplicate_field("max_value"));
@@ -301,6 +395,13 @@ impl<'de> Visitor<'de> for OptionVisitor {
 
                     name = Some(map.next_value()?);
                 }
+                OptionField::NameLocalizations => {
+                    if name_localizations.is_some() {
+                        return Err(DeError::duplicate_field("name_localizations"));
+                    }
+
+                    name_localizations = Some(map.next_value()?);
+                }
                 OptionField::Options => {
                     if options.is_some() {
                         return Err(DeError::duplicate_field("options"));
@@ -338,6 +439,8 @@ impl<'de> Visitor<'de> for OptionVisitor {
         );
 
         let autocomplete = autocomplete.unwrap_or_default();
+        let description_localizations = description_localizations.flatten();
+        let name_localizations = name_localizations.flatten();
         let required = required.unwrap_or_default();
 
         Ok(match kind {
@@ -346,7 +449,9 @@ impl<'de> Visitor<'de> for OptionVisitor {
 
                 CommandOption::SubCommand(OptionsCommandOptionData {
                     description,
+                    description_localizations,
                     name,
+                    name_localizations,
                     options,
                 })
             }
@@ -355,7 +460,9 @@ impl<'de> Visitor<'de> for OptionVisitor {
 
                 CommandOption::SubCommandGroup(OptionsCommandOptionData {
                     description,
+                    description_localizations,
                     name,
+                    name_localizations,
                     options,
                 })
             }
@@ -363,51 +470,67 @@ impl<'de> Visitor<'de> for OptionVisitor {
                 autocomplete,
                 choices: choices.flatten().unwrap_or_default(),
                 description,
+                description_localizations,
                 name,
+                name_localizations,
                 required,
             }),
             CommandOptionType::Integer => CommandOption::Integer(NumberCommandOptionData {
                 autocomplete,
                 choices: choices.flatten().unwrap_or_default(),
                 description,
+                description_localizations,
                 max_value: max_value.flatten(),
                 min_value: min_value.flatten(),
                 name,
+                name_localizations,
                 required,
             }),
             CommandOptionType::Boolean => CommandOption::Boolean(BaseCommandOptionData {
                 description,
+                description_localizations,
                 name,
+                name_localizations,
                 required,
             }),
             CommandOptionType::User => CommandOption::User(BaseCommandOptionData {
                 description,
+                description_localizations,
                 name,
+                name_localizations,
                 required,
             }),
             CommandOptionType::Channel => CommandOption::Channel(ChannelCommandOptionData {
                 channel_types: channel_types.flatten().unwrap_or_default(),
                 description,
+                description_localizations,
                 name,
+                name_localizations,
                 required,
             }),
             CommandOptionType::Role => CommandOption::Role(BaseCommandOptionData {
                 description,
+                description_localizations,
                 name,
+                name_localizations,
                 required,
             }),
             CommandOptionType::Mentionable => CommandOption::Mentionable(BaseCommandOptionData {
                 description,
+                description_localizations,
                 name,
+                name_localizations,
                 required,
             }),
             CommandOptionType::Number => CommandOption::Number(NumberCommandOptionData {
                 autocomplete,
                 choices: choices.flatten().unwrap_or_default(),
                 description,
+                description_localizations,
                 max_value: max_value.flatten(),
                 min_value: min_value.flatten(),
                 name,
+                name_localizations,
                 required,
             }),
         })
@@ -426,8 +549,22 @@ impl<'de> Visitor<'de> for OptionVisitor {
 pub struct BaseCommandOptionData {
     /// Description of the option. It must be 100 characters or less.
     pub description: String,
+    /// Localization dictionary for the `description` field.
+    ///
+    /// See [Discord Docs/Localization].
+    ///
+    /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<BTreeMap<String, String>>,
     /// Name of the option. It must be 32 characters or less.
     pub name: String,
+    /// Localization dictionary for the `name` field.
+    ///
+    /// Keys should be valid locales. See [Discord Docs/Localization].
+    ///
+    /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<BTreeMap<String, String>>,
     /// Whether the option is required to be completed by a user.
     #[serde(default)]
     pub required: bool,
@@ -442,8 +579,22 @@ pub struct BaseCommandOptionData {
 pub struct OptionsCommandOptionData {
     /// Description of the option. It must be 100 characters or less.
     pub description: String,
+    /// Localization dictionary for the `description` field.
+    ///
+    /// See [Discord Docs/Localization].
+    ///
+    /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<BTreeMap<String, String>>,
     /// Name of the option. It must be 32 characters or less.
     pub name: String,
+    /// Localization dictionary for the `name` field.
+    ///
+    /// Keys should be valid locales. See [Discord Docs/Localization].
+    ///
+    /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<BTreeMap<String, String>>,
     /// Used for specifying the nested options in a [`SubCommand`] or
     /// [`SubCommandGroup`].
     ///
@@ -471,8 +622,22 @@ pub struct ChoiceCommandOptionData {
     pub choices: Vec<CommandOptionChoice>,
     /// Description of the option. It must be 100 characters or less.
     pub description: String,
+    /// Localization dictionary for the `description` field.
+    ///
+    /// See [Discord Docs/Localization].
+    ///
+    /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<BTreeMap<String, String>>,
     /// Name of the option. It must be 32 characters or less.
     pub name: String,
+    /// Localization dictionary for the `name` field.
+    ///
+    /// Keys should be valid locales. See [Discord Docs/Localization].
+    ///
+    /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<BTreeMap<String, String>>,
     /// Whether or not the option is required to be completed by a user.
     #[serde(default)]
     pub required: bool,
@@ -490,8 +655,22 @@ pub struct ChannelCommandOptionData {
     pub channel_types: Vec<ChannelType>,
     /// Description of the option. It must be 100 characters or less.
     pub description: String,
+    /// Localization dictionary for the `description` field.
+    ///
+    /// See [Discord Docs/Localization].
+    ///
+    /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<BTreeMap<String, String>>,
     /// Name of the option. It must be 32 characters or less.
     pub name: String,
+    /// Localization dictionary for the `name` field.
+    ///
+    /// Keys should be valid locales. See [Discord Docs/Localization].
+    ///
+    /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<BTreeMap<String, String>>,
     /// Whether or not the option is required to be completed by a user.
     #[serde(default)]
     pub required: bool,
@@ -516,6 +695,13 @@ pub struct NumberCommandOptionData {
     pub choices: Vec<CommandOptionChoice>,
     /// Description of the option. It must be 100 characters or less.
     pub description: String,
+    /// Localization dictionary for the `description` field.
+    ///
+    /// See [Discord Docs/Localization].
+    ///
+    /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<BTreeMap<String, String>>,
     /// Maximum value permitted.
     #[serde(default)]
     pub max_value: Option<CommandOptionValue>,
@@ -524,6 +710,13 @@ pub struct NumberCommandOptionData {
     pub min_value: Option<CommandOptionValue>,
     /// Name of the option. It must be 32 characters or less.
     pub name: String,
+    /// Localization dictionary for the `name` field.
+    ///
+    /// Keys should be valid locales. See [Discord Docs/Localization].
+    ///
+    /// [Discord Docs/Localization]: https://discord.com/developers/docs/interactions/application-commands#localization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<BTreeMap<String, String>>,
     /// Whether or not the option is required to be completed by a user.
     #[serde(default)]
     pub required: bool,
@@ -537,9 +730,60 @@ pub struct NumberCommandOptionData {
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum CommandOptionChoice {
-    String { name: String, value: String },
-    Int { name: String, value: i64 },
-    Number { name: String, value: Number },
+    String {
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name_localizations: Option<BTreeMap<String, String>>,
+        value: String,
+    },
+    Int {
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name_localizations: Option<BTreeMap<String, String>>,
+        value: i64,
+    },
+    Number {
+        name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name_localizations: Option<BTreeMap<String, String>>,
+        value: Number,
+    },
+}
+
+impl CommandOptionChoice {
+    /// Name of the choice.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::String { name, .. } | Self::Int { name, .. } | Self::Number { name, .. } => name,
+        }
+    }
+
+    /// Name of the choice in a locale, such as the [`locale`] of an
+    /// interaction.
+    ///
+    /// Falls back to the [`name`] if there is no localization for the locale.
+    ///
+    /// [`locale`]: crate::application::interaction::Interaction::locale
+    /// [`name`]: Self::name
+    pub fn localized_name(&self, locale: &str) -> &str {
+        match self {
+            Self::String {
+                name,
+                name_localizations,
+                ..
+            }
+            | Self::Int {
+                name,
+                name_localizations,
+                ..
+            }
+            | Self::Number {
+                name,
+                name_localizations,
+                ..
+            } => super::localize(name, name_localizations.as_ref(), locale),
+        }
+    }
 }
 
 /// Type used in `max_value` and `min_value` command option field.
@@ -624,7 +868,7 @@ mod tests {
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
     use static_assertions::assert_impl_all;
-    use std::{fmt::Debug, hash::Hash};
+    use std::{collections::BTreeMap, fmt::Debug, hash::Hash};
 
     assert_impl_all!(
         Number: Clone,
@@ -644,7 +888,9 @@ mod tests {
     fn test_issue_1150() {
         let value = CommandOption::SubCommand(OptionsCommandOptionData {
             description: "ponyville".to_owned(),
+            description_localizations: None,
             name: "equestria".to_owned(),
+            name_localizations: None,
             options: Vec::new(),
         });
 
@@ -673,84 +919,113 @@ mod tests {
     fn test_command_option_full() {
         let value = Command {
             application_id: Some(Id::new(100)),
+            default_member_permissions: None,
             default_permission: Some(true),
             description: "this command is a test".into(),
+            description_localizations: None,
+            dm_permission: None,
             guild_id: Some(Id::new(300)),
             id: Some(Id::new(200)),
             kind: CommandType::ChatInput,
             name: "test command".into(),
+            name_localizations: None,
             options: Vec::from([CommandOption::SubCommandGroup(OptionsCommandOptionData {
                 description: "sub group desc".into(),
+                description_localizations: None,
                 name: "sub group name".into(),
+                name_localizations: None,
                 options: Vec::from([CommandOption::SubCommand(OptionsCommandOptionData {
                     description: "sub command desc".into(),
+                    description_localizations: None,
                     name: "sub command name".into(),
+                    name_localizations: None,
                     options: Vec::from([
                         CommandOption::String(ChoiceCommandOptionData {
                             autocomplete: true,
                             choices: Vec::new(),
                             description: "string manual desc".into(),
+                            description_localizations: None,
                             name: "string_manual".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::String(ChoiceCommandOptionData {
                             autocomplete: false,
                             choices: Vec::from([CommandOptionChoice::String {
                                 name: "choicea".into(),
+                                name_localizations: None,
                                 value: "choice_a".into(),
                             }]),
                             description: "string desc".into(),
+                            description_localizations: None,
                             name: "string".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::Integer(NumberCommandOptionData {
                             autocomplete: false,
                             choices: Vec::from([CommandOptionChoice::Int {
                                 name: "choice2".into(),
+                                name_localizations: None,
                                 value: 2,
                             }]),
                             description: "int desc".into(),
+                            description_localizations: None,
                             max_value: Some(CommandOptionValue::Integer(20)),
                             min_value: Some(CommandOptionValue::Integer(10)),
                             name: "int".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::Boolean(BaseCommandOptionData {
                             description: "bool desc".into(),
+                            description_localizations: None,
                             name: "bool".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::User(BaseCommandOptionData {
                             description: "user desc".into(),
+                            description_localizations: None,
                             name: "user".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::Channel(ChannelCommandOptionData {
                             channel_types: Vec::from([ChannelType::GuildText]),
                             description: "channel desc".into(),
+                            description_localizations: None,
                             name: "channel".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::Role(BaseCommandOptionData {
                             description: "role desc".into(),
+                            description_localizations: None,
                             name: "role".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::Mentionable(BaseCommandOptionData {
                             description: "mentionable desc".into(),
+                            description_localizations: None,
                             name: "mentionable".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::Number(NumberCommandOptionData {
                             autocomplete: false,
                             choices: Vec::from([CommandOptionChoice::Number {
                                 name: "choice3".into(),
+                                name_localizations: None,
                                 value: Number(2.0),
                             }]),
                             description: "number desc".into(),
+                            description_localizations: None,
                             max_value: Some(CommandOptionValue::Number(Number(5.5))),
                             min_value: Some(CommandOptionValue::Number(Number(10.0))),
                             name: "number".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                     ]),
@@ -992,6 +1267,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_command_option_localizations() {
+        let value = CommandOption::String(ChoiceCommandOptionData {
+            autocomplete: false,
+            choices: Vec::from([CommandOptionChoice::String {
+                name: "dog".into(),
+                name_localizations: Some(BTreeMap::from([("de".into(), "hund".into())])),
+                value: "animal_dog".into(),
+            }]),
+            description: "kind of animal".into(),
+            description_localizations: Some(BTreeMap::from([(
+                "de".into(),
+                "Art des Tieres".into(),
+            )])),
+            name: "animal".into(),
+            name_localizations: Some(BTreeMap::from([("de".into(), "tier".into())])),
+            required: true,
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CommandOptionEnvelope",
+                    len: 7,
+                },
+                Token::Str("choices"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "CommandOptionChoice",
+                    len: 3,
+                },
+                Token::Str("name"),
+                Token::Str("dog"),
+                Token::Str("name_localizations"),
+                Token::Some,
+                Token::Map { len: Some(1) },
+                Token::Str("de"),
+                Token::Str("hund"),
+                Token::MapEnd,
+                Token::Str("value"),
+                Token::Str("animal_dog"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("description"),
+                Token::Str("kind of animal"),
+                Token::Str("description_localizations"),
+                Token::Some,
+                Token::Map { len: Some(1) },
+                Token::Str("de"),
+                Token::Str("Art des Tieres"),
+                Token::MapEnd,
+                Token::Str("name"),
+                Token::Str("animal"),
+                Token::Str("name_localizations"),
+                Token::Some,
+                Token::Map { len: Some(1) },
+                Token::Str("de"),
+                Token::Str("tier"),
+                Token::MapEnd,
+                Token::Str("required"),
+                Token::Bool(true),
+                Token::Str("type"),
+                Token::U8(3),
+                Token::StructEnd,
+            ],
+        );
+
+        assert_eq!("tier", value.localized_name("de"));
+        assert_eq!("Art des Tieres", value.localized_description("de"));
+        assert_eq!("animal", value.localized_name("fr"));
+    }
+
     #[test]
     fn test_number() {
         const NUMBER_1: Number = Number(12.34_f64);
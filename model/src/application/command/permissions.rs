@@ -1,6 +1,7 @@
 use crate::id::{
     marker::{
-        ApplicationMarker, CommandMarker, GenericMarker, GuildMarker, RoleMarker, UserMarker,
+        ApplicationMarker, ChannelMarker, CommandMarker, GenericMarker, GuildMarker, RoleMarker,
+        UserMarker,
     },
     Id,
 };
@@ -23,10 +24,31 @@ pub struct CommandPermissions {
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CommandPermissionsType {
+    /// Permission of a channel.
+    ///
+    /// Use [`all_channels`] to target all channels in a guild.
+    ///
+    /// [`all_channels`]: Self::all_channels
+    Channel(Id<ChannelMarker>),
     Role(Id<RoleMarker>),
     User(Id<UserMarker>),
 }
 
+impl CommandPermissionsType {
+    /// Target all channels in a guild.
+    ///
+    /// Discord represents all channels by the ID of the guild minus one, so
+    /// returns [`None`] if the ID of the guild is 1.
+    pub const fn all_channels(guild_id: Id<GuildMarker>) -> Option<Self> {
+        #[allow(clippy::option_if_let_else)]
+        if let Some(id) = Id::new_checked(guild_id.get() - 1) {
+            Some(Self::Channel(id))
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize)]
 struct CommandPermissionsData {
     id: Id<GenericMarker>,
@@ -40,6 +62,7 @@ struct CommandPermissionsData {
 enum CommandPermissionsDataType {
     Role = 1,
     User = 2,
+    Channel = 3,
 }
 
 impl<'de> Deserialize<'de> for CommandPermissions {
@@ -52,6 +75,13 @@ impl<'de> Deserialize<'de> for CommandPermissions {
         let _span_enter = span.enter();
 
         let id = match data.kind {
+            CommandPermissionsDataType::Channel => {
+                let id = data.id.cast();
+                #[cfg(feature = "tracing")]
+                tracing::trace!(id = %id.get(), kind = ?data.kind);
+
+                CommandPermissionsType::Channel(id)
+            }
             CommandPermissionsDataType::Role => {
                 let id = data.id.cast();
                 #[cfg(feature = "tracing")]
@@ -79,10 +109,12 @@ impl Serialize for CommandPermissions {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let data = CommandPermissionsData {
             id: match self.id {
+                CommandPermissionsType::Channel(channel_id) => channel_id.cast(),
                 CommandPermissionsType::Role(role_id) => role_id.cast(),
                 CommandPermissionsType::User(user_id) => user_id.cast(),
             },
             kind: match self.id {
+                CommandPermissionsType::Channel(_) => CommandPermissionsDataType::Channel,
                 CommandPermissionsType::Role(_) => CommandPermissionsDataType::Role,
                 CommandPermissionsType::User(_) => CommandPermissionsDataType::User,
             },
@@ -124,4 +156,33 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_command_permissions_channel() {
+        let value = CommandPermissions {
+            id: CommandPermissionsType::all_channels(Id::new(200)).unwrap(),
+            permission: false,
+        };
+
+        assert_eq!(CommandPermissionsType::Channel(Id::new(199)), value.id);
+        assert_eq!(None, CommandPermissionsType::all_channels(Id::new(1)));
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CommandPermissionsData",
+                    len: 3,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("199"),
+                Token::Str("type"),
+                Token::U8(3),
+                Token::Str("permission"),
+                Token::Bool(false),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -68,6 +68,33 @@ impl Interaction {
             Self::MessageComponent(component) => component.id,
         }
     }
+
+    /// Return the selected language of the user who triggered the inner
+    /// interaction.
+    ///
+    /// Returns `None` for [`Ping`] interactions.
+    ///
+    /// [`Ping`]: Self::Ping
+    pub fn locale(&self) -> Option<&str> {
+        match self {
+            Self::Ping(_) => None,
+            Self::ApplicationCommand(command) | Self::ApplicationCommandAutocomplete(command) => {
+                Some(&command.locale)
+            }
+            Self::MessageComponent(component) => Some(&component.locale),
+        }
+    }
+
+    /// Return the token of the inner interaction.
+    pub fn token(&self) -> &str {
+        match self {
+            Self::Ping(ping) => &ping.token,
+            Self::ApplicationCommand(command) | Self::ApplicationCommandAutocomplete(command) => {
+                &command.token
+            }
+            Self::MessageComponent(component) => &component.token,
+        }
+    }
 }
 
 impl<'de> Deserialize<'de> for Interaction {
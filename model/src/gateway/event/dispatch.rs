@@ -20,6 +20,7 @@ pub enum DispatchEvent {
     ChannelDelete(ChannelDelete),
     ChannelPinsUpdate(ChannelPinsUpdate),
     ChannelUpdate(ChannelUpdate),
+    CommandPermissionsUpdate(CommandPermissionsUpdate),
     GiftCodeUpdate,
     GuildCreate(Box<GuildCreate>),
     GuildDelete(Box<GuildDelete>),
@@ -78,6 +79,7 @@ impl DispatchEvent {
             Self::ChannelDelete(_) => EventType::ChannelDelete,
             Self::ChannelPinsUpdate(_) => EventType::ChannelPinsUpdate,
             Self::ChannelUpdate(_) => EventType::ChannelUpdate,
+            Self::CommandPermissionsUpdate(_) => EventType::CommandPermissionsUpdate,
             Self::GiftCodeUpdate => EventType::GiftCodeUpdate,
             Self::GuildCreate(_) => EventType::GuildCreate,
             Self::GuildDelete(_) => EventType::GuildDelete,
@@ -139,6 +141,7 @@ impl TryFrom<Event> for DispatchEvent {
             Event::ChannelDelete(v) => Self::ChannelDelete(v),
             Event::ChannelPinsUpdate(v) => Self::ChannelPinsUpdate(v),
             Event::ChannelUpdate(v) => Self::ChannelUpdate(v),
+            Event::CommandPermissionsUpdate(v) => Self::CommandPermissionsUpdate(v),
             Event::GiftCodeUpdate => Self::GiftCodeUpdate,
             Event::GuildCreate(v) => Self::GuildCreate(v),
             Event::GuildDelete(v) => Self::GuildDelete(v),
@@ -222,6 +225,9 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
             "CHANNEL_UPDATE" => {
                 DispatchEvent::ChannelUpdate(ChannelUpdate::deserialize(deserializer)?)
             }
+            "APPLICATION_COMMAND_PERMISSIONS_UPDATE" => DispatchEvent::CommandPermissionsUpdate(
+                CommandPermissionsUpdate::deserialize(deserializer)?,
+            ),
             "GIFT_CODE_UPDATE" => {
                 deserializer.deserialize_ignored_any(IgnoredAny)?;
 
@@ -369,9 +375,52 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
 #[cfg(test)]
 mod tests {
     use super::{DispatchEvent, DispatchEventWithTypeDeserializer};
+    use crate::{
+        application::command::permissions::{
+            CommandPermissions, CommandPermissionsType, GuildCommandPermissions,
+        },
+        gateway::payload::incoming::CommandPermissionsUpdate,
+        id::Id,
+    };
     use serde::de::DeserializeSeed;
     use serde_json::Deserializer;
 
+    #[test]
+    fn test_command_permissions_update() {
+        let input = r#"{
+            "application_id": "1",
+            "guild_id": "2",
+            "id": "3",
+            "permissions": [
+                {
+                    "id": "4",
+                    "permission": true,
+                    "type": 3
+                }
+            ]
+        }"#;
+
+        let deserializer =
+            DispatchEventWithTypeDeserializer::new("APPLICATION_COMMAND_PERMISSIONS_UPDATE");
+        let mut json_deserializer = Deserializer::from_str(input);
+        let event = deserializer.deserialize(&mut json_deserializer).unwrap();
+
+        assert_eq!(
+            event,
+            DispatchEvent::CommandPermissionsUpdate(CommandPermissionsUpdate(
+                GuildCommandPermissions {
+                    application_id: Id::new(1),
+                    guild_id: Id::new(2),
+                    id: Id::new(3),
+                    permissions: Vec::from([CommandPermissions {
+                        id: CommandPermissionsType::Channel(Id::new(4)),
+                        permission: true,
+                    }]),
+                }
+            ))
+        );
+    }
+
     #[test]
     fn test_gift_code_update() {
         // Input will be ignored so long as it's valid JSON.
@@ -12,6 +12,8 @@ pub enum EventType {
     ChannelDelete,
     ChannelPinsUpdate,
     ChannelUpdate,
+    #[serde(rename = "APPLICATION_COMMAND_PERMISSIONS_UPDATE")]
+    CommandPermissionsUpdate,
     GatewayHeartbeat,
     GatewayHeartbeatAck,
     GatewayHello,
@@ -59,6 +61,7 @@ pub enum EventType {
     RoleDelete,
     #[serde(rename = "GUILD_ROLE_UPDATE")]
     RoleUpdate,
+    ShardCommandRatelimited,
     ShardConnected,
     ShardConnecting,
     ShardDisconnected,
@@ -92,6 +95,7 @@ impl EventType {
             Self::ChannelDelete => Some("CHANNEL_DELETE"),
             Self::ChannelPinsUpdate => Some("CHANNEL_PINS_UPDATE"),
             Self::ChannelUpdate => Some("CHANNEL_UPDATE"),
+            Self::CommandPermissionsUpdate => Some("APPLICATION_COMMAND_PERMISSIONS_UPDATE"),
             Self::GiftCodeUpdate => Some("GIFT_CODE_UPDATE"),
             Self::GuildCreate => Some("GUILD_CREATE"),
             Self::GuildDelete => Some("GUILD_DELETE"),
@@ -143,6 +147,7 @@ impl EventType {
             | Self::GatewayHello
             | Self::GatewayInvalidateSession
             | Self::GatewayReconnect
+            | Self::ShardCommandRatelimited
             | Self::ShardConnected
             | Self::ShardConnecting
             | Self::ShardDisconnected
@@ -165,6 +170,7 @@ impl<'a> TryFrom<&'a str> for EventType {
             "CHANNEL_DELETE" => Ok(Self::ChannelDelete),
             "CHANNEL_PINS_UPDATE" => Ok(Self::ChannelPinsUpdate),
             "CHANNEL_UPDATE" => Ok(Self::ChannelUpdate),
+            "APPLICATION_COMMAND_PERMISSIONS_UPDATE" => Ok(Self::CommandPermissionsUpdate),
             "GIFT_CODE_UPDATE" => Ok(Self::GiftCodeUpdate),
             "GUILD_CREATE" => Ok(Self::GuildCreate),
             "GUILD_DELETE" => Ok(Self::GuildDelete),
@@ -239,6 +245,10 @@ mod tests {
         assert_variant(EventType::ChannelDelete, "CHANNEL_DELETE");
         assert_variant(EventType::ChannelPinsUpdate, "CHANNEL_PINS_UPDATE");
         assert_variant(EventType::ChannelUpdate, "CHANNEL_UPDATE");
+        assert_variant(
+            EventType::CommandPermissionsUpdate,
+            "APPLICATION_COMMAND_PERMISSIONS_UPDATE",
+        );
         assert_variant(EventType::GatewayHeartbeat, "GATEWAY_HEARTBEAT");
         assert_variant(EventType::GatewayHeartbeatAck, "GATEWAY_HEARTBEAT_ACK");
         assert_variant(EventType::GatewayHello, "GATEWAY_HELLO");
@@ -284,6 +294,10 @@ mod tests {
         assert_variant(EventType::RoleCreate, "GUILD_ROLE_CREATE");
         assert_variant(EventType::RoleDelete, "GUILD_ROLE_DELETE");
         assert_variant(EventType::RoleUpdate, "GUILD_ROLE_UPDATE");
+        assert_variant(
+            EventType::ShardCommandRatelimited,
+            "SHARD_COMMAND_RATELIMITED",
+        );
         assert_variant(EventType::ShardConnected, "SHARD_CONNECTED");
         assert_variant(EventType::ShardConnecting, "SHARD_CONNECTING");
         assert_variant(EventType::ShardDisconnected, "SHARD_DISCONNECTED");
@@ -35,6 +35,8 @@ pub enum Event {
     ChannelPinsUpdate(ChannelPinsUpdate),
     /// A channel was updated.
     ChannelUpdate(ChannelUpdate),
+    /// Permissions of an application command were updated.
+    CommandPermissionsUpdate(CommandPermissionsUpdate),
     /// A heartbeat was sent to or received from the gateway.
     GatewayHeartbeat(u64),
     /// A heartbeat acknowledgement was received from the gateway.
@@ -112,6 +114,9 @@ pub enum Event {
     RoleDelete(RoleDelete),
     /// A role was updated in a guild.
     RoleUpdate(RoleUpdate),
+    /// A command sent over a shard was delayed by the shard's command
+    /// ratelimiter.
+    ShardCommandRatelimited(CommandRatelimited),
     /// A shard is now in a connected stage after being fully connected to the
     /// gateway.
     ShardConnected(Connected),
@@ -171,6 +176,7 @@ impl Event {
             Self::ChannelDelete(_) => EventType::ChannelDelete,
             Self::ChannelPinsUpdate(_) => EventType::ChannelPinsUpdate,
             Self::ChannelUpdate(_) => EventType::ChannelUpdate,
+            Self::CommandPermissionsUpdate(_) => EventType::CommandPermissionsUpdate,
             Self::GatewayHeartbeat(_) => EventType::GatewayHeartbeat,
             Self::GatewayHeartbeatAck => EventType::GatewayHeartbeatAck,
             Self::GatewayHello(_) => EventType::GatewayHello,
@@ -207,6 +213,7 @@ impl Event {
             Self::RoleCreate(_) => EventType::RoleCreate,
             Self::RoleDelete(_) => EventType::RoleDelete,
             Self::RoleUpdate(_) => EventType::RoleUpdate,
+            Self::ShardCommandRatelimited(_) => EventType::ShardCommandRatelimited,
             Self::ShardConnected(_) => EventType::ShardConnected,
             Self::ShardConnecting(_) => EventType::ShardConnecting,
             Self::ShardDisconnected(_) => EventType::ShardDisconnected,
@@ -242,6 +249,7 @@ impl From<Box<DispatchEvent>> for Event {
             DispatchEvent::ChannelDelete(v) => Self::ChannelDelete(v),
             DispatchEvent::ChannelPinsUpdate(v) => Self::ChannelPinsUpdate(v),
             DispatchEvent::ChannelUpdate(v) => Self::ChannelUpdate(v),
+            DispatchEvent::CommandPermissionsUpdate(v) => Self::CommandPermissionsUpdate(v),
             DispatchEvent::GiftCodeUpdate => Self::GiftCodeUpdate,
             DispatchEvent::GuildCreate(v) => Self::GuildCreate(v),
             DispatchEvent::GuildDelete(v) => Self::GuildDelete(v),
@@ -308,6 +316,7 @@ impl From<GatewayEvent> for Event {
 impl From<ShardEvent> for Event {
     fn from(event: ShardEvent) -> Self {
         match event {
+            ShardEvent::CommandRatelimited(v) => Self::ShardCommandRatelimited(v),
             ShardEvent::Connected(v) => Self::ShardConnected(v),
             ShardEvent::Connecting(v) => Self::ShardConnecting(v),
             ShardEvent::Disconnected(v) => Self::ShardDisconnected(v),
@@ -1,6 +1,19 @@
 use super::{Event, EventConversionError};
 use serde::{Deserialize, Serialize};
 
+/// Indicator that a command sent over a shard has been delayed by the shard's
+/// command ratelimiter.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct CommandRatelimited {
+    /// Number of commands, including the delayed one, waiting for the
+    /// ratelimiter.
+    pub queued: u32,
+    /// Number of milliseconds until the ratelimiter is next refilled.
+    pub refill_in: u64,
+    /// The ID of the shard whose command was delayed.
+    pub shard_id: u64,
+}
+
 /// Indicator that a shard is now fully connected.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Connected {
@@ -68,6 +81,9 @@ pub struct Resuming {
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ShardEvent {
+    /// A command sent over a shard was delayed by the shard's command
+    /// ratelimiter.
+    CommandRatelimited(CommandRatelimited),
     /// A shard is now in a Connected stage after being fully connected to the
     /// gateway.
     Connected(Connected),
@@ -92,6 +108,7 @@ impl TryFrom<Event> for ShardEvent {
 
     fn try_from(event: Event) -> Result<Self, Self::Error> {
         Ok(match event {
+            Event::ShardCommandRatelimited(v) => Self::CommandRatelimited(v),
             Event::ShardConnected(v) => Self::Connected(v),
             Event::ShardConnecting(v) => Self::Connecting(v),
             Event::ShardDisconnected(v) => Self::Disconnected(v),
@@ -108,11 +125,37 @@ impl TryFrom<Event> for ShardEvent {
 #[cfg(test)]
 mod tests {
     use super::{
-        Connected, Connecting, Disconnected, Event, Identifying, Payload, Reconnecting, Resuming,
-        ShardEvent,
+        CommandRatelimited, Connected, Connecting, Disconnected, Event, Identifying, Payload,
+        Reconnecting, Resuming, ShardEvent,
     };
     use serde_test::Token;
 
+    #[test]
+    fn test_command_ratelimited() {
+        let value = CommandRatelimited {
+            queued: 2,
+            refill_in: 30_000,
+            shard_id: 4,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CommandRatelimited",
+                    len: 3,
+                },
+                Token::Str("queued"),
+                Token::U32(2),
+                Token::Str("refill_in"),
+                Token::U64(30_000),
+                Token::Str("shard_id"),
+                Token::U64(4),
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn test_connected() {
         let value = Connected {
@@ -274,6 +317,16 @@ mod tests {
 
     #[test]
     fn test_shard_event_try_from_event() {
+        let command_ratelimited = Event::ShardCommandRatelimited(CommandRatelimited {
+            queued: 2,
+            refill_in: 30_000,
+            shard_id: 4,
+        });
+        assert!(matches!(
+            command_ratelimited.try_into().unwrap(),
+            ShardEvent::CommandRatelimited(_)
+        ));
+
         let connected = Event::ShardConnected(Connected {
             heartbeat_interval: 41_250,
             shard_id: 4,
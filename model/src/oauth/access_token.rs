@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use std::str::SplitWhitespace;
+
+/// `OAuth2` access token granted to an application.
+///
+/// Access tokens are granted by exchanging an authorization code, refreshing
+/// a previously granted token, or via the client credentials flow.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AccessToken {
+    /// Token to authorize requests with as a bearer token.
+    pub access_token: String,
+    /// Number of seconds until the access token expires.
+    pub expires_in: u64,
+    /// Token to refresh the access token with.
+    ///
+    /// Not granted via the client credentials flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// Space-separated list of the scopes granted.
+    pub scope: String,
+    /// Type of the token, which is always `Bearer`.
+    pub token_type: String,
+}
+
+impl AccessToken {
+    /// Iterator over the scopes granted.
+    pub fn scopes(&self) -> SplitWhitespace<'_> {
+        self.scope.split_whitespace()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AccessToken;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        AccessToken: access_token,
+        expires_in,
+        refresh_token,
+        scope,
+        token_type
+    );
+    assert_impl_all!(
+        AccessToken: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize
+    );
+
+    #[test]
+    fn test_access_token() {
+        let value = AccessToken {
+            access_token: "6qrZcUqja7812RVdnEKjpzOL4CvHBFG".to_owned(),
+            expires_in: 604_800,
+            refresh_token: Some("D43f5y0ahjqew82jZ4NViEr2YafMKhue".to_owned()),
+            scope: "identify guilds".to_owned(),
+            token_type: "Bearer".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AccessToken",
+                    len: 5,
+                },
+                Token::Str("access_token"),
+                Token::Str("6qrZcUqja7812RVdnEKjpzOL4CvHBFG"),
+                Token::Str("expires_in"),
+                Token::U64(604_800),
+                Token::Str("refresh_token"),
+                Token::Some,
+                Token::Str("D43f5y0ahjqew82jZ4NViEr2YafMKhue"),
+                Token::Str("scope"),
+                Token::Str("identify guilds"),
+                Token::Str("token_type"),
+                Token::Str("Bearer"),
+                Token::StructEnd,
+            ],
+        );
+
+        assert_eq!(
+            ["identify", "guilds"],
+            value.scopes().collect::<Vec<_>>()[..]
+        );
+    }
+}
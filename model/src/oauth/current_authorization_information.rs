@@ -0,0 +1,146 @@
+use crate::{
+    datetime::Timestamp,
+    id::{marker::ApplicationMarker, Id},
+    user::User,
+    util::image_hash::ImageHash,
+};
+use serde::{Deserialize, Serialize};
+
+/// Application an `OAuth2` authorization has been granted to.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AuthorizationApplication {
+    /// Whether the application's bot can be added to guilds by anyone.
+    pub bot_public: bool,
+    /// Whether the application's bot requires the full authorization code
+    /// grant to be added to guilds.
+    pub bot_require_code_grant: bool,
+    /// Description of the application.
+    pub description: String,
+    /// Hash of the application's icon.
+    pub icon: Option<ImageHash>,
+    /// ID of the application.
+    pub id: Id<ApplicationMarker>,
+    /// Name of the application.
+    pub name: String,
+    /// Hex-encoded key to verify interactions sent to the application.
+    pub verify_key: String,
+}
+
+/// Information about the `OAuth2` authorization of the current bearer token.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct CurrentAuthorizationInformation {
+    /// Application the authorization has been granted to.
+    pub application: AuthorizationApplication,
+    /// When the access token expires.
+    pub expires: Timestamp,
+    /// Scopes the user has authorized the application for.
+    pub scopes: Vec<String>,
+    /// User who has authorized the application.
+    ///
+    /// Present if the `identify` scope has been authorized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuthorizationApplication, CurrentAuthorizationInformation};
+    use crate::{datetime::Timestamp, id::Id, test::image_hash};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug, hash::Hash, str::FromStr};
+
+    assert_fields!(
+        AuthorizationApplication: bot_public,
+        bot_require_code_grant,
+        description,
+        icon,
+        id,
+        name,
+        verify_key
+    );
+    assert_fields!(
+        CurrentAuthorizationInformation: application,
+        expires,
+        scopes,
+        user
+    );
+    assert_impl_all!(
+        AuthorizationApplication: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize
+    );
+    assert_impl_all!(
+        CurrentAuthorizationInformation: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize
+    );
+
+    #[test]
+    fn test_current_authorization_information() -> Result<(), Box<dyn Error>> {
+        let value = CurrentAuthorizationInformation {
+            application: AuthorizationApplication {
+                bot_public: true,
+                bot_require_code_grant: false,
+                description: "a pretty cool application".to_owned(),
+                icon: Some(image_hash::ICON),
+                id: Id::new(1),
+                name: "cool application".to_owned(),
+                verify_key: "key".to_owned(),
+            },
+            expires: Timestamp::from_str("2021-12-23T14:29:19.046000+00:00")?,
+            scopes: vec!["guilds.join".to_owned()],
+            user: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CurrentAuthorizationInformation",
+                    len: 3,
+                },
+                Token::Str("application"),
+                Token::Struct {
+                    name: "AuthorizationApplication",
+                    len: 7,
+                },
+                Token::Str("bot_public"),
+                Token::Bool(true),
+                Token::Str("bot_require_code_grant"),
+                Token::Bool(false),
+                Token::Str("description"),
+                Token::Str("a pretty cool application"),
+                Token::Str("icon"),
+                Token::Some,
+                Token::Str(image_hash::ICON_INPUT),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("cool application"),
+                Token::Str("verify_key"),
+                Token::Str("key"),
+                Token::StructEnd,
+                Token::Str("expires"),
+                Token::Str("2021-12-23T14:29:19.046000+00:00"),
+                Token::Str("scopes"),
+                Token::Seq { len: Some(1) },
+                Token::Str("guilds.join"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
+}
@@ -1,8 +1,15 @@
 pub mod current_application_info;
+pub mod current_authorization_information;
 pub mod team;
 
+mod access_token;
 mod partial_application;
 
 pub use self::{
-    current_application_info::CurrentApplicationInfo, partial_application::PartialApplication,
+    access_token::AccessToken,
+    current_application_info::CurrentApplicationInfo,
+    current_authorization_information::{
+        AuthorizationApplication, CurrentAuthorizationInformation,
+    },
+    partial_application::PartialApplication,
 };
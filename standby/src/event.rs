@@ -27,6 +27,7 @@ pub const fn guild_id(event: &Event) -> Option<Id<GuildMarker>> {
         Event::ChannelCreate(e) => channel_guild_id(&e.0),
         Event::ChannelDelete(e) => channel_guild_id(&e.0),
         Event::ChannelUpdate(e) => channel_guild_id(&e.0),
+        Event::CommandPermissionsUpdate(e) => Some(e.0.guild_id),
         Event::GuildCreate(e) => Some(e.0.id),
         Event::GuildDelete(e) => Some(e.id),
         Event::GuildEmojisUpdate(e) => Some(e.guild_id),
@@ -77,6 +78,7 @@ pub const fn guild_id(event: &Event) -> Option<Id<GuildMarker>> {
         | Event::PresencesReplace
         | Event::Ready(_)
         | Event::Resumed
+        | Event::ShardCommandRatelimited(_)
         | Event::ShardConnected(_)
         | Event::ShardConnecting(_)
         | Event::ShardDisconnected(_)
@@ -0,0 +1,207 @@
+//! Parsing of the attributes of derived types.
+
+use proc_macro2::Span;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Attribute, Error, Ident, Lit, Meta, Result, Token,
+};
+
+/// Maximum length of a description.
+const DESCRIPTION_LENGTH_MAX: usize = 100;
+
+/// Key and optional value of an attribute, such as `name = "ping"` or
+/// `autocomplete`.
+struct Entry {
+    key: Ident,
+    negative: bool,
+    value: Option<Lit>,
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let key = input.parse()?;
+        let mut negative = false;
+        let mut value = None;
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+
+            if input.peek(Token![-]) {
+                input.parse::<Token![-]>()?;
+                negative = true;
+            }
+
+            value = Some(input.parse()?);
+        }
+
+        Ok(Self {
+            key,
+            negative,
+            value,
+        })
+    }
+}
+
+/// Numeric value of an entry, keeping integers apart from floats.
+#[derive(Clone, Copy)]
+pub enum Number {
+    Float(f64),
+    Integer(i64),
+}
+
+/// Numeric or string value of an entry.
+pub enum Value {
+    Number(Number),
+    String(String),
+}
+
+/// Entries of the attributes of an item in a namespace, such as `command`.
+pub struct Attributes {
+    entries: Vec<Entry>,
+}
+
+impl Attributes {
+    /// Parse the attributes in a namespace, returning an error if any of their
+    /// keys isn't allowed.
+    pub fn parse(attrs: &[Attribute], namespace: &str, allowed: &[&str]) -> Result<Self> {
+        let mut entries = Vec::new();
+
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident(namespace)) {
+            let parsed = attr.parse_args_with(Punctuated::<Entry, Token![,]>::parse_terminated)?;
+
+            for entry in parsed {
+                if !allowed.iter().any(|key| entry.key == key) {
+                    return Err(Error::new(
+                        entry.key.span(),
+                        format!("unknown attribute, expected one of: {}", allowed.join(", ")),
+                    ));
+                }
+
+                entries.push(entry);
+            }
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Whether a flag, such as `autocomplete` or `autocomplete = true`, is
+    /// set.
+    pub fn flag(&self, key: &str) -> Result<bool> {
+        match self.find(key) {
+            Some(Entry { value: None, .. }) => Ok(true),
+            Some(_) => Ok(self.bool(key)?.unwrap_or_default()),
+            None => Ok(false),
+        }
+    }
+
+    /// Value of a boolean entry.
+    pub fn bool(&self, key: &str) -> Result<Option<bool>> {
+        match self.find(key) {
+            Some(Entry {
+                value: Some(Lit::Bool(value)),
+                negative: false,
+                ..
+            }) => Ok(Some(value.value)),
+            Some(entry) => Err(Error::new(entry.key.span(), "expected a boolean")),
+            None => Ok(None),
+        }
+    }
+
+    /// Value of a numeric entry.
+    pub fn number(&self, key: &str) -> Result<Option<Number>> {
+        match self.value(key)? {
+            Some((Value::Number(number), _)) => Ok(Some(number)),
+            Some((Value::String(_), span)) => Err(Error::new(span, "expected a number")),
+            None => Ok(None),
+        }
+    }
+
+    /// Value of a numeric or string entry.
+    pub fn value(&self, key: &str) -> Result<Option<(Value, Span)>> {
+        let entry = match self.find(key) {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+
+        let value = match &entry.value {
+            Some(Lit::Int(value)) => {
+                let digits = if entry.negative {
+                    format!("-{}", value.base10_digits())
+                } else {
+                    value.base10_digits().to_owned()
+                };
+
+                let value = digits
+                    .parse()
+                    .map_err(|_| Error::new(value.span(), "integer is out of range"))?;
+
+                Value::Number(Number::Integer(value))
+            }
+            Some(Lit::Float(value)) => {
+                let value = value.base10_parse::<f64>()?;
+
+                Value::Number(Number::Float(if entry.negative { -value } else { value }))
+            }
+            Some(Lit::Str(value)) if !entry.negative => Value::String(value.value()),
+            _ => return Err(Error::new(entry.key.span(), "expected a string or number")),
+        };
+
+        Ok(Some((value, entry.key.span())))
+    }
+
+    /// Value of a string entry.
+    pub fn string(&self, key: &str) -> Result<Option<(String, Span)>> {
+        match self.find(key) {
+            Some(Entry {
+                value: Some(Lit::Str(value)),
+                negative: false,
+                ..
+            }) => Ok(Some((value.value(), value.span()))),
+            Some(entry) => Err(Error::new(entry.key.span(), "expected a string")),
+            None => Ok(None),
+        }
+    }
+
+    /// Last entry with a key.
+    fn find(&self, key: &str) -> Option<&Entry> {
+        self.entries.iter().rev().find(|entry| entry.key == key)
+    }
+}
+
+/// Description of an item from its doc comments, with the lines joined by
+/// spaces.
+pub fn description(attrs: &[Attribute], span: Span) -> Result<String> {
+    let mut lines = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("doc")) {
+        if let Meta::NameValue(meta) = attr.parse_meta()? {
+            if let Lit::Str(line) = meta.lit {
+                let line = line.value();
+                let line = line.trim();
+
+                if !line.is_empty() {
+                    lines.push(line.to_owned());
+                }
+            }
+        }
+    }
+
+    let description = lines.join(" ");
+
+    if description.is_empty() {
+        return Err(Error::new(
+            span,
+            "a doc comment is required as the description",
+        ));
+    }
+
+    if description.chars().count() > DESCRIPTION_LENGTH_MAX {
+        return Err(Error::new(
+            span,
+            "description must be 100 characters or less",
+        ));
+    }
+
+    Ok(description)
+}
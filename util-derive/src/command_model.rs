@@ -0,0 +1,115 @@
+//! Derive of `CommandModel`.
+
+use crate::{
+    create_command::variant_name,
+    input::{Body, Command},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Result};
+
+/// Expand the derive of `CommandModel`.
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let command = Command::parse(input)?;
+
+    let body = match &command.body {
+        Body::Options { fields, unit } => {
+            let idents = fields.iter().map(|field| field.ident).collect::<Vec<_>>();
+            let values = fields
+                .iter()
+                .map(|field| format_ident!("__{}", field.ident))
+                .collect::<Vec<_>>();
+            let names = fields.iter().map(|field| &field.name).collect::<Vec<_>>();
+            let types = fields.iter().map(|field| field.ty).collect::<Vec<_>>();
+
+            let construct = if *unit {
+                quote!(Self)
+            } else {
+                quote! {
+                    Self {
+                        #(#idents: ::twilight_util::command::__private::finish(#names, #values)?,)*
+                    }
+                }
+            };
+
+            if fields.is_empty() {
+                return Ok(expand_impl(
+                    &command,
+                    &quote! {
+                        if let ::std::option::Option::Some(option) = options.into_iter().next() {
+                            return ::std::result::Result::Err(
+                                ::twilight_util::command::__private::unknown(option.name),
+                            );
+                        }
+
+                        ::std::result::Result::Ok(#construct)
+                    },
+                ));
+            }
+
+            quote! {
+                #(let mut #values: ::std::option::Option<#types> = ::std::option::Option::None;)*
+
+                for option in options {
+                    #(
+                        if option.name == #names {
+                            #values = ::std::option::Option::Some(
+                                ::twilight_util::command::__private::parse(#names, option.value)?,
+                            );
+
+                            continue;
+                        }
+                    )*
+
+                    return ::std::result::Result::Err(
+                        ::twilight_util::command::__private::unknown(option.name),
+                    );
+                }
+
+                ::std::result::Result::Ok(#construct)
+            }
+        }
+        Body::Subcommands(variants) => {
+            let variants = variants.iter().map(|variant| {
+                let name = variant_name(variant.rename.as_deref(), variant.ty);
+                let variant_ident = variant.ident;
+                let ty = variant.ty;
+
+                quote! {
+                    if name == #name {
+                        return <#ty as ::twilight_util::command::CommandModel>::from_options(options)
+                            .map(Self::#variant_ident);
+                    }
+                }
+            });
+
+            quote! {
+                let (name, options) =
+                    ::twilight_util::command::__private::subcommand_options(options)?;
+
+                #(#variants)*
+
+                ::std::result::Result::Err(::twilight_util::command::__private::unknown(name))
+            }
+        }
+    };
+
+    Ok(expand_impl(&command, &body))
+}
+
+/// Implementation of `CommandModel` with the body of `from_options`.
+fn expand_impl(command: &Command<'_>, body: &TokenStream) -> TokenStream {
+    let ident = command.ident;
+    let (impl_generics, ty_generics, where_clause) = command.generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::twilight_util::command::CommandModel for #ident #ty_generics #where_clause {
+            fn from_options(
+                options: ::std::vec::Vec<::twilight_util::command::__private::CommandDataOption>,
+            ) -> ::std::result::Result<Self, ::twilight_util::command::ParseError> {
+                #body
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Derive of `CommandOption`.
+
+use crate::attributes::{Attributes, Number, Value};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Ident, Result};
+
+/// Keys allowed in the `option` attribute of a variant.
+const VARIANT_KEYS: &[&str] = &["name", "value"];
+
+/// Expand the derive of `CommandOption`.
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "only enums of choices can derive `CommandOption`",
+            ))
+        }
+    };
+
+    let mut idents = Vec::with_capacity(data.variants.len());
+    let mut names = Vec::with_capacity(data.variants.len());
+    let mut values = Vec::with_capacity(data.variants.len());
+    let mut kind = None;
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(variant.span(), "choices must be unit variants"));
+        }
+
+        let attributes = Attributes::parse(&variant.attrs, "option", VARIANT_KEYS)?;
+        let default = variant.ident.to_string();
+        let (variant_kind, value, span) = match attributes.value("value")? {
+            Some((Value::Number(Number::Float(value)), span)) => {
+                (Kind::Number, quote!(#value), span)
+            }
+            Some((Value::Number(Number::Integer(value)), span)) => {
+                (Kind::Integer, quote!(#value), span)
+            }
+            Some((Value::String(value), span)) => (Kind::String, quote!(#value), span),
+            None => (Kind::String, quote!(#default), variant.ident.span()),
+        };
+
+        if kind.map_or(false, |kind| kind != variant_kind) {
+            return Err(Error::new(
+                span,
+                "choice values must all be strings, integers, or numbers",
+            ));
+        }
+
+        kind = Some(variant_kind);
+        idents.push(&variant.ident);
+        names.push(attributes.string("name")?.map_or(default, |(name, _)| name));
+        values.push(value);
+    }
+
+    let kind = kind.unwrap_or(Kind::String);
+    let from_option = from_option(kind, &idents, &values);
+    let choices = names.iter().zip(&values).map(|(name, value)| {
+        let (variant, value) = match kind {
+            Kind::Integer => (quote!(Int), quote!(#value)),
+            Kind::Number => (
+                quote!(Number),
+                quote!(::twilight_util::command::__private::Number(#value)),
+            ),
+            Kind::String => (
+                quote!(String),
+                quote!(::std::borrow::ToOwned::to_owned(#value)),
+            ),
+        };
+
+        quote! {
+            ::twilight_util::command::__private::CommandOptionChoice::#variant {
+                name: ::std::borrow::ToOwned::to_owned(#name),
+                name_localizations: ::std::option::Option::None,
+                value: #value,
+            }
+        }
+    });
+    let option_type = match kind {
+        Kind::Integer => quote!(Integer),
+        Kind::Number => quote!(Number),
+        Kind::String => quote!(String),
+    };
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::twilight_util::command::__private::Sealed for #ident #ty_generics #where_clause {}
+
+        #[automatically_derived]
+        impl #impl_generics ::twilight_util::command::CommandOption for #ident #ty_generics #where_clause {
+            const KIND: ::twilight_util::command::__private::CommandOptionType =
+                ::twilight_util::command::__private::CommandOptionType::#option_type;
+
+            fn from_option(
+                value: ::twilight_util::command::__private::CommandOptionValue,
+            ) -> ::std::option::Option<Self> {
+                #from_option
+            }
+
+            fn choices() -> ::std::vec::Vec<::twilight_util::command::__private::CommandOptionChoice> {
+                ::std::vec![#(#choices),*]
+            }
+        }
+    })
+}
+
+/// Body of `CommandOption::from_option`, matching the value against the
+/// values of the choices.
+fn from_option(kind: Kind, idents: &[&Ident], values: &[TokenStream]) -> TokenStream {
+    match kind {
+        Kind::Integer => quote! {
+            match value {
+                ::twilight_util::command::__private::CommandOptionValue::Integer(value) => {
+                    match value {
+                        #(#values => ::std::option::Option::Some(Self::#idents),)*
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                _ => ::std::option::Option::None,
+            }
+        },
+        Kind::Number => quote! {
+            match value {
+                ::twilight_util::command::__private::CommandOptionValue::Number(value) => {
+                    #(if value == ::twilight_util::command::__private::Number(#values) {
+                        return ::std::option::Option::Some(Self::#idents);
+                    })*
+
+                    ::std::option::Option::None
+                }
+                _ => ::std::option::Option::None,
+            }
+        },
+        Kind::String => quote! {
+            match value {
+                ::twilight_util::command::__private::CommandOptionValue::String(value) => {
+                    match value.as_str() {
+                        #(#values => ::std::option::Option::Some(Self::#idents),)*
+                        _ => ::std::option::Option::None,
+                    }
+                }
+                _ => ::std::option::Option::None,
+            }
+        },
+    }
+}
+
+/// Type of the values of the choices.
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Integer,
+    Number,
+    String,
+}
@@ -0,0 +1,158 @@
+//! Derive of `CreateCommand`.
+
+use crate::{
+    attributes::{self, Number},
+    input::{Body, Command},
+};
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, DeriveInput, Result};
+
+/// Expand the derive of `CreateCommand`.
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let command = Command::parse(input)?;
+    let description = attributes::description(command.attrs, command.ident.span())?;
+    let ident = command.ident;
+    let name = &command.name;
+    let (impl_generics, ty_generics, where_clause) = command.generics.split_for_impl();
+
+    let default_permission = if let Some(default_permission) = command.default_permission {
+        quote!(::std::option::Option::Some(#default_permission))
+    } else {
+        quote!(::std::option::Option::None)
+    };
+
+    // Checks of the option and subcommand types are evaluated at compile time
+    // in constants, which can't refer to the generics of the command, so
+    // generic commands are only checked when the command is created.
+    let generic = !command.generics.params.is_empty();
+    let mut checks = Vec::new();
+
+    let options = match &command.body {
+        Body::Options { fields, .. } => fields
+            .iter()
+            .map(|field| {
+                let description = attributes::description(field.attrs, field.ident.span())?;
+                let autocomplete = field.autocomplete;
+                let channel_types = &field.channel_types;
+                let max_value = optional_limit(field.max_value);
+                let min_value = optional_limit(field.min_value);
+                let name = &field.name;
+                let ty = field.ty;
+
+                let fractional = [field.max_value, field.min_value]
+                    .iter()
+                    .any(|limit| matches!(limit, Some(Number::Float(_))));
+
+                if fractional && !generic {
+                    checks.push(quote_spanned! {ty.span()=>
+                        const _: () = ::twilight_util::command::__private::assert_integer_limits(
+                            <#ty as ::twilight_util::command::CommandOption>::KIND,
+                        );
+                    });
+                }
+
+                Ok(quote! {
+                    ::twilight_util::command::__private::option::<#ty>(
+                        #name,
+                        #description,
+                        ::twilight_util::command::__private::OptionAttributes {
+                            autocomplete: #autocomplete,
+                            channel_types: ::std::vec![
+                                #(::twilight_util::command::__private::ChannelType::#channel_types),*
+                            ],
+                            max_value: #max_value,
+                            min_value: #min_value,
+                        },
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?,
+        Body::Subcommands(variants) => variants
+            .iter()
+            .map(|variant| {
+                let name = variant_name(variant.rename.as_deref(), variant.ty);
+                let ty = variant.ty;
+
+                Ok(quote! {
+                    ::twilight_util::command::__private::subcommand(
+                        #name,
+                        <#ty as ::twilight_util::command::CreateCommand>::create_command(),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?,
+    };
+
+    let nesting = match &command.body {
+        Body::Options { .. } => quote!(0),
+        Body::Subcommands(variants) => {
+            let tys = variants.iter().map(|variant| variant.ty);
+
+            if !generic {
+                checks.extend(tys.clone().map(|ty| {
+                    quote_spanned! {ty.span()=>
+                        const _: () = ::twilight_util::command::__private::assert_subcommand(
+                            <#ty as ::twilight_util::command::CreateCommand>::__NESTING,
+                        );
+                    }
+                }));
+            }
+
+            quote! {
+                ::twilight_util::command::__private::nesting(&[
+                    #(<#tys as ::twilight_util::command::CreateCommand>::__NESTING),*
+                ])
+            }
+        }
+    };
+
+    Ok(quote! {
+        #(#checks)*
+
+        #[automatically_derived]
+        impl #impl_generics ::twilight_util::command::CreateCommand for #ident #ty_generics #where_clause {
+            const NAME: &'static str = #name;
+
+            #[doc(hidden)]
+            const __NESTING: u8 = #nesting;
+
+            fn create_command() -> ::twilight_util::command::__private::Command {
+                ::twilight_util::command::__private::command(
+                    #name,
+                    #description,
+                    #default_permission,
+                    ::std::vec![#(#options),*],
+                )
+            }
+        }
+    })
+}
+
+/// Name of a subcommand variant: its rename, or the name of its command.
+pub fn variant_name(rename: Option<&str>, ty: &syn::Type) -> TokenStream {
+    if let Some(rename) = rename {
+        quote!(#rename)
+    } else {
+        quote!(<#ty as ::twilight_util::command::CreateCommand>::NAME)
+    }
+}
+
+/// Tokens of an optional limit of an option.
+fn optional_limit(value: Option<Number>) -> TokenStream {
+    match value {
+        Some(Number::Float(value)) => quote! {
+            ::std::option::Option::Some(
+                ::twilight_util::command::__private::CommandOptionLimit::Number(
+                    ::twilight_util::command::__private::Number(#value),
+                ),
+            )
+        },
+        Some(Number::Integer(value)) => quote! {
+            ::std::option::Option::Some(
+                ::twilight_util::command::__private::CommandOptionLimit::Integer(#value),
+            )
+        },
+        None => quote!(::std::option::Option::None),
+    }
+}
@@ -0,0 +1,201 @@
+//! Parsing of the types deriving `CommandModel` and `CreateCommand`.
+
+use crate::attributes::{Attributes, Number};
+use proc_macro2::Span;
+use syn::{
+    spanned::Spanned, Attribute, Data, DeriveInput, Error, Fields, Generics, Ident, Result, Type,
+};
+
+/// Maximum length of the name of a command or option.
+const NAME_LENGTH_MAX: usize = 32;
+
+/// Keys allowed in the `command` attribute of the type.
+const COMMAND_KEYS: &[&str] = &["default_permission", "name"];
+
+/// Keys allowed in the `command` attribute of a field.
+const FIELD_KEYS: &[&str] = &[
+    "autocomplete",
+    "channel_types",
+    "max_value",
+    "min_value",
+    "rename",
+];
+
+/// Keys allowed in the `command` attribute of a variant.
+const VARIANT_KEYS: &[&str] = &["rename"];
+
+/// Type deriving a command.
+pub struct Command<'a> {
+    pub attrs: &'a [Attribute],
+    pub body: Body<'a>,
+    pub default_permission: Option<bool>,
+    pub generics: &'a Generics,
+    pub ident: &'a Ident,
+    pub name: String,
+}
+
+/// Contents of a command.
+pub enum Body<'a> {
+    /// Struct whose fields are options.
+    Options {
+        fields: Vec<OptionField<'a>>,
+        unit: bool,
+    },
+    /// Enum whose variants are subcommands.
+    Subcommands(Vec<Subcommand<'a>>),
+}
+
+/// Field of a struct that is an option.
+pub struct OptionField<'a> {
+    pub attrs: &'a [Attribute],
+    pub autocomplete: bool,
+    pub channel_types: Vec<Ident>,
+    pub ident: &'a Ident,
+    pub max_value: Option<Number>,
+    pub min_value: Option<Number>,
+    pub name: String,
+    pub ty: &'a Type,
+}
+
+/// Variant of an enum that is a subcommand or subcommand group.
+pub struct Subcommand<'a> {
+    pub ident: &'a Ident,
+    pub rename: Option<String>,
+    pub ty: &'a Type,
+}
+
+impl<'a> Command<'a> {
+    /// Parse the type deriving a command.
+    pub fn parse(input: &'a DeriveInput) -> Result<Self> {
+        let attributes = Attributes::parse(&input.attrs, "command", COMMAND_KEYS)?;
+        let name = match attributes.string("name")? {
+            Some((name, span)) => validate_name(name, span)?,
+            None => {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "missing `#[command(name = \"...\")]` attribute",
+                ))
+            }
+        };
+
+        let body = match &input.data {
+            Data::Struct(data) => match &data.fields {
+                Fields::Named(fields) => Body::Options {
+                    fields: fields
+                        .named
+                        .iter()
+                        .map(OptionField::parse)
+                        .collect::<Result<_>>()?,
+                    unit: false,
+                },
+                Fields::Unit => Body::Options {
+                    fields: Vec::new(),
+                    unit: true,
+                },
+                Fields::Unnamed(_) => {
+                    return Err(Error::new(
+                        input.ident.span(),
+                        "tuple structs can't be commands",
+                    ))
+                }
+            },
+            Data::Enum(data) => Body::Subcommands(
+                data.variants
+                    .iter()
+                    .map(|variant| {
+                        let attributes =
+                            Attributes::parse(&variant.attrs, "command", VARIANT_KEYS)?;
+                        let rename = attributes
+                            .string("rename")?
+                            .map(|(name, span)| validate_name(name, span))
+                            .transpose()?;
+
+                        let ty = match &variant.fields {
+                            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                                &fields.unnamed[0].ty
+                            }
+                            _ => {
+                                return Err(Error::new(
+                                    variant.span(),
+                                    "variants must have a single unnamed field",
+                                ))
+                            }
+                        };
+
+                        Ok(Subcommand {
+                            ident: &variant.ident,
+                            rename,
+                            ty,
+                        })
+                    })
+                    .collect::<Result<_>>()?,
+            ),
+            Data::Union(_) => {
+                return Err(Error::new(input.ident.span(), "unions can't be commands"))
+            }
+        };
+
+        Ok(Self {
+            attrs: &input.attrs,
+            body,
+            default_permission: attributes.bool("default_permission")?,
+            generics: &input.generics,
+            ident: &input.ident,
+            name,
+        })
+    }
+}
+
+impl<'a> OptionField<'a> {
+    /// Parse a field of a struct.
+    fn parse(field: &'a syn::Field) -> Result<Self> {
+        let attributes = Attributes::parse(&field.attrs, "command", FIELD_KEYS)?;
+        let ident = field
+            .ident
+            .as_ref()
+            .expect("fields of named structs have an ident");
+
+        let name = match attributes.string("rename")? {
+            Some((name, span)) => validate_name(name, span)?,
+            None => ident.to_string().trim_start_matches("r#").to_owned(),
+        };
+
+        let channel_types = match attributes.string("channel_types")? {
+            Some((types, span)) => types
+                .split_whitespace()
+                .map(|kind| {
+                    syn::parse_str::<Ident>(kind)
+                        .map_err(|_| Error::new(span, "invalid channel type"))?;
+
+                    Ok(Ident::new(kind, span))
+                })
+                .collect::<Result<_>>()?,
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            attrs: &field.attrs,
+            autocomplete: attributes.flag("autocomplete")?,
+            channel_types,
+            ident,
+            max_value: attributes.number("max_value")?,
+            min_value: attributes.number("min_value")?,
+            name,
+            ty: &field.ty,
+        })
+    }
+}
+
+/// Validate the name of a command or option.
+fn validate_name(name: String, span: Span) -> Result<String> {
+    let count = name.chars().count();
+
+    if count == 0 || count > NAME_LENGTH_MAX || name.chars().any(char::is_uppercase) {
+        return Err(Error::new(
+            span,
+            "names must be 1 to 32 characters long and lowercase",
+        ));
+    }
+
+    Ok(name)
+}
@@ -0,0 +1,60 @@
+//! # twilight-util-derive
+//!
+//! Derive macros for the `command` feature of `twilight-util`.
+//!
+//! The macros are re-exported by `twilight_util::command`, which documents
+//! their usage, and expand to code referring to that module.
+
+#![deny(
+    clippy::all,
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unsafe_code,
+    unused,
+    warnings
+)]
+
+mod attributes;
+mod command_model;
+mod command_option;
+mod create_command;
+mod input;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derive `CommandModel`, parsing the options of a command into a struct or
+/// a subcommand into a variant of an enum.
+#[proc_macro_derive(CommandModel, attributes(command))]
+pub fn command_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    command_model::expand(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derive `CommandOption` for an enum of string, integer, or number choices.
+#[proc_macro_derive(CommandOption, attributes(option))]
+pub fn command_option(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    command_option::expand(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derive `CreateCommand`, creating a command whose options are the fields of
+/// a struct or whose subcommands are the variants of an enum.
+#[proc_macro_derive(CreateCommand, attributes(command))]
+pub fn create_command(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    create_command::expand(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
@@ -33,6 +33,7 @@ use twilight_model::{
         NumberCommandOptionData, OptionsCommandOptionData,
     },
     channel::ChannelType,
+    guild::Permissions,
     id::{marker::GuildMarker, Id},
 };
 
@@ -48,12 +49,16 @@ impl CommandBuilder {
     pub const fn new(name: String, description: String, kind: CommandType) -> Self {
         Self(Command {
             application_id: None,
+            default_member_permissions: None,
             default_permission: None,
             description,
+            description_localizations: None,
+            dm_permission: None,
             guild_id: None,
             id: None,
             kind,
             name,
+            name_localizations: None,
             options: Vec::new(),
             version: Id::new(1),
         })
@@ -75,6 +80,21 @@ impl CommandBuilder {
         self
     }
 
+    /// Set the default member permission required to run the command.
+    ///
+    /// Setting this to [`Permissions::empty()`] prohibits anyone from running
+    /// the command, except for guild administrators.
+    ///
+    /// Defaults to [`None`].
+    pub const fn default_member_permissions(
+        mut self,
+        default_member_permissions: Permissions,
+    ) -> Self {
+        self.0.default_member_permissions = Some(default_member_permissions);
+
+        self
+    }
+
     /// Set the default permission of the command.
     ///
     /// Defaults to [`None`].
@@ -84,6 +104,47 @@ impl CommandBuilder {
         self
     }
 
+    /// Set the localization dictionary for the command description.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized description.
+    ///
+    /// Defaults to [`None`].
+    pub fn description_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.description_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
+    /// Set whether the command is available in DMs.
+    ///
+    /// This is only relevant for globally-scoped commands.
+    ///
+    /// Defaults to [`None`].
+    pub const fn dm_permission(mut self, dm_permission: bool) -> Self {
+        self.0.dm_permission = Some(dm_permission);
+
+        self
+    }
+
+    /// Set the localization dictionary for the command name.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name.
+    ///
+    /// Defaults to [`None`].
+    pub fn name_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.name_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
     /// Add an option to the command.
     ///
     /// Defaults to an empty list.
@@ -109,7 +170,9 @@ impl BooleanBuilder {
     pub const fn new(name: String, description: String) -> Self {
         Self(BaseCommandOptionData {
             description,
+            description_localizations: None,
             name,
+            name_localizations: None,
             required: false,
         })
     }
@@ -121,6 +184,36 @@ impl BooleanBuilder {
         CommandOption::Boolean(self.0)
     }
 
+    /// Set the localization dictionary for the option description.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized description.
+    ///
+    /// Defaults to [`None`].
+    pub fn description_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.description_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
+    /// Set the localization dictionary for the option name.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name.
+    ///
+    /// Defaults to [`None`].
+    pub fn name_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.name_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
     /// Set whether this option is required.
     ///
     /// Defaults to false.
@@ -149,7 +242,9 @@ impl ChannelBuilder {
         Self(ChannelCommandOptionData {
             channel_types: Vec::new(),
             description,
+            description_localizations: None,
             name,
+            name_localizations: None,
             required: false,
         })
     }
@@ -170,6 +265,36 @@ impl ChannelBuilder {
         self
     }
 
+    /// Set the localization dictionary for the option description.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized description.
+    ///
+    /// Defaults to [`None`].
+    pub fn description_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.description_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
+    /// Set the localization dictionary for the option name.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name.
+    ///
+    /// Defaults to [`None`].
+    pub fn name_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.name_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
     /// Set whether this option is required.
     ///
     /// Defaults to false.
@@ -198,9 +323,11 @@ impl IntegerBuilder {
             autocomplete: false,
             choices: Vec::new(),
             description,
+            description_localizations: None,
             max_value: None,
             min_value: None,
             name,
+            name_localizations: None,
             required: false,
         })
     }
@@ -221,6 +348,25 @@ impl IntegerBuilder {
         self
     }
 
+    /// Set the localization dictionary for the name of a choice.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name. Choices are matched by their name, so this must be
+    /// called after [`choices`].
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// [`choices`]: Self::choices
+    pub fn choice_localizations(
+        mut self,
+        choice_name: &str,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        set_choice_localizations(&mut self.0.choices, choice_name, localizations);
+
+        self
+    }
+
     /// Set the list of choices for an option.
     ///
     /// Accepts tuples of `(String, i64)` corresponding to the name and value.
@@ -229,12 +375,31 @@ impl IntegerBuilder {
     pub fn choices(mut self, choices: impl IntoIterator<Item = (String, i64)>) -> Self {
         self.0.choices = choices
             .into_iter()
-            .map(|(name, value)| CommandOptionChoice::Int { name, value })
+            .map(|(name, value)| CommandOptionChoice::Int {
+                name,
+                name_localizations: None,
+                value,
+            })
             .collect();
 
         self
     }
 
+    /// Set the localization dictionary for the option description.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized description.
+    ///
+    /// Defaults to [`None`].
+    pub fn description_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.description_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
     /// Set the maximum value permitted.
     ///
     /// Defaults to no limit.
@@ -253,6 +418,21 @@ impl IntegerBuilder {
         self
     }
 
+    /// Set the localization dictionary for the option name.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name.
+    ///
+    /// Defaults to [`None`].
+    pub fn name_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.name_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
     /// Set whether this option is required.
     ///
     /// Defaults to false.
@@ -280,7 +460,9 @@ impl MentionableBuilder {
     pub const fn new(name: String, description: String) -> Self {
         Self(BaseCommandOptionData {
             description,
+            description_localizations: None,
             name,
+            name_localizations: None,
             required: false,
         })
     }
@@ -292,6 +474,36 @@ impl MentionableBuilder {
         CommandOption::Mentionable(self.0)
     }
 
+    /// Set the localization dictionary for the option description.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized description.
+    ///
+    /// Defaults to [`None`].
+    pub fn description_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.description_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
+    /// Set the localization dictionary for the option name.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name.
+    ///
+    /// Defaults to [`None`].
+    pub fn name_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.name_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
     /// Set whether this option is required.
     ///
     /// Defaults to false.
@@ -321,9 +533,11 @@ impl NumberBuilder {
             autocomplete: false,
             choices: Vec::new(),
             description,
+            description_localizations: None,
             max_value: None,
             min_value: None,
             name,
+            name_localizations: None,
             required: false,
         })
     }
@@ -344,6 +558,25 @@ impl NumberBuilder {
         self
     }
 
+    /// Set the localization dictionary for the name of a choice.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name. Choices are matched by their name, so this must be
+    /// called after [`choices`].
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// [`choices`]: Self::choices
+    pub fn choice_localizations(
+        mut self,
+        choice_name: &str,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        set_choice_localizations(&mut self.0.choices, choice_name, localizations);
+
+        self
+    }
+
     /// Set the list of choices for an option.
     ///
     /// Accepts tuples of `(String, Number)` corresponding to the name and
@@ -353,12 +586,31 @@ impl NumberBuilder {
     pub fn choices(mut self, choices: impl IntoIterator<Item = (String, Number)>) -> Self {
         self.0.choices = choices
             .into_iter()
-            .map(|(name, value)| CommandOptionChoice::Number { name, value })
+            .map(|(name, value)| CommandOptionChoice::Number {
+                name,
+                name_localizations: None,
+                value,
+            })
             .collect();
 
         self
     }
 
+    /// Set the localization dictionary for the option description.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized description.
+    ///
+    /// Defaults to [`None`].
+    pub fn description_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.description_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
     /// Set the maximum value permitted.
     ///
     /// Defaults to no limit.
@@ -377,6 +629,21 @@ impl NumberBuilder {
         self
     }
 
+    /// Set the localization dictionary for the option name.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name.
+    ///
+    /// Defaults to [`None`].
+    pub fn name_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.name_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
     /// Set whether this option is required.
     ///
     /// Defaults to false.
@@ -404,7 +671,9 @@ impl RoleBuilder {
     pub const fn new(name: String, description: String) -> Self {
         Self(BaseCommandOptionData {
             description,
+            description_localizations: None,
             name,
+            name_localizations: None,
             required: false,
         })
     }
@@ -416,6 +685,36 @@ impl RoleBuilder {
         CommandOption::Role(self.0)
     }
 
+    /// Set the localization dictionary for the option description.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized description.
+    ///
+    /// Defaults to [`None`].
+    pub fn description_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.description_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
+    /// Set the localization dictionary for the option name.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name.
+    ///
+    /// Defaults to [`None`].
+    pub fn name_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.name_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
     /// Set whether this option is required.
     ///
     /// Defaults to false.
@@ -445,7 +744,9 @@ impl StringBuilder {
             autocomplete: false,
             choices: Vec::new(),
             description,
+            description_localizations: None,
             name,
+            name_localizations: None,
             required: false,
         })
     }
@@ -466,6 +767,25 @@ impl StringBuilder {
         self
     }
 
+    /// Set the localization dictionary for the name of a choice.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name. Choices are matched by their name, so this must be
+    /// called after [`choices`].
+    ///
+    /// Defaults to [`None`].
+    ///
+    /// [`choices`]: Self::choices
+    pub fn choice_localizations(
+        mut self,
+        choice_name: &str,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        set_choice_localizations(&mut self.0.choices, choice_name, localizations);
+
+        self
+    }
+
     /// Set the list of choices for an option.
     ///
     /// Accepts tuples of `(String, String)` corresponding to the name and
@@ -475,12 +795,46 @@ impl StringBuilder {
     pub fn choices(mut self, choices: impl IntoIterator<Item = (String, String)>) -> Self {
         self.0.choices = choices
             .into_iter()
-            .map(|(name, value)| CommandOptionChoice::String { name, value })
+            .map(|(name, value)| CommandOptionChoice::String {
+                name,
+                name_localizations: None,
+                value,
+            })
             .collect();
 
         self
     }
 
+    /// Set the localization dictionary for the option description.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized description.
+    ///
+    /// Defaults to [`None`].
+    pub fn description_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.description_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
+    /// Set the localization dictionary for the option name.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name.
+    ///
+    /// Defaults to [`None`].
+    pub fn name_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.name_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
     /// Set whether this option is required.
     ///
     /// Defaults to false.
@@ -508,7 +862,9 @@ impl SubCommandBuilder {
     pub const fn new(name: String, description: String) -> Self {
         Self(OptionsCommandOptionData {
             description,
+            description_localizations: None,
             name,
+            name_localizations: None,
             options: Vec::new(),
         })
     }
@@ -520,6 +876,36 @@ impl SubCommandBuilder {
         CommandOption::SubCommand(self.0)
     }
 
+    /// Set the localization dictionary for the option description.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized description.
+    ///
+    /// Defaults to [`None`].
+    pub fn description_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.description_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
+    /// Set the localization dictionary for the option name.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name.
+    ///
+    /// Defaults to [`None`].
+    pub fn name_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.name_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
     /// Add an option to the sub command.
     ///
     /// Defaults to an empty list.
@@ -551,7 +937,9 @@ impl SubCommandGroupBuilder {
     pub const fn new(name: String, description: String) -> Self {
         Self(OptionsCommandOptionData {
             description,
+            description_localizations: None,
             name,
+            name_localizations: None,
             options: Vec::new(),
         })
     }
@@ -563,6 +951,36 @@ impl SubCommandGroupBuilder {
         CommandOption::SubCommandGroup(self.0)
     }
 
+    /// Set the localization dictionary for the option description.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized description.
+    ///
+    /// Defaults to [`None`].
+    pub fn description_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.description_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
+    /// Set the localization dictionary for the option name.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name.
+    ///
+    /// Defaults to [`None`].
+    pub fn name_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.name_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
     /// Set the list of sub commands to the group.
     ///
     /// Defaults to no subcommands.
@@ -590,7 +1008,9 @@ impl UserBuilder {
     pub const fn new(name: String, description: String) -> Self {
         Self(BaseCommandOptionData {
             description,
+            description_localizations: None,
             name,
+            name_localizations: None,
             required: false,
         })
     }
@@ -602,6 +1022,36 @@ impl UserBuilder {
         CommandOption::User(self.0)
     }
 
+    /// Set the localization dictionary for the option description.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized description.
+    ///
+    /// Defaults to [`None`].
+    pub fn description_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.description_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
+    /// Set the localization dictionary for the option name.
+    ///
+    /// Accepts tuples of `(String, String)` corresponding to the locale and
+    /// the localized name.
+    ///
+    /// Defaults to [`None`].
+    pub fn name_localizations(
+        mut self,
+        localizations: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.0.name_localizations = Some(localizations.into_iter().collect());
+
+        self
+    }
+
     /// Set whether this option is required.
     ///
     /// Defaults to false.
@@ -618,6 +1068,32 @@ impl From<UserBuilder> for CommandOption {
     }
 }
 
+/// Set the localizations of the choice with the given name.
+fn set_choice_localizations(
+    choices: &mut [CommandOptionChoice],
+    choice_name: &str,
+    localizations: impl IntoIterator<Item = (String, String)>,
+) {
+    let choice = choices
+        .iter_mut()
+        .find(|choice| choice.name() == choice_name);
+
+    if let Some(
+        CommandOptionChoice::Int {
+            name_localizations, ..
+        }
+        | CommandOptionChoice::Number {
+            name_localizations, ..
+        }
+        | CommandOptionChoice::String {
+            name_localizations, ..
+        },
+    ) = choice
+    {
+        *name_localizations = Some(localizations.into_iter().collect());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -711,21 +1187,31 @@ mod tests {
             guild_id: None,
             kind: CommandType::ChatInput,
             name: String::from("permissions"),
+            name_localizations: None,
+            default_member_permissions: None,
             default_permission: None,
             description: String::from("Get or edit permissions for a user or a role"),
+            description_localizations: None,
+            dm_permission: None,
             id: None,
             options: Vec::from([
                 CommandOption::SubCommandGroup(OptionsCommandOptionData {
                     description: String::from("Get or edit permissions for a user"),
+                    description_localizations: None,
                     name: String::from("user"),
+                    name_localizations: None,
                     options: Vec::from([
                         CommandOption::SubCommand(OptionsCommandOptionData {
                             description: String::from("Get permissions for a user"),
+                            description_localizations: None,
                             name: String::from("get"),
+                            name_localizations: None,
                             options: Vec::from([
                                 CommandOption::User(BaseCommandOptionData {
                                     description: String::from("The user to get"),
+                                    description_localizations: None,
                                     name: String::from("user"),
+                                    name_localizations: None,
                                     required: true,
                                 }),
                                 CommandOption::Channel(ChannelCommandOptionData {
@@ -734,18 +1220,24 @@ mod tests {
                                         "The channel permissions to get. If omitted, the guild \
                                          permissions will be returned",
                                     ),
+                                    description_localizations: None,
                                     name: String::from("channel"),
+                                    name_localizations: None,
                                     required: false,
                                 }),
                             ]),
                         }),
                         CommandOption::SubCommand(OptionsCommandOptionData {
                             description: String::from("Edit permissions for a user"),
+                            description_localizations: None,
                             name: String::from("edit"),
+                            name_localizations: None,
                             options: Vec::from([
                                 CommandOption::User(BaseCommandOptionData {
                                     description: String::from("The user to edit"),
+                                    description_localizations: None,
                                     name: String::from("user"),
+                                    name_localizations: None,
                                     required: true,
                                 }),
                                 CommandOption::Channel(ChannelCommandOptionData {
@@ -754,7 +1246,9 @@ mod tests {
                                         "The channel permissions to edit. If omitted, the guild \
                                          permissions will be edited",
                                     ),
+                                    description_localizations: None,
                                     name: String::from("channel"),
+                                    name_localizations: None,
                                     required: false,
                                 }),
                             ]),
@@ -763,15 +1257,21 @@ mod tests {
                 }),
                 CommandOption::SubCommandGroup(OptionsCommandOptionData {
                     description: String::from("Get or edit permissions for a role"),
+                    description_localizations: None,
                     name: String::from("role"),
+                    name_localizations: None,
                     options: Vec::from([
                         CommandOption::SubCommand(OptionsCommandOptionData {
                             description: String::from("Get permissions for a role"),
+                            description_localizations: None,
                             name: String::from("get"),
+                            name_localizations: None,
                             options: Vec::from([
                                 CommandOption::Role(BaseCommandOptionData {
                                     description: String::from("The role to get"),
+                                    description_localizations: None,
                                     name: String::from("role"),
+                                    name_localizations: None,
                                     required: true,
                                 }),
                                 CommandOption::Channel(ChannelCommandOptionData {
@@ -780,18 +1280,24 @@ mod tests {
                                         "The channel permissions to get. If omitted, the guild \
                                          permissions will be returned",
                                     ),
+                                    description_localizations: None,
                                     name: String::from("channel"),
+                                    name_localizations: None,
                                     required: false,
                                 }),
                             ]),
                         }),
                         CommandOption::SubCommand(OptionsCommandOptionData {
                             description: String::from("Edit permissions for a role"),
+                            description_localizations: None,
                             name: String::from("edit"),
+                            name_localizations: None,
                             options: Vec::from([
                                 CommandOption::Role(BaseCommandOptionData {
                                     description: String::from("The role to edit"),
+                                    description_localizations: None,
                                     name: String::from("role"),
+                                    name_localizations: None,
                                     required: true,
                                 }),
                                 CommandOption::Channel(ChannelCommandOptionData {
@@ -800,16 +1306,20 @@ mod tests {
                                         "The channel permissions to edit. If omitted, the guild \
                                          permissions will be edited",
                                     ),
+                                    description_localizations: None,
                                     name: String::from("channel"),
+                                    name_localizations: None,
                                     required: false,
                                 }),
                                 CommandOption::Number(NumberCommandOptionData {
                                     autocomplete: true,
                                     choices: Vec::new(),
                                     description: String::from("The position of the new role"),
+                                    description_localizations: None,
                                     max_value: None,
                                     min_value: None,
                                     name: String::from("position"),
+                                    name_localizations: None,
                                     required: false,
                                 }),
                             ]),
@@ -822,4 +1332,55 @@ mod tests {
 
         assert_eq!(command, command_manual);
     }
+
+    #[test]
+    fn localizations() {
+        let command = CommandBuilder::new(
+            "animal".into(),
+            "Send an animal photo".into(),
+            CommandType::ChatInput,
+        )
+        .name_localizations([("de".into(), "tier".into())])
+        .description_localizations([("de".into(), "Sende ein Tierfoto".into())])
+        .option(
+            StringBuilder::new("kind".into(), "The kind of animal".into())
+                .name_localizations([("de".into(), "art".into())])
+                .choices([
+                    ("Dog".into(), "animal_dog".into()),
+                    ("Cat".into(), "animal_cat".into()),
+                ])
+                .choice_localizations("Cat", [("de".into(), "Katze".into())]),
+        )
+        .build();
+
+        assert_eq!("tier", command.localized_name("de"));
+        assert_eq!("Sende ein Tierfoto", command.localized_description("de"));
+        assert_eq!("animal", command.localized_name("fr"));
+
+        let option = &command.options[0];
+        assert_eq!("art", option.localized_name("de"));
+        assert_eq!("The kind of animal", option.localized_description("de"));
+
+        let choices = match option {
+            CommandOption::String(data) => &data.choices,
+            _ => panic!("expected a string option"),
+        };
+        assert_eq!("Dog", choices[0].localized_name("de"));
+        assert_eq!("Katze", choices[1].localized_name("de"));
+    }
+
+    #[test]
+    fn member_permissions() {
+        let command =
+            CommandBuilder::new("ban".into(), "Ban a member".into(), CommandType::ChatInput)
+                .default_member_permissions(Permissions::BAN_MEMBERS)
+                .dm_permission(false)
+                .build();
+
+        assert_eq!(
+            Some(Permissions::BAN_MEMBERS),
+            command.default_member_permissions
+        );
+        assert_eq!(Some(false), command.dm_permission);
+    }
 }
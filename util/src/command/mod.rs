@@ -0,0 +1,640 @@
+//! Define commands and parse their options with Rust types.
+//!
+//! Structs deriving [`CreateCommand`] create a chat input [`Command`] whose
+//! options are the struct's fields, while enums create a command whose
+//! subcommands are the enum's variants. Deriving [`CommandModel`] parses the
+//! [`CommandData`] of an interaction invoking the command into the type.
+//!
+//! Descriptions of commands and options are taken from their doc comments.
+//! Field types implement [`CommandOption`], which may be derived for enums to
+//! create an option with string, integer, or number choices.
+//!
+//! # Attributes
+//!
+//! Types deriving [`CreateCommand`] and [`CommandModel`] accept a `command`
+//! attribute:
+//!
+//! - `name = "..."` on a struct or enum sets the name of the command, and is
+//!   required;
+//! - `default_permission = false` on a struct or enum disables the command by
+//!   default;
+//! - `rename = "..."` on a field or variant sets the name of the option or
+//!   subcommand, defaulting to the field's name or the variant's command name;
+//! - `min_value = ...` and `max_value = ...` on a field set the range of a
+//!   number or integer option, where integer options only accept integer
+//!   limits;
+//! - `channel_types = "..."` on a field restricts a channel option to a
+//!   space-separated list of [`ChannelType`] variants;
+//! - `autocomplete` on a field enables autocomplete for the option.
+//!
+//! Enums deriving [`CommandOption`] accept an `option` attribute on variants:
+//! `name = "..."` sets the name of the choice displayed to users and
+//! `value = ...` the value sent, both defaulting to the variant's name. Values
+//! of all choices must be strings, integers such as `value = 6`, or numbers
+//! such as `value = 0.5`, creating a string, integer, or number option.
+//!
+//! Variants of command enums must have a single field whose type derives both
+//! traits. Variants whose type is itself an enum are subcommand groups, whose
+//! variants must be subcommands.
+//!
+//! Fractional limits on integer options and subcommand groups containing
+//! groups fail to compile, or panic when creating the command of a generic
+//! type.
+//!
+//! # Examples
+//!
+//! Define a command with a subcommand and a subcommand group:
+//!
+//! ```
+//! use twilight_util::command::{CommandModel, CommandOption, CreateCommand};
+//! use twilight_model::id::{marker::ChannelMarker, Id};
+//!
+//! /// Manage the bot's settings.
+//! #[derive(CommandModel, CreateCommand)]
+//! #[command(name = "settings")]
+//! enum Settings {
+//!     Color(SetColor),
+//!     Log(Log),
+//! }
+//!
+//! /// Color of the bot's embeds.
+//! #[derive(CommandModel, CreateCommand)]
+//! #[command(name = "color")]
+//! struct SetColor {
+//!     /// New color.
+//!     color: Color,
+//!     /// Brightness of the color.
+//!     #[command(min_value = 0, max_value = 100)]
+//!     brightness: Option<i64>,
+//! }
+//!
+//! #[derive(CommandOption)]
+//! enum Color {
+//!     Red,
+//!     #[option(name = "Blue", value = "blue")]
+//!     Blue,
+//! }
+//!
+//! /// Manage logging.
+//! #[derive(CommandModel, CreateCommand)]
+//! #[command(name = "log")]
+//! enum Log {
+//!     #[command(rename = "enable")]
+//!     Enable(EnableLog),
+//! }
+//!
+//! /// Log to a channel.
+//! #[derive(CommandModel, CreateCommand)]
+//! #[command(name = "channel")]
+//! struct EnableLog {
+//!     /// Channel to log to.
+//!     #[command(channel_types = "GuildText GuildNews")]
+//!     channel: Id<ChannelMarker>,
+//! }
+//!
+//! let command = Settings::create_command();
+//! assert_eq!("settings", command.name);
+//! assert_eq!(2, command.options.len());
+//! ```
+//!
+//! [`ChannelType`]: twilight_model::channel::ChannelType
+//! [`Command`]: twilight_model::application::command::Command
+
+pub use twilight_util_derive::{CommandModel, CommandOption, CreateCommand};
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    application::{
+        command::{
+            BaseCommandOptionData, ChannelCommandOptionData, ChoiceCommandOptionData, Command,
+            CommandOption as CommandOptionDefinition, CommandOptionChoice, CommandOptionType,
+            CommandType, NumberCommandOptionData, OptionsCommandOptionData,
+        },
+        interaction::application_command::{CommandData, CommandDataOption, CommandOptionValue},
+    },
+    id::{
+        marker::{ChannelMarker, GenericMarker, RoleMarker, UserMarker},
+        Id,
+    },
+};
+
+/// Parsing the options of a command into a [`CommandModel`] failed.
+#[derive(Debug)]
+pub struct ParseError {
+    kind: ParseErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ParseError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ParseErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ParseErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    /// Create an error of a type without a source.
+    const fn new(kind: ParseErrorType) -> Self {
+        Self { kind, source: None }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ParseErrorType::InvalidValue { name } => {
+                f.write_str("value of option ")?;
+                f.write_str(name)?;
+
+                f.write_str(" is invalid")
+            }
+            ParseErrorType::Missing { name } => {
+                f.write_str("required option ")?;
+                f.write_str(name)?;
+
+                f.write_str(" is missing")
+            }
+            ParseErrorType::SubcommandMissing => f.write_str("no subcommand was provided"),
+            ParseErrorType::UnknownOption { name } => {
+                f.write_str("option ")?;
+                f.write_str(name)?;
+
+                f.write_str(" is unknown")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ParseError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ParseErrorType {
+    /// Value of an option has a different type than the model's, or isn't
+    /// one of its choices.
+    InvalidValue {
+        /// Name of the option.
+        name: String,
+    },
+    /// Required option is missing.
+    Missing {
+        /// Name of the option.
+        name: String,
+    },
+    /// No subcommand was provided.
+    SubcommandMissing,
+    /// Option or subcommand isn't part of the model.
+    ///
+    /// This may happen if the registered command is out of date.
+    UnknownOption {
+        /// Name of the option.
+        name: String,
+    },
+}
+
+/// Type that can be parsed from the options of a command.
+///
+/// Refer to the [module documentation] for how to derive this.
+///
+/// [module documentation]: self
+pub trait CommandModel: Sized {
+    /// Parse the options of a command or subcommand.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the options don't match the model.
+    fn from_options(options: Vec<CommandDataOption>) -> Result<Self, ParseError>;
+
+    /// Parse the data of an interaction invoking the command.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the options don't match the model.
+    fn from_interaction(data: CommandData) -> Result<Self, ParseError> {
+        Self::from_options(data.options)
+    }
+}
+
+/// Type that creates the definition of a command.
+///
+/// Refer to the [module documentation] for how to derive this.
+///
+/// [module documentation]: self
+pub trait CreateCommand {
+    /// Name of the command.
+    const NAME: &'static str;
+
+    /// Create the definition of the command.
+    fn create_command() -> Command;
+
+    /// Levels of subcommands below the command, used by the derive to reject
+    /// subcommand groups containing groups.
+    #[doc(hidden)]
+    const __NESTING: u8 = 0;
+}
+
+/// Type of the value of a command option.
+///
+/// Wrapping a type in an [`Option`] makes the option optional.
+///
+/// This trait is sealed: it's implemented for the types of option values and
+/// may only be [derived] for enums of string choices.
+///
+/// [derived]: macro@CommandOption
+pub trait CommandOption: Sized + __private::Sealed {
+    /// Type of the option.
+    const KIND: CommandOptionType;
+
+    /// Whether the option is required.
+    const REQUIRED: bool = true;
+
+    /// Parse the value of the option, returning `None` if it's invalid.
+    fn from_option(value: CommandOptionValue) -> Option<Self>;
+
+    /// Value of the option if it isn't provided, if it isn't required.
+    #[must_use]
+    fn from_missing() -> Option<Self> {
+        None
+    }
+
+    /// Choices of the option.
+    #[must_use]
+    fn choices() -> Vec<CommandOptionChoice> {
+        Vec::new()
+    }
+}
+
+impl<T: CommandOption> __private::Sealed for Option<T> {}
+
+impl<T: CommandOption> CommandOption for Option<T> {
+    const KIND: CommandOptionType = T::KIND;
+
+    const REQUIRED: bool = false;
+
+    fn from_option(value: CommandOptionValue) -> Option<Self> {
+        T::from_option(value).map(Some)
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(None)
+    }
+
+    fn choices() -> Vec<CommandOptionChoice> {
+        T::choices()
+    }
+}
+
+/// Implement [`CommandOption`] for a type stored in a variant of
+/// [`CommandOptionValue`].
+macro_rules! impl_command_option {
+    ($($ty: ty => $kind: ident,)*) => {
+        $(
+            impl __private::Sealed for $ty {}
+
+            impl CommandOption for $ty {
+                const KIND: CommandOptionType = CommandOptionType::$kind;
+
+                fn from_option(value: CommandOptionValue) -> Option<Self> {
+                    match value {
+                        CommandOptionValue::$kind(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_command_option! {
+    bool => Boolean,
+    i64 => Integer,
+    Id<ChannelMarker> => Channel,
+    Id<GenericMarker> => Mentionable,
+    Id<RoleMarker> => Role,
+    Id<UserMarker> => User,
+    String => String,
+}
+
+impl __private::Sealed for f64 {}
+
+impl CommandOption for f64 {
+    const KIND: CommandOptionType = CommandOptionType::Number;
+
+    fn from_option(value: CommandOptionValue) -> Option<Self> {
+        match value {
+            CommandOptionValue::Number(number) => Some(number.0),
+            _ => None,
+        }
+    }
+}
+
+/// Items used by the derive macros.
+#[allow(clippy::must_use_candidate)]
+#[doc(hidden)]
+pub mod __private {
+    pub use twilight_model::{
+        application::{
+            command::{
+                Command, CommandOptionChoice, CommandOptionType,
+                CommandOptionValue as CommandOptionLimit, Number,
+            },
+            interaction::application_command::{CommandDataOption, CommandOptionValue},
+        },
+        channel::ChannelType,
+    };
+
+    use super::{
+        BaseCommandOptionData, ChannelCommandOptionData, ChoiceCommandOptionData, CommandOption,
+        CommandOptionDefinition, CommandType, NumberCommandOptionData, OptionsCommandOptionData,
+        ParseError, ParseErrorType,
+    };
+    use twilight_model::id::Id;
+
+    /// Supertrait sealing [`CommandOption`], implemented by its derive.
+    pub trait Sealed {}
+
+    /// Attributes of an option.
+    #[derive(Default)]
+    pub struct OptionAttributes {
+        pub autocomplete: bool,
+        pub channel_types: Vec<ChannelType>,
+        pub max_value: Option<CommandOptionLimit>,
+        pub min_value: Option<CommandOptionLimit>,
+    }
+
+    /// Create a chat input command.
+    pub fn command(
+        name: &str,
+        description: &str,
+        default_permission: Option<bool>,
+        options: Vec<CommandOptionDefinition>,
+    ) -> Command {
+        Command {
+            application_id: None,
+            default_member_permissions: None,
+            default_permission,
+            description: description.to_owned(),
+            description_localizations: None,
+            dm_permission: None,
+            guild_id: None,
+            id: None,
+            kind: CommandType::ChatInput,
+            name: name.to_owned(),
+            name_localizations: None,
+            options,
+            version: Id::new(1),
+        }
+    }
+
+    /// Create the definition of an option of a type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an integer option has a fractional limit.
+    #[allow(clippy::cast_precision_loss, clippy::needless_pass_by_value)]
+    pub fn option<T: CommandOption>(
+        name: &str,
+        description: &str,
+        attributes: OptionAttributes,
+    ) -> CommandOptionDefinition {
+        let base = BaseCommandOptionData {
+            description: description.to_owned(),
+            description_localizations: None,
+            name: name.to_owned(),
+            name_localizations: None,
+            required: T::REQUIRED,
+        };
+        let limit = |value| match (T::KIND, value) {
+            (CommandOptionType::Integer, CommandOptionLimit::Number(_)) => {
+                assert_integer_limits(T::KIND);
+
+                value
+            }
+            (_, CommandOptionLimit::Integer(value)) if T::KIND != CommandOptionType::Integer => {
+                CommandOptionLimit::Number(Number(value as f64))
+            }
+            _ => value,
+        };
+        let number = NumberCommandOptionData {
+            autocomplete: attributes.autocomplete,
+            choices: T::choices(),
+            description: base.description.clone(),
+            description_localizations: None,
+            max_value: attributes.max_value.map(limit),
+            min_value: attributes.min_value.map(limit),
+            name: base.name.clone(),
+            name_localizations: None,
+            required: base.required,
+        };
+
+        match T::KIND {
+            CommandOptionType::Boolean => CommandOptionDefinition::Boolean(base),
+            CommandOptionType::Channel => {
+                CommandOptionDefinition::Channel(ChannelCommandOptionData {
+                    channel_types: attributes.channel_types,
+                    description: base.description,
+                    description_localizations: None,
+                    name: base.name,
+                    name_localizations: None,
+                    required: base.required,
+                })
+            }
+            CommandOptionType::Integer => CommandOptionDefinition::Integer(number),
+            CommandOptionType::Mentionable => CommandOptionDefinition::Mentionable(base),
+            CommandOptionType::Number => CommandOptionDefinition::Number(number),
+            CommandOptionType::Role => CommandOptionDefinition::Role(base),
+            CommandOptionType::String => CommandOptionDefinition::String(ChoiceCommandOptionData {
+                autocomplete: attributes.autocomplete,
+                choices: T::choices(),
+                description: base.description,
+                description_localizations: None,
+                name: base.name,
+                name_localizations: None,
+                required: base.required,
+            }),
+            CommandOptionType::User => CommandOptionDefinition::User(base),
+            CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup => {
+                unreachable!("sealed option types aren't subcommands")
+            }
+        }
+    }
+
+    /// Assert that an option with a fractional limit isn't an integer option.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is an integer option.
+    pub const fn assert_integer_limits(kind: CommandOptionType) {
+        assert!(
+            !matches!(kind, CommandOptionType::Integer),
+            "integer options can't have fractional limits"
+        );
+    }
+
+    /// Assert that a subcommand of a command isn't a subcommand group
+    /// containing groups.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the subcommand has more than one level of subcommands.
+    pub const fn assert_subcommand(nesting: u8) {
+        assert!(
+            nesting <= 1,
+            "subcommand groups can only contain subcommands"
+        );
+    }
+
+    /// Levels of subcommands below a command from the levels below each of
+    /// its subcommands.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a subcommand is a subcommand group containing groups.
+    pub const fn nesting(subcommands: &[u8]) -> u8 {
+        let mut nesting = 1;
+        let mut index = 0;
+
+        while index < subcommands.len() {
+            assert_subcommand(subcommands[index]);
+
+            if subcommands[index] == 1 {
+                nesting = 2;
+            }
+
+            index += 1;
+        }
+
+        nesting
+    }
+
+    /// Create the definition of a subcommand from a command, or a subcommand
+    /// group if the command's options are subcommands.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the command's options contain subcommand groups.
+    pub fn subcommand(name: &str, command: Command) -> CommandOptionDefinition {
+        assert!(
+            !command
+                .options
+                .iter()
+                .any(|option| matches!(option, CommandOptionDefinition::SubCommandGroup(_))),
+            "subcommand groups can only contain subcommands"
+        );
+
+        let is_group = !command.options.is_empty()
+            && command
+                .options
+                .iter()
+                .all(|option| matches!(option, CommandOptionDefinition::SubCommand(_)));
+        // Localizations of the name only apply if the subcommand isn't renamed.
+        let name_localizations = if command.name == name {
+            command.name_localizations
+        } else {
+            None
+        };
+        let data = OptionsCommandOptionData {
+            description: command.description,
+            description_localizations: command.description_localizations,
+            name: name.to_owned(),
+            name_localizations,
+            options: command.options,
+        };
+
+        if is_group {
+            CommandOptionDefinition::SubCommandGroup(data)
+        } else {
+            CommandOptionDefinition::SubCommand(data)
+        }
+    }
+
+    /// Parse the value of an option.
+    pub fn parse<T: CommandOption>(name: &str, value: CommandOptionValue) -> Result<T, ParseError> {
+        T::from_option(value).ok_or_else(|| {
+            ParseError::new(ParseErrorType::InvalidValue {
+                name: name.to_owned(),
+            })
+        })
+    }
+
+    /// Return the parsed value of an option, or its value if missing.
+    pub fn finish<T: CommandOption>(name: &str, value: Option<T>) -> Result<T, ParseError> {
+        value.or_else(T::from_missing).ok_or_else(|| {
+            ParseError::new(ParseErrorType::Missing {
+                name: name.to_owned(),
+            })
+        })
+    }
+
+    /// Take the only option, which must be a subcommand or subcommand group,
+    /// returning its name and options.
+    pub fn subcommand_options(
+        options: Vec<CommandDataOption>,
+    ) -> Result<(String, Vec<CommandDataOption>), ParseError> {
+        let option = options
+            .into_iter()
+            .next()
+            .ok_or_else(|| ParseError::new(ParseErrorType::SubcommandMissing))?;
+
+        match option.value {
+            CommandOptionValue::SubCommand(options)
+            | CommandOptionValue::SubCommandGroup(options) => Ok((option.name, options)),
+            _ => Err(ParseError::new(ParseErrorType::InvalidValue {
+                name: option.name,
+            })),
+        }
+    }
+
+    /// Error of an option that isn't part of the model.
+    pub const fn unknown(name: String) -> ParseError {
+        ParseError::new(ParseErrorType::UnknownOption { name })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandOption, ParseError, ParseErrorType};
+    use static_assertions::{assert_impl_all, const_assert};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::application::{
+        command::CommandOptionType, interaction::application_command::CommandOptionValue,
+    };
+
+    assert_impl_all!(ParseError: Error, Send, Sync);
+    assert_impl_all!(ParseErrorType: Debug, Send, Sync);
+    const_assert!(bool::REQUIRED);
+    const_assert!(!Option::<bool>::REQUIRED);
+
+    #[test]
+    fn test_optional() {
+        assert_eq!(CommandOptionType::Boolean, Option::<bool>::KIND);
+        assert_eq!(None, bool::from_missing());
+        assert_eq!(Some(None), Option::<bool>::from_missing());
+        assert_eq!(
+            Some(Some(true)),
+            Option::<bool>::from_option(CommandOptionValue::Boolean(true))
+        );
+        assert_eq!(None, i64::from_option(CommandOptionValue::Boolean(true)));
+    }
+}
@@ -11,6 +11,11 @@
 //!
 //! Provides builders for large structs.
 //!
+//! ### `command`
+//!
+//! Allows defining commands and parsing their options with Rust types by
+//! deriving traits.
+//!
 //! ### `link`
 //!
 //! Provides implementations for parsing and formatting entities' URLs, such as
@@ -56,6 +61,9 @@
 #[cfg(feature = "builder")]
 pub mod builder;
 
+#[cfg(feature = "command")]
+pub mod command;
+
 #[cfg(feature = "link")]
 pub mod link;
 
@@ -75,6 +75,11 @@ pub enum WebhookParseErrorType {
 /// Parse the webhook ID and token from a webhook URL, if it exists in the
 /// string.
 ///
+/// URLs on the `discord.com` and legacy `discordapp.com` domains and their
+/// subdomains, such as `canary.discord.com`, are supported, with or without an
+/// API version such as `/api/v10/webhooks/`. Query parameters, such as a
+/// thread ID, are ignored.
+///
 /// # Examples
 ///
 /// Parse a webhook URL with a token:
@@ -120,13 +125,29 @@ pub enum WebhookParseErrorType {
 /// segment of the URL or the segment containing the webhook ID.
 pub fn parse(url: &str) -> Result<(Id<WebhookMarker>, Option<&str>), WebhookParseError> {
     let mut segments = {
-        let mut start = url.split("discord.com/api/webhooks/");
-        let path = start.nth(1).ok_or(WebhookParseError {
-            kind: WebhookParseErrorType::SegmentMissing,
-            source: None,
-        })?;
+        let (_, path) = url
+            .split_once("discord.com/api/")
+            .or_else(|| url.split_once("discordapp.com/api/"))
+            .ok_or(WebhookParseError {
+                kind: WebhookParseErrorType::SegmentMissing,
+                source: None,
+            })?;
 
-        path.split('/')
+        // Query parameters, such as a thread ID, aren't part of the path.
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+
+        // The API version segment, such as "v10", is optional.
+        let path = path
+            .split_once('/')
+            .filter(|(version, _)| is_version(version))
+            .map_or(path, |(_, path)| path);
+
+        path.strip_prefix("webhooks/")
+            .ok_or(WebhookParseError {
+                kind: WebhookParseErrorType::SegmentMissing,
+                source: None,
+            })?
+            .split('/')
     };
 
     let id_segment = segments.next().ok_or(WebhookParseError {
@@ -158,6 +179,13 @@ pub fn parse(url: &str) -> Result<(Id<WebhookMarker>, Option<&str>), WebhookPars
     Ok((Id::from(id), token))
 }
 
+/// Whether a path segment is an API version, such as "v10".
+fn is_version(segment: &str) -> bool {
+    segment.strip_prefix('v').map_or(false, |number| {
+        !number.is_empty() && number.bytes().all(|byte| byte.is_ascii_digit())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::{WebhookParseError, WebhookParseErrorType};
@@ -211,6 +239,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_url_forms() {
+        for url in [
+            "https://discordapp.com/api/webhooks/456/token",
+            "https://canary.discord.com/api/webhooks/456/token",
+            "https://ptb.discord.com/api/webhooks/456/token",
+            "https://ptb.discordapp.com/api/webhooks/456/token",
+            "https://discord.com/api/v10/webhooks/456/token",
+            "https://canary.discordapp.com/api/v9/webhooks/456/token",
+            "https://discord.com/api/webhooks/456/token?thread_id=789",
+            "https://discord.com/api/v10/webhooks/456/token?wait=true#fragment",
+        ] {
+            assert_eq!(
+                (Id::new(456), Some("token")),
+                super::parse(url).unwrap(),
+                "{}",
+                url,
+            );
+        }
+
+        assert_eq!(
+            (Id::new(456), None),
+            super::parse("https://discord.com/api/v10/webhooks/456?thread_id=789").unwrap(),
+        );
+    }
+
     #[test]
     fn test_parse_invalid() {
         // Base URL is improper.
@@ -234,5 +288,19 @@ mod tests {
                 .kind(),
             &WebhookParseErrorType::IdInvalid { .. },
         ));
+        // Version segment isn't followed by the webhooks segment.
+        assert!(matches!(
+            super::parse("https://discord.com/api/v10/channels/456")
+                .unwrap_err()
+                .kind(),
+            &WebhookParseErrorType::SegmentMissing,
+        ));
+        // Version segment isn't a version.
+        assert!(matches!(
+            super::parse("https://discord.com/api/vx/webhooks/456")
+                .unwrap_err()
+                .kind(),
+            &WebhookParseErrorType::SegmentMissing,
+        ));
     }
 }
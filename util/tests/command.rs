@@ -0,0 +1,382 @@
+use twilight_model::{
+    application::{
+        command::{
+            ChannelCommandOptionData, ChoiceCommandOptionData, CommandOption as Definition,
+            CommandOptionChoice, CommandOptionValue as Limit, Number, NumberCommandOptionData,
+            OptionsCommandOptionData,
+        },
+        interaction::application_command::{CommandDataOption, CommandOptionValue},
+    },
+    channel::ChannelType,
+    id::{marker::ChannelMarker, Id},
+};
+use twilight_util::command::{
+    CommandModel, CommandOption, CreateCommand, ParseError, ParseErrorType,
+};
+
+/// Manage the bot's settings.
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "settings", default_permission = false)]
+enum Settings {
+    Color(SetColor),
+    #[command(rename = "logging")]
+    Log(Log),
+    Reset(Reset),
+}
+
+/// Color of the bot's
+/// embeds.
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "color")]
+struct SetColor {
+    /// New color.
+    color: Color,
+    /// Brightness of the color.
+    #[command(min_value = 0, max_value = 100)]
+    brightness: Option<i64>,
+    /// Search for a color.
+    #[command(rename = "search", autocomplete)]
+    query: Option<String>,
+}
+
+#[derive(CommandOption, Debug, PartialEq)]
+enum Color {
+    Red,
+    #[option(name = "Blue", value = "blue")]
+    Blue,
+}
+
+/// Manage logging.
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "log")]
+enum Log {
+    Channel(LogChannel),
+}
+
+/// Log to a channel.
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "channel")]
+struct LogChannel {
+    /// Channel to log to.
+    #[command(channel_types = "GuildText GuildNews")]
+    channel: Id<ChannelMarker>,
+    /// Ratio of events to log.
+    #[command(min_value = -1.5)]
+    ratio: Option<f64>,
+}
+
+/// Reset the settings.
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "reset")]
+struct Reset;
+
+/// Roll a die.
+#[derive(CommandModel, CreateCommand, Debug, PartialEq)]
+#[command(name = "roll")]
+struct Roll {
+    /// Sides of the die.
+    sides: Sides,
+    /// Odds of rolling twice.
+    twice: Option<Odds>,
+    /// Multiplier of the roll.
+    #[command(min_value = 1, max_value = 2.5)]
+    multiplier: Option<f64>,
+}
+
+#[derive(CommandOption, Debug, PartialEq)]
+enum Sides {
+    #[option(value = 6)]
+    Six,
+    #[option(name = "Twenty", value = 20)]
+    Twenty,
+}
+
+#[derive(CommandOption, Debug, PartialEq)]
+enum Odds {
+    #[option(value = 0.5)]
+    Half,
+    #[option(value = -1.0)]
+    Never,
+}
+
+fn option(name: &str, value: CommandOptionValue) -> CommandDataOption {
+    CommandDataOption {
+        focused: false,
+        name: name.to_owned(),
+        value,
+    }
+}
+
+#[test]
+fn test_create_command() {
+    let command = Settings::create_command();
+    assert_eq!("settings", Settings::NAME);
+    assert_eq!("settings", command.name);
+    assert_eq!("Manage the bot's settings.", command.description);
+    assert_eq!(Some(false), command.default_permission);
+
+    assert_eq!(
+        vec![
+            Definition::SubCommand(OptionsCommandOptionData {
+                description: "Color of the bot's embeds.".to_owned(),
+                description_localizations: None,
+                name: "color".to_owned(),
+                name_localizations: None,
+                options: vec![
+                    Definition::String(ChoiceCommandOptionData {
+                        autocomplete: false,
+                        choices: vec![
+                            CommandOptionChoice::String {
+                                name: "Red".to_owned(),
+                                name_localizations: None,
+                                value: "Red".to_owned(),
+                            },
+                            CommandOptionChoice::String {
+                                name: "Blue".to_owned(),
+                                name_localizations: None,
+                                value: "blue".to_owned(),
+                            },
+                        ],
+                        description: "New color.".to_owned(),
+                        description_localizations: None,
+                        name: "color".to_owned(),
+                        name_localizations: None,
+                        required: true,
+                    }),
+                    Definition::Integer(NumberCommandOptionData {
+                        autocomplete: false,
+                        choices: Vec::new(),
+                        description: "Brightness of the color.".to_owned(),
+                        description_localizations: None,
+                        max_value: Some(Limit::Integer(100)),
+                        min_value: Some(Limit::Integer(0)),
+                        name: "brightness".to_owned(),
+                        name_localizations: None,
+                        required: false,
+                    }),
+                    Definition::String(ChoiceCommandOptionData {
+                        autocomplete: true,
+                        choices: Vec::new(),
+                        description: "Search for a color.".to_owned(),
+                        description_localizations: None,
+                        name: "search".to_owned(),
+                        name_localizations: None,
+                        required: false,
+                    }),
+                ],
+            }),
+            Definition::SubCommandGroup(OptionsCommandOptionData {
+                description: "Manage logging.".to_owned(),
+                description_localizations: None,
+                name: "logging".to_owned(),
+                name_localizations: None,
+                options: vec![Definition::SubCommand(OptionsCommandOptionData {
+                    description: "Log to a channel.".to_owned(),
+                    description_localizations: None,
+                    name: "channel".to_owned(),
+                    name_localizations: None,
+                    options: vec![
+                        Definition::Channel(ChannelCommandOptionData {
+                            channel_types: vec![ChannelType::GuildText, ChannelType::GuildNews],
+                            description: "Channel to log to.".to_owned(),
+                            description_localizations: None,
+                            name: "channel".to_owned(),
+                            name_localizations: None,
+                            required: true,
+                        }),
+                        Definition::Number(NumberCommandOptionData {
+                            autocomplete: false,
+                            choices: Vec::new(),
+                            description: "Ratio of events to log.".to_owned(),
+                            description_localizations: None,
+                            max_value: None,
+                            min_value: Some(Limit::Number(Number(-1.5))),
+                            name: "ratio".to_owned(),
+                            name_localizations: None,
+                            required: false,
+                        }),
+                    ],
+                })],
+            }),
+            Definition::SubCommand(OptionsCommandOptionData {
+                description: "Reset the settings.".to_owned(),
+                description_localizations: None,
+                name: "reset".to_owned(),
+                name_localizations: None,
+                options: Vec::new(),
+            }),
+        ],
+        command.options
+    );
+}
+
+#[test]
+fn test_create_command_choices() {
+    let command = Roll::create_command();
+
+    assert_eq!(
+        vec![
+            Definition::Integer(NumberCommandOptionData {
+                autocomplete: false,
+                choices: vec![
+                    CommandOptionChoice::Int {
+                        name: "Six".to_owned(),
+                        name_localizations: None,
+                        value: 6,
+                    },
+                    CommandOptionChoice::Int {
+                        name: "Twenty".to_owned(),
+                        name_localizations: None,
+                        value: 20,
+                    },
+                ],
+                description: "Sides of the die.".to_owned(),
+                description_localizations: None,
+                max_value: None,
+                min_value: None,
+                name: "sides".to_owned(),
+                name_localizations: None,
+                required: true,
+            }),
+            Definition::Number(NumberCommandOptionData {
+                autocomplete: false,
+                choices: vec![
+                    CommandOptionChoice::Number {
+                        name: "Half".to_owned(),
+                        name_localizations: None,
+                        value: Number(0.5),
+                    },
+                    CommandOptionChoice::Number {
+                        name: "Never".to_owned(),
+                        name_localizations: None,
+                        value: Number(-1.0),
+                    },
+                ],
+                description: "Odds of rolling twice.".to_owned(),
+                description_localizations: None,
+                max_value: None,
+                min_value: None,
+                name: "twice".to_owned(),
+                name_localizations: None,
+                required: false,
+            }),
+            Definition::Number(NumberCommandOptionData {
+                autocomplete: false,
+                choices: Vec::new(),
+                description: "Multiplier of the roll.".to_owned(),
+                description_localizations: None,
+                max_value: Some(Limit::Number(Number(2.5))),
+                min_value: Some(Limit::Number(Number(1.0))),
+                name: "multiplier".to_owned(),
+                name_localizations: None,
+                required: false,
+            }),
+        ],
+        command.options
+    );
+}
+
+#[test]
+fn test_parse_choices() -> Result<(), ParseError> {
+    let options = vec![
+        option("sides", CommandOptionValue::Integer(20)),
+        option("twice", CommandOptionValue::Number(Number(-1.0))),
+    ];
+
+    assert_eq!(
+        Roll {
+            sides: Sides::Twenty,
+            twice: Some(Odds::Never),
+            multiplier: None,
+        },
+        Roll::from_options(options)?
+    );
+
+    let error =
+        Roll::from_options(vec![option("sides", CommandOptionValue::Integer(7))]).unwrap_err();
+    assert!(matches!(error.kind(), ParseErrorType::InvalidValue { name } if name == "sides"));
+
+    let error = Roll::from_options(vec![
+        option("sides", CommandOptionValue::Integer(6)),
+        option("twice", CommandOptionValue::Number(Number(0.25))),
+    ])
+    .unwrap_err();
+    assert!(matches!(error.kind(), ParseErrorType::InvalidValue { name } if name == "twice"));
+
+    Ok(())
+}
+
+#[test]
+fn test_parse() -> Result<(), ParseError> {
+    let options = vec![option(
+        "color",
+        CommandOptionValue::SubCommand(vec![
+            option("color", CommandOptionValue::String("blue".to_owned())),
+            option("brightness", CommandOptionValue::Integer(50)),
+        ]),
+    )];
+
+    assert_eq!(
+        Settings::Color(SetColor {
+            color: Color::Blue,
+            brightness: Some(50),
+            query: None,
+        }),
+        Settings::from_options(options)?
+    );
+
+    let options = vec![option(
+        "logging",
+        CommandOptionValue::SubCommandGroup(vec![option(
+            "channel",
+            CommandOptionValue::SubCommand(vec![option(
+                "channel",
+                CommandOptionValue::Channel(Id::new(1)),
+            )]),
+        )]),
+    )];
+
+    assert_eq!(
+        Settings::Log(Log::Channel(LogChannel {
+            channel: Id::new(1),
+            ratio: None,
+        })),
+        Settings::from_options(options)?
+    );
+
+    let options = vec![option("reset", CommandOptionValue::SubCommand(Vec::new()))];
+    assert_eq!(Settings::Reset(Reset), Settings::from_options(options)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = SetColor::from_options(Vec::new()).unwrap_err();
+    assert!(matches!(error.kind(), ParseErrorType::Missing { name } if name == "color"));
+
+    let error = SetColor::from_options(vec![option(
+        "color",
+        CommandOptionValue::String("green".to_owned()),
+    )])
+    .unwrap_err();
+    assert!(matches!(error.kind(), ParseErrorType::InvalidValue { name } if name == "color"));
+
+    let error = SetColor::from_options(vec![option("other", CommandOptionValue::Boolean(true))])
+        .unwrap_err();
+    assert!(matches!(error.kind(), ParseErrorType::UnknownOption { name } if name == "other"));
+
+    let error =
+        Reset::from_options(vec![option("other", CommandOptionValue::Boolean(true))]).unwrap_err();
+    assert!(matches!(error.kind(), ParseErrorType::UnknownOption { name } if name == "other"));
+
+    let error = Settings::from_options(Vec::new()).unwrap_err();
+    assert!(matches!(error.kind(), ParseErrorType::SubcommandMissing));
+
+    let error = Settings::from_options(vec![option(
+        "other",
+        CommandOptionValue::SubCommand(Vec::new()),
+    )])
+    .unwrap_err();
+    assert!(matches!(error.kind(), ParseErrorType::UnknownOption { name } if name == "other"));
+}
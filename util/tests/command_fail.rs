@@ -0,0 +1,4 @@
+#[test]
+fn test_command_fail() {
+    trybuild::TestCases::new().compile_fail("tests/command_fail/*.rs");
+}
@@ -0,0 +1,12 @@
+use twilight_util::command::{CommandModel, CreateCommand};
+
+/// Roll a die.
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "roll")]
+struct Roll {
+    /// Sides of the die.
+    #[command(max_value = 20.5)]
+    sides: i64,
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use twilight_util::command::{CommandModel, CreateCommand};
+
+/// Manage the bot's settings.
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "settings")]
+enum Settings {
+    Log(Log),
+}
+
+/// Manage logging.
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "log")]
+enum Log {
+    Channel(LogChannel),
+}
+
+/// Manage the log channel.
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "channel")]
+enum LogChannel {
+    Set(SetLogChannel),
+}
+
+/// Set the log channel.
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "set")]
+struct SetLogChannel;
+
+fn main() {}
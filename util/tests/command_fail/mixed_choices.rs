@@ -0,0 +1,11 @@
+use twilight_util::command::CommandOption;
+
+#[derive(CommandOption)]
+enum Sides {
+    #[option(value = 6)]
+    Six,
+    #[option(value = "twenty")]
+    Twenty,
+}
+
+fn main() {}
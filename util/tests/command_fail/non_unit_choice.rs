@@ -0,0 +1,9 @@
+use twilight_util::command::CommandOption;
+
+#[derive(CommandOption)]
+enum Color {
+    Red,
+    Custom(String),
+}
+
+fn main() {}
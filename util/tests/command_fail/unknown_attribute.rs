@@ -0,0 +1,8 @@
+use twilight_util::command::{CommandModel, CreateCommand};
+
+/// Ping the bot.
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "ping", description = "Ping the bot.")]
+struct Ping;
+
+fn main() {}
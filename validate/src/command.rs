@@ -209,13 +209,16 @@ pub enum CommandValidationErrorType {
 
 /// Validate a [`Command`].
 ///
+/// Localizations of the description and name are validated in the same way as
+/// the description and name themselves.
+///
 /// # Errors
 ///
-/// Returns an error of type [`DescriptionInvalid`] if the description is
-/// invalid.
+/// Returns an error of type [`DescriptionInvalid`] if the description or one
+/// of its localizations is invalid.
 ///
 /// Returns an error of type [`NameLengthInvalid`] or [`NameCharacterInvalid`]
-/// if the name is invalid.
+/// if the name or one of its localizations is invalid.
 ///
 /// [`DescriptionInvalid`]: CommandValidationErrorType::DescriptionInvalid
 /// [`NameLengthInvalid`]: CommandValidationErrorType::NameLengthInvalid
@@ -223,17 +226,33 @@ pub enum CommandValidationErrorType {
 pub fn command(value: &Command) -> Result<(), CommandValidationError> {
     let Command {
         description,
+        description_localizations,
         name,
+        name_localizations,
         kind,
         ..
     } = value;
 
     self::description(description)?;
 
-    match kind {
-        CommandType::ChatInput => self::chat_input_name(name),
-        CommandType::User | CommandType::Message => self::name(name),
+    if let Some(description_localizations) = description_localizations {
+        description_localizations
+            .values()
+            .try_for_each(self::description)?;
+    }
+
+    let name_validator = match kind {
+        CommandType::ChatInput => self::chat_input_name,
+        CommandType::User | CommandType::Message => self::name,
+    };
+
+    name_validator(name)?;
+
+    if let Some(name_localizations) = name_localizations {
+        name_localizations.values().try_for_each(name_validator)?;
     }
+
+    Ok(())
 }
 
 /// Validate the description of a [`Command`].
@@ -381,41 +400,93 @@ fn name_characters(value: impl AsRef<str>) -> Result<(), CommandValidationError>
 
 /// Validate a single [`CommandOption`].
 ///
+/// Localizations of the description and name are validated in the same way as
+/// the description and name themselves.
+///
 /// # Errors
 ///
-/// Returns an error of type [`OptionDescriptionInvalid`] if the description is
-/// invalid.
+/// Returns an error of type [`OptionDescriptionInvalid`] if the description or
+/// one of its localizations is invalid.
 ///
 /// Returns an error of type [`OptionNameLengthInvalid`] or [`OptionNameCharacterInvalid`]
-/// if the name is invalid.
+/// if the name or one of its localizations is invalid.
 ///
 /// [`OptionDescriptionInvalid`]: CommandValidationErrorType::OptionDescriptionInvalid
 /// [`OptionNameLengthInvalid`]: CommandValidationErrorType::OptionNameLengthInvalid
 /// [`OptionNameCharacterInvalid`]: CommandValidationErrorType::OptionNameCharacterInvalid
 pub fn option(option: &CommandOption) -> Result<(), CommandValidationError> {
-    let (description, name) = match option {
+    let (description, description_localizations, name, name_localizations) = match option {
         CommandOption::SubCommand(_) | CommandOption::SubCommandGroup(_) => return Ok(()),
-        CommandOption::String(data) => (&data.description, &data.name),
-        CommandOption::Integer(data) | CommandOption::Number(data) => {
-            (&data.description, &data.name)
-        }
-        CommandOption::Channel(data) => (&data.description, &data.name),
+        CommandOption::String(data) => (
+            &data.description,
+            &data.description_localizations,
+            &data.name,
+            &data.name_localizations,
+        ),
+        CommandOption::Integer(data) | CommandOption::Number(data) => (
+            &data.description,
+            &data.description_localizations,
+            &data.name,
+            &data.name_localizations,
+        ),
+        CommandOption::Channel(data) => (
+            &data.description,
+            &data.description_localizations,
+            &data.name,
+            &data.name_localizations,
+        ),
         CommandOption::Boolean(data)
         | CommandOption::User(data)
         | CommandOption::Role(data)
-        | CommandOption::Mentionable(data) => (&data.description, &data.name),
+        | CommandOption::Mentionable(data) => (
+            &data.description,
+            &data.description_localizations,
+            &data.name,
+            &data.name_localizations,
+        ),
     };
 
-    let description_len = description.chars().count();
-    if description_len > OPTION_DESCRIPTION_LENGTH_MAX
-        && description_len < OPTION_DESCRIPTION_LENGTH_MIN
-    {
-        return Err(CommandValidationError {
-            kind: CommandValidationErrorType::OptionDescriptionInvalid,
-        });
+    self::option_description(description)?;
+
+    if let Some(description_localizations) = description_localizations {
+        description_localizations
+            .values()
+            .try_for_each(self::option_description)?;
+    }
+
+    self::option_name(name)?;
+
+    if let Some(name_localizations) = name_localizations {
+        name_localizations
+            .values()
+            .try_for_each(self::option_name)?;
     }
 
-    self::option_name(name)
+    Ok(())
+}
+
+/// Validate the description of a [`CommandOption`].
+///
+/// The length of the description must be more than
+/// [`OPTION_DESCRIPTION_LENGTH_MIN`] and less than or equal to
+/// [`OPTION_DESCRIPTION_LENGTH_MAX`].
+///
+/// # Errors
+///
+/// Returns an error of type [`OptionDescriptionInvalid`] if the description is
+/// invalid.
+///
+/// [`OptionDescriptionInvalid`]: CommandValidationErrorType::OptionDescriptionInvalid
+pub fn option_description(value: impl AsRef<str>) -> Result<(), CommandValidationError> {
+    let len = value.as_ref().chars().count();
+
+    if (OPTION_DESCRIPTION_LENGTH_MIN..=OPTION_DESCRIPTION_LENGTH_MAX).contains(&len) {
+        Ok(())
+    } else {
+        Err(CommandValidationError {
+            kind: CommandValidationErrorType::OptionDescriptionInvalid,
+        })
+    }
 }
 
 /// Validate a list of command options for count, order, and internal validity.
@@ -489,19 +560,30 @@ mod tests {
     #![allow(clippy::non_ascii_literal)]
 
     use super::*;
-    use twilight_model::{application::command::CommandType, id::Id};
+    use std::collections::BTreeMap;
+    use twilight_model::{
+        application::command::{BaseCommandOptionData, CommandType},
+        id::Id,
+    };
 
     // This tests [`description`] and [`name`] by proxy.
     #[test]
     fn test_command() {
         let valid_command = Command {
             application_id: Some(Id::new(1)),
+            default_member_permissions: None,
             default_permission: None,
             description: "a".repeat(100),
+            description_localizations: Some(BTreeMap::from([(
+                "en-US".to_owned(),
+                "e".repeat(100),
+            )])),
+            dm_permission: None,
             guild_id: Some(Id::new(2)),
             id: Some(Id::new(3)),
             kind: CommandType::ChatInput,
             name: "b".repeat(32),
+            name_localizations: Some(BTreeMap::from([("en-US".to_owned(), "f".repeat(32))])),
             options: Vec::new(),
             version: Id::new(4),
         };
@@ -511,10 +593,65 @@ mod tests {
         let invalid_command = Command {
             description: "c".repeat(101),
             name: "d".repeat(33),
-            ..valid_command
+            ..valid_command.clone()
         };
 
         assert!(command(&invalid_command).is_err());
+
+        let invalid_localization = Command {
+            name_localizations: Some(BTreeMap::from([("de".to_owned(), "Hallo".to_owned())])),
+            ..valid_command.clone()
+        };
+
+        assert!(matches!(
+            command(&invalid_localization).unwrap_err().kind(),
+            CommandValidationErrorType::NameCharacterInvalid { character: 'H' }
+        ));
+
+        let invalid_localization = Command {
+            description_localizations: Some(BTreeMap::from([("de".to_owned(), String::new())])),
+            ..valid_command
+        };
+
+        assert!(matches!(
+            command(&invalid_localization).unwrap_err().kind(),
+            CommandValidationErrorType::DescriptionInvalid
+        ));
+    }
+
+    #[test]
+    fn test_option() {
+        let valid_option = BaseCommandOptionData {
+            description: "a".repeat(100),
+            description_localizations: Some(BTreeMap::from([(
+                "en-US".to_owned(),
+                "b".repeat(100),
+            )])),
+            name: "c".repeat(32),
+            name_localizations: Some(BTreeMap::from([("en-US".to_owned(), "d".repeat(32))])),
+            required: false,
+        };
+
+        assert!(option(&CommandOption::Boolean(valid_option.clone())).is_ok());
+
+        let invalid_description = BaseCommandOptionData {
+            description: "e".repeat(101),
+            ..valid_option.clone()
+        };
+
+        assert!(matches!(
+            option(&CommandOption::Boolean(invalid_description))
+                .unwrap_err()
+                .kind(),
+            CommandValidationErrorType::OptionDescriptionInvalid
+        ));
+
+        let invalid_localization = BaseCommandOptionData {
+            name_localizations: Some(BTreeMap::from([("de".to_owned(), "f".repeat(33))])),
+            ..valid_option
+        };
+
+        assert!(option(&CommandOption::Boolean(invalid_localization)).is_err());
     }
 
     #[test]